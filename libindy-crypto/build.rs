@@ -5,6 +5,10 @@ use std::path::Path;
 fn main() {
 	let target = env::var("TARGET").unwrap();
 	println!("target={}", target);
+	if env::var("CARGO_FEATURE_BN_OPENSSL").is_err() {
+		// prebuilt OpenSSL is not needed for pure-Rust big number backend
+		return;
+	}
 	match target.find("-windows-") {
 		Some(..) => {
 			// do not build c-code on windows, use binaries
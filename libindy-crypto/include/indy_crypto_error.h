@@ -51,6 +51,10 @@ typedef enum
 
     // IO Error
     CommonIOError = 114,
+
+    // Group element (curve point) passed by library caller doesn't belong to the curve,
+    // to the prime-order subgroup or is unexpected infinity
+    CommonInvalidGroupElement = 119,
 } indy_crypto_error_t;
 
 #endif
@@ -0,0 +1,124 @@
+//! BLS signatures over BLS12-381 curve.
+//!
+//! Ver keys are G2 points and signatures are G1 points (IETF minimal-signature-size variant)
+//! encoded with ZCash encoding: 96 bytes compressed ver key and 48 bytes compressed signature.
+//! Methods without ciphersuite argument use `Ciphersuite::Legacy` as everywhere in `bls`;
+//! pass `Ciphersuite::Basic` (`BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_`) to interoperate with other IETF implementations.
+//!
+//! Available with `pair_bls12_381` feature independently of the default pairing backend.
+
+use bls::curve;
+use pair::bls12_381::Bls12381;
+
+pub type Generator = curve::Generator<Bls12381>;
+pub type SignKey = curve::SignKey<Bls12381>;
+pub type VerKey = curve::VerKey<Bls12381>;
+pub type Signature = curve::Signature<Bls12381>;
+pub type MultiSignature = curve::MultiSignature<Bls12381>;
+pub type MultiVerKey = curve::MultiVerKey<Bls12381>;
+pub type BitmapMultiSignature = curve::BitmapMultiSignature<Bls12381>;
+pub type AggregateSignature = curve::AggregateSignature<Bls12381>;
+pub type ProofOfPossession = curve::ProofOfPossession<Bls12381>;
+pub type StreamSigner = curve::StreamSigner<Bls12381>;
+pub type StreamVerifier = curve::StreamVerifier<Bls12381>;
+pub type Bls = curve::Bls<Bls12381>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bls::Ciphersuite;
+
+    fn _sign_key_one() -> SignKey {
+        let mut bytes = vec![0; 32];
+        bytes[31] = 1;
+        SignKey::from_bytes(&bytes).unwrap()
+    }
+
+    fn _to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02X}", b)).collect()
+    }
+
+    #[test]
+    fn generator_standard_works() {
+        // ZCash encoding of the standard G2 generator
+        let gen = Generator::standard().unwrap();
+        assert_eq!("93E02B6052719F607DACD3A088274F65596BD0D09920B61AB5DA61BBDC7F5049334CF11213945D57E5AC7D055D042B7E\
+                    024AA2B2F08F0A91260805272DC51051C6E47AD4FA403B02B4510B647AE3D1770BAC0326A805BBEFD48056C8C121BDB8",
+                   _to_hex(gen.as_compressed_bytes()));
+    }
+
+    #[test]
+    fn ver_key_new_works_for_sign_key_one() {
+        let gen = Generator::standard().unwrap();
+        let ver_key = VerKey::new(&gen, &_sign_key_one()).unwrap();
+        assert_eq!(gen.as_compressed_bytes(), ver_key.as_compressed_bytes());
+        assert_eq!(96, ver_key.as_compressed_bytes().len());
+    }
+
+    #[test]
+    fn sign_works_for_hash_to_curve_vector() {
+        // Signature of sign key 1 is the message hash, so it must match RFC 9380, appendix J.9.1 vector for "abc"
+        let ciphersuite = Ciphersuite::HashToCurve(b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_".to_vec());
+        let gen = Generator::standard().unwrap();
+        let sign_key = _sign_key_one();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+
+        let signature = Bls::sign_with_ciphersuite(b"abc", &sign_key, &ciphersuite).unwrap();
+        assert_eq!("83567BC5EF9C690C2AB2ECDF6A96EF1C139CC0B2F284DCA0A9A7943388A49A3AEE664BA5379A7655D3C68900BE2F6903",
+                   _to_hex(signature.as_compressed_bytes()));
+
+        let signature = Signature::from_compressed_bytes(signature.as_compressed_bytes()).unwrap();
+        assert!(Bls::verify_with_ciphersuite(&signature, b"abc", &ver_key, &gen, &ciphersuite).unwrap());
+        assert!(!Bls::verify(&signature, b"abc", &ver_key, &gen).unwrap());
+    }
+
+    #[test]
+    fn verify_works_for_published_vectors() {
+        // RFC 9380, appendix J.9.1: points are signatures of sign key 1 (ZCash uncompressed encoding of x, y),
+        // ver key of sign key 1 is ZCash compressed standard G2 generator
+        let ciphersuite = Ciphersuite::HashToCurve(b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_".to_vec());
+        let vectors: [(&[u8], &str); 2] = [
+            (b"", "052926ADD2207B76CA4FA57A8734416C8DC95E24501772C814278700EED6D1E4E8CF62D9C09DB0FAC349612B759E79A1\
+                   08BA738453BFED09CB546DBB0783DBB3A5F1F566ED67BB6BE0E8C67E2E81A4CC68EE29813BB7994998F3EAE0C9C6A265"),
+            (b"abc", "03567BC5EF9C690C2AB2ECDF6A96EF1C139CC0B2F284DCA0A9A7943388A49A3AEE664BA5379A7655D3C68900BE2F6903\
+                      0B9C15F3FE6E5CF4211F346271D7B01C8F3B28BE689C8429C85B67AF215533311F0B8DFAAA154FA6B88176C229F2885D")
+        ];
+
+        let gen = Generator::standard().unwrap();
+        let ver_key = VerKey::from_compressed_bytes(gen.as_compressed_bytes()).unwrap();
+
+        for &(message, signature_hex) in vectors.iter() {
+            let signature = Bls::sign_with_ciphersuite(message, &_sign_key_one(), &ciphersuite).unwrap();
+            assert_eq!(signature_hex, _to_hex(signature.as_bytes()));
+
+            let signature = Signature::from_bytes(signature.as_bytes()).unwrap();
+            let signature = Signature::from_compressed_bytes(signature.as_compressed_bytes()).unwrap();
+            assert!(Bls::verify_with_ciphersuite(&signature, message, &ver_key, &gen, &ciphersuite).unwrap());
+            assert!(!Bls::verify_with_ciphersuite(&signature, b"abcd", &ver_key, &gen, &ciphersuite).unwrap());
+        }
+    }
+
+    #[test]
+    fn signature_from_compressed_bytes_works_for_infinity() {
+        let mut bytes = vec![0; 48];
+        bytes[0] = 0xC0;
+        assert!(Signature::from_compressed_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn multi_signature_works() {
+        let gen = Generator::standard().unwrap();
+        let sign_key1 = SignKey::new(None).unwrap();
+        let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+        let sign_key2 = SignKey::new(None).unwrap();
+        let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+        let message = b"message";
+
+        let signature1 = Bls::sign(message, &sign_key1).unwrap();
+        let signature2 = Bls::sign(message, &sign_key2).unwrap();
+        let multi_sig = MultiSignature::new(&[&signature1, &signature2]).unwrap();
+        let multi_sig = MultiSignature::from_compressed_bytes(multi_sig.as_compressed_bytes()).unwrap();
+
+        assert!(Bls::verify_multi_sig(&multi_sig, message, &[&ver_key1, &ver_key2], &gen).unwrap());
+    }
+}
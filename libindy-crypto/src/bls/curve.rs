@@ -0,0 +1,1956 @@
+//! BLS signatures generic over pairing engine.
+//!
+//! Types of this module take pairing engine (see `pair::PairingEngine`) as type parameter, so signatures
+//! over different curves can be used in the same build independently of the default pairing backend.
+//! `bls` module re-exports them for the default engine and `bls::bls12_381` for BLS12-381.
+//!
+//! Domain separation tags of IETF ciphersuites are built from hash-to-curve suite of the engine,
+//! e.g. `BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_` for basic ciphersuite over BLS12-381.
+//! Points are encoded with engine encodings: for BLS12-381 it is ZCash encoding,
+//! so ver keys and signatures are compatible with other IETF BLS minimal-signature-size implementations.
+
+use bls::{Ciphersuite, DigestAlgorithm};
+use errors::IndyCryptoError;
+use pair::{PairingEngine, PairingPoint, PairingScalar, PairingTarget};
+
+use sha2::{Sha256, Sha512, Digest};
+
+use zeroize::Zeroize;
+
+use std::collections::HashSet;
+use std::marker::PhantomData;
+
+const POP_DOMAIN: &'static [u8] = b"INDY_CRYPTO_BLS_POP";
+
+/// BLS generator point.
+/// BLS algorithm requires choosing of generator point that must be known to all parties.
+/// The most of BLS methods require generator to be provided.
+#[derive(Debug)]
+pub struct Generator<E: PairingEngine> {
+    pub(crate) point: E::G2,
+    prepared: E::PreparedG2,
+    bytes: Vec<u8>,
+    compressed_bytes: Vec<u8>
+}
+
+impl<E: PairingEngine> Generator<E> {
+    /// Creates and returns random generator point that satisfy BLS algorithm requirements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::Generator;
+    /// Generator::new().unwrap();
+    /// ```
+    pub fn new() -> Result<Generator<E>, IndyCryptoError> {
+        Generator::_new(E::G2::new()?)
+    }
+
+    /// Returns standard generator point (base point of G2 group defined by curve parameters).
+    /// Unlike `Generator::new` it is the same for all parties, so it doesn't need to be distributed.
+    /// Ciphersuites other than `Ciphersuite::Legacy` expect ver keys created with this generator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::Generator;
+    /// let gen1 = Generator::standard().unwrap();
+    /// let gen2 = Generator::standard().unwrap();
+    /// assert_eq!(gen1.as_bytes(), gen2.as_bytes());
+    /// ```
+    pub fn standard() -> Result<Generator<E>, IndyCryptoError> {
+        Generator::_new(E::g2_base()?)
+    }
+
+    /// Returns BLS generator point bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let gen_bytes = gen.as_bytes();
+    /// assert!(gen_bytes.len() > 0);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Creates and returns generator point from bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::Generator;
+    /// let gen = Generator::new().unwrap();
+    /// let gen_bytes = gen.as_bytes();
+    /// Generator::from_bytes(gen_bytes).unwrap();
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Generator<E>, IndyCryptoError> {
+        Generator::_new(E::G2::from_bytes(bytes)?)
+    }
+
+    /// Returns generator point compressed bytes representation (x-coordinate with y sign flag).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::Generator;
+    /// let gen = Generator::new().unwrap();
+    /// let compressed_bytes = gen.as_compressed_bytes();
+    /// assert!(compressed_bytes.len() < gen.as_bytes().len());
+    /// ```
+    pub fn as_compressed_bytes(&self) -> &[u8] {
+        self.compressed_bytes.as_slice()
+    }
+
+    /// Creates and returns generator point from compressed bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::Generator;
+    /// let gen = Generator::new().unwrap();
+    /// Generator::from_compressed_bytes(gen.as_compressed_bytes()).unwrap();
+    /// ```
+    pub fn from_compressed_bytes(bytes: &[u8]) -> Result<Generator<E>, IndyCryptoError> {
+        Generator::_new(_point_from_compressed_bytes(bytes)?)
+    }
+
+    fn _new(point: E::G2) -> Result<Generator<E>, IndyCryptoError> {
+        Ok(Generator {
+            prepared: E::prepare_g2(&point)?,
+            bytes: point.to_bytes()?,
+            compressed_bytes: point.to_compressed_bytes()?,
+            point
+        })
+    }
+}
+
+/// BLS sign key.
+pub struct SignKey<E: PairingEngine> {
+    pub(crate) group_order_element: E::Scalar,
+    pub(crate) bytes: Vec<u8>
+}
+
+impl_secret_debug!(SignKey<E: PairingEngine> secret { group_order_element, bytes });
+
+impl<E: PairingEngine> SignKey<E> {
+    /// Creates and returns random (or seeded from seed) BLS sign key algorithm requirements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::SignKey;
+    /// SignKey::new(None).unwrap();
+    /// ```
+    pub fn new(seed: Option<&[u8]>) -> Result<SignKey<E>, IndyCryptoError> {
+        let group_order_element = match seed {
+            Some(seed) => E::Scalar::new_from_seed(seed)?,
+            _ => E::Scalar::new()?
+        };
+
+        Ok(SignKey {
+            bytes: group_order_element.to_bytes()?,
+            group_order_element
+        })
+    }
+
+    /// Returns BLS sign key bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //TODO: Provide an example!
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Creates and returns BLS sign key from bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //TODO: Provide an example!
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<SignKey<E>, IndyCryptoError> {
+        Ok(
+            SignKey {
+                group_order_element: E::Scalar::from_bytes(bytes)?,
+                bytes: bytes.to_vec()
+            }
+        )
+    }
+}
+
+impl<E: PairingEngine> Drop for SignKey<E> {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+/// BLS verification key.
+#[derive(Debug)]
+pub struct VerKey<E: PairingEngine> {
+    pub(crate) point: E::G2,
+    pub(crate) bytes: Vec<u8>,
+    pub(crate) compressed_bytes: Vec<u8>
+}
+
+impl<E: PairingEngine> VerKey<E> {
+    /// Creates and returns BLS ver key that corresponds to sign key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// VerKey::new(&gen, &sign_key).unwrap();
+    /// ```
+    pub fn new(gen: &Generator<E>, sign_key: &SignKey<E>) -> Result<VerKey<E>, IndyCryptoError> {
+        VerKey::_new(gen.point.mul(&sign_key.group_order_element)?)
+    }
+
+    /// Returns BLS verification key to bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //TODO: Provide an example!
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Creates and returns BLS verification key from bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //TODO: Provide an example!
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<VerKey<E>, IndyCryptoError> {
+        VerKey::_new(E::G2::from_bytes(bytes)?)
+    }
+
+    /// Returns BLS verification key compressed bytes representation (x-coordinate with y sign flag).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    /// let compressed_bytes = ver_key.as_compressed_bytes();
+    /// assert!(compressed_bytes.len() < ver_key.as_bytes().len());
+    /// ```
+    pub fn as_compressed_bytes(&self) -> &[u8] {
+        self.compressed_bytes.as_slice()
+    }
+
+    /// Creates and returns BLS verification key from compressed bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    /// VerKey::from_compressed_bytes(ver_key.as_compressed_bytes()).unwrap();
+    /// ```
+    pub fn from_compressed_bytes(bytes: &[u8]) -> Result<VerKey<E>, IndyCryptoError> {
+        VerKey::_new(_point_from_compressed_bytes(bytes)?)
+    }
+
+    fn _new(point: E::G2) -> Result<VerKey<E>, IndyCryptoError> {
+        Ok(VerKey {
+            bytes: point.to_bytes()?,
+            compressed_bytes: point.to_compressed_bytes()?,
+            point
+        })
+    }
+}
+
+/// BLS signature.
+#[derive(Debug)]
+pub struct Signature<E: PairingEngine> {
+    pub(crate) point: E::G1,
+    pub(crate) bytes: Vec<u8>,
+    pub(crate) compressed_bytes: Vec<u8>
+}
+
+impl<E: PairingEngine> Signature<E> {
+    /// Returns BLS signature to bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //TODO: Provide an example!
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Creates and returns BLS signature from bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //TODO: Provide an example!
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Signature<E>, IndyCryptoError> {
+        Signature::_new(E::G1::from_bytes(bytes)?)
+    }
+
+    /// Returns BLS signature compressed bytes representation (x-coordinate with y sign flag).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let signature = Bls::sign(&vec![1, 2, 3, 4, 5], &sign_key).unwrap();
+    /// let compressed_bytes = signature.as_compressed_bytes();
+    /// assert!(compressed_bytes.len() < signature.as_bytes().len());
+    /// ```
+    pub fn as_compressed_bytes(&self) -> &[u8] {
+        self.compressed_bytes.as_slice()
+    }
+
+    /// Creates and returns BLS signature from compressed bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let signature = Bls::sign(&vec![1, 2, 3, 4, 5], &sign_key).unwrap();
+    /// Signature::from_compressed_bytes(signature.as_compressed_bytes()).unwrap();
+    /// ```
+    pub fn from_compressed_bytes(bytes: &[u8]) -> Result<Signature<E>, IndyCryptoError> {
+        Signature::_new(_point_from_compressed_bytes(bytes)?)
+    }
+
+    fn _new(point: E::G1) -> Result<Signature<E>, IndyCryptoError> {
+        Ok(Signature {
+            bytes: point.to_bytes()?,
+            compressed_bytes: point.to_compressed_bytes()?,
+            point
+        })
+    }
+}
+
+/// BLS multi signature.
+#[derive(Debug)]
+pub struct MultiSignature<E: PairingEngine> {
+    point: E::G1,
+    bytes: Vec<u8>,
+    compressed_bytes: Vec<u8>
+}
+
+impl<E: PairingEngine> MultiSignature<E> {
+    /// Creates and returns multi signature for provided list of signatures.
+    ///
+    /// # Arguments
+    ///
+    /// * `signatures` - List of signatures
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let sign_key1 = SignKey::new(None).unwrap();
+    /// let sign_key2 = SignKey::new(None).unwrap();
+    ///
+    /// let message = vec![1, 2, 3, 4, 5];
+    ///
+    /// let signature1 = Bls::sign(&message, &sign_key1).unwrap();
+    /// let signature2 = Bls::sign(&message, &sign_key2).unwrap();
+    ///
+    /// let signatures = vec![
+    ///    &signature1,
+    ///    &signature2
+    /// ];
+    ///
+    /// MultiSignature::new(&signatures).unwrap();
+    /// ```
+    pub fn new(signatures: &[&Signature<E>]) -> Result<MultiSignature<E>, IndyCryptoError> {
+        let mut point = E::G1::new_inf()?;
+
+        for signature in signatures {
+            point = point.add(&signature.point)?;
+        }
+
+        MultiSignature::_new(point)
+    }
+
+    /// Returns BLS multi signature bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //TODO: Provide an example!
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Creates and returns BLS multi signature from bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //TODO: Provide an example!
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<MultiSignature<E>, IndyCryptoError> {
+        MultiSignature::_new(E::G1::from_bytes(bytes)?)
+    }
+
+    /// Returns BLS multi signature compressed bytes representation (x-coordinate with y sign flag).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let signature = Bls::sign(&vec![1, 2, 3, 4, 5], &sign_key).unwrap();
+    /// let multi_sig = MultiSignature::new(&[&signature]).unwrap();
+    /// let compressed_bytes = multi_sig.as_compressed_bytes();
+    /// assert!(compressed_bytes.len() < multi_sig.as_bytes().len());
+    /// ```
+    pub fn as_compressed_bytes(&self) -> &[u8] {
+        self.compressed_bytes.as_slice()
+    }
+
+    /// Creates and returns BLS multi signature from compressed bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let signature = Bls::sign(&vec![1, 2, 3, 4, 5], &sign_key).unwrap();
+    /// let multi_sig = MultiSignature::new(&[&signature]).unwrap();
+    /// MultiSignature::from_compressed_bytes(multi_sig.as_compressed_bytes()).unwrap();
+    /// ```
+    pub fn from_compressed_bytes(bytes: &[u8]) -> Result<MultiSignature<E>, IndyCryptoError> {
+        MultiSignature::_new(_point_from_compressed_bytes(bytes)?)
+    }
+
+    fn _new(point: E::G1) -> Result<MultiSignature<E>, IndyCryptoError> {
+        Ok(MultiSignature {
+            bytes: point.to_bytes()?,
+            compressed_bytes: point.to_compressed_bytes()?,
+            point
+        })
+    }
+}
+
+/// BLS multi verification key.
+/// Aggregates verification keys of the set of signers once, so multi signature over the same message
+/// can be verified against the whole set with two pairings.
+///
+/// Note: Verification keys must be accompanied with verified proofs of possession
+/// as aggregation is vulnerable to rogue key attacks otherwise.
+#[derive(Debug)]
+pub struct MultiVerKey<E: PairingEngine> {
+    point: E::G2,
+    ver_keys_count: u32,
+    bytes: Vec<u8>,
+    compressed_bytes: Vec<u8>
+}
+
+impl<E: PairingEngine> MultiVerKey<E> {
+    /// Creates and returns multi verification key for provided list of verification keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `ver_keys` - List of verification keys
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let ver_key1 = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+    /// let ver_key2 = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+    /// MultiVerKey::new(&[&ver_key1, &ver_key2]).unwrap();
+    /// ```
+    pub fn new(ver_keys: &[&VerKey<E>]) -> Result<MultiVerKey<E>, IndyCryptoError> {
+        if ver_keys.is_empty() {
+            return Err(IndyCryptoError::InvalidStructure(format!("Multi ver key requires at least one ver key")));
+        }
+
+        let mut point = E::G2::new_inf()?;
+
+        for ver_key in ver_keys {
+            point = point.add(&ver_key.point)?;
+        }
+
+        MultiVerKey::_new(point, ver_keys.len() as u32)
+    }
+
+    /// Adds verification key to the set.
+    ///
+    /// # Arguments
+    ///
+    /// * `ver_key` - Verification key to add
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let ver_key1 = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+    /// let ver_key2 = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+    /// let mut multi_ver_key = MultiVerKey::new(&[&ver_key1]).unwrap();
+    /// multi_ver_key.add(&ver_key2).unwrap();
+    /// assert_eq!(2, multi_ver_key.ver_keys_count());
+    /// ```
+    pub fn add(&mut self, ver_key: &VerKey<E>) -> Result<(), IndyCryptoError> {
+        *self = MultiVerKey::_new(self.point.add(&ver_key.point)?, self.ver_keys_count + 1)?;
+        Ok(())
+    }
+
+    /// Removes verification key from the set.
+    /// Caller is responsible for removing only keys that were added before.
+    ///
+    /// # Arguments
+    ///
+    /// * `ver_key` - Verification key to remove
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let ver_key1 = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+    /// let ver_key2 = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+    /// let mut multi_ver_key = MultiVerKey::new(&[&ver_key1, &ver_key2]).unwrap();
+    /// multi_ver_key.remove(&ver_key2).unwrap();
+    /// assert_eq!(1, multi_ver_key.ver_keys_count());
+    /// ```
+    pub fn remove(&mut self, ver_key: &VerKey<E>) -> Result<(), IndyCryptoError> {
+        if self.ver_keys_count <= 1 {
+            return Err(IndyCryptoError::InvalidStructure(format!("Multi ver key requires at least one ver key")));
+        }
+
+        *self = MultiVerKey::_new(self.point.sub(&ver_key.point)?, self.ver_keys_count - 1)?;
+        Ok(())
+    }
+
+    /// Returns number of verification keys in the set.
+    pub fn ver_keys_count(&self) -> u32 {
+        self.ver_keys_count
+    }
+
+    /// Returns BLS multi verification key bytes representation:
+    /// 4 bytes of big-endian ver keys count followed by aggregated point bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let ver_key = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+    /// let multi_ver_key = MultiVerKey::new(&[&ver_key]).unwrap();
+    /// assert_eq!(4 + ver_key.as_bytes().len(), multi_ver_key.as_bytes().len());
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Creates and returns BLS multi verification key from bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let ver_key = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+    /// let multi_ver_key = MultiVerKey::new(&[&ver_key]).unwrap();
+    /// MultiVerKey::from_bytes(multi_ver_key.as_bytes()).unwrap();
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<MultiVerKey<E>, IndyCryptoError> {
+        let (ver_keys_count, point_bytes) = MultiVerKey::<E>::_split_count(bytes)?;
+        MultiVerKey::_new(E::G2::from_bytes(point_bytes)?, ver_keys_count)
+    }
+
+    /// Returns BLS multi verification key compressed bytes representation:
+    /// 4 bytes of big-endian ver keys count followed by compressed aggregated point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let ver_key = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+    /// let multi_ver_key = MultiVerKey::new(&[&ver_key]).unwrap();
+    /// assert!(multi_ver_key.as_compressed_bytes().len() < multi_ver_key.as_bytes().len());
+    /// ```
+    pub fn as_compressed_bytes(&self) -> &[u8] {
+        self.compressed_bytes.as_slice()
+    }
+
+    /// Creates and returns BLS multi verification key from compressed bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let ver_key = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+    /// let multi_ver_key = MultiVerKey::new(&[&ver_key]).unwrap();
+    /// MultiVerKey::from_compressed_bytes(multi_ver_key.as_compressed_bytes()).unwrap();
+    /// ```
+    pub fn from_compressed_bytes(bytes: &[u8]) -> Result<MultiVerKey<E>, IndyCryptoError> {
+        let (ver_keys_count, point_bytes) = MultiVerKey::<E>::_split_count(bytes)?;
+        MultiVerKey::_new(_point_from_compressed_bytes(point_bytes)?, ver_keys_count)
+    }
+
+    fn _new(point: E::G2, ver_keys_count: u32) -> Result<MultiVerKey<E>, IndyCryptoError> {
+        let count_bytes = [(ver_keys_count >> 24) as u8, (ver_keys_count >> 16) as u8, (ver_keys_count >> 8) as u8, ver_keys_count as u8];
+
+        let mut bytes = count_bytes.to_vec();
+        bytes.extend_from_slice(&point.to_bytes()?);
+
+        let mut compressed_bytes = count_bytes.to_vec();
+        compressed_bytes.extend_from_slice(&point.to_compressed_bytes()?);
+
+        Ok(MultiVerKey {
+            point,
+            ver_keys_count,
+            bytes,
+            compressed_bytes
+        })
+    }
+
+    fn _split_count(bytes: &[u8]) -> Result<(u32, &[u8]), IndyCryptoError> {
+        if bytes.len() < 4 {
+            return Err(IndyCryptoError::InvalidStructure(format!("Invalid len of multi ver key bytes representation")));
+        }
+
+        let ver_keys_count = bytes[..4].iter().fold(0u32, |acc, b| (acc << 8) | *b as u32);
+
+        if ver_keys_count == 0 {
+            return Err(IndyCryptoError::InvalidStructure(format!("Multi ver key requires at least one ver key")));
+        }
+
+        Ok((ver_keys_count, &bytes[4..]))
+    }
+}
+
+/// BLS multi signature with participants bitmap.
+/// Bitmap is indexed against ordered set of validators, so it's enough to know the set
+/// to restore verification keys of signers.
+///
+/// Bytes representation is stable:
+/// 4 bytes of big-endian participants count,
+/// bitmap of `(participants_count + 7) / 8` bytes (bit of participant `i` is `bitmap[i / 8] >> (i % 8) & 1`),
+/// multi signature point bytes (compressed point for compressed representation).
+#[derive(Debug)]
+pub struct BitmapMultiSignature<E: PairingEngine> {
+    point: E::G1,
+    participants_count: u32,
+    bitmap: Vec<u8>,
+    bytes: Vec<u8>,
+    compressed_bytes: Vec<u8>
+}
+
+impl<E: PairingEngine> BitmapMultiSignature<E> {
+    /// Creates and returns multi signature for provided list of signatures of participants.
+    ///
+    /// # Arguments
+    ///
+    /// * `signatures` - List of signatures
+    /// * `indexes` - Indexes of signers in the validators set (in the same order as signatures)
+    /// * `participants_count` - Size of the validators set
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let message = vec![1, 2, 3, 4, 5];
+    /// let signature1 = Bls::sign(&message, &SignKey::new(None).unwrap()).unwrap();
+    /// let signature2 = Bls::sign(&message, &SignKey::new(None).unwrap()).unwrap();
+    ///
+    /// let multi_sig = BitmapMultiSignature::new(&[&signature1, &signature2], &[0, 2], 4).unwrap();
+    /// assert_eq!(vec![0, 2], multi_sig.participants());
+    /// ```
+    pub fn new(signatures: &[&Signature<E>], indexes: &[u32], participants_count: u32) -> Result<BitmapMultiSignature<E>, IndyCryptoError> {
+        if signatures.is_empty() || signatures.len() != indexes.len() {
+            return Err(IndyCryptoError::InvalidStructure(format!("Non empty lists of signatures and indexes of the same len are expected")));
+        }
+
+        let mut bitmap = vec![0u8; _bitmap_len(participants_count)];
+        let mut point = E::G1::new_inf()?;
+
+        for (signature, &index) in signatures.iter().zip(indexes) {
+            _set_participant(&mut bitmap, index, participants_count)?;
+            point = point.add(&signature.point)?;
+        }
+
+        BitmapMultiSignature::_new(point, participants_count, bitmap)
+    }
+
+    /// Adds signature of participant with provided index.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - Signature to add
+    /// * `index` - Index of signer in the validators set
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let message = vec![1, 2, 3, 4, 5];
+    /// let signature1 = Bls::sign(&message, &SignKey::new(None).unwrap()).unwrap();
+    /// let signature2 = Bls::sign(&message, &SignKey::new(None).unwrap()).unwrap();
+    ///
+    /// let mut multi_sig = BitmapMultiSignature::new(&[&signature1], &[0], 4).unwrap();
+    /// multi_sig.add(&signature2, 3).unwrap();
+    /// assert_eq!(vec![0, 3], multi_sig.participants());
+    /// ```
+    pub fn add(&mut self, signature: &Signature<E>, index: u32) -> Result<(), IndyCryptoError> {
+        let mut bitmap = self.bitmap.clone();
+        _set_participant(&mut bitmap, index, self.participants_count)?;
+
+        *self = BitmapMultiSignature::_new(self.point.add(&signature.point)?, self.participants_count, bitmap)?;
+        Ok(())
+    }
+
+    /// Merges partial multi signature created over the same validators set with disjoint participants.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Partial multi signature to merge
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let message = vec![1, 2, 3, 4, 5];
+    /// let signature1 = Bls::sign(&message, &SignKey::new(None).unwrap()).unwrap();
+    /// let signature2 = Bls::sign(&message, &SignKey::new(None).unwrap()).unwrap();
+    ///
+    /// let mut multi_sig = BitmapMultiSignature::new(&[&signature1], &[1], 4).unwrap();
+    /// let multi_sig2 = BitmapMultiSignature::new(&[&signature2], &[2], 4).unwrap();
+    /// multi_sig.merge(&multi_sig2).unwrap();
+    /// assert_eq!(vec![1, 2], multi_sig.participants());
+    /// ```
+    pub fn merge(&mut self, other: &BitmapMultiSignature<E>) -> Result<(), IndyCryptoError> {
+        if self.participants_count != other.participants_count {
+            return Err(IndyCryptoError::InvalidStructure(format!("Multi signatures created over different validators sets can't be merged")));
+        }
+
+        if self.bitmap.iter().zip(&other.bitmap).any(|(a, b)| a & b != 0) {
+            return Err(IndyCryptoError::InvalidStructure(format!("Multi signatures with intersecting participants can't be merged")));
+        }
+
+        let bitmap = self.bitmap.iter().zip(&other.bitmap).map(|(a, b)| a | b).collect();
+
+        *self = BitmapMultiSignature::_new(self.point.add(&other.point)?, self.participants_count, bitmap)?;
+        Ok(())
+    }
+
+    /// Returns indexes of participants in the validators set.
+    pub fn participants(&self) -> Vec<u32> {
+        (0..self.participants_count)
+            .filter(|&index| _is_participant(&self.bitmap, index))
+            .collect()
+    }
+
+    /// Returns size of the validators set.
+    pub fn participants_count(&self) -> u32 {
+        self.participants_count
+    }
+
+    /// Returns participants bitmap (bit of participant `i` is `bitmap[i / 8] >> (i % 8) & 1`).
+    pub fn as_bitmap(&self) -> &[u8] {
+        self.bitmap.as_slice()
+    }
+
+    /// Returns multi signature bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let signature = Bls::sign(&vec![1, 2, 3, 4, 5], &SignKey::new(None).unwrap()).unwrap();
+    /// let multi_sig = BitmapMultiSignature::new(&[&signature], &[0], 10).unwrap();
+    /// assert_eq!(4 + 2 + signature.as_bytes().len(), multi_sig.as_bytes().len());
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Creates and returns multi signature from bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let signature = Bls::sign(&vec![1, 2, 3, 4, 5], &SignKey::new(None).unwrap()).unwrap();
+    /// let multi_sig = BitmapMultiSignature::new(&[&signature], &[0], 10).unwrap();
+    /// BitmapMultiSignature::from_bytes(multi_sig.as_bytes()).unwrap();
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<BitmapMultiSignature<E>, IndyCryptoError> {
+        let (participants_count, bitmap, point_bytes) = _split_bitmap_bytes(bytes)?;
+        BitmapMultiSignature::_new(E::G1::from_bytes(point_bytes)?, participants_count, bitmap)
+    }
+
+    /// Returns multi signature compressed bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let signature = Bls::sign(&vec![1, 2, 3, 4, 5], &SignKey::new(None).unwrap()).unwrap();
+    /// let multi_sig = BitmapMultiSignature::new(&[&signature], &[0], 10).unwrap();
+    /// assert!(multi_sig.as_compressed_bytes().len() < multi_sig.as_bytes().len());
+    /// ```
+    pub fn as_compressed_bytes(&self) -> &[u8] {
+        self.compressed_bytes.as_slice()
+    }
+
+    /// Creates and returns multi signature from compressed bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let signature = Bls::sign(&vec![1, 2, 3, 4, 5], &SignKey::new(None).unwrap()).unwrap();
+    /// let multi_sig = BitmapMultiSignature::new(&[&signature], &[0], 10).unwrap();
+    /// BitmapMultiSignature::from_compressed_bytes(multi_sig.as_compressed_bytes()).unwrap();
+    /// ```
+    pub fn from_compressed_bytes(bytes: &[u8]) -> Result<BitmapMultiSignature<E>, IndyCryptoError> {
+        let (participants_count, bitmap, point_bytes) = _split_bitmap_bytes(bytes)?;
+        BitmapMultiSignature::_new(_point_from_compressed_bytes(point_bytes)?, participants_count, bitmap)
+    }
+
+    fn _new(point: E::G1, participants_count: u32, bitmap: Vec<u8>) -> Result<BitmapMultiSignature<E>, IndyCryptoError> {
+        let mut header = vec![(participants_count >> 24) as u8, (participants_count >> 16) as u8, (participants_count >> 8) as u8, participants_count as u8];
+        header.extend_from_slice(&bitmap);
+
+        let mut bytes = header.clone();
+        bytes.extend_from_slice(&point.to_bytes()?);
+
+        let mut compressed_bytes = header;
+        compressed_bytes.extend_from_slice(&point.to_compressed_bytes()?);
+
+        Ok(BitmapMultiSignature {
+            point,
+            participants_count,
+            bitmap,
+            bytes,
+            compressed_bytes
+        })
+    }
+}
+
+/// BLS aggregate signature.
+/// Combines signatures created by different signers over different messages.
+#[derive(Debug)]
+pub struct AggregateSignature<E: PairingEngine> {
+    point: E::G1,
+    bytes: Vec<u8>,
+    compressed_bytes: Vec<u8>
+}
+
+impl<E: PairingEngine> AggregateSignature<E> {
+    /// Creates and returns aggregate signature for provided list of signatures.
+    ///
+    /// # Arguments
+    ///
+    /// * `signatures` - List of signatures
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let sign_key1 = SignKey::new(None).unwrap();
+    /// let sign_key2 = SignKey::new(None).unwrap();
+    ///
+    /// let message1 = vec![1, 2, 3, 4, 5];
+    /// let message2 = vec![6, 7, 8, 9, 10];
+    ///
+    /// let signature1 = Bls::sign(&message1, &sign_key1).unwrap();
+    /// let signature2 = Bls::sign(&message2, &sign_key2).unwrap();
+    ///
+    /// AggregateSignature::new(&[&signature1, &signature2]).unwrap();
+    /// ```
+    pub fn new(signatures: &[&Signature<E>]) -> Result<AggregateSignature<E>, IndyCryptoError> {
+        let mut point = E::G1::new_inf()?;
+
+        for signature in signatures {
+            point = point.add(&signature.point)?;
+        }
+
+        AggregateSignature::_new(point)
+    }
+
+    /// Returns BLS aggregate signature bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let signature = Bls::sign(&vec![1, 2, 3, 4, 5], &sign_key).unwrap();
+    /// let aggregate_sig = AggregateSignature::new(&[&signature]).unwrap();
+    /// assert!(aggregate_sig.as_bytes().len() > 0);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Creates and returns BLS aggregate signature from bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let signature = Bls::sign(&vec![1, 2, 3, 4, 5], &sign_key).unwrap();
+    /// let aggregate_sig = AggregateSignature::new(&[&signature]).unwrap();
+    /// AggregateSignature::from_bytes(aggregate_sig.as_bytes()).unwrap();
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<AggregateSignature<E>, IndyCryptoError> {
+        AggregateSignature::_new(E::G1::from_bytes(bytes)?)
+    }
+
+    /// Returns BLS aggregate signature compressed bytes representation (x-coordinate with y sign flag).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let signature = Bls::sign(&vec![1, 2, 3, 4, 5], &sign_key).unwrap();
+    /// let aggregate_sig = AggregateSignature::new(&[&signature]).unwrap();
+    /// let compressed_bytes = aggregate_sig.as_compressed_bytes();
+    /// assert!(compressed_bytes.len() < aggregate_sig.as_bytes().len());
+    /// ```
+    pub fn as_compressed_bytes(&self) -> &[u8] {
+        self.compressed_bytes.as_slice()
+    }
+
+    /// Creates and returns BLS aggregate signature from compressed bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let signature = Bls::sign(&vec![1, 2, 3, 4, 5], &sign_key).unwrap();
+    /// let aggregate_sig = AggregateSignature::new(&[&signature]).unwrap();
+    /// AggregateSignature::from_compressed_bytes(aggregate_sig.as_compressed_bytes()).unwrap();
+    /// ```
+    pub fn from_compressed_bytes(bytes: &[u8]) -> Result<AggregateSignature<E>, IndyCryptoError> {
+        AggregateSignature::_new(_point_from_compressed_bytes(bytes)?)
+    }
+
+    fn _new(point: E::G1) -> Result<AggregateSignature<E>, IndyCryptoError> {
+        Ok(AggregateSignature {
+            bytes: point.to_bytes()?,
+            compressed_bytes: point.to_compressed_bytes()?,
+            point
+        })
+    }
+}
+
+/// BLS proof of possession.
+/// Proves that the owner of a verification key also holds the matching sign key.
+/// Must be checked for every verification key that takes part in multi signature verification
+/// to prevent rogue key attacks.
+#[derive(Debug)]
+pub struct ProofOfPossession<E: PairingEngine> {
+    point: E::G1,
+    bytes: Vec<u8>,
+    compressed_bytes: Vec<u8>
+}
+
+impl<E: PairingEngine> ProofOfPossession<E> {
+    /// Creates and returns BLS proof of possession that corresponds to provided ver key and sign key
+    /// with `Ciphersuite::Legacy`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ver_key` - Verification key
+    /// * `sign_key` - Sign key
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    /// ProofOfPossession::new(&ver_key, &sign_key).unwrap();
+    /// ```
+    pub fn new(ver_key: &VerKey<E>, sign_key: &SignKey<E>) -> Result<ProofOfPossession<E>, IndyCryptoError> {
+        ProofOfPossession::new_with_ciphersuite(ver_key, sign_key, &Ciphersuite::Legacy)
+    }
+
+    /// Creates and returns BLS proof of possession for provided ciphersuite.
+    /// Only `Ciphersuite::Legacy` and `Ciphersuite::ProofOfPossession` define proof of possession.
+    ///
+    /// # Arguments
+    ///
+    /// * `ver_key` - Verification key
+    /// * `sign_key` - Sign key
+    /// * `ciphersuite` - Ciphersuite
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::standard().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    /// ProofOfPossession::new_with_ciphersuite(&ver_key, &sign_key, &Ciphersuite::ProofOfPossession).unwrap();
+    /// ```
+    pub fn new_with_ciphersuite(ver_key: &VerKey<E>, sign_key: &SignKey<E>, ciphersuite: &Ciphersuite) -> Result<ProofOfPossession<E>, IndyCryptoError> {
+        ProofOfPossession::_new(Bls::_hash_pop(ver_key, ciphersuite)?.mul(&sign_key.group_order_element)?)
+    }
+
+    /// Returns BLS proof of possession bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    /// let pop = ProofOfPossession::new(&ver_key, &sign_key).unwrap();
+    /// let pop_bytes = pop.as_bytes();
+    /// assert!(pop_bytes.len() > 0);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Creates and returns BLS proof of possession from bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    /// let pop = ProofOfPossession::new(&ver_key, &sign_key).unwrap();
+    /// ProofOfPossession::from_bytes(pop.as_bytes()).unwrap();
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<ProofOfPossession<E>, IndyCryptoError> {
+        ProofOfPossession::_new(E::G1::from_bytes(bytes)?)
+    }
+
+    /// Returns BLS proof of possession compressed bytes representation (x-coordinate with y sign flag).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    /// let pop = ProofOfPossession::new(&ver_key, &sign_key).unwrap();
+    /// let compressed_bytes = pop.as_compressed_bytes();
+    /// assert!(compressed_bytes.len() < pop.as_bytes().len());
+    /// ```
+    pub fn as_compressed_bytes(&self) -> &[u8] {
+        self.compressed_bytes.as_slice()
+    }
+
+    /// Creates and returns BLS proof of possession from compressed bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    /// let pop = ProofOfPossession::new(&ver_key, &sign_key).unwrap();
+    /// ProofOfPossession::from_compressed_bytes(pop.as_compressed_bytes()).unwrap();
+    /// ```
+    pub fn from_compressed_bytes(bytes: &[u8]) -> Result<ProofOfPossession<E>, IndyCryptoError> {
+        ProofOfPossession::_new(_point_from_compressed_bytes(bytes)?)
+    }
+
+    fn _new(point: E::G1) -> Result<ProofOfPossession<E>, IndyCryptoError> {
+        Ok(ProofOfPossession {
+            bytes: point.to_bytes()?,
+            compressed_bytes: point.to_compressed_bytes()?,
+            point
+        })
+    }
+}
+
+/// Hash state of stream signer and verifier. Hash-to-curve of IETF ciphersuites can't be computed
+/// incrementally, so only legacy and prehashed messages are supported.
+enum MessageHasher {
+    Legacy(Sha256),
+    Sha256(Sha256),
+    Sha512(Sha512)
+}
+
+impl MessageHasher {
+    fn new(algorithm: Option<DigestAlgorithm>) -> MessageHasher {
+        match algorithm {
+            None => MessageHasher::Legacy(Sha256::default()),
+            Some(DigestAlgorithm::Sha256) => MessageHasher::Sha256(Sha256::default()),
+            Some(DigestAlgorithm::Sha512) => MessageHasher::Sha512(Sha512::default())
+        }
+    }
+
+    fn update(&mut self, chunk: &[u8]) {
+        match *self {
+            MessageHasher::Legacy(ref mut hasher) => hasher.input(chunk),
+            MessageHasher::Sha256(ref mut hasher) => hasher.input(chunk),
+            MessageHasher::Sha512(ref mut hasher) => hasher.input(chunk)
+        }
+    }
+
+    fn finish<E: PairingEngine>(self) -> Result<E::G1, IndyCryptoError> {
+        match self {
+            MessageHasher::Legacy(hasher) => E::g1_from_hash(hasher.result().as_slice()),
+            MessageHasher::Sha256(hasher) => Bls::<E>::_hash_prehashed(hasher.result().as_slice(), &DigestAlgorithm::Sha256),
+            MessageHasher::Sha512(hasher) => Bls::<E>::_hash_prehashed(hasher.result().as_slice(), &DigestAlgorithm::Sha512)
+        }
+    }
+}
+
+/// Incremental signer that accepts message chunk by chunk.
+pub struct StreamSigner<E: PairingEngine> {
+    hasher: MessageHasher,
+    _engine: PhantomData<E>
+}
+
+impl<E: PairingEngine> StreamSigner<E> {
+    /// Creates signer that produces the same signatures as `Bls::sign_with_ciphersuite` with `Ciphersuite::Legacy`
+    /// over concatenation of chunks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let mut signer = StreamSigner::new();
+    /// signer.update(&[1, 2, 3]);
+    /// signer.update(&[4, 5]);
+    /// let signature = signer.sign(&sign_key).unwrap();
+    /// assert_eq!(Bls::sign(&[1, 2, 3, 4, 5], &sign_key).unwrap().as_bytes(), signature.as_bytes());
+    /// ```
+    pub fn new() -> StreamSigner<E> {
+        StreamSigner {
+            hasher: MessageHasher::new(None),
+            _engine: PhantomData
+        }
+    }
+
+    /// Creates signer that produces the same signatures as `Bls::sign_prehashed`
+    /// over digest of concatenation of chunks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let mut signer = StreamSigner::new_prehashed(DigestAlgorithm::Sha512);
+    /// signer.update(&[1, 2, 3, 4, 5]);
+    /// signer.sign(&sign_key).unwrap();
+    /// ```
+    pub fn new_prehashed(algorithm: DigestAlgorithm) -> StreamSigner<E> {
+        StreamSigner {
+            hasher: MessageHasher::new(Some(algorithm)),
+            _engine: PhantomData
+        }
+    }
+
+    /// Appends chunk to the message.
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.hasher.update(chunk)
+    }
+
+    /// Signs the message and returns signature.
+    pub fn sign(self, sign_key: &SignKey<E>) -> Result<Signature<E>, IndyCryptoError> {
+        Bls::_sign_point(&self.hasher.finish::<E>()?, sign_key)
+    }
+}
+
+/// Incremental verifier that accepts message chunk by chunk.
+pub struct StreamVerifier<E: PairingEngine> {
+    hasher: MessageHasher,
+    _engine: PhantomData<E>
+}
+
+impl<E: PairingEngine> StreamVerifier<E> {
+    /// Creates verifier of signatures created with `Ciphersuite::Legacy` or `StreamSigner::new`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    /// let signature = Bls::sign(&[1, 2, 3, 4, 5], &sign_key).unwrap();
+    ///
+    /// let mut verifier = StreamVerifier::new();
+    /// verifier.update(&[1, 2]);
+    /// verifier.update(&[3, 4, 5]);
+    /// assert!(verifier.verify(&signature, &ver_key, &gen).unwrap());
+    /// ```
+    pub fn new() -> StreamVerifier<E> {
+        StreamVerifier {
+            hasher: MessageHasher::new(None),
+            _engine: PhantomData
+        }
+    }
+
+    /// Creates verifier of signatures created with `Bls::sign_prehashed` or `StreamSigner::new_prehashed`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    ///
+    /// let mut signer = StreamSigner::new_prehashed(DigestAlgorithm::Sha256);
+    /// signer.update(&[1, 2, 3, 4, 5]);
+    /// let signature = signer.sign(&sign_key).unwrap();
+    ///
+    /// let mut verifier = StreamVerifier::new_prehashed(DigestAlgorithm::Sha256);
+    /// verifier.update(&[1, 2, 3, 4, 5]);
+    /// assert!(verifier.verify(&signature, &ver_key, &gen).unwrap());
+    /// ```
+    pub fn new_prehashed(algorithm: DigestAlgorithm) -> StreamVerifier<E> {
+        StreamVerifier {
+            hasher: MessageHasher::new(Some(algorithm)),
+            _engine: PhantomData
+        }
+    }
+
+    /// Appends chunk to the message.
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.hasher.update(chunk)
+    }
+
+    /// Verifies the message signature and returns true - if signature valid or false otherwise.
+    pub fn verify(self, signature: &Signature<E>, ver_key: &VerKey<E>, gen: &Generator<E>) -> Result<bool, IndyCryptoError> {
+        Bls::_verify_point(signature, &self.hasher.finish::<E>()?, ver_key, gen)
+    }
+}
+
+/// BLS signature scheme over pairing engine `E`.
+/// Methods without ciphersuite argument use `Ciphersuite::Legacy`, the same as `bls::Bls`,
+/// `ProofOfPossession::new` and `StreamSigner::new`, so their results are interchangeable.
+pub struct Bls<E: PairingEngine> {
+    _engine: PhantomData<E>
+}
+
+impl<E: PairingEngine> Bls<E> {
+    /// Signs the message with `Ciphersuite::Legacy` and returns signature.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - Message to sign
+    /// * `sign_key` - Sign key
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::curve::{Bls, SignKey};
+    /// use indy_crypto::pair::DefaultEngine;
+    /// let message = vec![1, 2, 3, 4, 5];
+    /// let sign_key = SignKey::<DefaultEngine>::new(None).unwrap();
+    /// Bls::sign(&message, &sign_key).unwrap();
+    /// ```
+    pub fn sign(message: &[u8], sign_key: &SignKey<E>) -> Result<Signature<E>, IndyCryptoError> {
+        Bls::sign_with_ciphersuite(message, sign_key, &Ciphersuite::Legacy)
+    }
+
+    /// Signs the message using provided ciphersuite and returns signature.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - Message to sign
+    /// * `sign_key` - Sign key
+    /// * `ciphersuite` - Method used to hash message to the curve
+    pub fn sign_with_ciphersuite(message: &[u8], sign_key: &SignKey<E>, ciphersuite: &Ciphersuite) -> Result<Signature<E>, IndyCryptoError> {
+        let ver_key = match *ciphersuite {
+            Ciphersuite::MessageAugmentation => Some(VerKey::new(&Generator::standard()?, sign_key)?),
+            _ => None
+        };
+
+        Bls::_sign_point(&Bls::_hash_with_ciphersuite(message, ver_key.as_ref(), ciphersuite)?, sign_key)
+    }
+
+    /// Verifies the message signature created with `Ciphersuite::Legacy`
+    /// and returns true - if signature valid or false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - Signature to verify
+    /// * `message` - Message to verify
+    /// * `ver_key` - Verification key
+    /// * `gen` - Generator point
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::curve::{Bls, Generator, SignKey, VerKey};
+    /// use indy_crypto::pair::DefaultEngine;
+    /// let gen = Generator::<DefaultEngine>::standard().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    /// let message = vec![1, 2, 3, 4, 5];
+    /// let signature = Bls::sign(&message, &sign_key).unwrap();
+    ///
+    /// let valid = Bls::verify(&signature, &message, &ver_key, &gen).unwrap();
+    /// assert!(valid);
+    /// ```
+    pub fn verify(signature: &Signature<E>, message: &[u8], ver_key: &VerKey<E>, gen: &Generator<E>) -> Result<bool, IndyCryptoError> {
+        Bls::verify_with_ciphersuite(signature, message, ver_key, gen, &Ciphersuite::Legacy)
+    }
+
+    /// Verifies the message signature created with provided ciphersuite
+    /// and returns true - if signature valid or false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - Signature to verify
+    /// * `message` - Message to verify
+    /// * `ver_key` - Verification key
+    /// * `gen` - Generator point
+    /// * `ciphersuite` - Method used to hash message to the curve
+    pub fn verify_with_ciphersuite(signature: &Signature<E>, message: &[u8], ver_key: &VerKey<E>, gen: &Generator<E>,
+                                   ciphersuite: &Ciphersuite) -> Result<bool, IndyCryptoError> {
+        let h = Bls::_hash_with_ciphersuite(message, Some(ver_key), ciphersuite)?;
+        Bls::_verify_point(signature, &h, ver_key, gen)
+    }
+
+    /// Signs the digest of the message computed with provided algorithm and returns signature.
+    ///
+    /// # Arguments
+    ///
+    /// * `digest` - Digest of the message to sign
+    /// * `algorithm` - Algorithm used to compute the digest
+    /// * `sign_key` - Sign key
+    pub fn sign_prehashed(digest: &[u8], algorithm: &DigestAlgorithm, sign_key: &SignKey<E>) -> Result<Signature<E>, IndyCryptoError> {
+        Bls::_sign_point(&Bls::<E>::_hash_prehashed(digest, algorithm)?, sign_key)
+    }
+
+    /// Verifies the signature of the message digest computed with provided algorithm
+    /// and returns true - if signature valid or false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - Signature to verify
+    /// * `digest` - Digest of the message to verify
+    /// * `algorithm` - Algorithm used to compute the digest
+    /// * `ver_key` - Verification key
+    /// * `gen` - Generator point
+    pub fn verify_prehashed(signature: &Signature<E>, digest: &[u8], algorithm: &DigestAlgorithm, ver_key: &VerKey<E>,
+                            gen: &Generator<E>) -> Result<bool, IndyCryptoError> {
+        let h = Bls::<E>::_hash_prehashed(digest, algorithm)?;
+        Bls::_verify_point(signature, &h, ver_key, gen)
+    }
+
+    /// Verifies the message multi signature created with `Ciphersuite::Legacy`
+    /// and returns true - if signature valid or false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `multi_sig` - Multi signature to verify
+    /// * `message` - Message to verify
+    /// * `ver_keys` - List of verification keys
+    /// * `gen` - Generator point
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::curve::{Bls, Generator, MultiSignature, SignKey, VerKey};
+    /// use indy_crypto::pair::DefaultEngine;
+    /// let gen = Generator::<DefaultEngine>::standard().unwrap();
+    ///
+    /// let sign_key1 = SignKey::new(None).unwrap();
+    /// let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+    /// let sign_key2 = SignKey::new(None).unwrap();
+    /// let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+    ///
+    /// let message = vec![1, 2, 3, 4, 5];
+    ///
+    /// let signature1 = Bls::sign(&message, &sign_key1).unwrap();
+    /// let signature2 = Bls::sign(&message, &sign_key2).unwrap();
+    /// let multi_sig = MultiSignature::new(&[&signature1, &signature2]).unwrap();
+    ///
+    /// let valid = Bls::verify_multi_sig(&multi_sig, &message, &[&ver_key1, &ver_key2], &gen).unwrap();
+    /// assert!(valid)
+    /// ```
+    pub fn verify_multi_sig(multi_sig: &MultiSignature<E>, message: &[u8], ver_keys: &[&VerKey<E>], gen: &Generator<E>) -> Result<bool, IndyCryptoError> {
+        Bls::verify_multi_sig_with_ciphersuite(multi_sig, message, ver_keys, gen, &Ciphersuite::Legacy)
+    }
+
+    /// Verifies the message multi signature created with provided ciphersuite
+    /// and returns true - if signature valid or false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `multi_sig` - Multi signature to verify
+    /// * `message` - Message to verify
+    /// * `ver_keys` - List of verification keys
+    /// * `gen` - Generator point
+    /// * `ciphersuite` - Ciphersuite used to create signatures
+    pub fn verify_multi_sig_with_ciphersuite(multi_sig: &MultiSignature<E>, message: &[u8], ver_keys: &[&VerKey<E>], gen: &Generator<E>,
+                                             ciphersuite: &Ciphersuite) -> Result<bool, IndyCryptoError> {
+        if ver_keys.is_empty() {
+            return Err(IndyCryptoError::InvalidStructure(format!("Element not found")));
+        }
+
+        let mut pairs: Vec<(E::G1, &E::G2)> = Vec::new();
+        for ver_key in ver_keys {
+            let h = Bls::_hash_with_ciphersuite(message, Some(ver_key), ciphersuite)?;
+            pairs.push((h, &ver_key.point));
+        }
+
+        Bls::_verify_pairs(&multi_sig.point, gen, &pairs)
+    }
+
+    /// Verifies the message multi signature created with `Ciphersuite::Legacy` against precomputed
+    /// multi verification key and returns true - if signature valid or false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `multi_sig` - Multi signature to verify
+    /// * `message` - Message to verify
+    /// * `multi_ver_key` - Multi verification key of signers
+    /// * `gen` - Generator point
+    pub fn verify_multi_sig_for_multi_ver_key(multi_sig: &MultiSignature<E>, message: &[u8], multi_ver_key: &MultiVerKey<E>,
+                                              gen: &Generator<E>) -> Result<bool, IndyCryptoError> {
+        Bls::verify_multi_sig_for_multi_ver_key_with_ciphersuite(multi_sig, message, multi_ver_key, gen, &Ciphersuite::Legacy)
+    }
+
+    /// Verifies the message multi signature created with provided ciphersuite against precomputed
+    /// multi verification key and returns true - if signature valid or false otherwise.
+    /// Message augmentation ciphersuite isn't supported as message hash depends on each verification key.
+    ///
+    /// # Arguments
+    ///
+    /// * `multi_sig` - Multi signature to verify
+    /// * `message` - Message to verify
+    /// * `multi_ver_key` - Multi verification key of signers
+    /// * `gen` - Generator point
+    /// * `ciphersuite` - Ciphersuite used to create signatures
+    pub fn verify_multi_sig_for_multi_ver_key_with_ciphersuite(multi_sig: &MultiSignature<E>, message: &[u8], multi_ver_key: &MultiVerKey<E>,
+                                                               gen: &Generator<E>, ciphersuite: &Ciphersuite) -> Result<bool, IndyCryptoError> {
+        if let Ciphersuite::MessageAugmentation = *ciphersuite {
+            return Err(IndyCryptoError::InvalidStructure(format!("Multi ver key can't be used with message augmentation ciphersuite")));
+        }
+
+        let h = Bls::<E>::_hash_with_ciphersuite(message, None, ciphersuite)?;
+        Bls::_verify_pairs(&multi_sig.point, gen, &[(h, &multi_ver_key.point)])
+    }
+
+    /// Verifies the message bitmap multi signature created with `Ciphersuite::Legacy` against ordered validators set
+    /// and returns true - if signature valid and at least `min_signers` participants signed or false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `multi_sig` - Bitmap multi signature to verify
+    /// * `message` - Message to verify
+    /// * `ver_keys` - Ordered list of verification keys of validators set
+    /// * `min_signers` - Minimal number of participants required
+    /// * `gen` - Generator point
+    pub fn verify_with_quorum(multi_sig: &BitmapMultiSignature<E>, message: &[u8], ver_keys: &[&VerKey<E>], min_signers: u32,
+                              gen: &Generator<E>) -> Result<bool, IndyCryptoError> {
+        Bls::verify_with_quorum_with_ciphersuite(multi_sig, message, ver_keys, min_signers, gen, &Ciphersuite::Legacy)
+    }
+
+    /// Verifies the message bitmap multi signature created with provided ciphersuite against ordered validators set
+    /// and returns true - if signature valid and at least `min_signers` participants signed or false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `multi_sig` - Bitmap multi signature to verify
+    /// * `message` - Message to verify
+    /// * `ver_keys` - Ordered list of verification keys of validators set
+    /// * `min_signers` - Minimal number of participants required
+    /// * `gen` - Generator point
+    /// * `ciphersuite` - Ciphersuite used to create signatures
+    pub fn verify_with_quorum_with_ciphersuite(multi_sig: &BitmapMultiSignature<E>, message: &[u8], ver_keys: &[&VerKey<E>], min_signers: u32,
+                                               gen: &Generator<E>, ciphersuite: &Ciphersuite) -> Result<bool, IndyCryptoError> {
+        if ver_keys.len() != multi_sig.participants_count as usize {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("Multi signature was created over validators set of size {}, but {} ver keys provided", multi_sig.participants_count, ver_keys.len())));
+        }
+
+        let participants = multi_sig.participants();
+
+        if (participants.len() as u32) < min_signers {
+            return Ok(false);
+        }
+
+        let mut pairs: Vec<(E::G1, &E::G2)> = Vec::new();
+        if let Ciphersuite::MessageAugmentation = *ciphersuite {
+            for index in participants {
+                let ver_key = ver_keys[index as usize];
+                pairs.push((Bls::_hash_with_ciphersuite(message, Some(ver_key), ciphersuite)?, &ver_key.point));
+            }
+
+            return Bls::_verify_pairs(&multi_sig.point, gen, &pairs);
+        }
+
+        let mut ver_key_point = E::G2::new_inf()?;
+        for index in participants {
+            ver_key_point = ver_key_point.add(&ver_keys[index as usize].point)?;
+        }
+
+        let h = Bls::<E>::_hash_with_ciphersuite(message, None, ciphersuite)?;
+        Bls::_verify_pairs(&multi_sig.point, gen, &[(h, &ver_key_point)])
+    }
+
+    /// Verifies the aggregate signature over distinct messages created with `Ciphersuite::Legacy`
+    /// and returns true - if signature valid or false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `aggregate_sig` - Aggregate signature to verify
+    /// * `messages` - List of signed messages
+    /// * `ver_keys` - List of verification keys messages correspond to
+    /// * `gen` - Generator point
+    pub fn verify_aggregate(aggregate_sig: &AggregateSignature<E>, messages: &[&[u8]], ver_keys: &[&VerKey<E>],
+                            gen: &Generator<E>) -> Result<bool, IndyCryptoError> {
+        Bls::verify_aggregate_with_ciphersuite(aggregate_sig, messages, ver_keys, gen, &Ciphersuite::Legacy)
+    }
+
+    /// Verifies the aggregate signature over distinct messages created with provided ciphersuite
+    /// and returns true - if signature valid or false otherwise.
+    /// Each message must be signed by the signer of corresponding verification key.
+    /// Duplicate messages are rejected as they make aggregate signature vulnerable to rogue key attacks.
+    ///
+    /// # Arguments
+    ///
+    /// * `aggregate_sig` - Aggregate signature to verify
+    /// * `messages` - List of signed messages
+    /// * `ver_keys` - List of verification keys messages correspond to
+    /// * `gen` - Generator point
+    /// * `ciphersuite` - Ciphersuite used to create signatures
+    pub fn verify_aggregate_with_ciphersuite(aggregate_sig: &AggregateSignature<E>, messages: &[&[u8]], ver_keys: &[&VerKey<E>],
+                                             gen: &Generator<E>, ciphersuite: &Ciphersuite) -> Result<bool, IndyCryptoError> {
+        if messages.len() != ver_keys.len() {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("Lists of messages and ver keys have different len: {}, {}", messages.len(), ver_keys.len())));
+        }
+
+        let mut unique_messages: HashSet<&[u8]> = HashSet::new();
+        for message in messages {
+            if !unique_messages.insert(*message) {
+                return Err(IndyCryptoError::InvalidStructure(format!("Duplicate message in aggregate signature")));
+            }
+        }
+
+        if messages.is_empty() {
+            return Err(IndyCryptoError::InvalidStructure(format!("Element not found")));
+        }
+
+        let mut pairs: Vec<(E::G1, &E::G2)> = Vec::new();
+        for (message, ver_key) in messages.iter().zip(ver_keys.iter()) {
+            pairs.push((Bls::_hash_with_ciphersuite(message, Some(ver_key), ciphersuite)?, &ver_key.point));
+        }
+
+        Bls::_verify_pairs(&aggregate_sig.point, gen, &pairs)
+    }
+
+    /// Verifies many independent message signatures created with `Ciphersuite::Legacy` at once.
+    ///
+    /// Returns None if all signatures are valid or index of the first invalid signature otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `signatures` - List of signatures to verify
+    /// * `messages` - List of messages signatures correspond to
+    /// * `ver_keys` - List of verification keys signatures correspond to
+    /// * `gen` - Generator point
+    pub fn verify_batch(signatures: &[&Signature<E>], messages: &[&[u8]], ver_keys: &[&VerKey<E>],
+                        gen: &Generator<E>) -> Result<Option<usize>, IndyCryptoError> {
+        Bls::verify_batch_with_ciphersuite(signatures, messages, ver_keys, gen, &Ciphersuite::Legacy)
+    }
+
+    /// Verifies many independent message signatures created with provided ciphersuite at once.
+    /// Uses random linear combination of signatures, so the whole batch requires only n + 1 pairings.
+    /// If batch is rejected it is split to find the first invalid signature.
+    ///
+    /// Returns None if all signatures are valid or index of the first invalid signature otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `signatures` - List of signatures to verify
+    /// * `messages` - List of messages signatures correspond to
+    /// * `ver_keys` - List of verification keys signatures correspond to
+    /// * `gen` - Generator point
+    /// * `ciphersuite` - Ciphersuite used to create signatures
+    pub fn verify_batch_with_ciphersuite(signatures: &[&Signature<E>], messages: &[&[u8]], ver_keys: &[&VerKey<E>],
+                                         gen: &Generator<E>, ciphersuite: &Ciphersuite) -> Result<Option<usize>, IndyCryptoError> {
+        if signatures.len() != messages.len() || signatures.len() != ver_keys.len() {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("Lists of signatures, messages and ver keys have different len: {}, {}, {}",
+                        signatures.len(), messages.len(), ver_keys.len())));
+        }
+
+        if signatures.is_empty() {
+            return Err(IndyCryptoError::InvalidStructure(format!("Empty list of signatures")));
+        }
+
+        let mut hashes: Vec<E::G1> = Vec::new();
+        for (message, ver_key) in messages.iter().zip(ver_keys.iter()) {
+            hashes.push(Bls::_hash_with_ciphersuite(message, Some(ver_key), ciphersuite)?);
+        }
+
+        Bls::_find_invalid_in_batch(signatures, &hashes, ver_keys, gen, 0)
+    }
+
+    /// Verifies the proof of possession created with `Ciphersuite::Legacy`
+    /// and returns true - if proof valid or false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `pop` - Proof of possession to verify
+    /// * `ver_key` - Verification key
+    /// * `gen` - Generator point
+    pub fn verify_pop(pop: &ProofOfPossession<E>, ver_key: &VerKey<E>, gen: &Generator<E>) -> Result<bool, IndyCryptoError> {
+        Bls::verify_pop_with_ciphersuite(pop, ver_key, gen, &Ciphersuite::Legacy)
+    }
+
+    /// Verifies the proof of possession created with provided ciphersuite
+    /// and returns true - if proof valid or false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `pop` - Proof of possession to verify
+    /// * `ver_key` - Verification key
+    /// * `gen` - Generator point
+    /// * `ciphersuite` - Ciphersuite used to create proof of possession
+    pub fn verify_pop_with_ciphersuite(pop: &ProofOfPossession<E>, ver_key: &VerKey<E>, gen: &Generator<E>,
+                                       ciphersuite: &Ciphersuite) -> Result<bool, IndyCryptoError> {
+        let h = Bls::_hash_pop(ver_key, ciphersuite)?;
+        Bls::_verify_pairs(&pop.point, gen, &[(h, &ver_key.point)])
+    }
+
+    fn _find_invalid_in_batch(signatures: &[&Signature<E>], hashes: &[E::G1], ver_keys: &[&VerKey<E>], gen: &Generator<E>,
+                              offset: usize) -> Result<Option<usize>, IndyCryptoError> {
+        if Bls::_verify_batch(signatures, hashes, ver_keys, gen)? {
+            return Ok(None);
+        }
+
+        if signatures.len() == 1 {
+            return Ok(Some(offset));
+        }
+
+        let mid = signatures.len() / 2;
+
+        if let Some(idx) = Bls::_find_invalid_in_batch(&signatures[..mid], &hashes[..mid], &ver_keys[..mid], gen, offset)? {
+            return Ok(Some(idx));
+        }
+
+        Bls::_find_invalid_in_batch(&signatures[mid..], &hashes[mid..], &ver_keys[mid..], gen, offset + mid)
+    }
+
+    fn _verify_batch(signatures: &[&Signature<E>], hashes: &[E::G1], ver_keys: &[&VerKey<E>], gen: &Generator<E>) -> Result<bool, IndyCryptoError> {
+        if signatures.is_empty() {
+            return Err(IndyCryptoError::InvalidStructure(format!("Element not found")));
+        }
+
+        let mut signature_sum = E::G1::new_inf()?;
+        let mut pairs: Vec<(E::G1, &E::G2)> = Vec::new();
+
+        for ((signature, h), ver_key) in signatures.iter().zip(hashes.iter()).zip(ver_keys.iter()) {
+            let r = E::Scalar::new()?;
+
+            signature_sum = signature_sum.add(&signature.point.mul(&r)?)?;
+            pairs.push((h.mul(&r)?, &ver_key.point));
+        }
+
+        Bls::_verify_pairs(&signature_sum, gen, &pairs)
+    }
+
+    /// Legacy hash: SHA-256 of the message mapped to G1 with try-and-increment.
+    pub(crate) fn _hash(message: &[u8]) -> Result<E::G1, IndyCryptoError> {
+        let mut hasher = Sha256::default();
+        hasher.input(message);
+
+        E::g1_from_hash(hasher.result().as_slice())
+    }
+
+    fn _hash_prehashed(digest: &[u8], algorithm: &DigestAlgorithm) -> Result<E::G1, IndyCryptoError> {
+        if digest.len() != algorithm.digest_len() {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("Invalid len of {:?} digest: expected {}, actual {}", algorithm, algorithm.digest_len(), digest.len())));
+        }
+
+        let tag = algorithm.tag();
+        let mut message = vec![tag.len() as u8];
+        message.extend_from_slice(tag);
+        message.extend_from_slice(digest);
+
+        E::hash_to_g1(&message, format!("BLS_SIG_{}PREHASHED_", E::G1_HASH_SUITE).as_bytes())
+    }
+
+    fn _sign_point(h: &E::G1, sign_key: &SignKey<E>) -> Result<Signature<E>, IndyCryptoError> {
+        Signature::_new(h.mul(&sign_key.group_order_element)?)
+    }
+
+    fn _verify_point(signature: &Signature<E>, h: &E::G1, ver_key: &VerKey<E>, gen: &Generator<E>) -> Result<bool, IndyCryptoError> {
+        Bls::_verify_pairs(&signature.point, gen, &[(*h, &ver_key.point)])
+    }
+
+    /// Checks e(signature, gen) == e(h1, ver_key1) * e(h2, ver_key2) * ...
+    /// as e(signature, gen) * e(-h1, ver_key1) * e(-h2, ver_key2) * ... == 1 with single final exponentiation.
+    pub(crate) fn _verify_pairs(signature: &E::G1, gen: &Generator<E>, pairs: &[(E::G1, &E::G2)]) -> Result<bool, IndyCryptoError> {
+        let mut prepared_pairs: Vec<(E::G1, E::PreparedG2)> = Vec::new();
+        for &(ref h, ver_key) in pairs {
+            prepared_pairs.push((h.neg()?, E::prepare_g2(ver_key)?));
+        }
+
+        let mut multi_pair: Vec<(&E::G1, &E::PreparedG2)> = vec![(signature, &gen.prepared)];
+        multi_pair.extend(prepared_pairs.iter().map(|&(ref h, ref ver_key)| (h, ver_key)));
+
+        E::multi_pair_prepared(&multi_pair)?.is_unity()
+    }
+
+    /// Message augmentation prepends compressed ver key as IETF BLS signature defines public key serialization.
+    pub(crate) fn _hash_with_ciphersuite(message: &[u8], ver_key: Option<&VerKey<E>>, ciphersuite: &Ciphersuite) -> Result<E::G1, IndyCryptoError> {
+        let dst = match ciphersuite.dst::<E>() {
+            Some(dst) => dst,
+            None => return Bls::<E>::_hash(message)
+        };
+
+        match *ciphersuite {
+            Ciphersuite::MessageAugmentation => {
+                let ver_key = ver_key.ok_or(IndyCryptoError::InvalidStructure(format!("Ver key is required for message augmentation")))?;
+
+                let mut augmented_message = ver_key.as_compressed_bytes().to_vec();
+                augmented_message.extend_from_slice(message);
+                E::hash_to_g1(&augmented_message, &dst)
+            }
+            _ => E::hash_to_g1(message, &dst)
+        }
+    }
+
+    fn _hash_pop(ver_key: &VerKey<E>, ciphersuite: &Ciphersuite) -> Result<E::G1, IndyCryptoError> {
+        match *ciphersuite {
+            Ciphersuite::Legacy => {
+                // Domain prefix keeps proof of possession and signature over the same bytes apart
+                let mut hasher = Sha256::default();
+                hasher.input(POP_DOMAIN);
+                hasher.input(ver_key.as_bytes());
+
+                E::g1_from_hash(hasher.result().as_slice())
+            }
+            Ciphersuite::ProofOfPossession =>
+                E::hash_to_g1(ver_key.as_compressed_bytes(), format!("BLS_POP_{}POP_", E::G1_HASH_SUITE).as_bytes()),
+            _ => Err(IndyCryptoError::InvalidStructure(format!("Proof of possession is not defined for ciphersuite {:?}", ciphersuite)))
+        }
+    }
+}
+
+/// Compressed representation is able to encode infinity explicitly,
+/// but neither key nor signature can be infinity.
+fn _point_from_compressed_bytes<P: PairingPoint>(bytes: &[u8]) -> Result<P, IndyCryptoError> {
+    let point = P::from_compressed_bytes(bytes)?;
+
+    if point.is_inf()? {
+        return Err(IndyCryptoError::InvalidGroupElement("Infinity point is not allowed".to_string()));
+    }
+
+    Ok(point)
+}
+
+fn _split_bitmap_bytes(bytes: &[u8]) -> Result<(u32, Vec<u8>, &[u8]), IndyCryptoError> {
+    if bytes.len() < 4 {
+        return Err(IndyCryptoError::InvalidStructure(format!("Invalid len of bitmap multi signature bytes representation")));
+    }
+
+    let participants_count = bytes[..4].iter().fold(0u32, |acc, b| (acc << 8) | *b as u32);
+    let bitmap_len = _bitmap_len(participants_count);
+
+    if bytes.len() < 4 + bitmap_len {
+        return Err(IndyCryptoError::InvalidStructure(format!("Invalid len of bitmap multi signature bytes representation")));
+    }
+
+    let bitmap = bytes[4..4 + bitmap_len].to_vec();
+
+    if participants_count % 8 != 0 && bitmap[bitmap_len - 1] >> (participants_count % 8) != 0 {
+        return Err(IndyCryptoError::InvalidStructure(format!("Bitmap contains participants out of validators set")));
+    }
+
+    if bitmap.iter().all(|&b| b == 0) {
+        return Err(IndyCryptoError::InvalidStructure(format!("Bitmap multi signature requires at least one participant")));
+    }
+
+    Ok((participants_count, bitmap, &bytes[4 + bitmap_len..]))
+}
+
+fn _bitmap_len(participants_count: u32) -> usize {
+    (participants_count as usize + 7) / 8
+}
+
+fn _is_participant(bitmap: &[u8], index: u32) -> bool {
+    bitmap[index as usize / 8] >> (index % 8) & 1 == 1
+}
+
+fn _set_participant(bitmap: &mut [u8], index: u32, participants_count: u32) -> Result<(), IndyCryptoError> {
+    if index >= participants_count {
+        return Err(IndyCryptoError::InvalidStructure(format!("Participant index {} is out of validators set of size {}", index, participants_count)));
+    }
+
+    if _is_participant(bitmap, index) {
+        return Err(IndyCryptoError::InvalidStructure(format!("Participant {} already signed", index)));
+    }
+
+    bitmap[index as usize / 8] |= 1 << (index % 8);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pair::DefaultEngine;
+
+    type E = DefaultEngine;
+
+    #[test]
+    fn bls_verify_works() {
+        let gen = Generator::<E>::standard().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let message = vec![1, 2, 3, 4, 5];
+
+        let signature = Bls::sign(&message, &sign_key).unwrap();
+        assert!(Bls::verify(&signature, &message, &ver_key, &gen).unwrap());
+        assert!(!Bls::verify(&signature, &vec![1, 2, 3, 4, 6], &ver_key, &gen).unwrap());
+    }
+
+    #[test]
+    fn bls_verify_works_for_message_augmentation() {
+        let gen = Generator::<E>::standard().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let message = vec![1, 2, 3, 4, 5];
+        let ciphersuite = Ciphersuite::MessageAugmentation;
+
+        let signature = Bls::sign_with_ciphersuite(&message, &sign_key, &ciphersuite).unwrap();
+        assert!(Bls::verify_with_ciphersuite(&signature, &message, &ver_key, &gen, &ciphersuite).unwrap());
+        assert!(!Bls::verify(&signature, &message, &ver_key, &gen).unwrap());
+    }
+
+    #[test]
+    fn bls_verify_works_for_legacy_ciphersuite() {
+        let gen = Generator::<E>::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let message = vec![1, 2, 3, 4, 5];
+
+        let signature = Bls::sign_with_ciphersuite(&message, &sign_key, &Ciphersuite::Legacy).unwrap();
+        assert!(Bls::verify_with_ciphersuite(&signature, &message, &ver_key, &gen, &Ciphersuite::Legacy).unwrap());
+        assert!(Bls::verify(&signature, &message, &ver_key, &gen).unwrap());
+        assert!(!Bls::verify_with_ciphersuite(&signature, &message, &ver_key, &gen, &Ciphersuite::Basic).unwrap());
+    }
+
+    #[test]
+    fn bls_verify_multi_sig_works() {
+        let gen = Generator::<E>::new().unwrap();
+
+        let sign_key1 = SignKey::new(None).unwrap();
+        let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+        let sign_key2 = SignKey::new(None).unwrap();
+        let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+
+        let message = vec![1, 2, 3, 4, 5];
+
+        let signature1 = Bls::sign(&message, &sign_key1).unwrap();
+        let signature2 = Bls::sign(&message, &sign_key2).unwrap();
+        let multi_sig = MultiSignature::new(&[&signature1, &signature2]).unwrap();
+
+        assert!(Bls::verify_multi_sig(&multi_sig, &message, &[&ver_key1, &ver_key2], &gen).unwrap());
+        assert!(!Bls::verify_multi_sig(&multi_sig, &message, &[&ver_key1], &gen).unwrap());
+    }
+
+    #[test]
+    fn bls_verify_with_quorum_works_for_message_augmentation() {
+        let gen = Generator::<E>::standard().unwrap();
+        let ciphersuite = Ciphersuite::MessageAugmentation;
+
+        let sign_key1 = SignKey::new(None).unwrap();
+        let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+        let sign_key2 = SignKey::new(None).unwrap();
+        let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+        let ver_key3 = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+
+        let message = vec![1, 2, 3, 4, 5];
+        let signature1 = Bls::sign_with_ciphersuite(&message, &sign_key1, &ciphersuite).unwrap();
+        let signature2 = Bls::sign_with_ciphersuite(&message, &sign_key2, &ciphersuite).unwrap();
+        let multi_sig = BitmapMultiSignature::new(&[&signature1, &signature2], &[0, 2], 3).unwrap();
+
+        let ver_keys = [&ver_key1, &ver_key3, &ver_key2];
+        assert!(Bls::verify_with_quorum_with_ciphersuite(&multi_sig, &message, &ver_keys, 2, &gen, &ciphersuite).unwrap());
+        assert!(!Bls::verify_with_quorum(&multi_sig, &message, &ver_keys, 2, &gen).unwrap());
+    }
+
+    #[test]
+    fn bls_verify_batch_works_for_ciphersuites() {
+        let gen = Generator::<E>::standard().unwrap();
+        let sign_key1 = SignKey::new(None).unwrap();
+        let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+        let sign_key2 = SignKey::new(None).unwrap();
+        let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+
+        let message1 = vec![1, 2, 3, 4, 5];
+        let message2 = vec![6, 7, 8, 9, 10];
+
+        let signature1 = Bls::sign(&message1, &sign_key1).unwrap();
+        let signature2 = Bls::sign_with_ciphersuite(&message2, &sign_key2, &Ciphersuite::Basic).unwrap();
+
+        let invalid_idx = Bls::verify_batch(&[&signature1, &signature2], &[message1.as_slice(), message2.as_slice()],
+                                            &[&ver_key1, &ver_key2], &gen).unwrap();
+        assert_eq!(Some(1), invalid_idx);
+    }
+
+    #[test]
+    fn bls_verify_pop_works_for_ietf_pop_over_compressed_ver_key() {
+        let gen = Generator::<E>::standard().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let ciphersuite = Ciphersuite::ProofOfPossession;
+
+        let pop = ProofOfPossession::new_with_ciphersuite(&ver_key, &sign_key, &ciphersuite).unwrap();
+        assert!(Bls::verify_pop_with_ciphersuite(&pop, &ver_key, &gen, &ciphersuite).unwrap());
+
+        let h = E::hash_to_g1(ver_key.as_compressed_bytes(), format!("BLS_POP_{}POP_", E::G1_HASH_SUITE).as_bytes()).unwrap();
+        assert_eq!(pop.as_bytes(), h.mul(&sign_key.group_order_element).unwrap().to_bytes().unwrap().as_slice());
+    }
+
+    #[test]
+    fn compressed_bytes_roundtrip_works() {
+        let gen = Generator::<E>::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let signature = Bls::sign(&[1, 2, 3], &sign_key).unwrap();
+
+        assert_eq!(gen.as_bytes(), Generator::<E>::from_compressed_bytes(gen.as_compressed_bytes()).unwrap().as_bytes());
+        assert_eq!(sign_key.as_bytes(), SignKey::<E>::from_bytes(sign_key.as_bytes()).unwrap().as_bytes());
+        assert_eq!(ver_key.as_bytes(), VerKey::<E>::from_compressed_bytes(ver_key.as_compressed_bytes()).unwrap().as_bytes());
+        assert_eq!(signature.as_bytes(), Signature::<E>::from_compressed_bytes(signature.as_compressed_bytes()).unwrap().as_bytes());
+        assert_eq!(signature.as_compressed_bytes(), Signature::<E>::from_bytes(signature.as_bytes()).unwrap().as_compressed_bytes());
+    }
+}
@@ -0,0 +1,918 @@
+//! Pedersen distributed key generation of threshold BLS sign key shares.
+//!
+//! Participants must exchange messages over authenticated channels: `Commitments` must be delivered
+//! with authenticated broadcast, so every participant gets the same commitments of the claimed sender.
+//! Shares are encrypted to recipient and complaints are signed with keys published in commitments,
+//! so they are authenticated as long as commitments are. Complaints and justifications must be broadcasted too,
+//! otherwise participants may disagree on the set of qualified participants.
+//!
+//! Types are generic over pairing engine like `bls::curve` types and use `pair::DefaultEngine` if it is omitted.
+
+use bls::curve::{Generator, VerKey};
+use bls::threshold::{SignKeyShare, VerKeyShare, eval_polynomial, index_to_group_order_element};
+use errors::IndyCryptoError;
+use pair::{DefaultEngine, PairingEngine, PairingPoint, PairingScalar, PairingTarget};
+use utils::json::{JsonEncodable, JsonDecodable};
+
+use serde::{Serialize, Deserialize};
+
+use sha2::{Sha256, Digest};
+
+use std::collections::{BTreeMap, BTreeSet};
+
+const SHARE_ENCRYPTION_DOMAIN: &'static [u8] = b"INDY_CRYPTO_BLS_DKG_SHARE";
+const COMPLAINT_DOMAIN: &'static [u8] = b"INDY_CRYPTO_BLS_DKG_COMPLAINT";
+
+/// Round 1 message broadcasted by every participant.
+/// Contains Feldman commitments to coefficients of participant polynomial, public key used to encrypt shares
+/// and public key used to verify complaints of participant.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(bound(serialize = "E::G2: Serialize", deserialize = "E::G2: Deserialize<'de>"))]
+pub struct Commitments<E: PairingEngine = DefaultEngine> {
+    sender: u32,
+    commitments: Vec<E::G2>,
+    encryption_key: E::G2,
+    complaint_key: E::G2
+}
+
+impl<E: PairingEngine> Commitments<E> {
+    pub fn sender(&self) -> u32 {
+        self.sender
+    }
+}
+
+impl<E: PairingEngine> JsonEncodable for Commitments<E> where E::G2: Serialize {}
+
+impl<'a, E: PairingEngine> JsonDecodable<'a> for Commitments<E> where E::G2: Deserialize<'a> {}
+
+/// Round 2 message sent by every participant to each other participant.
+/// Contains share of sender polynomial evaluated at recipient index and encrypted for recipient.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct EncryptedShare {
+    sender: u32,
+    recipient: u32,
+    ciphertext: Vec<u8>
+}
+
+impl EncryptedShare {
+    pub fn sender(&self) -> u32 {
+        self.sender
+    }
+
+    pub fn recipient(&self) -> u32 {
+        self.recipient
+    }
+}
+
+impl JsonEncodable for EncryptedShare {}
+
+impl<'a> JsonDecodable<'a> for EncryptedShare {}
+
+/// Round 3 message broadcasted by participant that received missed or invalid share.
+/// Signed with complaint key of sender, so nobody can complain on behalf of other participant.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(bound(serialize = "E::G1: Serialize", deserialize = "E::G1: Deserialize<'de>"))]
+pub struct Complaint<E: PairingEngine = DefaultEngine> {
+    sender: u32,
+    accused: u32,
+    signature: E::G1
+}
+
+impl<E: PairingEngine> Complaint<E> {
+    pub fn sender(&self) -> u32 {
+        self.sender
+    }
+
+    pub fn accused(&self) -> u32 {
+        self.accused
+    }
+}
+
+impl<E: PairingEngine> JsonEncodable for Complaint<E> where E::G1: Serialize {}
+
+impl<'a, E: PairingEngine> JsonDecodable<'a> for Complaint<E> where E::G1: Deserialize<'a> {}
+
+/// Round 3 answer broadcasted by accused participant.
+/// Reveals share for complaining participant, so anyone can check it against commitments.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(bound(serialize = "E::Scalar: Serialize", deserialize = "E::Scalar: Deserialize<'de>"))]
+pub struct Justification<E: PairingEngine = DefaultEngine> {
+    sender: u32,
+    recipient: u32,
+    share: E::Scalar
+}
+
+impl<E: PairingEngine> Justification<E> {
+    pub fn sender(&self) -> u32 {
+        self.sender
+    }
+
+    pub fn recipient(&self) -> u32 {
+        self.recipient
+    }
+}
+
+impl<E: PairingEngine> JsonEncodable for Justification<E> where E::Scalar: Serialize {}
+
+impl<'a, E: PairingEngine> JsonDecodable<'a> for Justification<E> where E::Scalar: Deserialize<'a> {}
+
+/// Result of finished key generation for one participant.
+#[derive(Debug)]
+pub struct DistributedKey<E: PairingEngine = DefaultEngine> {
+    qualified: Vec<u32>,
+    sign_key_share: SignKeyShare<E>,
+    ver_key: VerKey<E>,
+    ver_key_shares: Vec<VerKeyShare<E>>
+}
+
+impl<E: PairingEngine> DistributedKey<E> {
+    /// Returns indexes of participants whose polynomials were used to build the key.
+    pub fn qualified(&self) -> &[u32] {
+        self.qualified.as_slice()
+    }
+
+    /// Returns sign key share of this participant.
+    pub fn sign_key_share(&self) -> &SignKeyShare<E> {
+        &self.sign_key_share
+    }
+
+    /// Returns group ver key. Combined threshold signatures are verified against it.
+    pub fn ver_key(&self) -> &VerKey<E> {
+        &self.ver_key
+    }
+
+    /// Returns ver key shares of all participants ordered by index.
+    pub fn ver_key_shares(&self) -> &[VerKeyShare<E>] {
+        self.ver_key_shares.as_slice()
+    }
+}
+
+/// Participant of Pedersen distributed key generation with Feldman verifiable secret sharing.
+/// Nobody learns group sign key, every participant gets only share of it.
+///
+/// Protocol runs in rounds:
+///
+/// 1. Every participant broadcasts `Commitments`.
+/// 2. Every participant sends `EncryptedShare` to each other participant.
+/// 3. Every participant broadcasts `Complaint` for each missed or invalid share.
+///    Accused participants answer with `Justification` unless `threshold` or more participants complained,
+///    as revealing that many shares would reveal their polynomial.
+/// 4. Every participant finalizes key generation and gets `DistributedKey`.
+pub struct Participant<E: PairingEngine = DefaultEngine> {
+    index: u32,
+    threshold: u32,
+    participants_count: u32,
+    gen: E::G2,
+    encryption_key: E::Scalar,
+    complaint_key: E::Scalar,
+    coefficients: Vec<E::Scalar>,
+    commitments: Option<BTreeMap<u32, Commitments<E>>>,
+    shares: Option<BTreeMap<u32, E::Scalar>>
+}
+
+impl_secret_debug!(Participant<E: PairingEngine> { index, threshold, participants_count, gen, commitments } secret { encryption_key, complaint_key, coefficients, shares });
+
+impl<E: PairingEngine> Participant<E> {
+    /// Creates and returns participant of key generation.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of participant (from 1 to `participants_count`)
+    /// * `threshold` - Number of shares required to create signature
+    /// * `participants_count` - Total number of participants
+    /// * `gen` - Generator point
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// use indy_crypto::bls::dkg::*;
+    /// let gen = Generator::new().unwrap();
+    /// Participant::new(1, 2, 3, &gen).unwrap();
+    /// ```
+    pub fn new(index: u32, threshold: u32, participants_count: u32, gen: &Generator<E>) -> Result<Participant<E>, IndyCryptoError> {
+        if threshold == 0 || threshold > participants_count {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("Invalid threshold: {} of {}", threshold, participants_count)));
+        }
+
+        if index == 0 || index > participants_count {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("Invalid participant index: {}", index)));
+        }
+
+        let mut coefficients: Vec<E::Scalar> = Vec::new();
+        for _ in 0..threshold {
+            coefficients.push(E::Scalar::new()?);
+        }
+
+        Ok(Participant {
+            index,
+            threshold,
+            participants_count,
+            gen: gen.point,
+            encryption_key: E::Scalar::new()?,
+            complaint_key: E::Scalar::new()?,
+            coefficients,
+            commitments: None,
+            shares: None
+        })
+    }
+
+    /// Returns index of participant.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Round 1. Returns commitments that must be broadcasted to all participants.
+    pub fn commitments(&self) -> Result<Commitments<E>, IndyCryptoError> {
+        let mut commitments: Vec<E::G2> = Vec::new();
+        for coefficient in self.coefficients.iter() {
+            commitments.push(self.gen.mul(coefficient)?);
+        }
+
+        Ok(Commitments {
+            sender: self.index,
+            commitments,
+            encryption_key: self.gen.mul(&self.encryption_key)?,
+            complaint_key: self.gen.mul(&self.complaint_key)?
+        })
+    }
+
+    /// Round 2. Accepts commitments of all participants (including own)
+    /// and returns encrypted shares that must be sent to corresponding recipients.
+    /// Participants with missed or malformed commitments are excluded from key generation.
+    pub fn encrypted_shares(&mut self, commitments: &[&Commitments<E>]) -> Result<Vec<EncryptedShare>, IndyCryptoError> {
+        if self.commitments.is_some() {
+            return Err(IndyCryptoError::InvalidState(format!("Commitments are already accepted")));
+        }
+
+        let mut accepted: BTreeMap<u32, Commitments<E>> = BTreeMap::new();
+        for commitment in commitments {
+            if commitment.sender == 0 || commitment.sender > self.participants_count ||
+                commitment.commitments.len() != self.threshold as usize ||
+                accepted.contains_key(&commitment.sender) {
+                continue;
+            }
+            accepted.insert(commitment.sender, (*commitment).clone());
+        }
+
+        if !accepted.contains_key(&self.index) {
+            return Err(IndyCryptoError::InvalidStructure(format!("Own commitments are missed")));
+        }
+
+        let mut shares: Vec<EncryptedShare> = Vec::new();
+        for (recipient, commitment) in accepted.iter() {
+            if *recipient == self.index {
+                continue;
+            }
+
+            let share = eval_polynomial(&self.coefficients, *recipient)?;
+            shares.push(EncryptedShare {
+                sender: self.index,
+                recipient: *recipient,
+                ciphertext: self._encrypt(&share, &commitment.encryption_key, self.index, *recipient)?
+            });
+        }
+
+        self.commitments = Some(accepted);
+
+        Ok(shares)
+    }
+
+    /// Round 3. Accepts encrypted shares sent to this participant
+    /// and returns complaints against participants that sent missed or invalid shares.
+    pub fn complaints(&mut self, encrypted_shares: &[&EncryptedShare]) -> Result<Vec<Complaint<E>>, IndyCryptoError> {
+        if self.shares.is_some() {
+            return Err(IndyCryptoError::InvalidState(format!("Shares are already accepted")));
+        }
+
+        let mut shares: BTreeMap<u32, E::Scalar> = BTreeMap::new();
+        let mut complaints: Vec<Complaint<E>> = Vec::new();
+
+        {
+            let commitments = self._commitments()?;
+
+            for encrypted_share in encrypted_shares {
+                if encrypted_share.recipient != self.index || shares.contains_key(&encrypted_share.sender) {
+                    continue;
+                }
+
+                let commitment = match commitments.get(&encrypted_share.sender) {
+                    Some(commitment) => commitment,
+                    None => continue
+                };
+
+                let share = self._decrypt(&encrypted_share.ciphertext, &commitment.encryption_key,
+                                          encrypted_share.sender, self.index)?;
+
+                if let Some(share) = share {
+                    if _verify_share(&self.gen, &share, commitment, self.index)? {
+                        shares.insert(encrypted_share.sender, share);
+                    }
+                }
+            }
+
+            for sender in commitments.keys() {
+                if *sender == self.index {
+                    shares.insert(self.index, eval_polynomial(&self.coefficients, self.index)?);
+                } else if !shares.contains_key(sender) {
+                    complaints.push(self._complaint(*sender)?);
+                }
+            }
+        }
+
+        self.shares = Some(shares);
+
+        Ok(complaints)
+    }
+
+    /// Round 3 answer. Accepts complaints of all participants
+    /// and returns justifications for complaints against this participant.
+    /// Complaints with invalid signature are ignored. If `threshold` or more participants complained,
+    /// no justifications are returned as this participant is excluded from key generation anyway.
+    pub fn justifications(&self, complaints: &[&Complaint<E>]) -> Result<Vec<Justification<E>>, IndyCryptoError> {
+        let recipients = self._valid_complaints(complaints)?.remove(&self.index).unwrap_or_default();
+
+        // Revealing `threshold` shares would reveal the whole polynomial
+        if recipients.len() >= self.threshold as usize {
+            return Ok(Vec::new());
+        }
+
+        let mut justifications: Vec<Justification<E>> = Vec::new();
+        for recipient in recipients {
+            justifications.push(Justification {
+                sender: self.index,
+                recipient,
+                share: eval_polynomial(&self.coefficients, recipient)?
+            });
+        }
+
+        Ok(justifications)
+    }
+
+    /// Round 4. Accepts complaints and justifications of all participants
+    /// and returns sign key share of this participant and group ver key.
+    /// Participants that didn't answer complaint with valid justification or got complaints
+    /// from `threshold` or more participants are excluded from key generation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// use indy_crypto::bls::dkg::*;
+    /// use indy_crypto::bls::threshold::*;
+    ///
+    /// let gen = Generator::new().unwrap();
+    /// let mut participants: Vec<Participant> = (1..4).map(|i| Participant::new(i, 2, 3, &gen).unwrap()).collect();
+    ///
+    /// let commitments: Vec<Commitments> = participants.iter().map(|p| p.commitments().unwrap()).collect();
+    /// let commitments: Vec<&Commitments> = commitments.iter().collect();
+    ///
+    /// let mut encrypted_shares: Vec<EncryptedShare> = Vec::new();
+    /// for participant in participants.iter_mut() {
+    ///     encrypted_shares.extend(participant.encrypted_shares(&commitments).unwrap());
+    /// }
+    /// let encrypted_shares: Vec<&EncryptedShare> = encrypted_shares.iter().collect();
+    ///
+    /// let mut complaints: Vec<Complaint> = Vec::new();
+    /// for participant in participants.iter_mut() {
+    ///     complaints.extend(participant.complaints(&encrypted_shares).unwrap());
+    /// }
+    /// let complaints: Vec<&Complaint> = complaints.iter().collect();
+    ///
+    /// let mut justifications: Vec<Justification> = Vec::new();
+    /// for participant in participants.iter() {
+    ///     justifications.extend(participant.justifications(&complaints).unwrap());
+    /// }
+    /// let justifications: Vec<&Justification> = justifications.iter().collect();
+    ///
+    /// let keys: Vec<DistributedKey> =
+    ///     participants.iter().map(|p| p.finalize(&complaints, &justifications).unwrap()).collect();
+    ///
+    /// let message = vec![1, 2, 3, 4, 5];
+    /// let signature_share1 = Threshold::sign(&message, keys[0].sign_key_share()).unwrap();
+    /// let signature_share3 = Threshold::sign(&message, keys[2].sign_key_share()).unwrap();
+    /// let signature = Threshold::combine(&[&signature_share1, &signature_share3]).unwrap();
+    ///
+    /// assert!(Bls::verify(&signature, &message, keys[0].ver_key(), &gen).unwrap());
+    /// ```
+    pub fn finalize(&self, complaints: &[&Complaint<E>], justifications: &[&Justification<E>]) -> Result<DistributedKey<E>, IndyCryptoError> {
+        let commitments = self._commitments()?;
+        let mut shares = self.shares.clone()
+            .ok_or(IndyCryptoError::InvalidState(format!("Shares are not accepted yet")))?;
+
+        let mut qualified: BTreeSet<u32> = commitments.keys().cloned().collect();
+
+        for (accused, senders) in self._valid_complaints(complaints)? {
+            if senders.len() >= self.threshold as usize {
+                qualified.remove(&accused);
+                continue;
+            }
+
+            for sender in senders {
+                let mut justified = false;
+                for justification in justifications {
+                    if justification.sender != accused || justification.recipient != sender {
+                        continue;
+                    }
+
+                    if _verify_share(&self.gen, &justification.share, &commitments[&accused], sender)? {
+                        justified = true;
+                        if sender == self.index {
+                            shares.insert(accused, justification.share.clone());
+                        }
+                        break;
+                    }
+                }
+
+                if !justified {
+                    qualified.remove(&accused);
+                }
+            }
+        }
+
+        if qualified.is_empty() {
+            return Err(IndyCryptoError::InvalidState(format!("No qualified participants")));
+        }
+
+        let mut sign_key_share = E::Scalar::from_bytes(&[0])?;
+        let mut ver_key_point = E::G2::new_inf()?;
+        for dealer in qualified.iter() {
+            let share = shares.get(dealer)
+                .ok_or(IndyCryptoError::InvalidState(format!("Share of participant {} is missed", dealer)))?;
+
+            sign_key_share = sign_key_share.add_mod(share)?;
+            ver_key_point = ver_key_point.add(&commitments[dealer].commitments[0])?;
+        }
+
+        let mut ver_key_shares: Vec<VerKeyShare<E>> = Vec::new();
+        for index in 1..(self.participants_count + 1) {
+            let mut point = E::G2::new_inf()?;
+            for dealer in qualified.iter() {
+                point = point.add(&_eval_commitments(&commitments[dealer].commitments, index)?)?;
+            }
+            ver_key_shares.push(VerKeyShare::from_point(index, point)?);
+        }
+
+        Ok(DistributedKey {
+            qualified: qualified.into_iter().collect(),
+            sign_key_share: SignKeyShare::new(self.index, sign_key_share)?,
+            ver_key: VerKey {
+                point: ver_key_point,
+                bytes: ver_key_point.to_bytes()?,
+                compressed_bytes: ver_key_point.to_compressed_bytes()?
+            },
+            ver_key_shares
+        })
+    }
+
+    fn _commitments(&self) -> Result<&BTreeMap<u32, Commitments<E>>, IndyCryptoError> {
+        self.commitments.as_ref()
+            .ok_or(IndyCryptoError::InvalidState(format!("Commitments are not accepted yet")))
+    }
+
+    fn _complaint(&self, accused: u32) -> Result<Complaint<E>, IndyCryptoError> {
+        Ok(Complaint {
+            sender: self.index,
+            accused,
+            signature: _complaint_hash::<E>(self.index, accused)?.mul(&self.complaint_key)?
+        })
+    }
+
+    /// Returns senders of complaints with valid signature grouped by accused participant.
+    /// Complaints that the accused can't answer with justification are ignored.
+    fn _valid_complaints(&self, complaints: &[&Complaint<E>]) -> Result<BTreeMap<u32, BTreeSet<u32>>, IndyCryptoError> {
+        let commitments = self._commitments()?;
+        let mut valid: BTreeMap<u32, BTreeSet<u32>> = BTreeMap::new();
+
+        for complaint in complaints {
+            if complaint.sender == complaint.accused || !commitments.contains_key(&complaint.accused) {
+                continue;
+            }
+
+            let commitment = match commitments.get(&complaint.sender) {
+                Some(commitment) => commitment,
+                None => continue
+            };
+
+            let h = _complaint_hash::<E>(complaint.sender, complaint.accused)?;
+            if E::multi_pair(&[(&complaint.signature, &self.gen), (&h.neg()?, &commitment.complaint_key)])?.is_unity()? {
+                valid.entry(complaint.accused).or_default().insert(complaint.sender);
+            }
+        }
+
+        Ok(valid)
+    }
+
+    fn _encrypt(&self, share: &E::Scalar, encryption_key: &E::G2, sender: u32, recipient: u32) -> Result<Vec<u8>, IndyCryptoError> {
+        let plaintext = share.to_bytes()?;
+        let keystream = _keystream(&encryption_key.mul(&self.encryption_key)?, sender, recipient, plaintext.len())?;
+
+        Ok(plaintext.iter().zip(keystream.iter()).map(|(p, k)| p ^ k).collect())
+    }
+
+    fn _decrypt(&self, ciphertext: &[u8], encryption_key: &E::G2, sender: u32, recipient: u32) -> Result<Option<E::Scalar>, IndyCryptoError> {
+        let keystream = _keystream(&encryption_key.mul(&self.encryption_key)?, sender, recipient, ciphertext.len())?;
+        let plaintext: Vec<u8> = ciphertext.iter().zip(keystream.iter()).map(|(c, k)| c ^ k).collect();
+
+        Ok(E::Scalar::from_bytes(&plaintext).ok())
+    }
+}
+
+fn _keystream<P: PairingPoint>(shared_point: &P, sender: u32, recipient: u32, len: usize) -> Result<Vec<u8>, IndyCryptoError> {
+    let shared_bytes = shared_point.to_bytes()?;
+
+    let mut keystream: Vec<u8> = Vec::new();
+    let mut counter: u32 = 0;
+    while keystream.len() < len {
+        let mut hasher = Sha256::default();
+        hasher.input(SHARE_ENCRYPTION_DOMAIN);
+        hasher.input(&shared_bytes);
+        hasher.input(&_u32_to_bytes(sender));
+        hasher.input(&_u32_to_bytes(recipient));
+        hasher.input(&_u32_to_bytes(counter));
+        keystream.extend_from_slice(hasher.result().as_slice());
+        counter += 1;
+    }
+    keystream.truncate(len);
+
+    Ok(keystream)
+}
+
+fn _complaint_hash<E: PairingEngine>(sender: u32, accused: u32) -> Result<E::G1, IndyCryptoError> {
+    let mut message = _u32_to_bytes(sender).to_vec();
+    message.extend_from_slice(&_u32_to_bytes(accused));
+
+    E::hash_to_g1(&message, COMPLAINT_DOMAIN)
+}
+
+fn _u32_to_bytes(value: u32) -> [u8; 4] {
+    [(value >> 24) as u8, (value >> 16) as u8, (value >> 8) as u8, value as u8]
+}
+
+fn _eval_commitments<P: PairingPoint>(commitments: &[P], x: u32) -> Result<P, IndyCryptoError> {
+    let x: P::Scalar = index_to_group_order_element(x)?;
+
+    // Starts from the highest coefficient as multiplication of infinity point is not supported by all backends
+    let (last, rest) = commitments.split_last()
+        .ok_or(IndyCryptoError::InvalidStructure(format!("Empty list of commitments")))?;
+
+    let mut res = *last;
+    for commitment in rest.iter().rev() {
+        res = res.mul(&x)?.add(commitment)?;
+    }
+
+    Ok(res)
+}
+
+fn _verify_share<E: PairingEngine>(gen: &E::G2, share: &E::Scalar, commitments: &Commitments<E>, index: u32) -> Result<bool, IndyCryptoError> {
+    Ok(gen.mul(share)?.to_bytes()? == _eval_commitments(&commitments.commitments, index)?.to_bytes()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bls::{Bls, Generator};
+    use bls::threshold::{Threshold, SignatureShare};
+    use pair::GroupOrderElement;
+
+    const MESSAGE: &'static [u8] = &[1, 2, 3, 4, 5];
+
+    fn _participants(gen: &Generator, threshold: u32, participants_count: u32) -> Vec<Participant> {
+        (1..participants_count + 1).map(|i| Participant::new(i, threshold, participants_count, gen).unwrap()).collect()
+    }
+
+    fn _commitments(participants: &[Participant]) -> Vec<Commitments> {
+        participants.iter().map(|p| p.commitments().unwrap()).collect()
+    }
+
+    fn _encrypted_shares(participants: &mut [Participant], commitments: &[Commitments]) -> Vec<EncryptedShare> {
+        let commitments: Vec<&Commitments> = commitments.iter().collect();
+        let mut encrypted_shares: Vec<EncryptedShare> = Vec::new();
+        for participant in participants.iter_mut() {
+            encrypted_shares.extend(participant.encrypted_shares(&commitments).unwrap());
+        }
+        encrypted_shares
+    }
+
+    fn _complaints(participants: &mut [Participant], encrypted_shares: &[EncryptedShare]) -> Vec<Complaint> {
+        let encrypted_shares: Vec<&EncryptedShare> = encrypted_shares.iter().collect();
+        let mut complaints: Vec<Complaint> = Vec::new();
+        for participant in participants.iter_mut() {
+            complaints.extend(participant.complaints(&encrypted_shares).unwrap());
+        }
+        complaints
+    }
+
+    fn _justifications(participants: &[Participant], complaints: &[Complaint]) -> Vec<Justification> {
+        let complaints: Vec<&Complaint> = complaints.iter().collect();
+        let mut justifications: Vec<Justification> = Vec::new();
+        for participant in participants.iter() {
+            justifications.extend(participant.justifications(&complaints).unwrap());
+        }
+        justifications
+    }
+
+    fn _finalize(participants: &[Participant], complaints: &[Complaint], justifications: &[Justification]) -> Vec<DistributedKey> {
+        let complaints: Vec<&Complaint> = complaints.iter().collect();
+        let justifications: Vec<&Justification> = justifications.iter().collect();
+        participants.iter().map(|p| p.finalize(&complaints, &justifications).unwrap()).collect()
+    }
+
+    fn _sign_and_combine(keys: &[&DistributedKey]) -> ::bls::Signature {
+        let signature_shares: Vec<SignatureShare> =
+            keys.iter().map(|key| Threshold::sign(MESSAGE, key.sign_key_share()).unwrap()).collect();
+        let signature_shares: Vec<&SignatureShare> = signature_shares.iter().collect();
+        Threshold::combine(&signature_shares).unwrap()
+    }
+
+    #[test]
+    fn participant_new_works_for_invalid_params() {
+        let gen = Generator::new().unwrap();
+        assert!(Participant::new(1, 0, 3, &gen).is_err());
+        assert!(Participant::new(1, 4, 3, &gen).is_err());
+        assert!(Participant::new(0, 2, 3, &gen).is_err());
+        assert!(Participant::new(4, 2, 3, &gen).is_err());
+    }
+
+    #[test]
+    fn dkg_works() {
+        let gen = Generator::new().unwrap();
+        let mut participants = _participants(&gen, 3, 5);
+
+        let commitments = _commitments(&participants);
+        let encrypted_shares = _encrypted_shares(&mut participants, &commitments);
+        let complaints = _complaints(&mut participants, &encrypted_shares);
+        assert!(complaints.is_empty());
+
+        let justifications = _justifications(&participants, &complaints);
+        assert!(justifications.is_empty());
+
+        let keys = _finalize(&participants, &complaints, &justifications);
+
+        for key in keys.iter() {
+            assert_eq!(vec![1, 2, 3, 4, 5], key.qualified());
+            assert_eq!(keys[0].ver_key().as_bytes(), key.ver_key().as_bytes());
+            assert_eq!(5, key.ver_key_shares().len());
+        }
+
+        let signature = _sign_and_combine(&[&keys[4], &keys[0], &keys[2]]);
+        assert!(Bls::verify(&signature, MESSAGE, keys[1].ver_key(), &gen).unwrap());
+
+        let signature = _sign_and_combine(&[&keys[0], &keys[1]]);
+        assert!(!Bls::verify(&signature, MESSAGE, keys[1].ver_key(), &gen).unwrap());
+    }
+
+    #[test]
+    fn dkg_works_for_ver_key_shares() {
+        let gen = Generator::new().unwrap();
+        let mut participants = _participants(&gen, 2, 3);
+
+        let commitments = _commitments(&participants);
+        let encrypted_shares = _encrypted_shares(&mut participants, &commitments);
+        let complaints = _complaints(&mut participants, &encrypted_shares);
+        let justifications = _justifications(&participants, &complaints);
+        let keys = _finalize(&participants, &complaints, &justifications);
+
+        for key in keys.iter() {
+            let signature_share = Threshold::sign(MESSAGE, key.sign_key_share()).unwrap();
+            let ver_key_share = &keys[0].ver_key_shares()[(key.sign_key_share().index() - 1) as usize];
+            assert!(Threshold::verify_share(&signature_share, MESSAGE, ver_key_share, &gen).unwrap());
+        }
+    }
+
+    #[test]
+    fn dkg_works_for_justified_complaint() {
+        let gen = Generator::new().unwrap();
+        let mut participants = _participants(&gen, 2, 3);
+
+        let commitments = _commitments(&participants);
+        let mut encrypted_shares = _encrypted_shares(&mut participants, &commitments);
+
+        // Participant 1 sends corrupted share to participant 2
+        for encrypted_share in encrypted_shares.iter_mut() {
+            if encrypted_share.sender == 1 && encrypted_share.recipient == 2 {
+                encrypted_share.ciphertext[31] ^= 1;
+            }
+        }
+
+        let complaints = _complaints(&mut participants, &encrypted_shares);
+        assert_eq!(1, complaints.len());
+        assert_eq!(2, complaints[0].sender());
+        assert_eq!(1, complaints[0].accused());
+
+        let justifications = _justifications(&participants, &complaints);
+        assert_eq!(1, justifications.len());
+
+        let keys = _finalize(&participants, &complaints, &justifications);
+        assert_eq!(vec![1, 2, 3], keys[1].qualified());
+
+        let signature = _sign_and_combine(&[&keys[0], &keys[1]]);
+        assert!(Bls::verify(&signature, MESSAGE, keys[2].ver_key(), &gen).unwrap());
+    }
+
+    #[test]
+    fn dkg_works_for_unjustified_complaint() {
+        let gen = Generator::new().unwrap();
+        let mut participants = _participants(&gen, 2, 3);
+
+        let commitments = _commitments(&participants);
+        let encrypted_shares = _encrypted_shares(&mut participants, &commitments);
+
+        // Participant 3 doesn't send share to participant 1 and doesn't answer the complaint
+        let encrypted_shares: Vec<EncryptedShare> = encrypted_shares.into_iter()
+            .filter(|share| !(share.sender == 3 && share.recipient == 1))
+            .collect();
+
+        let complaints = _complaints(&mut participants, &encrypted_shares);
+        assert_eq!(1, complaints.len());
+
+        let keys = _finalize(&participants, &complaints, &[]);
+
+        for key in keys.iter() {
+            assert_eq!(vec![1, 2], key.qualified());
+            assert_eq!(keys[0].ver_key().as_bytes(), key.ver_key().as_bytes());
+        }
+
+        let signature = _sign_and_combine(&[&keys[0], &keys[2]]);
+        assert!(Bls::verify(&signature, MESSAGE, keys[1].ver_key(), &gen).unwrap());
+    }
+
+    #[test]
+    fn dkg_works_for_complaint_with_invalid_sender() {
+        let gen = Generator::new().unwrap();
+        let mut participants = _participants(&gen, 2, 3);
+
+        let commitments = _commitments(&participants);
+        let encrypted_shares = _encrypted_shares(&mut participants, &commitments);
+
+        let complaints = _complaints(&mut participants, &encrypted_shares);
+        assert!(complaints.is_empty());
+
+        // Forged complaints against honest participant 1 that it is unable to answer
+        let complaint = |sender: u32| {
+            let mut complaint = participants[1]._complaint(1).unwrap();
+            complaint.sender = sender;
+            complaint
+        };
+        let complaints = vec![complaint(0), complaint(4), complaint(1)];
+
+        let justifications = _justifications(&participants, &complaints);
+        assert!(justifications.is_empty());
+
+        let keys = _finalize(&participants, &complaints, &justifications);
+
+        for key in keys.iter() {
+            assert_eq!(vec![1, 2, 3], key.qualified());
+        }
+
+        let signature = _sign_and_combine(&[&keys[0], &keys[1]]);
+        assert!(Bls::verify(&signature, MESSAGE, keys[2].ver_key(), &gen).unwrap());
+    }
+
+    #[test]
+    fn dkg_works_for_forged_complaint() {
+        let gen = Generator::new().unwrap();
+        let mut participants = _participants(&gen, 2, 4);
+
+        let commitments = _commitments(&participants);
+        let encrypted_shares = _encrypted_shares(&mut participants, &commitments);
+
+        let complaints = _complaints(&mut participants, &encrypted_shares);
+        assert!(complaints.is_empty());
+
+        // Participant 4 complains against participant 3 on behalf of participants 1 and 2
+        let mut forged1 = participants[3]._complaint(3).unwrap();
+        forged1.sender = 1;
+        let mut forged2 = participants[3]._complaint(3).unwrap();
+        forged2.sender = 2;
+
+        // Signature of complaint of participant 1 against participant 2 is replayed against participant 3
+        let mut replayed = participants[0]._complaint(2).unwrap();
+        replayed.accused = 3;
+
+        let complaints = vec![forged1, forged2, replayed];
+
+        let justifications = _justifications(&participants, &complaints);
+        assert!(justifications.is_empty());
+
+        let keys = _finalize(&participants, &complaints, &justifications);
+
+        for key in keys.iter() {
+            assert_eq!(vec![1, 2, 3, 4], key.qualified());
+        }
+
+        let signature = _sign_and_combine(&[&keys[0], &keys[2]]);
+        assert!(Bls::verify(&signature, MESSAGE, keys[3].ver_key(), &gen).unwrap());
+    }
+
+    #[test]
+    fn dkg_works_for_threshold_complaints() {
+        let gen = Generator::new().unwrap();
+        let mut participants = _participants(&gen, 2, 4);
+
+        let commitments = _commitments(&participants);
+        let mut encrypted_shares = _encrypted_shares(&mut participants, &commitments);
+
+        // Participant 1 sends corrupted shares to participants 2 and 3
+        for encrypted_share in encrypted_shares.iter_mut() {
+            if encrypted_share.sender == 1 && (encrypted_share.recipient == 2 || encrypted_share.recipient == 3) {
+                encrypted_share.ciphertext[31] ^= 1;
+            }
+        }
+
+        let complaints = _complaints(&mut participants, &encrypted_shares);
+        assert_eq!(2, complaints.len());
+
+        // Participant 1 doesn't reveal its polynomial by answering threshold complaints
+        let justifications = _justifications(&participants, &complaints);
+        assert!(justifications.is_empty());
+
+        let keys = _finalize(&participants, &complaints, &justifications);
+
+        for key in keys.iter() {
+            assert_eq!(vec![2, 3, 4], key.qualified());
+            assert_eq!(keys[0].ver_key().as_bytes(), key.ver_key().as_bytes());
+        }
+
+        let signature = _sign_and_combine(&[&keys[1], &keys[3]]);
+        assert!(Bls::verify(&signature, MESSAGE, keys[0].ver_key(), &gen).unwrap());
+    }
+
+    #[test]
+    fn dkg_works_for_serialized_messages() {
+        let gen = Generator::new().unwrap();
+        let mut participants = _participants(&gen, 2, 3);
+
+        let commitments: Vec<Commitments> = _commitments(&participants).iter()
+            .map(|c| Commitments::from_json(&c.to_json().unwrap()).unwrap())
+            .collect();
+
+        let encrypted_shares: Vec<EncryptedShare> = _encrypted_shares(&mut participants, &commitments).iter()
+            .map(|s| EncryptedShare::from_json(&s.to_json().unwrap()).unwrap())
+            .collect();
+
+        let complaints = _complaints(&mut participants, &encrypted_shares);
+        let keys = _finalize(&participants, &complaints, &[]);
+
+        let signature = _sign_and_combine(&[&keys[1], &keys[2]]);
+        assert!(Bls::verify(&signature, MESSAGE, keys[0].ver_key(), &gen).unwrap());
+    }
+
+    #[test]
+    fn complaint_and_justification_serialization_works() {
+        let gen = Generator::new().unwrap();
+        let participant = Participant::new(2, 2, 3, &gen).unwrap();
+
+        let complaint = participant._complaint(1).unwrap();
+        let deserialized: Complaint = Complaint::from_json(&complaint.to_json().unwrap()).unwrap();
+        assert_eq!(2, deserialized.sender());
+        assert_eq!(1, deserialized.accused());
+        assert_eq!(complaint.signature, deserialized.signature);
+
+        let justification: Justification = Justification { sender: 1, recipient: 2, share: GroupOrderElement::new().unwrap() };
+        let deserialized: Justification = Justification::from_json(&justification.to_json().unwrap()).unwrap();
+        assert_eq!(justification.share, deserialized.share);
+    }
+
+    #[test]
+    fn encrypted_shares_works_for_missed_own_commitments() {
+        let gen = Generator::new().unwrap();
+        let mut participants = _participants(&gen, 2, 3);
+
+        let commitments = _commitments(&participants[1..]);
+        let commitments: Vec<&Commitments> = commitments.iter().collect();
+
+        assert!(participants[0].encrypted_shares(&commitments).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "pair_bls12_381")]
+    fn dkg_works_for_bls12_381() {
+        use bls::bls12_381;
+
+        let gen = bls12_381::Generator::standard().unwrap();
+        let mut participants: Vec<Participant<_>> = (1..4).map(|i| Participant::new(i, 2, 3, &gen).unwrap()).collect();
+
+        let commitments: Vec<Commitments<_>> = participants.iter().map(|p| p.commitments().unwrap()).collect();
+        let commitments: Vec<&Commitments<_>> = commitments.iter().collect();
+
+        let mut encrypted_shares: Vec<EncryptedShare> = Vec::new();
+        for participant in participants.iter_mut() {
+            encrypted_shares.extend(participant.encrypted_shares(&commitments).unwrap());
+        }
+        let encrypted_shares: Vec<&EncryptedShare> = encrypted_shares.iter().collect();
+
+        for participant in participants.iter_mut() {
+            assert!(participant.complaints(&encrypted_shares).unwrap().is_empty());
+        }
+
+        let keys: Vec<DistributedKey<_>> = participants.iter().map(|p| p.finalize(&[], &[]).unwrap()).collect();
+
+        let signature_share1 = Threshold::sign(MESSAGE, keys[0].sign_key_share()).unwrap();
+        let signature_share2 = Threshold::sign(MESSAGE, keys[1].sign_key_share()).unwrap();
+        let signature = Threshold::combine(&[&signature_share1, &signature_share2]).unwrap();
+        assert!(bls12_381::Bls::verify(&signature, MESSAGE, keys[2].ver_key(), &gen).unwrap());
+    }
+}
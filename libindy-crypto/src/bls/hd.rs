@@ -0,0 +1,334 @@
+use bls::curve::SignKey;
+use errors::IndyCryptoError;
+use pair::{DefaultEngine, PairingEngine, PairingScalar};
+
+use sha2::{Sha256, Digest};
+
+use std::marker::PhantomData;
+
+/// Salt of HKDF_mod_r (hashed before every use).
+const KEYGEN_SALT: &'static [u8] = b"BLS-SIG-KEYGEN-SALT-";
+
+/// Minimal len of master seed.
+const MIN_SEED_LEN: usize = 32;
+
+/// Len of HKDF_mod_r output: ceil((3 * ceil(log2(r))) / 16) for 254 and 255 bits group orders.
+const HKDF_MOD_R_LEN: usize = 48;
+
+/// Number of chunks in each half of Lamport sign key.
+const LAMPORT_CHUNKS_COUNT: usize = 255;
+
+const SHA256_BLOCK_SIZE: usize = 64;
+const SHA256_OUTPUT_SIZE: usize = 32;
+
+/// Hierarchical deterministic derivation of BLS sign keys from a single seed.
+///
+/// Follows EIP-2333 tree structure (HKDF_mod_r for master key, Lamport one-time
+/// public key compression for child keys) with reduction modulo group order of pairing engine `E`,
+/// so derived keys match the ones of BLS12-381 implementations only for BLS12-381 engine.
+pub struct KeyDerivation<E: PairingEngine = DefaultEngine> {
+    _engine: PhantomData<E>
+}
+
+impl<E: PairingEngine> KeyDerivation<E> {
+    /// Derives master sign key (root of the derivation tree) from seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Secret seed, at least 32 bytes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::SignKey;
+    /// use indy_crypto::bls::hd::KeyDerivation;
+    /// let seed = [1u8; 32];
+    /// let sign_key: SignKey = KeyDerivation::derive_master_sign_key(&seed).unwrap();
+    /// ```
+    pub fn derive_master_sign_key(seed: &[u8]) -> Result<SignKey<E>, IndyCryptoError> {
+        if seed.len() < MIN_SEED_LEN {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("Invalid len of seed: expected at least {}, actual {}", MIN_SEED_LEN, seed.len())));
+        }
+
+        _sign_key(_hkdf_mod_r(seed)?)
+    }
+
+    /// Derives child sign key with given index from parent sign key.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - Parent sign key
+    /// * `index` - Index of the child
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::SignKey;
+    /// use indy_crypto::bls::hd::KeyDerivation;
+    /// let master: SignKey = KeyDerivation::derive_master_sign_key(&[1u8; 32]).unwrap();
+    /// KeyDerivation::derive_child_sign_key(&master, 0).unwrap();
+    /// ```
+    pub fn derive_child_sign_key(parent: &SignKey<E>, index: u32) -> Result<SignKey<E>, IndyCryptoError> {
+        let lamport_pk = _parent_sign_key_to_lamport_pk(&parent.group_order_element, index)?;
+        _sign_key(_hkdf_mod_r(&lamport_pk)?)
+    }
+
+    /// Derives sign key for path (like `m/12381/3600/0/0`) from seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Secret seed, at least 32 bytes
+    /// * `path` - Derivation path
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::SignKey;
+    /// use indy_crypto::bls::hd::KeyDerivation;
+    /// let sign_key: SignKey = KeyDerivation::derive_sign_key(&[1u8; 32], "m/12381/3600/0/0").unwrap();
+    /// ```
+    pub fn derive_sign_key(seed: &[u8], path: &str) -> Result<SignKey<E>, IndyCryptoError> {
+        let indexes = KeyDerivation::parse_path(path)?;
+
+        let mut sign_key = KeyDerivation::derive_master_sign_key(seed)?;
+        for index in indexes {
+            sign_key = KeyDerivation::derive_child_sign_key(&sign_key, index)?;
+        }
+
+        Ok(sign_key)
+    }
+}
+
+impl KeyDerivation {
+    /// Parses derivation path and returns indexes of children from the master key.
+    /// Path starts with `m` followed by `/`-separated decimal indexes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::hd::KeyDerivation;
+    /// assert_eq!(vec![12381, 3600, 0, 0], KeyDerivation::parse_path("m/12381/3600/0/0").unwrap());
+    /// assert!(KeyDerivation::parse_path("m").unwrap().is_empty());
+    /// ```
+    pub fn parse_path(path: &str) -> Result<Vec<u32>, IndyCryptoError> {
+        let mut parts = path.split('/');
+
+        if parts.next() != Some("m") {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("Invalid derivation path: {}, it must start with m", path)));
+        }
+
+        parts
+            .map(|part| {
+                if part.is_empty() || !part.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(IndyCryptoError::InvalidStructure(
+                        format!("Invalid derivation path: {}, index {} is not a number", path, part)));
+                }
+
+                part.parse::<u32>()
+                    .map_err(|_| IndyCryptoError::InvalidStructure(
+                        format!("Invalid derivation path: {}, index {} is out of range", path, part)))
+            })
+            .collect()
+    }
+}
+
+fn _sign_key<E: PairingEngine>(group_order_element: E::Scalar) -> Result<SignKey<E>, IndyCryptoError> {
+    let bytes = group_order_element.to_bytes()?;
+    Ok(SignKey {
+        group_order_element,
+        bytes
+    })
+}
+
+/// HKDF_mod_r from EIP-2333: hashes input key material to non-zero element of group order.
+fn _hkdf_mod_r<S: PairingScalar>(ikm: &[u8]) -> Result<S, IndyCryptoError> {
+    let mut ikm_prime = ikm.to_vec();
+    ikm_prime.push(0);
+
+    let info = [(HKDF_MOD_R_LEN >> 8) as u8, HKDF_MOD_R_LEN as u8];
+
+    let mut salt = KEYGEN_SALT.to_vec();
+    loop {
+        salt = _sha256(&salt);
+
+        let prk = _hkdf_extract(&salt, &ikm_prime);
+        let okm = _hkdf_expand(&prk, &info, HKDF_MOD_R_LEN);
+        let sk = S::from_wide_bytes(&okm)?;
+
+        if !sk.is_zero() {
+            return Ok(sk);
+        }
+    }
+}
+
+fn _parent_sign_key_to_lamport_pk<S: PairingScalar>(parent: &S, index: u32) -> Result<Vec<u8>, IndyCryptoError> {
+    let salt = [(index >> 24) as u8, (index >> 16) as u8, (index >> 8) as u8, index as u8];
+
+    let ikm = parent.to_bytes()?;
+    let not_ikm: Vec<u8> = ikm.iter().map(|b| !b).collect();
+
+    let mut hasher = Sha256::default();
+    for lamport_ikm in &[ikm, not_ikm] {
+        let okm = _hkdf_expand(&_hkdf_extract(&salt, lamport_ikm), &[], SHA256_OUTPUT_SIZE * LAMPORT_CHUNKS_COUNT);
+        for chunk in okm.chunks(SHA256_OUTPUT_SIZE) {
+            hasher.input(&_sha256(chunk));
+        }
+    }
+
+    Ok(hasher.result().to_vec())
+}
+
+fn _sha256(data: &[u8]) -> Vec<u8> {
+    let mut hasher = Sha256::default();
+    hasher.input(data);
+    hasher.result().to_vec()
+}
+
+/// HMAC-SHA256 (RFC 2104).
+fn _hmac_sha256(key: &[u8], data: &[&[u8]]) -> Vec<u8> {
+    let mut key = if key.len() > SHA256_BLOCK_SIZE { _sha256(key) } else { key.to_vec() };
+    key.resize(SHA256_BLOCK_SIZE, 0);
+
+    let mut inner = Sha256::default();
+    inner.input(&key.iter().map(|b| b ^ 0x36).collect::<Vec<u8>>());
+    for part in data {
+        inner.input(part);
+    }
+
+    let mut outer = Sha256::default();
+    outer.input(&key.iter().map(|b| b ^ 0x5c).collect::<Vec<u8>>());
+    outer.input(&inner.result());
+    outer.result().to_vec()
+}
+
+/// HKDF-Extract with SHA-256 (RFC 5869).
+fn _hkdf_extract(salt: &[u8], ikm: &[u8]) -> Vec<u8> {
+    _hmac_sha256(salt, &[ikm])
+}
+
+/// HKDF-Expand with SHA-256 (RFC 5869), `len` must not exceed 255 * 32.
+fn _hkdf_expand(prk: &[u8], info: &[u8], len: usize) -> Vec<u8> {
+    let blocks_count = (len + SHA256_OUTPUT_SIZE - 1) / SHA256_OUTPUT_SIZE;
+
+    let mut okm: Vec<u8> = Vec::new();
+    let mut t: Vec<u8> = Vec::new();
+    for counter in 1..(blocks_count + 1) {
+        t = _hmac_sha256(prk, &[&t, info, &[counter as u8]]);
+        okm.extend_from_slice(&t);
+    }
+    okm.truncate(len);
+    okm
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use errors::ToErrorCode;
+    use ffi::ErrorCode;
+    use pair::DefaultEngine;
+    #[cfg(feature = "pair_amcl")]
+    use pair::amcl::Bn254;
+    #[cfg(feature = "pair_bls12_381")]
+    use pair::bls12_381::Bls12381;
+
+    const SEED: [u8; 64] = [
+        0xc5, 0x52, 0x57, 0xc3, 0x60, 0xc0, 0x7c, 0x72, 0x02, 0x9a, 0xeb, 0xc1, 0xb5, 0x3c, 0x05, 0xed,
+        0x03, 0x62, 0xad, 0xa3, 0x8e, 0xad, 0x3e, 0x3e, 0x9e, 0xfa, 0x37, 0x08, 0xe5, 0x34, 0x95, 0x53,
+        0x1f, 0x09, 0xa6, 0x98, 0x75, 0x99, 0xd1, 0x82, 0x64, 0xc1, 0xe1, 0xc9, 0x2f, 0x2c, 0xf1, 0x41,
+        0x63, 0x0c, 0x7a, 0x3c, 0x4a, 0xb7, 0xc8, 0x1b, 0x2f, 0x00, 0x16, 0x98, 0xe7, 0x46, 0x3b, 0x04
+    ];
+
+    #[test]
+    fn hkdf_works() {
+        // RFC 5869, test case 1
+        let ikm = [0x0b; 22];
+        let salt = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c];
+        let info = [0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9];
+
+        let prk = _hkdf_extract(&salt, &ikm);
+        assert_eq!(vec![0x07, 0x77, 0x09, 0x36, 0x2c, 0x2e, 0x32, 0xdf, 0x0d, 0xdc, 0x3f, 0x0d, 0xc4, 0x7b, 0xba, 0x63,
+                        0x90, 0xb6, 0xc7, 0x3b, 0xb5, 0x0f, 0x9c, 0x31, 0x22, 0xec, 0x84, 0x4a, 0xd7, 0xc2, 0xb3, 0xe5], prk);
+
+        let okm = _hkdf_expand(&prk, &info, 42);
+        assert_eq!(vec![0x3c, 0xb2, 0x5f, 0x25, 0xfa, 0xac, 0xd5, 0x7a, 0x90, 0x43, 0x4f, 0x64, 0xd0, 0x36, 0x2f, 0x2a,
+                        0x2d, 0x2d, 0x0a, 0x90, 0xcf, 0x1a, 0x5a, 0x4c, 0x5d, 0xb0, 0x2d, 0x56, 0xec, 0xc4, 0xc5, 0xbf,
+                        0x34, 0x00, 0x72, 0x08, 0xd5, 0xb8, 0x87, 0x18, 0x58, 0x65], okm);
+    }
+
+    #[cfg(feature = "pair_amcl")]
+    #[test]
+    fn derive_master_sign_key_works() {
+        let sign_key = KeyDerivation::<Bn254>::derive_master_sign_key(&SEED).unwrap();
+        assert_eq!(vec![0x24, 0x6b, 0xce, 0x57, 0x84, 0x05, 0x1a, 0xb0, 0x83, 0xfe, 0x35, 0x40, 0x38, 0xf2, 0x94, 0xe1,
+                        0x9f, 0x43, 0xb8, 0x8a, 0xeb, 0xc4, 0xab, 0x35, 0x59, 0xf1, 0xf6, 0x4c, 0x16, 0xb7, 0x4d, 0x50], sign_key.as_bytes());
+    }
+
+    #[test]
+    fn derive_master_sign_key_works_for_short_seed() {
+        let err = KeyDerivation::<DefaultEngine>::derive_master_sign_key(&[1u8; 31]).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+    }
+
+    #[cfg(feature = "pair_amcl")]
+    #[test]
+    fn derive_child_sign_key_works() {
+        let master = KeyDerivation::<Bn254>::derive_master_sign_key(&SEED).unwrap();
+        let sign_key = KeyDerivation::<Bn254>::derive_child_sign_key(&master, 0).unwrap();
+        assert_eq!(vec![0x07, 0xbe, 0x54, 0xe2, 0x4b, 0xd6, 0x24, 0xb7, 0xb0, 0x04, 0x7f, 0x47, 0x96, 0x3a, 0x1f, 0x0d,
+                        0xfb, 0x28, 0x53, 0x35, 0x58, 0xb4, 0xcf, 0xe7, 0xa1, 0x58, 0x5f, 0x0b, 0x01, 0xb9, 0x54, 0xf9], sign_key.as_bytes());
+    }
+
+    #[cfg(feature = "pair_bls12_381")]
+    #[test]
+    fn derive_sign_key_works_for_bls12_381() {
+        // EIP-2333, test case 0
+        let master = KeyDerivation::<Bls12381>::derive_master_sign_key(&SEED).unwrap();
+        assert_eq!(vec![0x0d, 0x73, 0x59, 0xd5, 0x79, 0x63, 0xab, 0x8f, 0xbb, 0xde, 0x18, 0x52, 0xdc, 0xf5, 0x53, 0xfe,
+                        0xdb, 0xc3, 0x1f, 0x46, 0x4d, 0x80, 0xee, 0x7d, 0x40, 0xae, 0x68, 0x31, 0x22, 0xb4, 0x50, 0x70], master.as_bytes());
+
+        let sign_key = KeyDerivation::<Bls12381>::derive_child_sign_key(&master, 0).unwrap();
+        assert_eq!(vec![0x2d, 0x18, 0xbd, 0x6c, 0x14, 0xe6, 0xd1, 0x5b, 0xf8, 0xb5, 0x08, 0x5c, 0x9b, 0x74, 0xf3, 0xda,
+                        0xae, 0x3b, 0x03, 0xcc, 0x20, 0x14, 0x77, 0x0a, 0x59, 0x9d, 0x8c, 0x15, 0x39, 0xe5, 0x0f, 0x8e], sign_key.as_bytes());
+    }
+
+    #[cfg(feature = "pair_amcl")]
+    #[test]
+    fn derive_child_sign_key_works_for_max_index() {
+        let seed: Vec<u8> = (0..32).collect();
+        let master = KeyDerivation::<Bn254>::derive_master_sign_key(&seed).unwrap();
+        assert_eq!(vec![0x13, 0x50, 0x81, 0x82, 0x71, 0xa4, 0x15, 0xe7, 0xcd, 0xaf, 0xb8, 0x5a, 0x5a, 0x01, 0xad, 0x83,
+                        0xa1, 0x5f, 0x3b, 0x84, 0xe4, 0x53, 0x97, 0x39, 0x6e, 0xc2, 0x27, 0x39, 0x4c, 0x3f, 0x64, 0xb8], master.as_bytes());
+
+        let sign_key = KeyDerivation::<Bn254>::derive_child_sign_key(&master, u32::max_value()).unwrap();
+        assert_eq!(vec![0x0b, 0x3f, 0x3c, 0xa9, 0x5e, 0x98, 0x4c, 0x6d, 0x98, 0x8b, 0xbf, 0xb7, 0x65, 0x35, 0x59, 0x77,
+                        0xc9, 0x50, 0xb0, 0x3e, 0x7a, 0x4a, 0x51, 0x85, 0x14, 0xdc, 0xd8, 0x51, 0xf9, 0xf5, 0x78, 0xf5], sign_key.as_bytes());
+    }
+
+    #[cfg(feature = "pair_amcl")]
+    #[test]
+    fn derive_sign_key_works() {
+        let seed: Vec<u8> = (0..32).collect();
+        let sign_key = KeyDerivation::<Bn254>::derive_sign_key(&seed, "m/12381/3600/0/0").unwrap();
+        assert_eq!(vec![0x21, 0x50, 0xa7, 0x22, 0xb5, 0x73, 0x90, 0x48, 0xd0, 0xe8, 0x87, 0xc4, 0xd0, 0x93, 0x4d, 0x05,
+                        0xa7, 0xf2, 0xf4, 0xe2, 0xca, 0xdd, 0xd4, 0xb3, 0x3e, 0xb1, 0x8a, 0x4c, 0x7b, 0x03, 0xf5, 0x6b], sign_key.as_bytes());
+
+        let master = KeyDerivation::<Bn254>::derive_sign_key(&seed, "m").unwrap();
+        assert_eq!(KeyDerivation::<Bn254>::derive_master_sign_key(&seed).unwrap().as_bytes(), master.as_bytes());
+    }
+
+    #[test]
+    fn parse_path_works() {
+        assert_eq!(vec![0, 1, 4294967295], KeyDerivation::parse_path("m/0/1/4294967295").unwrap());
+        assert!(KeyDerivation::parse_path("m").unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_path_works_for_invalid_path() {
+        for path in &["", "/0", "n/0", "m/", "m//0", "m/0/", "m/-1", "m/+1", "m/1a", "m/0'", "m/4294967296", " m/0"] {
+            let err = KeyDerivation::parse_path(path).unwrap_err();
+            assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+        }
+    }
+}
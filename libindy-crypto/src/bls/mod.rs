@@ -1,323 +1,216 @@
 use errors::IndyCryptoError;
-use pair::{GroupOrderElement, PointG2, PointG1, Pair};
-
-use sha2::{Sha256, Digest};
-
-/// BLS generator point.
-/// BLS algorithm requires choosing of generator point that must be known to all parties.
-/// The most of BLS methods require generator to be provided.
-#[derive(Debug)]
-pub struct Generator {
-    point: PointG2,
-    bytes: Vec<u8>
+use pair::{DefaultEngine, PairingEngine};
+
+#[cfg(feature = "pair_bls12_381")]
+pub mod bls12_381;
+pub mod curve;
+pub mod dkg;
+pub mod hd;
+pub mod threshold;
+
+/// BLS types over the default pairing engine (see `pair::DefaultEngine`).
+/// Use `bls::curve` to pick the engine explicitly.
+pub type Generator = curve::Generator<DefaultEngine>;
+pub type SignKey = curve::SignKey<DefaultEngine>;
+pub type VerKey = curve::VerKey<DefaultEngine>;
+pub type Signature = curve::Signature<DefaultEngine>;
+pub type MultiSignature = curve::MultiSignature<DefaultEngine>;
+pub type MultiVerKey = curve::MultiVerKey<DefaultEngine>;
+pub type BitmapMultiSignature = curve::BitmapMultiSignature<DefaultEngine>;
+pub type AggregateSignature = curve::AggregateSignature<DefaultEngine>;
+pub type ProofOfPossession = curve::ProofOfPossession<DefaultEngine>;
+pub type StreamSigner = curve::StreamSigner<DefaultEngine>;
+pub type StreamVerifier = curve::StreamVerifier<DefaultEngine>;
+
+/// BLS signature scheme variant. Defines how message is hashed to the curve
+/// and separates signatures of different schemes and protocols from each other.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Ciphersuite {
+    /// SHA-256 followed by try-and-increment without domain separation.
+    /// Used by default to keep existing signatures and proofs of possession valid.
+    Legacy,
+    /// IETF basic scheme. Messages in aggregate signature must be distinct.
+    Basic,
+    /// IETF message augmentation scheme. Signer ver key is prepended to the message before hashing,
+    /// so ver key must be created with `Generator::standard`.
+    MessageAugmentation,
+    /// IETF proof of possession scheme. Ver keys must be accompanied by proof of possession
+    /// created with the same ciphersuite before used in multi signature.
+    ProofOfPossession,
+    /// IETF hash-to-curve (expand_message_xmd with SHA-256 and map of the curve) with provided domain separation tag.
+    HashToCurve(Vec<u8>)
 }
 
-impl Generator {
-    /// Creates and returns random generator point that satisfy BLS algorithm requirements.
-    ///
-    /// # Example
+impl Ciphersuite {
+    /// Returns domain separation tag used to hash messages to G1 of pairing engine `E` or None for legacy ciphersuite.
     ///
-    /// ```
-    /// use indy_crypto::bls::Generator;
-    /// Generator::new().unwrap();
-    /// ```
-    pub fn new() -> Result<Generator, IndyCryptoError> {
-        let point = PointG2::new()?;
-        Ok(Generator {
-            point: point,
-            bytes: point.to_bytes()?
-        })
+    /// Tags follow IETF BLS signature naming with hash-to-curve suite of the engine,
+    /// e.g. `BLS_SIG_BN254G1_XMD:SHA-256_SVDW_RO_NUL_` for basic ciphersuite over AMCL BN254.
+    pub fn dst<E: PairingEngine>(&self) -> Option<Vec<u8>> {
+        match *self {
+            Ciphersuite::Legacy => None,
+            Ciphersuite::Basic => Some(format!("BLS_SIG_{}NUL_", E::G1_HASH_SUITE).into_bytes()),
+            Ciphersuite::MessageAugmentation => Some(format!("BLS_SIG_{}AUG_", E::G1_HASH_SUITE).into_bytes()),
+            Ciphersuite::ProofOfPossession => Some(format!("BLS_SIG_{}POP_", E::G1_HASH_SUITE).into_bytes()),
+            Ciphersuite::HashToCurve(ref dst) => Some(dst.clone())
+        }
     }
+}
 
-    /// Returns BLS generator point bytes representation.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use indy_crypto::bls::*;
-    /// let gen = Generator::new().unwrap();
-    /// let gen_bytes = gen.as_bytes();
-    /// assert!(gen_bytes.len() > 0);
-    /// ```
-    pub fn as_bytes(&self) -> &[u8] {
-        self.bytes.as_slice()
-    }
+/// Digest algorithm used to prehash message before signing.
+/// Algorithm tag is hashed to the curve together with the digest, so prehashed signatures
+/// can't be confused with signatures over the message bytes or digests of other algorithms.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DigestAlgorithm {
+    Sha256,
+    Sha512
+}
 
-    /// Creates and returns generator point from bytes representation.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use indy_crypto::bls::Generator;
-    /// let gen = Generator::new().unwrap();
-    /// let gen_bytes = gen.as_bytes();
-    /// Generator::from_bytes(gen_bytes).unwrap();
-    /// ```
-    pub fn from_bytes(bytes: &[u8]) -> Result<Generator, IndyCryptoError> {
-        Ok(
-            Generator {
-                point: PointG2::from_bytes(bytes)?,
-                bytes: bytes.to_vec()
-            }
-        )
+impl DigestAlgorithm {
+    /// Returns tag of the algorithm.
+    pub fn tag(&self) -> &'static [u8] {
+        match *self {
+            DigestAlgorithm::Sha256 => b"SHA-256",
+            DigestAlgorithm::Sha512 => b"SHA-512"
+        }
     }
-}
 
-/// BLS sign key.
-#[derive(Debug)]
-pub struct SignKey {
-    group_order_element: GroupOrderElement,
-    bytes: Vec<u8>
+    /// Returns len of the digest produced by the algorithm.
+    pub fn digest_len(&self) -> usize {
+        match *self {
+            DigestAlgorithm::Sha256 => 32,
+            DigestAlgorithm::Sha512 => 64
+        }
+    }
 }
 
-impl SignKey {
-    /// Creates and returns random (or seeded from seed) BLS sign key algorithm requirements.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use indy_crypto::bls::Generator;
-    /// Generator::new().unwrap();
-    /// ```
-    pub fn new(seed: Option<&[u8]>) -> Result<SignKey, IndyCryptoError> {
-        let group_order_element = match seed {
-            Some(seed) => GroupOrderElement::new_from_seed(seed)?,
-            _ => GroupOrderElement::new()?
-        };
+/// BLS signature scheme over the default pairing engine.
+/// Methods without ciphersuite argument use `Ciphersuite::Legacy` to keep existing signatures valid.
+pub struct Bls {}
 
-        Ok(SignKey {
-            group_order_element: group_order_element,
-            bytes: group_order_element.to_bytes()?
-        })
-    }
+impl Bls {
 
-    /// Returns BLS sign key bytes representation.
+    /// Signs the message and returns signature.
     ///
-    /// # Example
+    /// # Arguments
     ///
-    /// ```
-    /// //TODO: Provide an example!
-    /// ```
-    pub fn as_bytes(&self) -> &[u8] {
-        self.bytes.as_slice()
-    }
-
-    /// Creates and returns BLS sign key from bytes representation.
+    /// * `message` - Message to sign
+    /// * `sign_key` - Sign key
     ///
     /// # Example
     ///
     /// ```
-    /// //TODO: Provide an example!
+    /// use indy_crypto::bls::*;
+    /// let message = vec![1, 2, 3, 4, 5];
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// Bls::sign(&message, &sign_key).unwrap();
     /// ```
-    pub fn from_bytes(bytes: &[u8]) -> Result<SignKey, IndyCryptoError> {
-        Ok(
-            SignKey {
-                group_order_element: GroupOrderElement::from_bytes(bytes)?,
-                bytes: bytes.to_vec()
-            }
-        )
+    pub fn sign(message: &[u8], sign_key: &SignKey) -> Result<Signature, IndyCryptoError> {
+        curve::Bls::sign_with_ciphersuite(message, sign_key, &Ciphersuite::Legacy)
     }
-}
-
-/// BLS verification key.
-#[derive(Debug)]
-pub struct VerKey {
-    point: PointG2,
-    bytes: Vec<u8>
-}
 
-impl VerKey {
-    /// Creates and returns BLS ver key that corresponds to sign key.
+    /// Signs the message using provided ciphersuite and returns signature.
     ///
-    /// # Example
+    /// # Arguments
     ///
-    /// ```
-    /// use indy_crypto::bls::Generator;
-    /// Generator::new().unwrap();
-    /// ```
-    pub fn new(gen: &Generator, sign_key: &SignKey) -> Result<VerKey, IndyCryptoError> {
-        let point = gen.point.mul(&sign_key.group_order_element)?;
-
-        Ok(VerKey {
-            point: point,
-            bytes: point.to_bytes()?
-        })
-    }
-
-    /// Returns BLS verification key to bytes representation.
+    /// * `message` - Message to sign
+    /// * `sign_key` - Sign key
+    /// * `ciphersuite` - Method used to hash message to the curve
     ///
     /// # Example
     ///
     /// ```
-    /// //TODO: Provide an example!
+    /// use indy_crypto::bls::*;
+    /// let message = vec![1, 2, 3, 4, 5];
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// Bls::sign_with_ciphersuite(&message, &sign_key, &Ciphersuite::Basic).unwrap();
     /// ```
-    pub fn as_bytes(&self) -> &[u8] {
-        self.bytes.as_slice()
+    pub fn sign_with_ciphersuite(message: &[u8], sign_key: &SignKey, ciphersuite: &Ciphersuite) -> Result<Signature, IndyCryptoError> {
+        curve::Bls::sign_with_ciphersuite(message, sign_key, ciphersuite)
     }
 
-    /// Creates and returns BLS verification key from bytes representation.
+    /// Verifies the message signature and returns true - if signature valid or false otherwise.
     ///
-    /// # Example
+    /// # Arguments
     ///
-    /// ```
-    /// //TODO: Provide an example!
-    /// ```
-    pub fn from_bytes(bytes: &[u8]) -> Result<VerKey, IndyCryptoError> {
-        let point = PointG2::from_bytes(bytes)?;
-        Ok(
-            VerKey {
-                point,
-                bytes: bytes.to_vec()
-            }
-        )
-    }
-}
-
-/// BLS signature.
-#[derive(Debug)]
-pub struct Signature {
-    point: PointG1,
-    bytes: Vec<u8>,
-}
-
-impl Signature {
-    /// Returns BLS signature to bytes representation.
+    /// * `signature` - Signature to verify
+    /// * `message` - Message to verify
+    /// * `ver_key` - Verification key
+    /// * `gen` - Generator point
     ///
     /// # Example
     ///
     /// ```
-    /// //TODO: Provide an example!
-    /// ```
-    pub fn as_bytes(&self) -> &[u8] {
-        self.bytes.as_slice()
-    }
-
-    /// Creates and returns BLS signature from bytes representation.
-    ///
-    /// # Example
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    /// let message = vec![1, 2, 3, 4, 5];
+    /// let signature = Bls::sign(&message, &sign_key).unwrap();
     ///
+    /// let valid = Bls::verify(&signature, &message, &ver_key, &gen).unwrap();
+    /// assert!(valid);
     /// ```
-    /// //TODO: Provide an example!
-    /// ```
-    pub fn from_bytes(bytes: &[u8]) -> Result<Signature, IndyCryptoError> {
-        let point = PointG1::from_bytes(bytes)?;
-        Ok(
-            Signature {
-                point,
-                bytes: bytes.to_vec()
-            }
-        )
-    }
-}
-
-/// BLS multi signature.
-#[derive(Debug)]
-pub struct MultiSignature {
-    point: PointG1,
-    bytes: Vec<u8>,
-}
-
-impl MultiSignature {
-    /// Creates and returns multi signature for provided list of signatures.
-   ///
-   /// # Arguments
-   ///
-   /// * `signatures` - List of signatures
-   ///
-   /// # Example
-   ///
-   /// ```
-   /// use indy_crypto::bls::*;
-   /// let sign_key1 = SignKey::new(None).unwrap();
-   /// let sign_key2 = SignKey::new(None).unwrap();
-   ///
-   /// let message = vec![1, 2, 3, 4, 5];
-   ///
-   /// let signature1 = Bls::sign(&message, &sign_key1).unwrap();
-   /// let signature2 = Bls::sign(&message, &sign_key2).unwrap();
-   ///
-   /// let signatures = vec![
-   ///    &signature1,
-   ///    &signature2
-   /// ];
-   ///
-   /// MultiSignature::new(&signatures).unwrap();
-   /// ```
-    pub fn new(signatures: &[&Signature]) -> Result<MultiSignature, IndyCryptoError> {
-        let mut point = PointG1::new_inf()?;
-
-        for signature in signatures {
-            point = point.add(&signature.point)?;
-        }
-
-        Ok(MultiSignature {
-            point,
-            bytes: point.to_bytes()?
-        })
+    pub fn verify(signature: &Signature, message: &[u8], ver_key: &VerKey, gen: &Generator) -> Result<bool, IndyCryptoError> {
+        curve::Bls::verify_with_ciphersuite(signature, message, ver_key, gen, &Ciphersuite::Legacy)
     }
 
-    /// Returns BLS multi signature bytes representation.
+    /// Verifies the message signature created with provided ciphersuite
+    /// and returns true - if signature valid or false otherwise.
     ///
-    /// # Example
+    /// # Arguments
     ///
-    /// ```
-    /// //TODO: Provide an example!
-    /// ```
-    pub fn as_bytes(&self) -> &[u8] {
-        self.bytes.as_slice()
-    }
-
-    /// Creates and returns BLS multi signature from bytes representation.
+    /// * `signature` - Signature to verify
+    /// * `message` - Message to verify
+    /// * `ver_key` - Verification key
+    /// * `gen` - Generator point
+    /// * `ciphersuite` - Method used to hash message to the curve
     ///
     /// # Example
     ///
     /// ```
-    /// //TODO: Provide an example!
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::standard().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    /// let message = vec![1, 2, 3, 4, 5];
+    /// let signature = Bls::sign_with_ciphersuite(&message, &sign_key, &Ciphersuite::MessageAugmentation).unwrap();
+    ///
+    /// let valid = Bls::verify_with_ciphersuite(&signature, &message, &ver_key, &gen, &Ciphersuite::MessageAugmentation).unwrap();
+    /// assert!(valid);
     /// ```
-    pub fn from_bytes(bytes: &[u8]) -> Result<MultiSignature, IndyCryptoError> {
-        let point = PointG1::from_bytes(bytes)?;
-        Ok(
-            MultiSignature {
-                point: point,
-                bytes: bytes.to_vec()
-            }
-        )
+    pub fn verify_with_ciphersuite(signature: &Signature, message: &[u8], ver_key: &VerKey, gen: &Generator, ciphersuite: &Ciphersuite) -> Result<bool, IndyCryptoError> {
+        curve::Bls::verify_with_ciphersuite(signature, message, ver_key, gen, ciphersuite)
     }
-}
-
-pub struct Bls {}
-
-impl Bls {
 
-    /// Signs the message and returns signature.
+    /// Signs the digest of the message computed with provided algorithm and returns signature.
     ///
     /// # Arguments
     ///
-    /// * `message` - Message to sign
+    /// * `digest` - Digest of the message to sign
+    /// * `algorithm` - Algorithm used to compute the digest
     /// * `sign_key` - Sign key
     ///
     /// # Example
     ///
     /// ```
     /// use indy_crypto::bls::*;
-    /// let message = vec![1, 2, 3, 4, 5];
+    /// let digest = [7u8; 32];
     /// let sign_key = SignKey::new(None).unwrap();
-    /// Bls::sign(&message, &sign_key).unwrap();
+    /// Bls::sign_prehashed(&digest, &DigestAlgorithm::Sha256, &sign_key).unwrap();
     /// ```
-    pub fn sign(message: &[u8], sign_key: &SignKey) -> Result<Signature, IndyCryptoError> {
-        let point = Bls::_hash(message)?.mul(&sign_key.group_order_element)?;
-        Ok(Signature {
-            point,
-            bytes: point.to_bytes()?
-        })
+    pub fn sign_prehashed(digest: &[u8], algorithm: &DigestAlgorithm, sign_key: &SignKey) -> Result<Signature, IndyCryptoError> {
+        curve::Bls::sign_prehashed(digest, algorithm, sign_key)
     }
 
-    /// Verifies the message signature and returns true - if signature valid or false otherwise.
+    /// Verifies the signature of the message digest computed with provided algorithm
+    /// and returns true - if signature valid or false otherwise.
     ///
     /// # Arguments
     ///
     /// * `signature` - Signature to verify
-    /// * `message` - Message to verify
+    /// * `digest` - Digest of the message to verify
+    /// * `algorithm` - Algorithm used to compute the digest
     /// * `ver_key` - Verification key
     /// * `gen` - Generator point
     ///
@@ -328,15 +221,14 @@ impl Bls {
     /// let gen = Generator::new().unwrap();
     /// let sign_key = SignKey::new(None).unwrap();
     /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
-    /// let message = vec![1, 2, 3, 4, 5];
-    /// let signature = Bls::sign(&message, &sign_key).unwrap();
+    /// let digest = [7u8; 64];
+    /// let signature = Bls::sign_prehashed(&digest, &DigestAlgorithm::Sha512, &sign_key).unwrap();
     ///
-    /// let valid = Bls::verify(&signature, &message, &ver_key, &gen).unwrap();
+    /// let valid = Bls::verify_prehashed(&signature, &digest, &DigestAlgorithm::Sha512, &ver_key, &gen).unwrap();
     /// assert!(valid);
     /// ```
-    pub fn verify(signature: &Signature, message: &[u8], ver_key: &VerKey, gen: &Generator) -> Result<bool, IndyCryptoError> {
-        let h = Bls::_hash(message)?;
-        Ok(Pair::pair(&signature.point, &gen.point)?.eq(&Pair::pair(&h, &ver_key.point)?))
+    pub fn verify_prehashed(signature: &Signature, digest: &[u8], algorithm: &DigestAlgorithm, ver_key: &VerKey, gen: &Generator) -> Result<bool, IndyCryptoError> {
+        curve::Bls::verify_prehashed(signature, digest, algorithm, ver_key, gen)
     }
 
     /// Verifies the message multi signature and returns true - if signature valid or false otherwise.
@@ -379,58 +271,335 @@ impl Bls {
     /// assert!(valid)
     /// ```
     pub fn verify_multi_sig(multi_sig: &MultiSignature, message: &[u8], ver_keys: &[&VerKey], gen: &Generator) -> Result<bool, IndyCryptoError> {
-        let mut multi_sig_e_list: Vec<Pair> = Vec::new();
-        for ver_key in ver_keys {
-            let h = Bls::_hash(message)?;
-            multi_sig_e_list.push(Pair::pair(&h, &ver_key.point)?);
-        }
-
-        let mut multi_sig_e = multi_sig_e_list.get(0).ok_or(IndyCryptoError::InvalidStructure(format!("Element not found")))?.clone();
-        for e in multi_sig_e_list[1..].to_vec() {
-            multi_sig_e = multi_sig_e.mul(&e)?;
-        }
-
-        Ok(Pair::pair(&multi_sig.point, &gen.point)?.eq(&multi_sig_e))
+        curve::Bls::verify_multi_sig_with_ciphersuite(multi_sig, message, ver_keys, gen, &Ciphersuite::Legacy)
     }
 
-    fn _hash(message: &[u8]) -> Result<PointG1, IndyCryptoError> {
-        let mut hasher = Sha256::default();
-        hasher.input(message);
-
-        Ok(PointG1::from_hash(hasher.result().as_slice())?)
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn generator_new_works() {
-        Generator::new().unwrap();
+    /// Verifies the message multi signature created with provided ciphersuite
+    /// and returns true - if signature valid or false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `multi_sig` - Multi signature to verify
+    /// * `message` - Message to verify
+    /// * `ver_keys` - List of verification keys
+    /// * `gen` - Generator point
+    /// * `ciphersuite` - Ciphersuite used to create signatures
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::standard().unwrap();
+    ///
+    /// let sign_key1 = SignKey::new(None).unwrap();
+    /// let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+    /// let sign_key2 = SignKey::new(None).unwrap();
+    /// let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+    ///
+    /// let message = vec![1, 2, 3, 4, 5];
+    /// let ciphersuite = Ciphersuite::ProofOfPossession;
+    ///
+    /// let signature1 = Bls::sign_with_ciphersuite(&message, &sign_key1, &ciphersuite).unwrap();
+    /// let signature2 = Bls::sign_with_ciphersuite(&message, &sign_key2, &ciphersuite).unwrap();
+    /// let multi_sig = MultiSignature::new(&[&signature1, &signature2]).unwrap();
+    ///
+    /// let valid = Bls::verify_multi_sig_with_ciphersuite(&multi_sig, &message, &[&ver_key1, &ver_key2], &gen, &ciphersuite).unwrap();
+    /// assert!(valid)
+    /// ```
+    pub fn verify_multi_sig_with_ciphersuite(multi_sig: &MultiSignature, message: &[u8], ver_keys: &[&VerKey], gen: &Generator, ciphersuite: &Ciphersuite) -> Result<bool, IndyCryptoError> {
+        curve::Bls::verify_multi_sig_with_ciphersuite(multi_sig, message, ver_keys, gen, ciphersuite)
     }
 
-    #[test]
-    fn sign_key_new_works() {
-        SignKey::new(None).unwrap();
+    /// Verifies the message multi signature against precomputed multi verification key
+    /// and returns true - if signature valid or false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `multi_sig` - Multi signature to verify
+    /// * `message` - Message to verify
+    /// * `multi_ver_key` - Multi verification key of signers
+    /// * `gen` - Generator point
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    ///
+    /// let sign_key1 = SignKey::new(None).unwrap();
+    /// let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+    /// let sign_key2 = SignKey::new(None).unwrap();
+    /// let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+    /// let multi_ver_key = MultiVerKey::new(&[&ver_key1, &ver_key2]).unwrap();
+    ///
+    /// let message = vec![1, 2, 3, 4, 5];
+    /// let signature1 = Bls::sign(&message, &sign_key1).unwrap();
+    /// let signature2 = Bls::sign(&message, &sign_key2).unwrap();
+    /// let multi_sig = MultiSignature::new(&[&signature1, &signature2]).unwrap();
+    ///
+    /// let valid = Bls::verify_multi_sig_for_multi_ver_key(&multi_sig, &message, &multi_ver_key, &gen).unwrap();
+    /// assert!(valid)
+    /// ```
+    pub fn verify_multi_sig_for_multi_ver_key(multi_sig: &MultiSignature, message: &[u8], multi_ver_key: &MultiVerKey, gen: &Generator) -> Result<bool, IndyCryptoError> {
+        curve::Bls::verify_multi_sig_for_multi_ver_key_with_ciphersuite(multi_sig, message, multi_ver_key, gen, &Ciphersuite::Legacy)
     }
 
-    #[test]
-    fn sign_key_new_works_for_seed() {
-        let seed = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 2, 3, 4, 5, 6, 7, 8, 9, 10, 21, 2, 3, 4, 5, 6, 7, 8, 9, 10, 31, 32];
-        SignKey::new(Some(&seed)).unwrap();
+    /// Verifies the message multi signature created with provided ciphersuite against precomputed
+    /// multi verification key and returns true - if signature valid or false otherwise.
+    /// Message augmentation ciphersuite isn't supported as message hash depends on each verification key.
+    ///
+    /// # Arguments
+    ///
+    /// * `multi_sig` - Multi signature to verify
+    /// * `message` - Message to verify
+    /// * `multi_ver_key` - Multi verification key of signers
+    /// * `gen` - Generator point
+    /// * `ciphersuite` - Ciphersuite used to create signatures
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::standard().unwrap();
+    ///
+    /// let sign_key1 = SignKey::new(None).unwrap();
+    /// let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+    /// let sign_key2 = SignKey::new(None).unwrap();
+    /// let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+    /// let multi_ver_key = MultiVerKey::new(&[&ver_key1, &ver_key2]).unwrap();
+    ///
+    /// let message = vec![1, 2, 3, 4, 5];
+    /// let ciphersuite = Ciphersuite::ProofOfPossession;
+    ///
+    /// let signature1 = Bls::sign_with_ciphersuite(&message, &sign_key1, &ciphersuite).unwrap();
+    /// let signature2 = Bls::sign_with_ciphersuite(&message, &sign_key2, &ciphersuite).unwrap();
+    /// let multi_sig = MultiSignature::new(&[&signature1, &signature2]).unwrap();
+    ///
+    /// let valid = Bls::verify_multi_sig_for_multi_ver_key_with_ciphersuite(&multi_sig, &message, &multi_ver_key, &gen, &ciphersuite).unwrap();
+    /// assert!(valid)
+    /// ```
+    pub fn verify_multi_sig_for_multi_ver_key_with_ciphersuite(multi_sig: &MultiSignature, message: &[u8], multi_ver_key: &MultiVerKey, gen: &Generator, ciphersuite: &Ciphersuite) -> Result<bool, IndyCryptoError> {
+        curve::Bls::verify_multi_sig_for_multi_ver_key_with_ciphersuite(multi_sig, message, multi_ver_key, gen, ciphersuite)
     }
 
-    #[test]
-    fn ver_key_new_works() {
-        let gen = Generator::new().unwrap();
-        let sign_key = SignKey::new(None).unwrap();
-        VerKey::new(&gen, &sign_key).unwrap();
+    /// Verifies the message bitmap multi signature against ordered validators set
+    /// and returns true - if signature valid and at least `min_signers` participants signed or false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `multi_sig` - Bitmap multi signature to verify
+    /// * `message` - Message to verify
+    /// * `ver_keys` - Ordered list of verification keys of validators set
+    /// * `min_signers` - Minimal number of participants required
+    /// * `gen` - Generator point
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    ///
+    /// let sign_key1 = SignKey::new(None).unwrap();
+    /// let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+    /// let sign_key2 = SignKey::new(None).unwrap();
+    /// let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+    /// let ver_key3 = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+    ///
+    /// let message = vec![1, 2, 3, 4, 5];
+    /// let signature1 = Bls::sign(&message, &sign_key1).unwrap();
+    /// let signature2 = Bls::sign(&message, &sign_key2).unwrap();
+    /// let multi_sig = BitmapMultiSignature::new(&[&signature1, &signature2], &[0, 1], 3).unwrap();
+    ///
+    /// let valid = Bls::verify_with_quorum(&multi_sig, &message, &[&ver_key1, &ver_key2, &ver_key3], 2, &gen).unwrap();
+    /// assert!(valid);
+    ///
+    /// let valid = Bls::verify_with_quorum(&multi_sig, &message, &[&ver_key1, &ver_key2, &ver_key3], 3, &gen).unwrap();
+    /// assert!(!valid);
+    /// ```
+    pub fn verify_with_quorum(multi_sig: &BitmapMultiSignature, message: &[u8], ver_keys: &[&VerKey], min_signers: u32, gen: &Generator) -> Result<bool, IndyCryptoError> {
+        curve::Bls::verify_with_quorum_with_ciphersuite(multi_sig, message, ver_keys, min_signers, gen, &Ciphersuite::Legacy)
     }
 
-    #[test]
-    fn bls_sign_works() {
-        let sign_key = SignKey::new(None).unwrap();
+    /// Verifies the aggregate signature over distinct messages and returns true - if signature valid or false otherwise.
+    /// Each message must be signed by the signer of corresponding verification key.
+    /// Duplicate messages are rejected as they make aggregate signature vulnerable to rogue key attacks.
+    ///
+    /// # Arguments
+    ///
+    /// * `aggregate_sig` - Aggregate signature to verify
+    /// * `messages` - List of signed messages
+    /// * `ver_keys` - List of verification keys messages correspond to
+    /// * `gen` - Generator point
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    ///
+    /// let sign_key1 = SignKey::new(None).unwrap();
+    /// let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+    /// let sign_key2 = SignKey::new(None).unwrap();
+    /// let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+    ///
+    /// let message1 = vec![1, 2, 3, 4, 5];
+    /// let message2 = vec![6, 7, 8, 9, 10];
+    ///
+    /// let signature1 = Bls::sign(&message1, &sign_key1).unwrap();
+    /// let signature2 = Bls::sign(&message2, &sign_key2).unwrap();
+    ///
+    /// let aggregate_sig = AggregateSignature::new(&[&signature1, &signature2]).unwrap();
+    ///
+    /// let valid = Bls::verify_aggregate(&aggregate_sig,
+    ///                                   &[message1.as_slice(), message2.as_slice()],
+    ///                                   &[&ver_key1, &ver_key2],
+    ///                                   &gen).unwrap();
+    /// assert!(valid)
+    /// ```
+    pub fn verify_aggregate(aggregate_sig: &AggregateSignature, messages: &[&[u8]], ver_keys: &[&VerKey], gen: &Generator) -> Result<bool, IndyCryptoError> {
+        curve::Bls::verify_aggregate_with_ciphersuite(aggregate_sig, messages, ver_keys, gen, &Ciphersuite::Legacy)
+    }
+
+    /// Verifies many independent message signatures at once.
+    /// Uses random linear combination of signatures, so the whole batch requires only n + 1 pairings.
+    /// If batch is rejected it is split to find the first invalid signature.
+    ///
+    /// Returns None if all signatures are valid or index of the first invalid signature otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `signatures` - List of signatures to verify
+    /// * `messages` - List of messages signatures correspond to
+    /// * `ver_keys` - List of verification keys signatures correspond to
+    /// * `gen` - Generator point
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    ///
+    /// let sign_key1 = SignKey::new(None).unwrap();
+    /// let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+    /// let sign_key2 = SignKey::new(None).unwrap();
+    /// let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+    ///
+    /// let message1 = vec![1, 2, 3, 4, 5];
+    /// let message2 = vec![6, 7, 8, 9, 10];
+    ///
+    /// let signature1 = Bls::sign(&message1, &sign_key1).unwrap();
+    /// let signature2 = Bls::sign(&message2, &sign_key2).unwrap();
+    ///
+    /// let invalid_idx = Bls::verify_batch(&[&signature1, &signature2],
+    ///                                     &[message1.as_slice(), message2.as_slice()],
+    ///                                     &[&ver_key1, &ver_key2],
+    ///                                     &gen).unwrap();
+    /// assert!(invalid_idx.is_none());
+    /// ```
+    pub fn verify_batch(signatures: &[&Signature], messages: &[&[u8]], ver_keys: &[&VerKey], gen: &Generator) -> Result<Option<usize>, IndyCryptoError> {
+        curve::Bls::verify_batch_with_ciphersuite(signatures, messages, ver_keys, gen, &Ciphersuite::Legacy)
+    }
+
+    /// Verifies the proof of possession and returns true - if proof valid or false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `pop` - Proof of possession to verify
+    /// * `ver_key` - Verification key
+    /// * `gen` - Generator point
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    /// let pop = ProofOfPossession::new(&ver_key, &sign_key).unwrap();
+    ///
+    /// let valid = Bls::verify_pop(&pop, &ver_key, &gen).unwrap();
+    /// assert!(valid);
+    /// ```
+    pub fn verify_pop(pop: &ProofOfPossession, ver_key: &VerKey, gen: &Generator) -> Result<bool, IndyCryptoError> {
+        curve::Bls::verify_pop_with_ciphersuite(pop, ver_key, gen, &Ciphersuite::Legacy)
+    }
+
+    /// Verifies the proof of possession created with provided ciphersuite
+    /// and returns true - if proof valid or false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `pop` - Proof of possession to verify
+    /// * `ver_key` - Verification key
+    /// * `gen` - Generator point
+    /// * `ciphersuite` - Ciphersuite used to create proof of possession
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::standard().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    /// let ciphersuite = Ciphersuite::ProofOfPossession;
+    /// let pop = ProofOfPossession::new_with_ciphersuite(&ver_key, &sign_key, &ciphersuite).unwrap();
+    ///
+    /// let valid = Bls::verify_pop_with_ciphersuite(&pop, &ver_key, &gen, &ciphersuite).unwrap();
+    /// assert!(valid);
+    /// ```
+    pub fn verify_pop_with_ciphersuite(pop: &ProofOfPossession, ver_key: &VerKey, gen: &Generator, ciphersuite: &Ciphersuite) -> Result<bool, IndyCryptoError> {
+        curve::Bls::verify_pop_with_ciphersuite(pop, ver_key, gen, ciphersuite)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pair::{PointG1, PointG2};
+    use sha2::{Sha256, Sha512, Digest};
+    use errors::ToErrorCode;
+    use ffi::ErrorCode;
+
+    #[test]
+    fn generator_new_works() {
+        Generator::new().unwrap();
+    }
+
+    #[test]
+    fn generator_standard_works() {
+        let gen1 = Generator::standard().unwrap();
+        let gen2 = Generator::standard().unwrap();
+        assert_eq!(gen1.as_bytes(), gen2.as_bytes());
+    }
+
+    #[test]
+    fn sign_key_new_works() {
+        SignKey::new(None).unwrap();
+    }
+
+    #[test]
+    fn sign_key_new_works_for_seed() {
+        let seed = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 2, 3, 4, 5, 6, 7, 8, 9, 10, 21, 2, 3, 4, 5, 6, 7, 8, 9, 10, 31, 32];
+        SignKey::new(Some(&seed)).unwrap();
+    }
+
+    #[test]
+    #[cfg(not(feature = "log_secrets"))]
+    fn sign_key_debug_works() {
+        let sign_key = SignKey::new(None).unwrap();
+        assert_eq!("SignKey { group_order_element: [redacted], bytes: [redacted] }", format!("{:?}", sign_key));
+    }
+
+    #[test]
+    fn ver_key_new_works() {
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        VerKey::new(&gen, &sign_key).unwrap();
+    }
+
+    #[test]
+    fn bls_sign_works() {
+        let sign_key = SignKey::new(None).unwrap();
         let message = vec![1, 2, 3, 4, 5];
 
         Bls::sign(&message, &sign_key).unwrap();
@@ -451,48 +620,346 @@ mod tests {
             &signature2
         ];
 
-        MultiSignature::new(&signatures).unwrap();
+        MultiSignature::new(&signatures).unwrap();
+    }
+
+    #[test]
+    fn generator_from_compressed_bytes_works() {
+        let gen = Generator::new().unwrap();
+        let gen2 = Generator::from_compressed_bytes(gen.as_compressed_bytes()).unwrap();
+        assert_eq!(gen.as_bytes(), gen2.as_bytes());
+    }
+
+    #[test]
+    fn ver_key_from_compressed_bytes_works() {
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        assert_eq!(PointG2::COMPRESSED_BYTES_REPR_SIZE, ver_key.as_compressed_bytes().len());
+
+        let ver_key2 = VerKey::from_compressed_bytes(ver_key.as_compressed_bytes()).unwrap();
+        assert_eq!(ver_key.as_bytes(), ver_key2.as_bytes());
+        assert_eq!(ver_key.as_compressed_bytes(), ver_key2.as_compressed_bytes());
+    }
+
+    #[test]
+    fn ver_key_from_compressed_bytes_works_for_invalid_len() {
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+
+        assert!(VerKey::from_compressed_bytes(ver_key.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn ver_key_from_bytes_works_for_invalid_point() {
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+
+        let mut bytes = ver_key.as_bytes().to_vec();
+        bytes[PointG2::BYTES_REPR_SIZE - 1] ^= 0x01;
+
+        let err = VerKey::from_bytes(&bytes).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidGroupElement);
+    }
+
+    #[test]
+    fn ver_key_from_compressed_bytes_works_for_infinity() {
+        let bytes = PointG2::new_inf().unwrap().to_compressed_bytes().unwrap();
+
+        let err = VerKey::from_compressed_bytes(&bytes).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidGroupElement);
+    }
+
+    #[test]
+    fn signature_from_bytes_works_for_infinity() {
+        let bytes = PointG1::new_inf().unwrap().to_bytes().unwrap();
+
+        let err = Signature::from_bytes(&bytes).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidGroupElement);
+
+        let bytes = PointG1::new_inf().unwrap().to_compressed_bytes().unwrap();
+
+        let err = Signature::from_compressed_bytes(&bytes).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidGroupElement);
+    }
+
+    #[test]
+    fn signature_from_compressed_bytes_works() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let signature = Bls::sign(&message, &sign_key).unwrap();
+        assert_eq!(PointG1::COMPRESSED_BYTES_REPR_SIZE, signature.as_compressed_bytes().len());
+
+        let signature2 = Signature::from_compressed_bytes(signature.as_compressed_bytes()).unwrap();
+        assert_eq!(signature.as_bytes(), signature2.as_bytes());
+        assert!(Bls::verify(&signature2, &message, &ver_key, &gen).unwrap());
+    }
+
+    #[test]
+    fn multi_signature_from_compressed_bytes_works() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let signature1 = Bls::sign(&message, &SignKey::new(None).unwrap()).unwrap();
+        let signature2 = Bls::sign(&message, &SignKey::new(None).unwrap()).unwrap();
+        let multi_sig = MultiSignature::new(&[&signature1, &signature2]).unwrap();
+
+        let multi_sig2 = MultiSignature::from_compressed_bytes(multi_sig.as_compressed_bytes()).unwrap();
+        assert_eq!(multi_sig.as_bytes(), multi_sig2.as_bytes());
+    }
+
+    #[test]
+    fn verify_works() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let signature = Bls::sign(&message, &sign_key).unwrap();
+
+        let valid = Bls::verify(&signature, &message, &ver_key, &gen).unwrap();
+        assert!(valid)
+    }
+
+    #[test]
+    fn verify_works_for_invalid_message() {
+        let message = vec![1, 2, 3, 4, 5];
+        let message_invalid = vec![1, 2, 3, 4, 5, 6];
+
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let signature = Bls::sign(&message, &sign_key).unwrap();
+
+        let valid = Bls::verify(&signature, &message_invalid, &ver_key, &gen).unwrap();
+        assert!(!valid)
+    }
+
+    #[test]
+    fn verify_works_for_invalid_signature() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+
+        let signature_invalid = Bls::sign(&message, &sign_key).unwrap();
+
+        let valid = Bls::verify(&signature_invalid, &message, &ver_key, &gen).unwrap();
+        assert!(!valid)
+    }
+
+    #[test]
+    fn verify_with_ciphersuite_works_for_hash_to_curve() {
+        let message = vec![1, 2, 3, 4, 5];
+        let ciphersuite = Ciphersuite::HashToCurve(b"INDY_CRYPTO_TEST_DST".to_vec());
+
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let signature = Bls::sign_with_ciphersuite(&message, &sign_key, &ciphersuite).unwrap();
+
+        let valid = Bls::verify_with_ciphersuite(&signature, &message, &ver_key, &gen, &ciphersuite).unwrap();
+        assert!(valid);
+
+        let valid = Bls::verify(&signature, &message, &ver_key, &gen).unwrap();
+        assert!(!valid);
+    }
+
+    #[test]
+    fn verify_with_ciphersuite_works_for_different_dst() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let signature = Bls::sign_with_ciphersuite(&message, &sign_key, &Ciphersuite::HashToCurve(b"DST1".to_vec())).unwrap();
+
+        let valid = Bls::verify_with_ciphersuite(&signature, &message, &ver_key, &gen, &Ciphersuite::HashToCurve(b"DST2".to_vec())).unwrap();
+        assert!(!valid)
+    }
+
+    #[test]
+    fn verify_with_ciphersuite_works_for_ietf_schemes() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::standard().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+
+        for ciphersuite in vec![Ciphersuite::Basic, Ciphersuite::MessageAugmentation, Ciphersuite::ProofOfPossession] {
+            let signature = Bls::sign_with_ciphersuite(&message, &sign_key, &ciphersuite).unwrap();
+
+            let valid = Bls::verify_with_ciphersuite(&signature, &message, &ver_key, &gen, &ciphersuite).unwrap();
+            assert!(valid);
+        }
+    }
+
+    #[test]
+    fn verify_with_ciphersuite_works_for_other_scheme() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::standard().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let signature = Bls::sign_with_ciphersuite(&message, &sign_key, &Ciphersuite::Basic).unwrap();
+
+        for ciphersuite in vec![Ciphersuite::Legacy, Ciphersuite::MessageAugmentation, Ciphersuite::ProofOfPossession] {
+            let valid = Bls::verify_with_ciphersuite(&signature, &message, &ver_key, &gen, &ciphersuite).unwrap();
+            assert!(!valid);
+        }
+    }
+
+    #[test]
+    fn verify_with_ciphersuite_works_for_message_augmentation_and_other_ver_key() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::standard().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+        let signature = Bls::sign_with_ciphersuite(&message, &sign_key, &Ciphersuite::MessageAugmentation).unwrap();
+
+        let valid = Bls::verify_with_ciphersuite(&signature, &message, &ver_key, &gen, &Ciphersuite::MessageAugmentation).unwrap();
+        assert!(!valid)
+    }
+
+    #[test]
+    fn verify_multi_sig_with_ciphersuite_works_for_message_augmentation() {
+        let message = vec![1, 2, 3, 4, 5];
+        let ciphersuite = Ciphersuite::MessageAugmentation;
+
+        let gen = Generator::standard().unwrap();
+        let sign_key1 = SignKey::new(None).unwrap();
+        let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+        let sign_key2 = SignKey::new(None).unwrap();
+        let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+
+        let signature1 = Bls::sign_with_ciphersuite(&message, &sign_key1, &ciphersuite).unwrap();
+        let signature2 = Bls::sign_with_ciphersuite(&message, &sign_key2, &ciphersuite).unwrap();
+        let multi_sig = MultiSignature::new(&[&signature1, &signature2]).unwrap();
+
+        let valid = Bls::verify_multi_sig_with_ciphersuite(&multi_sig, &message, &[&ver_key1, &ver_key2], &gen, &ciphersuite).unwrap();
+        assert!(valid)
+    }
+
+    #[test]
+    fn verify_pop_with_ciphersuite_works() {
+        let gen = Generator::standard().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let pop = ProofOfPossession::new_with_ciphersuite(&ver_key, &sign_key, &Ciphersuite::ProofOfPossession).unwrap();
+
+        assert!(Bls::verify_pop_with_ciphersuite(&pop, &ver_key, &gen, &Ciphersuite::ProofOfPossession).unwrap());
+        assert!(!Bls::verify_pop(&pop, &ver_key, &gen).unwrap());
+    }
+
+    #[test]
+    fn proof_of_possession_new_with_ciphersuite_works_for_basic() {
+        let gen = Generator::standard().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+
+        assert!(ProofOfPossession::new_with_ciphersuite(&ver_key, &sign_key, &Ciphersuite::Basic).is_err());
+    }
+
+    #[test]
+    fn verify_with_ciphersuite_works_for_legacy() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let signature = Bls::sign(&message, &sign_key).unwrap();
+
+        let valid = Bls::verify_with_ciphersuite(&signature, &message, &ver_key, &gen, &Ciphersuite::Legacy).unwrap();
+        assert!(valid)
+    }
+
+    #[test]
+    fn stream_signer_works() {
+        let message: Vec<u8> = (0..1000).map(|i| i as u8).collect();
+
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+
+        let mut signer = StreamSigner::new();
+        for chunk in message.chunks(64) {
+            signer.update(chunk);
+        }
+        let signature = signer.sign(&sign_key).unwrap();
+        assert_eq!(Bls::sign(&message, &sign_key).unwrap().as_bytes(), signature.as_bytes());
+
+        let mut verifier = StreamVerifier::new();
+        for chunk in message.chunks(100) {
+            verifier.update(chunk);
+        }
+        assert!(verifier.verify(&signature, &ver_key, &gen).unwrap());
+
+        let mut verifier = StreamVerifier::new();
+        verifier.update(&message[1..]);
+        assert!(!verifier.verify(&signature, &ver_key, &gen).unwrap());
     }
 
     #[test]
-    fn verify_works() {
-        let message = vec![1, 2, 3, 4, 5];
+    fn stream_signer_works_for_prehashed() {
+        let message: Vec<u8> = (0..1000).map(|i| i as u8).collect();
 
         let gen = Generator::new().unwrap();
         let sign_key = SignKey::new(None).unwrap();
         let ver_key = VerKey::new(&gen, &sign_key).unwrap();
-        let signature = Bls::sign(&message, &sign_key).unwrap();
 
-        let valid = Bls::verify(&signature, &message, &ver_key, &gen).unwrap();
-        assert!(valid)
+        let mut signer = StreamSigner::new_prehashed(DigestAlgorithm::Sha512);
+        for chunk in message.chunks(64) {
+            signer.update(chunk);
+        }
+        let signature = signer.sign(&sign_key).unwrap();
+
+        let mut hasher = Sha512::default();
+        hasher.input(&message);
+        let digest = hasher.result().to_vec();
+        assert_eq!(Bls::sign_prehashed(&digest, &DigestAlgorithm::Sha512, &sign_key).unwrap().as_bytes(), signature.as_bytes());
+
+        let mut verifier = StreamVerifier::new_prehashed(DigestAlgorithm::Sha512);
+        verifier.update(&message);
+        assert!(verifier.verify(&signature, &ver_key, &gen).unwrap());
+
+        let mut verifier = StreamVerifier::new();
+        verifier.update(&message);
+        assert!(!verifier.verify(&signature, &ver_key, &gen).unwrap());
     }
 
     #[test]
-    fn verify_works_for_invalid_message() {
-        let message = vec![1, 2, 3, 4, 5];
-        let message_invalid = vec![1, 2, 3, 4, 5, 6];
-
+    fn verify_prehashed_works_for_other_paths() {
         let gen = Generator::new().unwrap();
         let sign_key = SignKey::new(None).unwrap();
         let ver_key = VerKey::new(&gen, &sign_key).unwrap();
-        let signature = Bls::sign(&message, &sign_key).unwrap();
 
-        let valid = Bls::verify(&signature, &message_invalid, &ver_key, &gen).unwrap();
-        assert!(!valid)
+        let mut hasher = Sha256::default();
+        hasher.input(&[1, 2, 3, 4, 5]);
+        let digest = hasher.result().to_vec();
+
+        let signature = Bls::sign_prehashed(&digest, &DigestAlgorithm::Sha256, &sign_key).unwrap();
+        assert!(Bls::verify_prehashed(&signature, &digest, &DigestAlgorithm::Sha256, &ver_key, &gen).unwrap());
+
+        // Legacy signature of the message hashes the same SHA-256 digest
+        assert!(!Bls::verify(&signature, &[1, 2, 3, 4, 5], &ver_key, &gen).unwrap());
+        assert!(!Bls::verify(&signature, &digest, &ver_key, &gen).unwrap());
+
+        let signature = Bls::sign(&[1, 2, 3, 4, 5], &sign_key).unwrap();
+        assert!(!Bls::verify_prehashed(&signature, &digest, &DigestAlgorithm::Sha256, &ver_key, &gen).unwrap());
     }
 
     #[test]
-    fn verify_works_for_invalid_signature() {
-        let message = vec![1, 2, 3, 4, 5];
-
-        let gen = Generator::new().unwrap();
+    fn sign_prehashed_works_for_invalid_digest_len() {
         let sign_key = SignKey::new(None).unwrap();
-        let ver_key = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
-
-        let signature_invalid = Bls::sign(&message, &sign_key).unwrap();
 
-        let valid = Bls::verify(&signature_invalid, &message, &ver_key, &gen).unwrap();
-        assert!(!valid)
+        let err = Bls::sign_prehashed(&[0; 32], &DigestAlgorithm::Sha512, &sign_key).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
     }
 
     #[test]
@@ -583,4 +1050,432 @@ mod tests {
 
         assert!(!valid)
     }
+
+    #[test]
+    fn multi_ver_key_new_works_for_empty_ver_keys() {
+        let err = MultiVerKey::new(&[]).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+    }
+
+    #[test]
+    fn multi_ver_key_from_bytes_works() {
+        let gen = Generator::new().unwrap();
+        let ver_key1 = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+        let ver_key2 = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+        let multi_ver_key = MultiVerKey::new(&[&ver_key1, &ver_key2]).unwrap();
+
+        let multi_ver_key2 = MultiVerKey::from_bytes(multi_ver_key.as_bytes()).unwrap();
+        assert_eq!(2, multi_ver_key2.ver_keys_count());
+        assert_eq!(multi_ver_key.as_bytes(), multi_ver_key2.as_bytes());
+
+        let multi_ver_key3 = MultiVerKey::from_compressed_bytes(multi_ver_key.as_compressed_bytes()).unwrap();
+        assert_eq!(multi_ver_key.as_bytes(), multi_ver_key3.as_bytes());
+    }
+
+    #[test]
+    fn multi_ver_key_from_bytes_works_for_zero_count() {
+        let gen = Generator::new().unwrap();
+        let ver_key = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+
+        let mut bytes = vec![0, 0, 0, 0];
+        bytes.extend_from_slice(ver_key.as_bytes());
+
+        let err = MultiVerKey::from_bytes(&bytes).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+    }
+
+    #[test]
+    fn multi_ver_key_add_remove_works() {
+        let gen = Generator::new().unwrap();
+        let ver_key1 = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+        let ver_key2 = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+        let ver_key3 = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+
+        let mut multi_ver_key = MultiVerKey::new(&[&ver_key1, &ver_key2]).unwrap();
+        multi_ver_key.add(&ver_key3).unwrap();
+        assert_eq!(MultiVerKey::new(&[&ver_key1, &ver_key2, &ver_key3]).unwrap().as_bytes(), multi_ver_key.as_bytes());
+
+        multi_ver_key.remove(&ver_key1).unwrap();
+        assert_eq!(MultiVerKey::new(&[&ver_key2, &ver_key3]).unwrap().as_bytes(), multi_ver_key.as_bytes());
+    }
+
+    #[test]
+    fn multi_ver_key_remove_works_for_last_ver_key() {
+        let gen = Generator::new().unwrap();
+        let ver_key = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+
+        let mut multi_ver_key = MultiVerKey::new(&[&ver_key]).unwrap();
+        let err = multi_ver_key.remove(&ver_key).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+    }
+
+    #[test]
+    fn verify_multi_sig_for_multi_ver_key_works() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::new().unwrap();
+        let sign_key1 = SignKey::new(None).unwrap();
+        let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+        let sign_key2 = SignKey::new(None).unwrap();
+        let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+        let ver_key3 = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+
+        let signature1 = Bls::sign(&message, &sign_key1).unwrap();
+        let signature2 = Bls::sign(&message, &sign_key2).unwrap();
+        let multi_signature = MultiSignature::new(&[&signature1, &signature2]).unwrap();
+
+        let mut multi_ver_key = MultiVerKey::new(&[&ver_key1, &ver_key2, &ver_key3]).unwrap();
+        assert!(!Bls::verify_multi_sig_for_multi_ver_key(&multi_signature, &message, &multi_ver_key, &gen).unwrap());
+
+        multi_ver_key.remove(&ver_key3).unwrap();
+        assert!(Bls::verify_multi_sig_for_multi_ver_key(&multi_signature, &message, &multi_ver_key, &gen).unwrap());
+        assert!(!Bls::verify_multi_sig_for_multi_ver_key(&multi_signature, &vec![1, 2, 3], &multi_ver_key, &gen).unwrap());
+    }
+
+    #[test]
+    fn verify_multi_sig_for_multi_ver_key_works_for_message_augmentation() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::standard().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let ciphersuite = Ciphersuite::MessageAugmentation;
+
+        let signature = Bls::sign_with_ciphersuite(&message, &sign_key, &ciphersuite).unwrap();
+        let multi_signature = MultiSignature::new(&[&signature]).unwrap();
+        let multi_ver_key = MultiVerKey::new(&[&ver_key]).unwrap();
+
+        let err = Bls::verify_multi_sig_for_multi_ver_key_with_ciphersuite(&multi_signature, &message, &multi_ver_key, &gen, &ciphersuite).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+    }
+
+    #[test]
+    fn bitmap_multi_signature_new_works_for_invalid_indexes() {
+        let signature1 = Bls::sign(&vec![1, 2, 3, 4, 5], &SignKey::new(None).unwrap()).unwrap();
+        let signature2 = Bls::sign(&vec![1, 2, 3, 4, 5], &SignKey::new(None).unwrap()).unwrap();
+
+        assert!(BitmapMultiSignature::new(&[&signature1, &signature2], &[0], 4).is_err());
+        assert!(BitmapMultiSignature::new(&[&signature1, &signature2], &[1, 1], 4).is_err());
+        assert!(BitmapMultiSignature::new(&[&signature1], &[4], 4).is_err());
+        assert!(BitmapMultiSignature::new(&[], &[], 4).is_err());
+    }
+
+    #[test]
+    fn bitmap_multi_signature_add_works_for_duplicate_participant() {
+        let signature1 = Bls::sign(&vec![1, 2, 3, 4, 5], &SignKey::new(None).unwrap()).unwrap();
+        let signature2 = Bls::sign(&vec![1, 2, 3, 4, 5], &SignKey::new(None).unwrap()).unwrap();
+
+        let mut multi_sig = BitmapMultiSignature::new(&[&signature1], &[2], 4).unwrap();
+        let err = multi_sig.add(&signature2, 2).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+        assert_eq!(vec![2], multi_sig.participants());
+    }
+
+    #[test]
+    fn bitmap_multi_signature_merge_works() {
+        let message = vec![1, 2, 3, 4, 5];
+        let signatures: Vec<Signature> = (0..3).map(|_| Bls::sign(&message, &SignKey::new(None).unwrap()).unwrap()).collect();
+
+        let mut multi_sig = BitmapMultiSignature::new(&[&signatures[0]], &[0], 10).unwrap();
+        let multi_sig2 = BitmapMultiSignature::new(&[&signatures[1], &signatures[2]], &[5, 9], 10).unwrap();
+        multi_sig.merge(&multi_sig2).unwrap();
+
+        let expected = BitmapMultiSignature::new(&[&signatures[0], &signatures[1], &signatures[2]], &[0, 5, 9], 10).unwrap();
+        assert_eq!(vec![0, 5, 9], multi_sig.participants());
+        assert_eq!(expected.as_bytes(), multi_sig.as_bytes());
+    }
+
+    #[test]
+    fn bitmap_multi_signature_merge_works_for_intersecting_participants() {
+        let message = vec![1, 2, 3, 4, 5];
+        let signature1 = Bls::sign(&message, &SignKey::new(None).unwrap()).unwrap();
+        let signature2 = Bls::sign(&message, &SignKey::new(None).unwrap()).unwrap();
+
+        let mut multi_sig = BitmapMultiSignature::new(&[&signature1], &[3], 10).unwrap();
+        let multi_sig2 = BitmapMultiSignature::new(&[&signature1, &signature2], &[3, 4], 10).unwrap();
+        assert!(multi_sig.merge(&multi_sig2).is_err());
+
+        let multi_sig3 = BitmapMultiSignature::new(&[&signature2], &[4], 11).unwrap();
+        assert!(multi_sig.merge(&multi_sig3).is_err());
+    }
+
+    #[test]
+    fn bitmap_multi_signature_from_bytes_works() {
+        let signature1 = Bls::sign(&vec![1, 2, 3, 4, 5], &SignKey::new(None).unwrap()).unwrap();
+        let signature2 = Bls::sign(&vec![1, 2, 3, 4, 5], &SignKey::new(None).unwrap()).unwrap();
+        let multi_sig = BitmapMultiSignature::new(&[&signature1, &signature2], &[1, 8], 10).unwrap();
+
+        assert_eq!(&[0, 0, 0, 10, 0b0000_0010, 0b0000_0001], &multi_sig.as_bytes()[..6]);
+
+        let multi_sig2 = BitmapMultiSignature::from_bytes(multi_sig.as_bytes()).unwrap();
+        assert_eq!(multi_sig.as_bytes(), multi_sig2.as_bytes());
+        assert_eq!(vec![1, 8], multi_sig2.participants());
+
+        let multi_sig3 = BitmapMultiSignature::from_compressed_bytes(multi_sig.as_compressed_bytes()).unwrap();
+        assert_eq!(multi_sig.as_bytes(), multi_sig3.as_bytes());
+    }
+
+    #[test]
+    fn bitmap_multi_signature_from_bytes_works_for_invalid_bitmap() {
+        let signature = Bls::sign(&vec![1, 2, 3, 4, 5], &SignKey::new(None).unwrap()).unwrap();
+        let multi_sig = BitmapMultiSignature::new(&[&signature], &[1], 10).unwrap();
+
+        let mut bytes = multi_sig.as_bytes().to_vec();
+        bytes[5] = 0b0000_0100;
+        assert!(BitmapMultiSignature::from_bytes(&bytes).is_err());
+
+        let mut bytes = multi_sig.as_bytes().to_vec();
+        bytes[4] = 0;
+        assert!(BitmapMultiSignature::from_bytes(&bytes).is_err());
+
+        assert!(BitmapMultiSignature::from_bytes(&multi_sig.as_bytes()[..5]).is_err());
+    }
+
+    #[test]
+    fn verify_with_quorum_works() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::new().unwrap();
+        let sign_keys: Vec<SignKey> = (0..4).map(|_| SignKey::new(None).unwrap()).collect();
+        let ver_keys: Vec<VerKey> = sign_keys.iter().map(|sign_key| VerKey::new(&gen, sign_key).unwrap()).collect();
+        let ver_keys: Vec<&VerKey> = ver_keys.iter().collect();
+
+        let signature1 = Bls::sign(&message, &sign_keys[1]).unwrap();
+        let signature3 = Bls::sign(&message, &sign_keys[3]).unwrap();
+
+        let mut multi_sig = BitmapMultiSignature::new(&[&signature1], &[1], 4).unwrap();
+        assert!(Bls::verify_with_quorum(&multi_sig, &message, &ver_keys, 1, &gen).unwrap());
+        assert!(!Bls::verify_with_quorum(&multi_sig, &message, &ver_keys, 2, &gen).unwrap());
+
+        multi_sig.add(&signature3, 3).unwrap();
+        assert!(Bls::verify_with_quorum(&multi_sig, &message, &ver_keys, 2, &gen).unwrap());
+        assert!(!Bls::verify_with_quorum(&multi_sig, &vec![1, 2, 3], &ver_keys, 2, &gen).unwrap());
+    }
+
+    #[test]
+    fn verify_with_quorum_works_for_wrong_participant() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::new().unwrap();
+        let sign_keys: Vec<SignKey> = (0..3).map(|_| SignKey::new(None).unwrap()).collect();
+        let ver_keys: Vec<VerKey> = sign_keys.iter().map(|sign_key| VerKey::new(&gen, sign_key).unwrap()).collect();
+        let ver_keys: Vec<&VerKey> = ver_keys.iter().collect();
+
+        let signature = Bls::sign(&message, &sign_keys[0]).unwrap();
+        let multi_sig = BitmapMultiSignature::new(&[&signature], &[1], 3).unwrap();
+        assert!(!Bls::verify_with_quorum(&multi_sig, &message, &ver_keys, 1, &gen).unwrap());
+
+        let err = Bls::verify_with_quorum(&multi_sig, &message, &ver_keys[..2], 1, &gen).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+    }
+
+    #[test]
+    fn proof_of_possession_new_works() {
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        ProofOfPossession::new(&ver_key, &sign_key).unwrap();
+    }
+
+    #[test]
+    fn proof_of_possession_from_bytes_to_bytes_works() {
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let pop = ProofOfPossession::new(&ver_key, &sign_key).unwrap();
+
+        let pop2 = ProofOfPossession::from_bytes(pop.as_bytes()).unwrap();
+        assert_eq!(pop.as_bytes(), pop2.as_bytes());
+    }
+
+    #[test]
+    fn verify_pop_works() {
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let pop = ProofOfPossession::new(&ver_key, &sign_key).unwrap();
+
+        let valid = Bls::verify_pop(&pop, &ver_key, &gen).unwrap();
+        assert!(valid)
+    }
+
+    #[test]
+    fn verify_pop_works_for_invalid_sign_key() {
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let pop_invalid = ProofOfPossession::new(&ver_key, &SignKey::new(None).unwrap()).unwrap();
+
+        let valid = Bls::verify_pop(&pop_invalid, &ver_key, &gen).unwrap();
+        assert!(!valid)
+    }
+
+    #[test]
+    fn verify_pop_works_for_signature_over_ver_key() {
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let signature = Bls::sign(ver_key.as_bytes(), &sign_key).unwrap();
+        let pop_invalid = ProofOfPossession::from_bytes(signature.as_bytes()).unwrap();
+
+        let valid = Bls::verify_pop(&pop_invalid, &ver_key, &gen).unwrap();
+        assert!(!valid)
+    }
+
+    #[test]
+    fn verify_batch_works() {
+        let message1 = vec![1, 2, 3, 4, 5];
+        let message2 = vec![6, 7, 8, 9, 10];
+        let message3 = vec![11, 12, 13, 14, 15];
+
+        let gen = Generator::new().unwrap();
+        let sign_key1 = SignKey::new(None).unwrap();
+        let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+        let sign_key2 = SignKey::new(None).unwrap();
+        let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+
+        let signature1 = Bls::sign(&message1, &sign_key1).unwrap();
+        let signature2 = Bls::sign(&message2, &sign_key2).unwrap();
+        let signature3 = Bls::sign(&message3, &sign_key1).unwrap();
+
+        let invalid_idx = Bls::verify_batch(&[&signature1, &signature2, &signature3],
+                                            &[message1.as_slice(), message2.as_slice(), message3.as_slice()],
+                                            &[&ver_key1, &ver_key2, &ver_key1],
+                                            &gen).unwrap();
+        assert_eq!(None, invalid_idx);
+    }
+
+    #[test]
+    fn verify_batch_works_for_invalid_signature() {
+        let message1 = vec![1, 2, 3, 4, 5];
+        let message2 = vec![6, 7, 8, 9, 10];
+        let message3 = vec![11, 12, 13, 14, 15];
+
+        let gen = Generator::new().unwrap();
+        let sign_key1 = SignKey::new(None).unwrap();
+        let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+        let sign_key2 = SignKey::new(None).unwrap();
+        let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+
+        let signature1 = Bls::sign(&message1, &sign_key1).unwrap();
+        let signature2 = Bls::sign(&message2, &sign_key2).unwrap();
+        let signature3_invalid = Bls::sign(&message3, &sign_key2).unwrap();
+
+        let invalid_idx = Bls::verify_batch(&[&signature1, &signature2, &signature3_invalid],
+                                            &[message1.as_slice(), message2.as_slice(), message3.as_slice()],
+                                            &[&ver_key1, &ver_key2, &ver_key1],
+                                            &gen).unwrap();
+        assert_eq!(Some(2), invalid_idx);
+    }
+
+    #[test]
+    fn verify_batch_works_for_swapped_signatures() {
+        let message1 = vec![1, 2, 3, 4, 5];
+        let message2 = vec![6, 7, 8, 9, 10];
+
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+
+        let signature1 = Bls::sign(&message1, &sign_key).unwrap();
+        let signature2 = Bls::sign(&message2, &sign_key).unwrap();
+
+        let invalid_idx = Bls::verify_batch(&[&signature2, &signature1],
+                                            &[message1.as_slice(), message2.as_slice()],
+                                            &[&ver_key, &ver_key],
+                                            &gen).unwrap();
+        assert_eq!(Some(0), invalid_idx);
+    }
+
+    #[test]
+    fn verify_batch_works_for_different_lens() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let signature = Bls::sign(&message, &sign_key).unwrap();
+
+        let res = Bls::verify_batch(&[&signature], &[message.as_slice(), message.as_slice()], &[&ver_key], &gen);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn aggregate_signature_new_works() {
+        let message1 = vec![1, 2, 3, 4, 5];
+        let message2 = vec![6, 7, 8, 9, 10];
+
+        let sign_key1 = SignKey::new(None).unwrap();
+        let sign_key2 = SignKey::new(None).unwrap();
+
+        let signature1 = Bls::sign(&message1, &sign_key1).unwrap();
+        let signature2 = Bls::sign(&message2, &sign_key2).unwrap();
+
+        AggregateSignature::new(&[&signature1, &signature2]).unwrap();
+    }
+
+    #[test]
+    fn verify_aggregate_works() {
+        let message1 = vec![1, 2, 3, 4, 5];
+        let message2 = vec![6, 7, 8, 9, 10];
+
+        let gen = Generator::new().unwrap();
+        let sign_key1 = SignKey::new(None).unwrap();
+        let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+        let sign_key2 = SignKey::new(None).unwrap();
+        let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+
+        let signature1 = Bls::sign(&message1, &sign_key1).unwrap();
+        let signature2 = Bls::sign(&message2, &sign_key2).unwrap();
+
+        let aggregate_sig = AggregateSignature::new(&[&signature1, &signature2]).unwrap();
+        let valid = Bls::verify_aggregate(&aggregate_sig,
+                                          &[message1.as_slice(), message2.as_slice()],
+                                          &[&ver_key1, &ver_key2],
+                                          &gen).unwrap();
+        assert!(valid)
+    }
+
+    #[test]
+    fn verify_aggregate_works_for_swapped_messages() {
+        let message1 = vec![1, 2, 3, 4, 5];
+        let message2 = vec![6, 7, 8, 9, 10];
+
+        let gen = Generator::new().unwrap();
+        let sign_key1 = SignKey::new(None).unwrap();
+        let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+        let sign_key2 = SignKey::new(None).unwrap();
+        let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+
+        let signature1 = Bls::sign(&message1, &sign_key1).unwrap();
+        let signature2 = Bls::sign(&message2, &sign_key2).unwrap();
+
+        let aggregate_sig = AggregateSignature::new(&[&signature1, &signature2]).unwrap();
+        let valid = Bls::verify_aggregate(&aggregate_sig,
+                                          &[message2.as_slice(), message1.as_slice()],
+                                          &[&ver_key1, &ver_key2],
+                                          &gen).unwrap();
+        assert!(!valid)
+    }
+
+    #[test]
+    fn verify_aggregate_works_for_duplicate_messages() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::new().unwrap();
+        let sign_key1 = SignKey::new(None).unwrap();
+        let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+        let sign_key2 = SignKey::new(None).unwrap();
+        let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+
+        let signature1 = Bls::sign(&message, &sign_key1).unwrap();
+        let signature2 = Bls::sign(&message, &sign_key2).unwrap();
+
+        let aggregate_sig = AggregateSignature::new(&[&signature1, &signature2]).unwrap();
+        let res = Bls::verify_aggregate(&aggregate_sig,
+                                        &[message.as_slice(), message.as_slice()],
+                                        &[&ver_key1, &ver_key2],
+                                        &gen);
+        assert!(res.is_err())
+    }
 }
\ No newline at end of file
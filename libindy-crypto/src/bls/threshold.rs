@@ -0,0 +1,560 @@
+//! Threshold BLS signatures: sign key is split to shares with Shamir secret sharing
+//! and any `threshold` signature shares are combined to signature of the original sign key.
+//!
+//! Types are generic over pairing engine like `bls::curve` types and use `pair::DefaultEngine` if it is omitted.
+
+use bls::Ciphersuite;
+use bls::curve::{Bls, Generator, SignKey, Signature};
+use errors::IndyCryptoError;
+use pair::{DefaultEngine, PairingEngine, PairingPoint, PairingScalar};
+
+use std::collections::HashSet;
+use std::marker::PhantomData;
+
+use zeroize::Zeroize;
+
+const INDEX_BYTES_SIZE: usize = 4;
+
+/// Share of BLS sign key.
+/// Any `threshold` of `shares_count` shares are enough to create signature that corresponds to the original sign key.
+pub struct SignKeyShare<E: PairingEngine = DefaultEngine> {
+    index: u32,
+    group_order_element: E::Scalar,
+    bytes: Vec<u8>
+}
+
+impl_secret_debug!(SignKeyShare<E: PairingEngine> { index } secret { group_order_element, bytes });
+
+impl<E: PairingEngine> SignKeyShare<E> {
+    /// Returns index of the share (starts from 1).
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns BLS sign key share bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// use indy_crypto::bls::threshold::*;
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let shares = Threshold::split_sign_key(&sign_key, 2, 3).unwrap();
+    /// assert!(shares[0].as_bytes().len() > 0);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Creates and returns BLS sign key share from bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// use indy_crypto::bls::threshold::*;
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let shares = Threshold::split_sign_key(&sign_key, 2, 3).unwrap();
+    /// let share: SignKeyShare = SignKeyShare::from_bytes(shares[0].as_bytes()).unwrap();
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<SignKeyShare<E>, IndyCryptoError> {
+        let (index, element_bytes) = _split_index(bytes)?;
+        Ok(
+            SignKeyShare {
+                index,
+                group_order_element: E::Scalar::from_bytes(element_bytes)?,
+                bytes: bytes.to_vec()
+            }
+        )
+    }
+
+    pub(crate) fn new(index: u32, group_order_element: E::Scalar) -> Result<SignKeyShare<E>, IndyCryptoError> {
+        let bytes = _join_index(index, &group_order_element.to_bytes()?);
+        Ok(SignKeyShare {
+            index,
+            group_order_element,
+            bytes
+        })
+    }
+}
+
+impl<E: PairingEngine> Drop for SignKeyShare<E> {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+/// Verification key that corresponds to BLS sign key share.
+/// Used to verify signature shares before combining.
+#[derive(Debug)]
+pub struct VerKeyShare<E: PairingEngine = DefaultEngine> {
+    index: u32,
+    point: E::G2,
+    bytes: Vec<u8>
+}
+
+impl<E: PairingEngine> VerKeyShare<E> {
+    /// Creates and returns verification key share that corresponds to sign key share.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// use indy_crypto::bls::threshold::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let shares = Threshold::split_sign_key(&sign_key, 2, 3).unwrap();
+    /// VerKeyShare::new(&gen, &shares[0]).unwrap();
+    /// ```
+    pub fn new(gen: &Generator<E>, sign_key_share: &SignKeyShare<E>) -> Result<VerKeyShare<E>, IndyCryptoError> {
+        VerKeyShare::from_point(sign_key_share.index, gen.point.mul(&sign_key_share.group_order_element)?)
+    }
+
+    /// Returns index of the share (starts from 1).
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns verification key share bytes representation.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Creates and returns verification key share from bytes representation.
+    pub fn from_bytes(bytes: &[u8]) -> Result<VerKeyShare<E>, IndyCryptoError> {
+        let (index, point_bytes) = _split_index(bytes)?;
+        Ok(
+            VerKeyShare {
+                index,
+                point: E::G2::from_bytes(point_bytes)?,
+                bytes: bytes.to_vec()
+            }
+        )
+    }
+
+    pub(crate) fn from_point(index: u32, point: E::G2) -> Result<VerKeyShare<E>, IndyCryptoError> {
+        Ok(VerKeyShare {
+            index,
+            point,
+            bytes: _join_index(index, &point.to_bytes()?)
+        })
+    }
+}
+
+/// Partial BLS signature created with sign key share.
+#[derive(Debug)]
+pub struct SignatureShare<E: PairingEngine = DefaultEngine> {
+    index: u32,
+    point: E::G1,
+    bytes: Vec<u8>
+}
+
+impl<E: PairingEngine> SignatureShare<E> {
+    /// Returns index of the share (starts from 1).
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns signature share bytes representation.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Creates and returns signature share from bytes representation.
+    pub fn from_bytes(bytes: &[u8]) -> Result<SignatureShare<E>, IndyCryptoError> {
+        let (index, point_bytes) = _split_index(bytes)?;
+        Ok(
+            SignatureShare {
+                index,
+                point: E::G1::from_bytes(point_bytes)?,
+                bytes: bytes.to_vec()
+            }
+        )
+    }
+}
+
+/// Threshold BLS signature scheme over pairing engine `E`.
+pub struct Threshold<E: PairingEngine = DefaultEngine> {
+    _engine: PhantomData<E>
+}
+
+impl<E: PairingEngine> Threshold<E> {
+    /// Splits sign key to `shares_count` shares using Shamir secret sharing.
+    /// Any `threshold` shares are enough to create signature verifiable with the original ver key.
+    ///
+    /// # Arguments
+    ///
+    /// * `sign_key` - Sign key to split
+    /// * `threshold` - Number of shares required to create signature
+    /// * `shares_count` - Total number of shares
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// use indy_crypto::bls::threshold::*;
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let shares = Threshold::split_sign_key(&sign_key, 2, 3).unwrap();
+    /// assert_eq!(3, shares.len());
+    /// ```
+    pub fn split_sign_key(sign_key: &SignKey<E>, threshold: u32, shares_count: u32) -> Result<Vec<SignKeyShare<E>>, IndyCryptoError> {
+        if threshold == 0 || threshold > shares_count {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("Invalid threshold: {} of {}", threshold, shares_count)));
+        }
+
+        let mut coefficients: Vec<E::Scalar> = vec![sign_key.group_order_element.clone()];
+        for _ in 1..threshold {
+            coefficients.push(E::Scalar::new()?);
+        }
+
+        let mut shares: Vec<SignKeyShare<E>> = Vec::new();
+        for index in 1..(shares_count + 1) {
+            shares.push(SignKeyShare::new(index, eval_polynomial(&coefficients, index)?)?);
+        }
+
+        Ok(shares)
+    }
+
+    /// Signs the message with sign key share and returns signature share.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// use indy_crypto::bls::threshold::*;
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let shares = Threshold::split_sign_key(&sign_key, 2, 3).unwrap();
+    /// Threshold::sign(&vec![1, 2, 3, 4, 5], &shares[0]).unwrap();
+    /// ```
+    pub fn sign(message: &[u8], sign_key_share: &SignKeyShare<E>) -> Result<SignatureShare<E>, IndyCryptoError> {
+        Threshold::sign_with_ciphersuite(message, sign_key_share, &Ciphersuite::Legacy)
+    }
+
+    /// Signs the message with sign key share using provided ciphersuite and returns signature share.
+    /// Combined signature is verified with `Bls::verify_with_ciphersuite` and the same ciphersuite.
+    ///
+    /// `Ciphersuite::MessageAugmentation` isn't supported as message must be augmented with ver key
+    /// of the original sign key that share holder doesn't know.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - Message to sign
+    /// * `sign_key_share` - Sign key share
+    /// * `ciphersuite` - Method used to hash message to the curve
+    pub fn sign_with_ciphersuite(message: &[u8], sign_key_share: &SignKeyShare<E>, ciphersuite: &Ciphersuite) -> Result<SignatureShare<E>, IndyCryptoError> {
+        let point = Bls::<E>::_hash_with_ciphersuite(message, None, ciphersuite)?.mul(&sign_key_share.group_order_element)?;
+        Ok(SignatureShare {
+            index: sign_key_share.index,
+            point,
+            bytes: _join_index(sign_key_share.index, &point.to_bytes()?)
+        })
+    }
+
+    /// Verifies the message signature share against verification key share
+    /// and returns true - if signature share valid or false otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// use indy_crypto::bls::threshold::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let shares = Threshold::split_sign_key(&sign_key, 2, 3).unwrap();
+    /// let ver_key_share = VerKeyShare::new(&gen, &shares[0]).unwrap();
+    /// let message = vec![1, 2, 3, 4, 5];
+    /// let signature_share = Threshold::sign(&message, &shares[0]).unwrap();
+    ///
+    /// let valid = Threshold::verify_share(&signature_share, &message, &ver_key_share, &gen).unwrap();
+    /// assert!(valid);
+    /// ```
+    pub fn verify_share(signature_share: &SignatureShare<E>, message: &[u8], ver_key_share: &VerKeyShare<E>, gen: &Generator<E>) -> Result<bool, IndyCryptoError> {
+        Threshold::verify_share_with_ciphersuite(signature_share, message, ver_key_share, gen, &Ciphersuite::Legacy)
+    }
+
+    /// Verifies the message signature share created with provided ciphersuite against verification key share
+    /// and returns true - if signature share valid or false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature_share` - Signature share to verify
+    /// * `message` - Message to verify
+    /// * `ver_key_share` - Verification key share
+    /// * `gen` - Generator point
+    /// * `ciphersuite` - Method used to hash message to the curve
+    pub fn verify_share_with_ciphersuite(signature_share: &SignatureShare<E>, message: &[u8], ver_key_share: &VerKeyShare<E>, gen: &Generator<E>,
+                                         ciphersuite: &Ciphersuite) -> Result<bool, IndyCryptoError> {
+        if signature_share.index != ver_key_share.index {
+            return Ok(false);
+        }
+
+        let h = Bls::<E>::_hash_with_ciphersuite(message, None, ciphersuite)?;
+        Bls::_verify_pairs(&signature_share.point, gen, &[(h, &ver_key_share.point)])
+    }
+
+    /// Combines signature shares to signature using Lagrange interpolation.
+    /// At least `threshold` valid shares with distinct indexes must be provided.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// use indy_crypto::bls::threshold::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    /// let shares = Threshold::split_sign_key(&sign_key, 2, 3).unwrap();
+    ///
+    /// let message = vec![1, 2, 3, 4, 5];
+    /// let signature_share1 = Threshold::sign(&message, &shares[0]).unwrap();
+    /// let signature_share3 = Threshold::sign(&message, &shares[2]).unwrap();
+    ///
+    /// let signature = Threshold::combine(&[&signature_share1, &signature_share3]).unwrap();
+    /// assert!(Bls::verify(&signature, &message, &ver_key, &gen).unwrap());
+    /// ```
+    pub fn combine(signature_shares: &[&SignatureShare<E>]) -> Result<Signature<E>, IndyCryptoError> {
+        let indexes: Vec<u32> = signature_shares.iter().map(|share| share.index).collect();
+        let coefficients = lagrange_coefficients::<E::Scalar>(&indexes)?;
+
+        let mut point = E::G1::new_inf()?;
+        for (share, coefficient) in signature_shares.iter().zip(coefficients.iter()) {
+            point = point.add(&share.point.mul(coefficient)?)?;
+        }
+
+        Ok(Signature {
+            point,
+            bytes: point.to_bytes()?,
+            compressed_bytes: point.to_compressed_bytes()?
+        })
+    }
+}
+
+/// Evaluates polynomial with provided coefficients (starting from free term) at point `x`.
+pub(crate) fn eval_polynomial<S: PairingScalar>(coefficients: &[S], x: u32) -> Result<S, IndyCryptoError> {
+    let x: S = index_to_group_order_element(x)?;
+
+    let mut res = S::from_bytes(&[0])?;
+    for coefficient in coefficients.iter().rev() {
+        res = res.mul_mod(&x)?.add_mod(coefficient)?;
+    }
+
+    Ok(res)
+}
+
+/// Calculates Lagrange coefficients at zero for provided distinct non zero indexes.
+pub(crate) fn lagrange_coefficients<S: PairingScalar>(indexes: &[u32]) -> Result<Vec<S>, IndyCryptoError> {
+    if indexes.is_empty() {
+        return Err(IndyCryptoError::InvalidStructure(format!("Empty list of shares")));
+    }
+
+    let mut unique_indexes: HashSet<u32> = HashSet::new();
+    for index in indexes {
+        if *index == 0 || !unique_indexes.insert(*index) {
+            return Err(IndyCryptoError::InvalidStructure(format!("Invalid or duplicate share index: {}", index)));
+        }
+    }
+
+    let mut coefficients: Vec<S> = Vec::new();
+    for i in indexes {
+        let x_i: S = index_to_group_order_element(*i)?;
+
+        let mut num = S::from_bytes(&[1])?;
+        let mut denom = S::from_bytes(&[1])?;
+
+        for j in indexes {
+            if i == j { continue; }
+
+            let x_j: S = index_to_group_order_element(*j)?;
+            num = num.mul_mod(&x_j)?;
+            denom = denom.mul_mod(&x_j.add_mod(&x_i.mod_neg()?)?)?;
+        }
+
+        coefficients.push(num.mul_mod(&denom.inverse()?)?);
+    }
+
+    Ok(coefficients)
+}
+
+pub(crate) fn index_to_group_order_element<S: PairingScalar>(index: u32) -> Result<S, IndyCryptoError> {
+    S::from_bytes(&_index_to_bytes(index))
+}
+
+fn _index_to_bytes(index: u32) -> Vec<u8> {
+    (0..INDEX_BYTES_SIZE).rev().map(|i| (index >> (i * 8)) as u8).collect()
+}
+
+fn _join_index(index: u32, bytes: &[u8]) -> Vec<u8> {
+    let mut res = _index_to_bytes(index);
+    res.extend_from_slice(bytes);
+    res
+}
+
+fn _split_index(bytes: &[u8]) -> Result<(u32, &[u8]), IndyCryptoError> {
+    if bytes.len() <= INDEX_BYTES_SIZE {
+        return Err(IndyCryptoError::InvalidStructure(
+            "Invalid len of bytes representation".to_string()));
+    }
+
+    let index = bytes[..INDEX_BYTES_SIZE].iter().fold(0u32, |acc, b| (acc << 8) | *b as u32);
+    Ok((index, &bytes[INDEX_BYTES_SIZE..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bls::{Bls, Generator, SignKey, VerKey};
+
+    #[test]
+    fn split_sign_key_works() {
+        let sign_key = SignKey::new(None).unwrap();
+        let shares = Threshold::split_sign_key(&sign_key, 3, 5).unwrap();
+
+        assert_eq!(5, shares.len());
+        assert_eq!(vec![1, 2, 3, 4, 5], shares.iter().map(|share| share.index()).collect::<Vec<u32>>());
+    }
+
+    #[test]
+    fn split_sign_key_works_for_invalid_threshold() {
+        let sign_key = SignKey::new(None).unwrap();
+        assert!(Threshold::split_sign_key(&sign_key, 0, 5).is_err());
+        assert!(Threshold::split_sign_key(&sign_key, 6, 5).is_err());
+    }
+
+    #[test]
+    fn sign_key_share_from_bytes_to_bytes_works() {
+        let sign_key = SignKey::new(None).unwrap();
+        let shares = Threshold::split_sign_key(&sign_key, 2, 3).unwrap();
+
+        let share: SignKeyShare = SignKeyShare::from_bytes(shares[1].as_bytes()).unwrap();
+        assert_eq!(2, share.index());
+        assert_eq!(shares[1].as_bytes(), share.as_bytes());
+    }
+
+    #[test]
+    fn combine_works_for_any_threshold_shares() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let shares = Threshold::split_sign_key(&sign_key, 3, 5).unwrap();
+
+        let signature_shares: Vec<SignatureShare> =
+            shares.iter().map(|share| Threshold::sign(&message, share).unwrap()).collect();
+
+        for subset in vec![vec![0, 1, 2], vec![4, 2, 0], vec![1, 3, 4], vec![0, 1, 2, 3, 4]] {
+            let subset_shares: Vec<&SignatureShare> = subset.iter().map(|i| &signature_shares[*i]).collect();
+            let signature = Threshold::combine(&subset_shares).unwrap();
+            assert!(Bls::verify(&signature, &message, &ver_key, &gen).unwrap());
+        }
+    }
+
+    #[test]
+    fn combine_works_for_less_than_threshold_shares() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let shares = Threshold::split_sign_key(&sign_key, 3, 5).unwrap();
+
+        let signature_share1 = Threshold::sign(&message, &shares[0]).unwrap();
+        let signature_share2 = Threshold::sign(&message, &shares[1]).unwrap();
+
+        let signature = Threshold::combine(&[&signature_share1, &signature_share2]).unwrap();
+        assert!(!Bls::verify(&signature, &message, &ver_key, &gen).unwrap());
+    }
+
+    #[test]
+    fn combine_works_for_duplicate_shares() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let sign_key = SignKey::new(None).unwrap();
+        let shares = Threshold::split_sign_key(&sign_key, 2, 3).unwrap();
+        let signature_share = Threshold::sign(&message, &shares[0]).unwrap();
+
+        assert!(Threshold::combine(&[&signature_share, &signature_share]).is_err());
+    }
+
+    #[test]
+    fn verify_share_works() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let shares = Threshold::split_sign_key(&sign_key, 2, 3).unwrap();
+        let ver_key_share1 = VerKeyShare::new(&gen, &shares[0]).unwrap();
+        let ver_key_share2 = VerKeyShare::new(&gen, &shares[1]).unwrap();
+
+        let signature_share = Threshold::sign(&message, &shares[0]).unwrap();
+
+        assert!(Threshold::verify_share(&signature_share, &message, &ver_key_share1, &gen).unwrap());
+        assert!(!Threshold::verify_share(&signature_share, &message, &ver_key_share2, &gen).unwrap());
+    }
+
+    #[test]
+    fn combine_works_for_ciphersuites() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let shares = Threshold::split_sign_key(&sign_key, 2, 3).unwrap();
+        let ver_key_share = VerKeyShare::new(&gen, &shares[1]).unwrap();
+
+        for ciphersuite in vec![Ciphersuite::Legacy, Ciphersuite::Basic, Ciphersuite::ProofOfPossession, Ciphersuite::HashToCurve(b"TEST_DST".to_vec())] {
+            let signature_share1 = Threshold::sign_with_ciphersuite(&message, &shares[0], &ciphersuite).unwrap();
+            let signature_share2 = Threshold::sign_with_ciphersuite(&message, &shares[1], &ciphersuite).unwrap();
+            assert!(Threshold::verify_share_with_ciphersuite(&signature_share2, &message, &ver_key_share, &gen, &ciphersuite).unwrap());
+
+            let signature = Threshold::combine(&[&signature_share1, &signature_share2]).unwrap();
+            assert!(Bls::verify_with_ciphersuite(&signature, &message, &ver_key, &gen, &ciphersuite).unwrap());
+            assert_eq!(Bls::sign_with_ciphersuite(&message, &sign_key, &ciphersuite).unwrap().as_bytes(), signature.as_bytes());
+        }
+    }
+
+    #[test]
+    fn verify_share_works_for_other_ciphersuite() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let shares = Threshold::split_sign_key(&sign_key, 2, 3).unwrap();
+        let ver_key_share = VerKeyShare::new(&gen, &shares[0]).unwrap();
+
+        let signature_share = Threshold::sign_with_ciphersuite(&message, &shares[0], &Ciphersuite::Basic).unwrap();
+
+        assert!(!Threshold::verify_share(&signature_share, &message, &ver_key_share, &gen).unwrap());
+        assert!(!Threshold::verify_share_with_ciphersuite(&signature_share, &message, &ver_key_share, &gen, &Ciphersuite::ProofOfPossession).unwrap());
+    }
+
+    #[test]
+    fn sign_with_ciphersuite_works_for_message_augmentation() {
+        let sign_key = SignKey::new(None).unwrap();
+        let shares = Threshold::split_sign_key(&sign_key, 2, 3).unwrap();
+
+        assert!(Threshold::sign_with_ciphersuite(&vec![1, 2, 3, 4, 5], &shares[0], &Ciphersuite::MessageAugmentation).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "pair_bls12_381")]
+    fn combine_works_for_bls12_381() {
+        use bls::bls12_381;
+
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = bls12_381::Generator::standard().unwrap();
+        let sign_key = bls12_381::SignKey::new(None).unwrap();
+        let ver_key = bls12_381::VerKey::new(&gen, &sign_key).unwrap();
+        let shares = Threshold::split_sign_key(&sign_key, 2, 3).unwrap();
+        let ver_key_share = VerKeyShare::new(&gen, &shares[2]).unwrap();
+
+        let signature_share1 = Threshold::sign(&message, &shares[0]).unwrap();
+        let signature_share3 = Threshold::sign(&message, &shares[2]).unwrap();
+        assert!(Threshold::verify_share(&signature_share3, &message, &ver_key_share, &gen).unwrap());
+
+        let signature = Threshold::combine(&[&signature_share1, &signature_share3]).unwrap();
+        assert!(bls12_381::Bls::verify(&signature, &message, &ver_key, &gen).unwrap());
+    }
+}
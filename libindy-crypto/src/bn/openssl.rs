@@ -6,6 +6,14 @@ use openssl::bn::{BigNum, BigNumRef, BigNumContext, MSB_MAYBE_ZERO};
 use openssl::hash::{hash2, MessageDigest, Hasher};
 use openssl::error::ErrorStack;
 
+use openssl_sys::{BIGNUM, BN_CTX, BN_MONT_CTX};
+
+use foreign_types::ForeignTypeRef;
+
+use libc::c_int;
+
+use zeroize::Zeroize;
+
 #[cfg(feature = "serialization")]
 use serde::ser::{Serialize, Serializer, Error as SError};
 
@@ -16,6 +24,13 @@ use std::error::Error;
 use std::fmt;
 use std::cmp::Ord;
 use std::cmp::Ordering;
+use std::ptr;
+
+extern "C" {
+    // Exported by all supported OpenSSL versions, but not wrapped by openssl crate
+    fn BN_mod_exp_mont_consttime(rr: *mut BIGNUM, a: *const BIGNUM, p: *const BIGNUM, m: *const BIGNUM,
+                                 ctx: *mut BN_CTX, in_mont: *mut BN_MONT_CTX) -> c_int;
+}
 
 pub struct BigNumberContext {
     openssl_bn_context: BigNumContext
@@ -110,6 +125,15 @@ impl BigNumber {
         Ok(self)
     }
 
+    pub fn is_negative(&self) -> bool {
+        self.openssl_bn.is_negative()
+    }
+
+    pub fn set_negative(&mut self, negative: bool) -> Result<&mut BigNumber, IndyCryptoError> {
+        self.openssl_bn.set_negative(negative);
+        Ok(self)
+    }
+
     pub fn from_u32(n: usize) -> Result<BigNumber, IndyCryptoError> {
         let bn = BigNum::from_u32(n as u32)?;
         Ok(BigNumber {
@@ -260,6 +284,19 @@ impl BigNumber {
         Ok(bn)
     }
 
+    /// Constant-time version of `mod_exp` that must be used if exponent is secret. Modulus must be odd.
+    pub fn mod_exp_secret(&self, a: &BigNumber, b: &BigNumber, ctx: Option<&mut BigNumberContext>) -> Result<BigNumber, IndyCryptoError> {
+        let mut bn = BigNumber::new()?;
+        match ctx {
+            Some(context) => bn._mod_exp_consttime(&self, &a, &b, context)?,
+            None => {
+                let mut ctx = BigNumber::new_context()?;
+                bn._mod_exp_consttime(&self, &a, &b, &mut ctx)?;
+            }
+        }
+        Ok(bn)
+    }
+
     pub fn modulus(&self, a: &BigNumber, ctx: Option<&mut BigNumberContext>) -> Result<BigNumber, IndyCryptoError> {
         let mut bn = BigNumber::new()?;
         match ctx {
@@ -309,8 +346,11 @@ impl BigNumber {
     }
 
     pub fn clone(&self) -> Result<BigNumber, IndyCryptoError> {
+        let mut bytes = self.openssl_bn.to_vec();
+        let res = BigNum::from_slice(&bytes[..]);
+        bytes.zeroize();
         Ok(BigNumber {
-            openssl_bn: BigNum::from_slice(&self.openssl_bn.to_vec()[..])?
+            openssl_bn: res?
         })
     }
 
@@ -323,6 +363,34 @@ impl BigNumber {
 
         Ok(sha256.finish2()?.to_vec())
     }
+
+    fn _mod_exp_consttime(&mut self, a: &BigNumber, p: &BigNumber, m: &BigNumber, ctx: &mut BigNumberContext) -> Result<(), IndyCryptoError> {
+        let res = unsafe {
+            BN_mod_exp_mont_consttime(self.openssl_bn.as_ptr(),
+                                      a.openssl_bn.as_ptr(),
+                                      p.openssl_bn.as_ptr(),
+                                      m.openssl_bn.as_ptr(),
+                                      ctx.openssl_bn_context.as_ptr(),
+                                      ptr::null_mut())
+        };
+
+        if res <= 0 {
+            return Err(ErrorStack::get().into());
+        }
+        Ok(())
+    }
+}
+
+impl Zeroize for BigNumber {
+    fn zeroize(&mut self) {
+        self.openssl_bn.clear();
+    }
+}
+
+impl Drop for BigNumber {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
 }
 
 impl Ord for BigNumber {
@@ -401,6 +469,45 @@ mod tests {
         assert!(end > random_prime);
     }
 
+    #[test]
+    fn zeroize_works() {
+        let mut a = BigNumber::rand(256).unwrap();
+        a.zeroize();
+        assert_eq!(BigNumber::new().unwrap(), a);
+        assert_eq!(0, a.num_bits().unwrap());
+    }
+
+    #[test]
+    fn set_negative_works() {
+        let mut a = BigNumber::from_u32(7).unwrap();
+        assert!(!a.is_negative());
+        a.set_negative(true).unwrap();
+        assert!(a.is_negative());
+        assert_eq!("-7", a.to_dec().unwrap());
+        a.set_negative(false).unwrap();
+        assert_eq!("7", a.to_dec().unwrap());
+
+        let mut zero = BigNumber::new().unwrap();
+        zero.set_negative(true).unwrap();
+        assert!(!zero.is_negative());
+    }
+
+    #[test]
+    fn mod_exp_secret_works() {
+        let n = BigNumber::from_dec("89130615566518407633233049924431462423262009616466442113924934935498489548279").unwrap();
+        let a = BigNumber::rand(512).unwrap();
+        let e = BigNumber::rand(600).unwrap();
+
+        let expected = a.mod_exp(&e, &n, None).unwrap();
+        assert_eq!(expected, a.mod_exp_secret(&e, &n, None).unwrap());
+    }
+
+    #[test]
+    fn mod_exp_secret_works_for_even_modulus() {
+        let a = BigNumber::from_u32(5).unwrap();
+        assert!(a.mod_exp_secret(&a, &BigNumber::from_u32(12).unwrap(), None).is_err());
+    }
+
     #[cfg(feature = "serialization")]
     #[derive(Serialize, Deserialize)]
     struct Test {
@@ -0,0 +1,966 @@
+//! Pure-Rust `BigNumber` backend on top of `num-bigint`.
+//!
+//! Used when `bn_rust` feature is enabled and `bn_openssl` is disabled. Doesn't require system
+//! OpenSSL, so it can be used for musl, mobile and WebAssembly targets.
+//!
+//! Behaviour follows OpenSSL backend, so backends are interchangeable and produce the same
+//! serialized values and hashes: numbers are signed, `modulus`, `mod_mul`, `mod_sub`, `mod_exp`
+//! and `inverse` return non-negative values and use absolute values of modulus and exponent,
+//! `to_bytes` and `clone` drop the sign and comparison is done by absolute values.
+
+use errors::IndyCryptoError;
+
+use num_bigint::{BigInt, BigUint, Sign};
+use num_integer::Integer;
+use num_traits::{Num, One, Signed, ToPrimitive, Zero};
+
+use rand::os::OsRng;
+use rand::Rng;
+
+use sha2::{Sha256, Digest};
+
+#[cfg(feature = "serialization")]
+use serde::ser::{Serialize, Serializer, Error as SError};
+
+#[cfg(feature = "serialization")]
+use serde::de::{Deserialize, Deserializer, Visitor, Error as DError};
+
+use zeroize::Zeroize;
+
+use std::fmt;
+use std::cmp::Ord;
+use std::cmp::Ordering;
+
+/// Upper bound of small primes used for trial division and sieving of prime candidates.
+const SMALL_PRIMES_BOUND: u32 = 2048;
+
+/// Upper bound of primes used for sieving of safe prime candidates.
+const SAFE_PRIME_SIEVE_BOUND: u32 = 1 << 16;
+
+/// Number of odd safe prime candidates sieved at once after each random start.
+const SAFE_PRIME_SIEVE_LEN: usize = 1 << 16;
+
+pub struct BigNumberContext {
+    _priv: ()
+}
+
+#[derive(Debug)]
+pub struct BigNumber {
+    bn: BigInt
+}
+
+impl BigNumber {
+    pub fn new_context() -> Result<BigNumberContext, IndyCryptoError> {
+        Ok(BigNumberContext {
+            _priv: ()
+        })
+    }
+
+    pub fn new() -> Result<BigNumber, IndyCryptoError> {
+        Ok(BigNumber {
+            bn: BigInt::zero()
+        })
+    }
+
+    pub fn generate_prime(size: usize) -> Result<BigNumber, IndyCryptoError> {
+        if size < 2 {
+            return Err(IndyCryptoError::InvalidStructure(format!("Invalid prime size: {}", size)));
+        }
+
+        let small_primes = _small_primes(SMALL_PRIMES_BOUND);
+        let checks = _prime_checks(size);
+
+        loop {
+            // Two most significant bits are set as OpenSSL does, so product of two primes has 2 * size bits
+            let mut candidate = _rand_bits(size)?;
+            candidate.set_bit(size as u64 - 1, true);
+            candidate.set_bit(size as u64 - 2, true);
+            candidate.set_bit(0, true);
+
+            if _is_probable_prime(&candidate, &small_primes, checks)? {
+                return Ok(BigNumber::_from_biguint(candidate));
+            }
+        }
+    }
+
+    /// Generates safe prime `p = 2q + 1` of `size + 1` bits, where `q` is prime of `size` bits.
+    pub fn generate_safe_prime(size: usize) -> Result<BigNumber, IndyCryptoError> {
+        if size < 2 {
+            return Err(IndyCryptoError::InvalidStructure(format!("Invalid prime size: {}", size)));
+        }
+
+        // Sieve only by primes less than any candidate, so small q and p are not sieved out themselves
+        let small_primes = _small_primes(SMALL_PRIMES_BOUND);
+        let sieve_primes: Vec<u32> = _small_primes(SAFE_PRIME_SIEVE_BOUND)
+            .into_iter()
+            .filter(|&r| r > 2 && ((r as u64) >> (size - 1).min(63)) == 0)
+            .collect();
+        let checks = _prime_checks(size + 1);
+
+        loop {
+            let mut start = _rand_bits(size)?;
+            start.set_bit(size as u64 - 1, true);
+            start.set_bit(0, true);
+
+            // Candidate q = start + 2i is sieved out if q or 2q + 1 is divisible by one of sieve primes
+            let mut is_sieved_out = vec![false; SAFE_PRIME_SIEVE_LEN];
+            for &r in sieve_primes.iter() {
+                let r = r as usize;
+                let rem = _rem_u32(&start, r as u32) as usize;
+                let inv_two = (r + 1) / 2;
+
+                for &target in [0, (r - 1) / 2].iter() {
+                    let mut i = (target + r - rem) % r * inv_two % r;
+                    while i < SAFE_PRIME_SIEVE_LEN {
+                        is_sieved_out[i] = true;
+                        i += r;
+                    }
+                }
+            }
+
+            for i in (0..SAFE_PRIME_SIEVE_LEN).filter(|&i| !is_sieved_out[i]) {
+                let q = &start + 2 * i as u32;
+                if q.bits() != size as u64 {
+                    break;
+                }
+
+                let p: BigUint = (&q << 1) + 1u32;
+
+                // Cheap Fermat tests first, most of candidates are rejected by them
+                if _fermat_test(&q) && _fermat_test(&p)
+                    && _is_probable_prime(&q, &small_primes, checks)?
+                    && _is_probable_prime(&p, &small_primes, checks)? {
+                    return Ok(BigNumber::_from_biguint(p));
+                }
+            }
+        }
+    }
+
+    pub fn generate_prime_in_range(start: &BigNumber, end: &BigNumber) -> Result<BigNumber, IndyCryptoError> {
+        let mut prime;
+        let mut iteration = 0;
+        let mut bn_ctx = BigNumber::new_context()?;
+        let sub = end.sub(start)?;
+
+        loop {
+            prime = sub.rand_range()?;
+            prime = prime.add(start)?;
+
+            if prime.is_prime(Some(&mut bn_ctx))? {
+                debug!("Found prime in {} iteration", iteration);
+                break;
+            }
+            iteration += 1;
+        }
+
+        Ok(prime)
+    }
+
+    pub fn is_prime(&self, _ctx: Option<&mut BigNumberContext>) -> Result<bool, IndyCryptoError> {
+        if self.bn.is_negative() {
+            return Ok(false);
+        }
+
+        // Same number of Miller-Rabin rounds as OpenSSL backend uses
+        let prime_len = self.to_dec()?.len();
+        let checks = (prime_len as f64).log2() as usize;
+
+        _is_probable_prime(self.bn.magnitude(), &_small_primes(SMALL_PRIMES_BOUND), checks)
+    }
+
+    pub fn rand(size: usize) -> Result<BigNumber, IndyCryptoError> {
+        Ok(BigNumber::_from_biguint(_rand_bits(size)?))
+    }
+
+    pub fn rand_range(&self) -> Result<BigNumber, IndyCryptoError> {
+        if !self.bn.is_positive() {
+            return Err(IndyCryptoError::InvalidStructure("Invalid range for random number".to_string()));
+        }
+
+        let range = self.bn.magnitude();
+        let size = range.bits() as usize;
+
+        loop {
+            let bn = _rand_bits(size)?;
+            if bn < *range {
+                return Ok(BigNumber::_from_biguint(bn));
+            }
+        }
+    }
+
+    pub fn num_bits(&self) -> Result<i32, IndyCryptoError> {
+        Ok(self.bn.bits() as i32)
+    }
+
+    pub fn is_bit_set(&self, n: i32) -> Result<bool, IndyCryptoError> {
+        Ok(n >= 0 && self.bn.magnitude().bit(n as u64))
+    }
+
+    pub fn set_bit(&mut self, n: i32) -> Result<&mut BigNumber, IndyCryptoError> {
+        if n < 0 {
+            return Err(IndyCryptoError::InvalidStructure(format!("Invalid bit number: {}", n)));
+        }
+
+        let sign = if self.bn.is_negative() { Sign::Minus } else { Sign::Plus };
+        let mut magnitude = self.bn.magnitude().clone();
+        magnitude.set_bit(n as u64, true);
+        self.bn = BigInt::from_biguint(sign, magnitude);
+        Ok(self)
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.bn.is_negative()
+    }
+
+    /// Zero stays non-negative, the same as OpenSSL backend does.
+    pub fn set_negative(&mut self, negative: bool) -> Result<&mut BigNumber, IndyCryptoError> {
+        let sign = if negative { Sign::Minus } else { Sign::Plus };
+        let magnitude = self.bn.magnitude().clone();
+        self.bn = BigInt::from_biguint(sign, magnitude);
+        Ok(self)
+    }
+
+    pub fn from_u32(n: usize) -> Result<BigNumber, IndyCryptoError> {
+        Ok(BigNumber {
+            bn: BigInt::from(n as u32)
+        })
+    }
+
+    pub fn from_dec(dec: &str) -> Result<BigNumber, IndyCryptoError> {
+        Ok(BigNumber {
+            bn: _parse(dec, 10)?
+        })
+    }
+
+    pub fn from_hex(hex: &str) -> Result<BigNumber, IndyCryptoError> {
+        Ok(BigNumber {
+            bn: _parse(hex, 16)?
+        })
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<BigNumber, IndyCryptoError> {
+        Ok(BigNumber {
+            bn: BigInt::from_bytes_be(Sign::Plus, bytes)
+        })
+    }
+
+    pub fn to_dec(&self) -> Result<String, IndyCryptoError> {
+        Ok(self.bn.to_str_radix(10))
+    }
+
+    /// Uppercase hex with even number of digits as OpenSSL prints it.
+    pub fn to_hex(&self) -> Result<String, IndyCryptoError> {
+        if self.bn.is_zero() {
+            return Ok("0".to_string());
+        }
+
+        let mut hex = self.bn.magnitude().to_str_radix(16).to_uppercase();
+        if hex.len() % 2 == 1 {
+            hex.insert(0, '0');
+        }
+        if self.bn.is_negative() {
+            hex.insert(0, '-');
+        }
+        Ok(hex)
+    }
+
+    /// Big-endian bytes of absolute value, empty for zero.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, IndyCryptoError> {
+        if self.bn.is_zero() {
+            return Ok(Vec::new());
+        }
+        Ok(self.bn.magnitude().to_bytes_be())
+    }
+
+    pub fn hash(data: &[u8]) -> Result<Vec<u8>, IndyCryptoError> {
+        let mut sha256 = Sha256::default();
+        sha256.input(data);
+        Ok(sha256.result().to_vec())
+    }
+
+    pub fn add(&self, a: &BigNumber) -> Result<BigNumber, IndyCryptoError> {
+        Ok(BigNumber {
+            bn: &self.bn + &a.bn
+        })
+    }
+
+    pub fn sub(&self, a: &BigNumber) -> Result<BigNumber, IndyCryptoError> {
+        Ok(BigNumber {
+            bn: &self.bn - &a.bn
+        })
+    }
+
+    pub fn sqr(&self, _ctx: Option<&mut BigNumberContext>) -> Result<BigNumber, IndyCryptoError> {
+        Ok(BigNumber {
+            bn: &self.bn * &self.bn
+        })
+    }
+
+    pub fn mul(&self, a: &BigNumber, _ctx: Option<&mut BigNumberContext>) -> Result<BigNumber, IndyCryptoError> {
+        Ok(BigNumber {
+            bn: &self.bn * &a.bn
+        })
+    }
+
+    pub fn mod_mul(&self, a: &BigNumber, n: &BigNumber, _ctx: Option<&mut BigNumberContext>) -> Result<BigNumber, IndyCryptoError> {
+        Ok(BigNumber {
+            bn: _nnmod(&(&self.bn * &a.bn), &n.bn)?
+        })
+    }
+
+    pub fn mod_sub(&self, a: &BigNumber, n: &BigNumber, _ctx: Option<&mut BigNumberContext>) -> Result<BigNumber, IndyCryptoError> {
+        Ok(BigNumber {
+            bn: _nnmod(&(&self.bn - &a.bn), &n.bn)?
+        })
+    }
+
+    pub fn div(&self, a: &BigNumber, _ctx: Option<&mut BigNumberContext>) -> Result<BigNumber, IndyCryptoError> {
+        if a.bn.is_zero() {
+            return Err(IndyCryptoError::InvalidStructure("Division by zero".to_string()));
+        }
+
+        Ok(BigNumber {
+            bn: &self.bn / &a.bn
+        })
+    }
+
+    pub fn add_word(&mut self, w: u32) -> Result<&mut BigNumber, IndyCryptoError> {
+        self.bn = &self.bn + w;
+        Ok(self)
+    }
+
+    pub fn sub_word(&mut self, w: u32) -> Result<&mut BigNumber, IndyCryptoError> {
+        self.bn = &self.bn - w;
+        Ok(self)
+    }
+
+    pub fn mul_word(&mut self, w: u32) -> Result<&mut BigNumber, IndyCryptoError> {
+        self.bn = &self.bn * w;
+        Ok(self)
+    }
+
+    pub fn div_word(&mut self, w: u32) -> Result<&mut BigNumber, IndyCryptoError> {
+        if w == 0 {
+            return Err(IndyCryptoError::InvalidStructure("Division by zero".to_string()));
+        }
+
+        self.bn = &self.bn / w;
+        Ok(self)
+    }
+
+    pub fn mod_exp(&self, a: &BigNumber, b: &BigNumber, _ctx: Option<&mut BigNumberContext>) -> Result<BigNumber, IndyCryptoError> {
+        let base = _nnmod(&self.bn, &b.bn)?;
+        let modulus = b.bn.magnitude();
+
+        if modulus.is_one() {
+            return BigNumber::new();
+        }
+
+        let bn = base.magnitude().modpow(a.bn.magnitude(), modulus);
+        Ok(BigNumber::_from_biguint(bn))
+    }
+
+    /// Constant-time version of `mod_exp` that must be used if exponent is secret. Modulus must be odd.
+    ///
+    /// Sequence of operations and memory accesses depends only on sizes of exponent and modulus in 64-bit words.
+    pub fn mod_exp_secret(&self, a: &BigNumber, b: &BigNumber, _ctx: Option<&mut BigNumberContext>) -> Result<BigNumber, IndyCryptoError> {
+        let modulus = b.bn.magnitude();
+
+        if modulus.is_even() {
+            return Err(IndyCryptoError::InvalidStructure("Modulus must be odd for constant-time exponentiation".to_string()));
+        }
+
+        if modulus.is_one() {
+            return BigNumber::new();
+        }
+
+        let base = _nnmod(&self.bn, &b.bn)?;
+
+        let bn = Montgomery::new(modulus).pow(base.magnitude(), a.bn.magnitude());
+        Ok(BigNumber::_from_biguint(bn))
+    }
+
+    pub fn modulus(&self, a: &BigNumber, _ctx: Option<&mut BigNumberContext>) -> Result<BigNumber, IndyCryptoError> {
+        Ok(BigNumber {
+            bn: _nnmod(&self.bn, &a.bn)?
+        })
+    }
+
+    pub fn exp(&self, a: &BigNumber, _ctx: Option<&mut BigNumberContext>) -> Result<BigNumber, IndyCryptoError> {
+        let exp = a.bn.magnitude().to_u32()
+            .ok_or(IndyCryptoError::InvalidStructure("Exponent is too large".to_string()))?;
+
+        Ok(BigNumber {
+            bn: self.bn.pow(exp)
+        })
+    }
+
+    pub fn inverse(&self, n: &BigNumber, _ctx: Option<&mut BigNumberContext>) -> Result<BigNumber, IndyCryptoError> {
+        let a = _nnmod(&self.bn, &n.bn)?;
+
+        let bn = a.magnitude().modinv(n.bn.magnitude())
+            .ok_or(IndyCryptoError::InvalidStructure("BigNumber has no inverse".to_string()))?;
+
+        Ok(BigNumber::_from_biguint(bn))
+    }
+
+    pub fn mod_div(&self, b: &BigNumber, p: &BigNumber) -> Result<BigNumber, IndyCryptoError> {
+        //(a*  (1/b mod p) mod p)
+
+        let mut context = BigNumber::new_context()?;
+
+        let res = b
+            .inverse(p, Some(&mut context))?
+            .mul(&self, Some(&mut context))?
+            .modulus(&p, Some(&mut context))?;
+        Ok(res)
+    }
+
+    /// Copy of absolute value, the same as OpenSSL backend does.
+    pub fn clone(&self) -> Result<BigNumber, IndyCryptoError> {
+        Ok(BigNumber::_from_biguint(self.bn.magnitude().clone()))
+    }
+
+    pub fn hash_array(nums: &Vec<Vec<u8>>) -> Result<Vec<u8>, IndyCryptoError> {
+        let mut sha256 = Sha256::default();
+
+        for num in nums.iter() {
+            sha256.input(&num);
+        }
+
+        Ok(sha256.result().to_vec())
+    }
+
+    fn _from_biguint(bn: BigUint) -> BigNumber {
+        BigNumber {
+            bn: BigInt::from_biguint(Sign::Plus, bn)
+        }
+    }
+}
+
+/// Best effort: `num-bigint` doesn't expose its digits buffer, so it is overwritten with zeros in
+/// place and normalized. Copies left by reallocations during arithmetic aren't covered.
+impl Zeroize for BigNumber {
+    fn zeroize(&mut self) {
+        let len = ((self.bn.bits() + 63) / 64 * 2) as usize;
+        self.bn.assign_from_slice(Sign::Plus, &vec![0u32; len]);
+    }
+}
+
+impl Drop for BigNumber {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Ord for BigNumber {
+    fn cmp(&self, other: &BigNumber) -> Ordering {
+        self.bn.magnitude().cmp(other.bn.magnitude())
+    }
+}
+
+impl Eq for BigNumber {}
+
+impl PartialOrd for BigNumber {
+    fn partial_cmp(&self, other: &BigNumber) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for BigNumber {
+    fn eq(&self, other: &BigNumber) -> bool {
+        self.bn == other.bn
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl Serialize for BigNumber {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        serializer.serialize_newtype_struct("BigNumber", &self.to_dec().map_err(SError::custom)?)
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl<'a> Deserialize<'a> for BigNumber {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'a> {
+        struct BigNumberVisitor;
+
+        impl<'a> Visitor<'a> for BigNumberVisitor {
+            type Value = BigNumber;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("expected BigNumber")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<BigNumber, E>
+                where E: DError
+            {
+                Ok(BigNumber::from_dec(value).map_err(DError::custom)?)
+            }
+        }
+
+        deserializer.deserialize_str(BigNumberVisitor)
+    }
+}
+
+/// Parses optional minus sign followed by digits. Unlike `from_str_radix` doesn't accept `+` and `_`.
+fn _parse(s: &str, radix: u32) -> Result<BigInt, IndyCryptoError> {
+    let digits = if s.starts_with('-') { &s[1..] } else { s };
+
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return Err(IndyCryptoError::InvalidStructure(format!("Invalid BigNumber string for radix {}", radix)));
+    }
+
+    BigInt::from_str_radix(s, radix)
+        .map_err(|err| IndyCryptoError::InvalidStructure(format!("Invalid BigNumber string: {}", err)))
+}
+
+/// Non-negative remainder of division by absolute value of `m`.
+fn _nnmod(a: &BigInt, m: &BigInt) -> Result<BigInt, IndyCryptoError> {
+    if m.is_zero() {
+        return Err(IndyCryptoError::InvalidStructure("Division by zero".to_string()));
+    }
+
+    Ok(a.mod_floor(&m.abs()))
+}
+
+/// Random number of up to `size` bits.
+fn _rand_bits(size: usize) -> Result<BigUint, IndyCryptoError> {
+    let len = (size + 7) / 8;
+    let mut bytes = vec![0u8; len];
+    let mut os_rng = OsRng::new().map_err(IndyCryptoError::IOError)?;
+    os_rng.fill_bytes(&mut bytes);
+
+    if let Some(first) = bytes.first_mut() {
+        *first &= 0xFF >> (len * 8 - size);
+    }
+
+    Ok(BigUint::from_bytes_be(&bytes))
+}
+
+fn _small_primes(bound: u32) -> Vec<u32> {
+    let mut is_composite = vec![false; bound as usize];
+    let mut primes = Vec::new();
+
+    for i in 2..bound as usize {
+        if !is_composite[i] {
+            primes.push(i as u32);
+            let mut j = i * i;
+            while j < bound as usize {
+                is_composite[j] = true;
+                j += i;
+            }
+        }
+    }
+
+    primes
+}
+
+fn _rem_u32(n: &BigUint, r: u32) -> u32 {
+    n.to_u32_digits()
+        .iter()
+        .rev()
+        .fold(0u64, |rem, &digit| ((rem << 32) | digit as u64) % r as u64) as u32
+}
+
+/// Number of Miller-Rabin rounds used by OpenSSL backend for numbers of given bits size.
+fn _prime_checks(size: usize) -> usize {
+    let dec_len = (size as f64 * 2f64.log10()).ceil();
+    dec_len.log2() as usize
+}
+
+fn _fermat_test(n: &BigUint) -> bool {
+    BigUint::from(2u32).modpow(&(n - 1u32), n).is_one()
+}
+
+fn _is_probable_prime(n: &BigUint, small_primes: &[u32], checks: usize) -> Result<bool, IndyCryptoError> {
+    for &r in small_primes.iter() {
+        if *n == BigUint::from(r) {
+            return Ok(true);
+        }
+        if _rem_u32(n, r) == 0 {
+            return Ok(false);
+        }
+    }
+
+    if *n < BigUint::from(2u32) {
+        return Ok(false);
+    }
+
+    // Miller-Rabin test with random bases in [2, n - 2]
+    let n_minus_one: BigUint = n - 1u32;
+    let s = n_minus_one.trailing_zeros().unwrap_or(0);
+    let d = &n_minus_one >> s;
+    let bases_range = BigNumber::_from_biguint(n - 3u32);
+
+    for _ in 0..checks.max(1) {
+        let base = bases_range.rand_range()?.bn.magnitude() + 2u32;
+        let mut x = base.modpow(&d, n);
+
+        if x.is_one() || x == n_minus_one {
+            continue;
+        }
+
+        let mut is_witness = true;
+        for _ in 1..s {
+            x = &x * &x % n;
+            if x == n_minus_one {
+                is_witness = false;
+                break;
+            }
+        }
+
+        if is_witness {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Montgomery arithmetic over fixed number of 64-bit words for constant-time exponentiation.
+///
+/// `num-bigint` operations strip leading zero words and branch on values, so they are not used
+/// for anything depending on secret data.
+struct Montgomery {
+    m: Vec<u64>,
+    // -m^-1 mod 2^64
+    m_inv: u64,
+    // R^2 mod m, where R = 2^(64 * len)
+    r2: Vec<u64>
+}
+
+impl Montgomery {
+    fn new(m: &BigUint) -> Montgomery {
+        let m_words = m.to_u64_digits();
+        let len = m_words.len();
+
+        // Newton iteration, each step doubles number of correct low bits of m^-1
+        let mut inv: u64 = 1;
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(m_words[0].wrapping_mul(inv)));
+        }
+
+        let r2 = (BigUint::one() << (128 * len)) % m;
+
+        Montgomery {
+            r2: _to_words(&r2, len),
+            m: m_words,
+            m_inv: inv.wrapping_neg()
+        }
+    }
+
+    /// `base ^ exp mod m` for `base < m` with fixed 4-bit window and full scan of precomputed table.
+    fn pow(&self, base: &BigUint, exp: &BigUint) -> BigUint {
+        let len = self.m.len();
+        let mut one = vec![0u64; len];
+        one[0] = 1;
+
+        let mut table = Vec::with_capacity(16);
+        table.push(self.mul(&one, &self.r2));
+        table.push(self.mul(&_to_words(base, len), &self.r2));
+        for i in 2..16 {
+            let next = self.mul(&table[i - 1], &table[1]);
+            table.push(next);
+        }
+
+        let mut acc = table[0].clone();
+        for word in exp.to_u64_digits().iter().rev() {
+            for window in (0..16).rev() {
+                for _ in 0..4 {
+                    acc = self.mul(&acc, &acc);
+                }
+                let index = (word >> (4 * window)) & 0xF;
+                acc = self.mul(&acc, &_select(&table, index));
+            }
+        }
+
+        _from_words(&self.mul(&acc, &one))
+    }
+
+    /// Montgomery product `a * b / R mod m` (CIOS method) for `a, b < m`.
+    fn mul(&self, a: &[u64], b: &[u64]) -> Vec<u64> {
+        let len = self.m.len();
+        let mut t = vec![0u64; len + 2];
+
+        for i in 0..len {
+            let mut carry = 0;
+            for j in 0..len {
+                let (lo, hi) = _mac(t[j], a[j], b[i], carry);
+                t[j] = lo;
+                carry = hi;
+            }
+            let (lo, hi) = _adc(t[len], carry, 0);
+            t[len] = lo;
+            t[len + 1] = hi;
+
+            let k = t[0].wrapping_mul(self.m_inv);
+            let (_, mut carry) = _mac(t[0], k, self.m[0], 0);
+            for j in 1..len {
+                let (lo, hi) = _mac(t[j], k, self.m[j], carry);
+                t[j - 1] = lo;
+                carry = hi;
+            }
+            let (lo, hi) = _adc(t[len], carry, 0);
+            t[len - 1] = lo;
+            t[len] = t[len + 1] + hi;
+        }
+
+        // t < 2m, so single subtraction of m is enough; result is selected by mask instead of branch
+        let mut res = vec![0u64; len];
+        let mut borrow = 0;
+        for j in 0..len {
+            let (diff, b) = _sbb(t[j], self.m[j], borrow);
+            res[j] = diff;
+            borrow = b;
+        }
+        let (_, borrow) = _sbb(t[len], 0, borrow);
+
+        let keep_t = 0u64.wrapping_sub(borrow);
+        for j in 0..len {
+            res[j] = (t[j] & keep_t) | (res[j] & !keep_t);
+        }
+        res
+    }
+}
+
+fn _mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let res = a as u128 + (b as u128) * (c as u128) + carry as u128;
+    (res as u64, (res >> 64) as u64)
+}
+
+fn _adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let res = a as u128 + b as u128 + carry as u128;
+    (res as u64, (res >> 64) as u64)
+}
+
+fn _sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let res = (a as u128).wrapping_sub(b as u128 + borrow as u128);
+    (res as u64, ((res >> 64) as u64) & 1)
+}
+
+/// Reads `table[index]` touching every entry.
+fn _select(table: &[Vec<u64>], index: u64) -> Vec<u64> {
+    let mut res = vec![0u64; table[0].len()];
+
+    for (i, entry) in table.iter().enumerate() {
+        let diff = i as u64 ^ index;
+        // all ones if diff == 0, zero otherwise
+        let mask = ((diff | diff.wrapping_neg()) >> 63).wrapping_sub(1);
+        for (r, e) in res.iter_mut().zip(entry.iter()) {
+            *r |= e & mask;
+        }
+    }
+    res
+}
+
+fn _to_words(n: &BigUint, len: usize) -> Vec<u64> {
+    let mut words = n.to_u64_digits();
+    words.resize(len, 0);
+    words
+}
+
+fn _from_words(words: &[u64]) -> BigUint {
+    let digits: Vec<u32> = words.iter()
+        .flat_map(|&w| vec![w as u32, (w >> 32) as u32])
+        .collect();
+    BigUint::new(digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json;
+
+    const RANGE_LEFT: usize = 592;
+    const RANGE_RIGHT: usize = 592;
+
+    #[test]
+    #[ignore] //TODO check
+    fn generate_prime_in_range_works() {
+        let start = BigNumber::rand(RANGE_LEFT).unwrap();
+        let end = BigNumber::rand(RANGE_RIGHT).unwrap();
+        let random_prime = BigNumber::generate_prime_in_range(&start, &end).unwrap();
+        assert!(start < random_prime);
+        assert!(end > random_prime);
+    }
+
+    #[test]
+    fn zeroize_works() {
+        let mut a = BigNumber::rand(256).unwrap();
+        a.zeroize();
+        assert_eq!(BigNumber::new().unwrap(), a);
+        assert_eq!(0, a.num_bits().unwrap());
+    }
+
+    #[test]
+    fn generate_prime_works() {
+        let prime = BigNumber::generate_prime(256).unwrap();
+        assert_eq!(256, prime.num_bits().unwrap());
+        assert!(prime.is_prime(None).unwrap());
+    }
+
+    #[test]
+    fn generate_safe_prime_works() {
+        let safe_prime = BigNumber::generate_safe_prime(256).unwrap();
+        assert_eq!(257, safe_prime.num_bits().unwrap());
+        assert!(safe_prime.is_prime(None).unwrap());
+
+        let mut q = safe_prime.clone().unwrap();
+        q.div_word(2).unwrap();
+        assert!(q.is_prime(None).unwrap());
+    }
+
+    #[test]
+    fn generate_safe_prime_works_for_small_size() {
+        let safe_prime = BigNumber::generate_safe_prime(4).unwrap();
+        // 23 = 2 * 11 + 1 is the only safe prime with 4 bits q
+        assert_eq!("23", safe_prime.to_dec().unwrap());
+    }
+
+    #[test]
+    fn is_prime_works() {
+        // 2^127 - 1 is prime, 2^128 + 1 is not
+        let mersenne = BigNumber::from_dec("170141183460469231731687303715884105727").unwrap();
+        let fermat = BigNumber::from_dec("340282366920938463463374607431768211457").unwrap();
+        assert!(mersenne.is_prime(None).unwrap());
+        assert!(!fermat.is_prime(None).unwrap());
+        assert!(BigNumber::from_u32(2).unwrap().is_prime(None).unwrap());
+        assert!(!BigNumber::from_u32(1).unwrap().is_prime(None).unwrap());
+        assert!(!BigNumber::from_dec("-7").unwrap().is_prime(None).unwrap());
+    }
+
+    #[test]
+    fn rand_range_works() {
+        let range = BigNumber::from_u32(3).unwrap();
+        for _ in 0..100 {
+            assert!(range.rand_range().unwrap() < range);
+        }
+        assert!(BigNumber::new().unwrap().rand_range().is_err());
+    }
+
+    #[test]
+    fn to_hex_works() {
+        assert_eq!("0", BigNumber::new().unwrap().to_hex().unwrap());
+        assert_eq!("0FFF", BigNumber::from_u32(4095).unwrap().to_hex().unwrap());
+        assert_eq!("-07", BigNumber::from_dec("-7").unwrap().to_hex().unwrap());
+        assert_eq!("2748", BigNumber::from_hex("0abc").unwrap().to_dec().unwrap());
+    }
+
+    #[test]
+    fn from_dec_works_for_invalid_string() {
+        assert!(BigNumber::from_dec("").is_err());
+        assert!(BigNumber::from_dec("-").is_err());
+        assert!(BigNumber::from_dec("+1").is_err());
+        assert!(BigNumber::from_dec("1_000").is_err());
+    }
+
+    #[test]
+    fn to_bytes_works() {
+        assert!(BigNumber::new().unwrap().to_bytes().unwrap().is_empty());
+        assert_eq!(vec![1, 0], BigNumber::from_u32(256).unwrap().to_bytes().unwrap());
+        assert_eq!(vec![7], BigNumber::from_dec("-7").unwrap().to_bytes().unwrap());
+    }
+
+    #[test]
+    fn modulus_works_for_negative() {
+        let a = BigNumber::from_dec("-7").unwrap();
+        assert_eq!("3", a.modulus(&BigNumber::from_u32(5).unwrap(), None).unwrap().to_dec().unwrap());
+        assert_eq!("3", a.modulus(&BigNumber::from_dec("-5").unwrap(), None).unwrap().to_dec().unwrap());
+        assert_eq!("-1", a.div(&BigNumber::from_u32(5).unwrap(), None).unwrap().to_dec().unwrap());
+        assert!(a.modulus(&BigNumber::new().unwrap(), None).is_err());
+    }
+
+    #[test]
+    fn mod_exp_works() {
+        let n = BigNumber::from_u32(23).unwrap();
+        let five = BigNumber::from_u32(5).unwrap();
+        assert_eq!("10", five.mod_exp(&BigNumber::from_u32(3).unwrap(), &n, None).unwrap().to_dec().unwrap());
+        assert_eq!("10", five.mod_exp(&BigNumber::from_dec("-3").unwrap(), &n, None).unwrap().to_dec().unwrap());
+        assert_eq!("13", BigNumber::from_dec("-5").unwrap().mod_exp(&BigNumber::from_u32(3).unwrap(), &n, None).unwrap().to_dec().unwrap());
+        assert_eq!("5", five.mod_exp(&five, &BigNumber::from_u32(12).unwrap(), None).unwrap().to_dec().unwrap());
+        assert_eq!("0", five.mod_exp(&five, &BigNumber::from_u32(1).unwrap(), None).unwrap().to_dec().unwrap());
+    }
+
+    #[test]
+    fn mod_exp_secret_works() {
+        let n = BigNumber::from_dec("89130615566518407633233049924431462423262009616466442113924934935498489548279").unwrap();
+
+        for _ in 0..10 {
+            let a = BigNumber::rand(512).unwrap();
+            let e = BigNumber::rand(600).unwrap();
+
+            let expected = a.mod_exp(&e, &n, None).unwrap();
+            assert_eq!(expected, a.mod_exp_secret(&e, &n, None).unwrap());
+        }
+    }
+
+    #[test]
+    fn mod_exp_secret_works_for_edge_cases() {
+        let n = BigNumber::from_u32(23).unwrap();
+        let five = BigNumber::from_u32(5).unwrap();
+        assert_eq!("10", five.mod_exp_secret(&BigNumber::from_dec("-3").unwrap(), &n, None).unwrap().to_dec().unwrap());
+        assert_eq!("13", BigNumber::from_dec("-5").unwrap().mod_exp_secret(&BigNumber::from_u32(3).unwrap(), &n, None).unwrap().to_dec().unwrap());
+        assert_eq!("1", five.mod_exp_secret(&BigNumber::new().unwrap(), &n, None).unwrap().to_dec().unwrap());
+        assert_eq!("0", n.mod_exp_secret(&five, &n, None).unwrap().to_dec().unwrap());
+        assert_eq!("0", five.mod_exp_secret(&five, &BigNumber::from_u32(1).unwrap(), None).unwrap().to_dec().unwrap());
+        assert!(five.mod_exp_secret(&five, &BigNumber::from_u32(12).unwrap(), None).is_err());
+    }
+
+    #[test]
+    fn inverse_works() {
+        let n = BigNumber::from_u32(5).unwrap();
+        assert_eq!("2", BigNumber::from_dec("-7").unwrap().inverse(&n, None).unwrap().to_dec().unwrap());
+        assert!(BigNumber::from_u32(10).unwrap().inverse(&n, None).is_err());
+    }
+
+    #[test]
+    fn set_negative_works() {
+        let mut a = BigNumber::from_u32(7).unwrap();
+        assert!(!a.is_negative());
+        a.set_negative(true).unwrap();
+        assert!(a.is_negative());
+        assert_eq!("-7", a.to_dec().unwrap());
+        a.set_negative(false).unwrap();
+        assert_eq!("7", a.to_dec().unwrap());
+
+        let mut zero = BigNumber::new().unwrap();
+        zero.set_negative(true).unwrap();
+        assert!(!zero.is_negative());
+    }
+
+    #[test]
+    fn cmp_works_for_negative() {
+        let a = BigNumber::from_dec("-7").unwrap();
+        assert!(a > BigNumber::from_u32(5).unwrap());
+        assert!(a != BigNumber::from_u32(7).unwrap());
+        assert_eq!("7", a.clone().unwrap().to_dec().unwrap());
+    }
+
+    #[cfg(feature = "serialization")]
+    #[derive(Serialize, Deserialize)]
+    struct Test {
+        field: BigNumber
+    }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn serialize_works() {
+        let s = Test { field: BigNumber::from_dec("1").unwrap() };
+        let serialized = serde_json::to_string(&s);
+
+        assert!(serialized.is_ok());
+        assert_eq!("{\"field\":\"1\"}", serialized.unwrap());
+    }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn deserialize_works() {
+        let s = "{\"field\":\"1\"}";
+        let bn: Result<Test, _> = serde_json::from_str(&s);
+
+        assert!(bn.is_ok());
+        assert_eq!("1", bn.unwrap().field.to_dec().unwrap());
+    }
+}
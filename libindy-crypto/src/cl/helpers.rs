@@ -1,8 +1,9 @@
-use bn::BigNumber;
+use bn::{BigNumber, BigNumberContext};
 use cl::*;
 use errors::IndyCryptoError;
 use pair::GroupOrderElement;
 use super::constants::*;
+use utils::secret::Secret;
 
 use std::cmp::max;
 use std::collections::{HashMap, HashSet};
@@ -74,7 +75,7 @@ pub fn _bn_rand(size: usize) -> Result<BigNumber, IndyCryptoError> {
 
     let res = BigNumber::rand(size)?;
 
-    trace!("Helpers::bn_rand: <<< res: {:?}", res);
+    trace!("Helpers::bn_rand: <<< res: {:?}", Secret(&res));
 
     Ok(res)
 }
@@ -94,13 +95,13 @@ pub fn _bn_rand_range(bn: &BigNumber) -> Result<BigNumber, IndyCryptoError> {
 
     let res = bn.rand_range()?;
 
-    trace!("Helpers::bn_rand_range: <<< res: {:?}", res);
+    trace!("Helpers::bn_rand_range: <<< res: {:?}", Secret(&res));
 
     Ok(res)
 }
 
 pub fn encode_attribute(attribute: &str, byte_order: ByteOrder) -> Result<BigNumber, IndyCryptoError> {
-    trace!("Helpers::encode_attribute: >>> attribute: {:?}, byte_order: {:?}", attribute, byte_order);
+    trace!("Helpers::encode_attribute: >>> attribute: {:?}, byte_order: {:?}", Secret(attribute), byte_order);
     let mut result = BigNumber::hash(attribute.as_bytes())?;
 
     if let ByteOrder::Little = byte_order {
@@ -109,7 +110,7 @@ pub fn encode_attribute(attribute: &str, byte_order: ByteOrder) -> Result<BigNum
 
     let encoded_attribute = BigNumber::from_bytes(&result)?;
 
-    trace!("Helpers::encode_attribute: <<< encoded_attribute: {:?}", encoded_attribute);
+    trace!("Helpers::encode_attribute: <<< encoded_attribute: {:?}", Secret(&encoded_attribute));
 
     Ok(encoded_attribute)
 }
@@ -188,7 +189,7 @@ pub fn _generate_safe_prime(size: usize) -> Result<BigNumber, IndyCryptoError> {
 
     let safe_prime = BigNumber::generate_safe_prime(size)?;
 
-    trace!("Helpers::generate_safe_prime: <<< safe_prime: {:?}", safe_prime);
+    trace!("Helpers::generate_safe_prime: <<< safe_prime: {:?}", Secret(&safe_prime));
 
     Ok(safe_prime)
 }
@@ -303,11 +304,12 @@ pub fn get_mtilde(unrevealed_attrs: &HashSet<String>) -> Result<HashMap<String,
         mtilde.insert(attr.clone(), bn_rand(LARGE_MVECT)?);
     }
 
-    trace!("Helpers::get_mtilde: <<< mtilde: {:?}", mtilde);
+    trace!("Helpers::get_mtilde: <<< mtilde: {:?}", Secret(&mtilde));
 
     Ok(mtilde)
 }
 
+//Prover passes secret tilde values, so constant-time exponentiation is used
 pub fn calc_teq(p_pub_key: &CredentialPrimaryPublicKey,
                 a_prime: &BigNumber,
                 e: &BigNumber,
@@ -317,11 +319,11 @@ pub fn calc_teq(p_pub_key: &CredentialPrimaryPublicKey,
                 m2tilde: &BigNumber,
                 unrevealed_attrs: &HashSet<String>) -> Result<BigNumber, IndyCryptoError> {
     trace!("Helpers::calc_teq: >>> p_pub_key: {:?}, p_pub_key: {:?}, e: {:?}, v: {:?}, m_tilde: {:?}, m1_tilde: {:?}, m2tilde: {:?}, \
-    unrevealed_attrs: {:?}", p_pub_key, a_prime, e, v, m_tilde, m1_tilde, m2tilde, unrevealed_attrs);
+    unrevealed_attrs: {:?}", p_pub_key, a_prime, Secret(e), Secret(v), Secret(m_tilde), Secret(m1_tilde), Secret(m2tilde), unrevealed_attrs);
 
     let mut ctx = BigNumber::new_context()?;
     let mut result: BigNumber = a_prime
-        .mod_exp(&e, &p_pub_key.n, Some(&mut ctx))?;
+        .mod_exp_secret(&e, &p_pub_key.n, Some(&mut ctx))?;
 
     for k in unrevealed_attrs.iter() {
         let cur_r = p_pub_key.r.get(k)
@@ -330,20 +332,20 @@ pub fn calc_teq(p_pub_key: &CredentialPrimaryPublicKey,
             .ok_or(IndyCryptoError::InvalidStructure(format!("Value by key '{}' not found in mtilde", k)))?;
 
         result = cur_r
-            .mod_exp(&cur_m, &p_pub_key.n, Some(&mut ctx))?
+            .mod_exp_secret(&cur_m, &p_pub_key.n, Some(&mut ctx))?
             .mod_mul(&result, &p_pub_key.n, Some(&mut ctx))?;
     }
 
     result = p_pub_key.s
-        .mod_exp(&v, &p_pub_key.n, Some(&mut ctx))?
+        .mod_exp_secret(&v, &p_pub_key.n, Some(&mut ctx))?
         .mod_mul(&result, &p_pub_key.n, Some(&mut ctx))?;
 
     result = p_pub_key.rms
-        .mod_exp(&m1_tilde, &p_pub_key.n, Some(&mut ctx))?
+        .mod_exp_secret(&m1_tilde, &p_pub_key.n, Some(&mut ctx))?
         .mod_mul(&result, &p_pub_key.n, Some(&mut ctx))?;
 
     result = p_pub_key.rctxt
-        .mod_exp(&m2tilde, &p_pub_key.n, Some(&mut ctx))?
+        .mod_exp_secret(&m2tilde, &p_pub_key.n, Some(&mut ctx))?
         .mod_mul(&result, &p_pub_key.n, Some(&mut ctx))?;
 
     trace!("Helpers::calc_teq: <<< t: {:?}", result);
@@ -351,13 +353,17 @@ pub fn calc_teq(p_pub_key: &CredentialPrimaryPublicKey,
     Ok(result)
 }
 
+//Same as in calc_teq: u, r, mj and alpha are secret tilde values when called by prover
+//Attribute enters delta of LE and LT predicates with minus sign, so Z^(-mj) is used for them
 pub fn calc_tge(p_pub_key: &CredentialPrimaryPublicKey,
                 u: &HashMap<String, BigNumber>,
                 r: &HashMap<String, BigNumber>,
                 mj: &BigNumber,
                 alpha: &BigNumber,
-                t: &HashMap<String, BigNumber>) -> Result<Vec<BigNumber>, IndyCryptoError> {
-    trace!("Helpers::calc_tge: >>> p_pub_key: {:?}, u: {:?}, r: {:?}, mj: {:?}, alpha: {:?}, t: {:?}", p_pub_key, u, r, mj, alpha, t);
+                t: &HashMap<String, BigNumber>,
+                predicate: &Predicate) -> Result<Vec<BigNumber>, IndyCryptoError> {
+    trace!("Helpers::calc_tge: >>> p_pub_key: {:?}, u: {:?}, r: {:?}, mj: {:?}, alpha: {:?}, t: {:?}, predicate: {:?}",
+           p_pub_key, Secret(u), Secret(r), Secret(mj), Secret(alpha), t, predicate);
 
     let mut tau_list: Vec<BigNumber> = Vec::new();
     let mut ctx = BigNumber::new_context()?;
@@ -369,9 +375,9 @@ pub fn calc_tge(p_pub_key: &CredentialPrimaryPublicKey,
             .ok_or(IndyCryptoError::InvalidStructure(format!("Value by key '{}' not found in r", i)))?;
 
         let t_tau = p_pub_key.z
-            .mod_exp(&cur_u, &p_pub_key.n, Some(&mut ctx))?
+            .mod_exp_secret(&cur_u, &p_pub_key.n, Some(&mut ctx))?
             .mod_mul(
-                &p_pub_key.s.mod_exp(&cur_r, &p_pub_key.n, Some(&mut ctx))?,
+                &p_pub_key.s.mod_exp_secret(&cur_r, &p_pub_key.n, Some(&mut ctx))?,
                 &p_pub_key.n, Some(&mut ctx)
             )?;
 
@@ -381,10 +387,15 @@ pub fn calc_tge(p_pub_key: &CredentialPrimaryPublicKey,
     let delta = r.get("DELTA")
         .ok_or(IndyCryptoError::InvalidStructure(format!("Value by key '{}' not found in r", "DELTA")))?;
 
-    let t_tau = p_pub_key.z
-        .mod_exp(&mj, &p_pub_key.n, Some(&mut ctx))?
+    let mut z_mj = p_pub_key.z.mod_exp_secret(&mj, &p_pub_key.n, Some(&mut ctx))?;
+
+    if predicate.is_less() {
+        z_mj = z_mj.inverse(&p_pub_key.n, Some(&mut ctx))?;
+    }
+
+    let t_tau = z_mj
         .mod_mul(
-            &p_pub_key.s.mod_exp(&delta, &p_pub_key.n, Some(&mut ctx))?,
+            &p_pub_key.s.mod_exp_secret(&delta, &p_pub_key.n, Some(&mut ctx))?,
             &p_pub_key.n, Some(&mut ctx)
         )?;
 
@@ -399,12 +410,12 @@ pub fn calc_tge(p_pub_key: &CredentialPrimaryPublicKey,
             .ok_or(IndyCryptoError::InvalidStructure(format!("Value by key '{}' not found in u", i)))?;
 
         q = cur_t
-            .mod_exp(&cur_u, &p_pub_key.n, Some(&mut ctx))?
+            .mod_exp_secret(&cur_u, &p_pub_key.n, Some(&mut ctx))?
             .mul(&q, Some(&mut ctx))?;
     }
 
     q = p_pub_key.s
-        .mod_exp(&alpha, &p_pub_key.n, Some(&mut ctx))?
+        .mod_exp_secret(&alpha, &p_pub_key.n, Some(&mut ctx))?
         .mod_mul(&q, &p_pub_key.n, Some(&mut ctx))?;
 
     tau_list.push(q);
@@ -414,21 +425,66 @@ pub fn calc_tge(p_pub_key: &CredentialPrimaryPublicKey,
     Ok(tau_list)
 }
 
-fn largest_square_less_than(delta: usize) -> usize {
-    (delta as f64).sqrt().floor() as usize
+fn largest_square_less_than(delta: u64) -> u64 {
+    (delta as f64).sqrt().floor() as u64
 }
 
+/// Numbers up to this bit length are decomposed by exhaustive search.
+const FOUR_SQUARES_SEARCH_BITS: i32 = 16;
+
 //Express the natural number `delta` as a sum of four integer squares,
-// i.e `delta = a^2 + b^2 + c^2 + d^2` using Lagrange's four-square theorem
-pub fn four_squares(delta: i32) -> Result<HashMap<String, BigNumber>, IndyCryptoError> {
-    trace!("Helpers::four_squares: >>> delta: {:?}", delta);
+// i.e `delta = a^2 + b^2 + c^2 + d^2` using Lagrange's four-square theorem.
+// Small numbers are decomposed by exhaustive search, large ones by randomized Rabin–Shallit algorithm.
+pub fn four_squares(delta: &BigNumber) -> Result<HashMap<String, BigNumber>, IndyCryptoError> {
+    trace!("Helpers::four_squares: >>> delta: {:?}", Secret(delta));
+
+    if delta.is_negative() {
+        return Err(IndyCryptoError::InvalidStructure(format!("Cannot express a negative number as sum of four squares {:?} ", Secret(delta))));
+    }
+
+    let mut ctx = BigNumber::new_context()?;
+
+    // delta = 4^v * n where n is not divisible by 4, roots of n are scaled by 2^v at the end
+    let mut n = delta.clone()?;
+    let mut v = 0;
+
+    while n.num_bits()? > FOUR_SQUARES_SEARCH_BITS && !n.is_bit_set(0)? && !n.is_bit_set(1)? {
+        n.div_word(4)?;
+        v += 1;
+    }
+
+    let mut roots = if n.num_bits()? <= FOUR_SQUARES_SEARCH_BITS {
+        let n = n.to_dec()?.parse::<u64>()
+            .map_err(|_| IndyCryptoError::InvalidStructure("Invalid number for four squares search".to_string()))?;
+
+        _four_squares_search(n)
+            .iter()
+            .map(|root| BigNumber::from_dec(&root.to_string()))
+            .collect::<Result<Vec<BigNumber>, IndyCryptoError>>()?
+    } else {
+        _four_squares_rabin_shallit(&n, &mut ctx)?
+    };
+
+    for root in roots.iter_mut() {
+        for _ in 0..v {
+            root.mul_word(2)?;
+        }
+    }
+
+    roots.sort_by(|a, b| b.cmp(a));
 
-    if delta < 0 {
-        return Err(IndyCryptoError::InvalidStructure(format!("Cannot express a negative number as sum of four squares {} ", delta)));
+    let mut res: HashMap<String, BigNumber> = HashMap::new();
+    for (i, root) in roots.into_iter().enumerate() {
+        res.insert(i.to_string(), root);
     }
 
-    let d = delta as usize;
-    let mut roots: [usize; 4] = [largest_square_less_than(d), 0, 0, 0];
+    trace!("Helpers::four_squares: <<< res: {:?}", Secret(&res));
+
+    Ok(res)
+}
+
+fn _four_squares_search(d: u64) -> [u64; 4] {
+    let mut roots: [u64; 4] = [largest_square_less_than(d), 0, 0, 0];
 
     'outer: for i in (1..roots[0] + 1).rev() {
         roots[0] = i;
@@ -461,15 +517,115 @@ pub fn four_squares(delta: i32) -> Result<HashMap<String, BigNumber>, IndyCrypto
         }
     }
 
-    let mut res: HashMap<String, BigNumber> = HashMap::new();
-    res.insert("0".to_string(), BigNumber::from_dec(&roots[0].to_string()[..])?);
-    res.insert("1".to_string(), BigNumber::from_dec(&roots[1].to_string()[..])?);
-    res.insert("2".to_string(), BigNumber::from_dec(&roots[2].to_string()[..])?);
-    res.insert("3".to_string(), BigNumber::from_dec(&roots[3].to_string()[..])?);
+    roots
+}
 
-    trace!("Helpers::four_squares: <<< res: {:?}", res);
+// Rabin–Shallit decomposition of `n` that is not divisible by 4:
+// odd `n` is doubled, so `m = 2 (mod 4)`, random `x`, `y` of different parity are picked
+// until `p = m - x^2 - y^2` is prime, `p = 1 (mod 4)` is then a sum of two squares.
+fn _four_squares_rabin_shallit(n: &BigNumber, ctx: &mut BigNumberContext) -> Result<Vec<BigNumber>, IndyCryptoError> {
+    let is_odd = n.is_bit_set(0)?;
 
-    Ok(res)
+    let mut m = n.clone()?;
+    if is_odd {
+        m.mul_word(2)?;
+    }
+
+    let mut half_m = m.clone()?;
+    half_m.div_word(2)?;
+    let mut range = _isqrt(&half_m, ctx)?;
+    range.add_word(1)?;
+
+    let mut roots = loop {
+        let x = range.rand_range()?;
+        let y = range.rand_range()?;
+
+        if x.is_bit_set(0)? == y.is_bit_set(0)? {
+            continue;
+        }
+
+        let p = m
+            .sub(&x.sqr(Some(ctx))?)?
+            .sub(&y.sqr(Some(ctx))?)?;
+
+        if !p.is_prime(Some(ctx))? {
+            continue;
+        }
+
+        let (z, w) = _two_squares(&p, ctx)?;
+        break vec![x, y, z, w];
+    };
+
+    if is_odd {
+        // m = 2n, so roots have even number of odd ones and can be paired by parity:
+        // n = ((a + b) / 2)^2 + ((a - b) / 2)^2 + ((c + d) / 2)^2 + ((c - d) / 2)^2
+        roots.sort_by_key(|root| root.is_bit_set(0).unwrap_or(false));
+
+        let mut halves = Vec::new();
+        for pair in roots.chunks(2) {
+            let (a, b) = if pair[0] >= pair[1] { (&pair[0], &pair[1]) } else { (&pair[1], &pair[0]) };
+
+            let mut sum = a.add(b)?;
+            sum.div_word(2)?;
+            let mut diff = a.sub(b)?;
+            diff.div_word(2)?;
+
+            halves.push(sum);
+            halves.push(diff);
+        }
+        roots = halves;
+    }
+
+    Ok(roots)
+}
+
+// Decomposes prime `p = 1 (mod 4)` as `z^2 + w^2`: finds square root `u` of -1 modulo `p`
+// and runs Euclidean algorithm on `(p, u)` until remainder drops below `sqrt(p)`.
+fn _two_squares(p: &BigNumber, ctx: &mut BigNumberContext) -> Result<(BigNumber, BigNumber), IndyCryptoError> {
+    let mut p_minus_one = p.clone()?;
+    p_minus_one.sub_word(1)?;
+
+    let mut exp = p_minus_one.clone()?;
+    exp.div_word(4)?;
+
+    loop {
+        let c = p.rand_range()?;
+        let u = c.mod_exp(&exp, p, Some(ctx))?;
+
+        if u.mod_mul(&u, p, Some(ctx))? != p_minus_one {
+            continue;
+        }
+
+        let mut a = p.clone()?;
+        let mut b = u;
+
+        while b.sqr(Some(ctx))? > *p {
+            let r = a.modulus(&b, Some(ctx))?;
+            a = b;
+            b = r;
+        }
+
+        let w = a.modulus(&b, Some(ctx))?;
+
+        if b.sqr(Some(ctx))?.add(&w.sqr(Some(ctx))?)? == *p {
+            return Ok((b, w));
+        }
+    }
+}
+
+fn _isqrt(n: &BigNumber, ctx: &mut BigNumberContext) -> Result<BigNumber, IndyCryptoError> {
+    let mut x = BigNumber::new()?;
+    x.set_bit((n.num_bits()? + 1) / 2)?;
+
+    loop {
+        let mut y = x.add(&n.div(&x, Some(ctx))?)?;
+        y.div_word(2)?;
+
+        if y >= x {
+            return Ok(x);
+        }
+        x = y;
+    }
 }
 
 pub fn group_element_to_bignum(el: &GroupOrderElement) -> Result<BigNumber, IndyCryptoError> {
@@ -489,16 +645,18 @@ pub fn create_tau_list_expected_values(r_pub_key: &CredentialRevocationPublicKey
 
     let t1 = proof_c.e;
     let t2 = PointG1::new_inf()?;
-    let t3 = Pair::pair(&r_pub_key.h0.add(&proof_c.g)?, &r_pub_key.h_cap)?
-        .mul(&Pair::pair(&proof_c.a, &r_pub_key.y)?.inverse()?)?;
-    let t4 = Pair::pair(&proof_c.g, &rev_reg.accum)?
-        .mul(&Pair::pair(&r_pub_key.g, &proof_c.w)?.mul(&rev_acc_pub_key.z)?.inverse()?)?;
+    // e(a, b) * e(c, d)^-1 is computed as single multi pairing e(a, b) * e(-c, d)
+    let t3 = Pair::multi_pair(&[(&r_pub_key.h0.add(&proof_c.g)?, &r_pub_key.h_cap),
+                                (&proof_c.a.neg()?, &r_pub_key.y)])?;
+    let t4 = Pair::multi_pair(&[(&proof_c.g, &rev_reg.accum),
+                                (&r_pub_key.g.neg()?, &proof_c.w)])?
+        .mul(&rev_acc_pub_key.z.inverse()?)?;
     let t5 = proof_c.d;
     let t6 = PointG1::new_inf()?;
-    let t7 = Pair::pair(&r_pub_key.pk.add(&proof_c.g)?, &proof_c.s)?
-        .mul(&Pair::pair(&r_pub_key.g, &r_pub_key.g_dash)?.inverse()?)?;
-    let t8 = Pair::pair(&proof_c.g, &r_pub_key.u)?
-        .mul(&Pair::pair(&r_pub_key.g, &proof_c.u)?.inverse()?)?;
+    let t7 = Pair::multi_pair(&[(&r_pub_key.pk.add(&proof_c.g)?, &proof_c.s),
+                                (&r_pub_key.g.neg()?, &r_pub_key.g_dash)])?;
+    let t8 = Pair::multi_pair(&[(&proof_c.g, &r_pub_key.u),
+                                (&r_pub_key.g.neg()?, &proof_c.u)])?;
 
     let non_revoc_proof_tau_list = NonRevocProofTauList {
         t1,
@@ -523,34 +681,32 @@ pub fn create_tau_list_values(r_pub_key: &CredentialRevocationPublicKey,
     trace!("Helpers::create_tau_list_values: >>> r_pub_key: {:?}, rev_reg: {:?}, params: {:?}, proof_c: {:?}",
            r_pub_key, rev_reg, params, proof_c);
 
-    let t1 = r_pub_key.h.mul(&params.rho)?.add(&r_pub_key.htilde.mul(&params.o)?)?;
-    let mut t2 = proof_c.e.mul(&params.c)?
-        .add(&r_pub_key.h.mul(&params.m.mod_neg()?)?)?
-        .add(&r_pub_key.htilde.mul(&params.t.mod_neg()?)?)?;
+    let t1 = PointG1::multi_mul(&[r_pub_key.h, r_pub_key.htilde], &[params.rho.clone(), params.o.clone()])?;
+    let mut t2 = PointG1::multi_mul(&[proof_c.e, r_pub_key.h, r_pub_key.htilde],
+                                    &[params.c.clone(), params.m.mod_neg()?, params.t.mod_neg()?])?;
     if t2.is_inf()? {
         t2 = PointG1::new_inf()?;
     }
-    let t3 = Pair::pair(&proof_c.a, &r_pub_key.h_cap)?.pow(&params.c)?
-        .mul(&Pair::pair(&r_pub_key.htilde, &r_pub_key.h_cap)?.pow(&params.r)?)?
-        .mul(&Pair::pair(&r_pub_key.htilde, &r_pub_key.y)?.pow(&params.rho)?
-            .mul(&Pair::pair(&r_pub_key.htilde, &r_pub_key.h_cap)?.pow(&params.m)?)?
-            .mul(&Pair::pair(&r_pub_key.h1, &r_pub_key.h_cap)?.pow(&params.m2)?)?
-            .mul(&Pair::pair(&r_pub_key.h2, &r_pub_key.h_cap)?.pow(&params.s)?)?.inverse()?)?;
-    let t4 = Pair::pair(&r_pub_key.htilde, &rev_reg.accum)?
-        .pow(&params.r)?
-        .mul(&Pair::pair(&r_pub_key.g.neg()?, &r_pub_key.h_cap)?.pow(&params.r_prime)?)?;
-    let t5 = r_pub_key.g.mul(&params.r)?.add(&r_pub_key.htilde.mul(&params.o_prime)?)?;
-    let mut t6 = proof_c.d.mul(&params.r_prime_prime)?
-        .add(&r_pub_key.g.mul(&params.m_prime.mod_neg()?)?)?
-        .add(&r_pub_key.htilde.mul(&params.t_prime.mod_neg()?)?)?;
+    // Exponents are moved to G1 side (e(a, b)^k = e(a * k, b)) and pairings with
+    // the same G2 point are merged, so each tau value needs single multi pairing
+    let t3 = Pair::multi_pair(&[(&PointG1::multi_mul(&[proof_c.a, r_pub_key.htilde, r_pub_key.h1, r_pub_key.h2],
+                                                     &[params.c.clone(), params.r.add_mod(&params.m.mod_neg()?)?, params.m2.mod_neg()?, params.s.mod_neg()?])?,
+                                 &r_pub_key.h_cap),
+                                (&r_pub_key.htilde.mul(&params.rho.mod_neg()?)?, &r_pub_key.y)])?;
+    let t4 = Pair::multi_pair(&[(&r_pub_key.htilde.mul(&params.r)?, &rev_reg.accum),
+                                (&r_pub_key.g.neg()?.mul(&params.r_prime)?, &r_pub_key.h_cap)])?;
+    let t5 = PointG1::multi_mul(&[r_pub_key.g, r_pub_key.htilde], &[params.r.clone(), params.o_prime.clone()])?;
+    let mut t6 = PointG1::multi_mul(&[proof_c.d, r_pub_key.g, r_pub_key.htilde],
+                                    &[params.r_prime_prime.clone(), params.m_prime.mod_neg()?, params.t_prime.mod_neg()?])?;
     if t6.is_inf()? {
         t6 = PointG1::new_inf()?;
     }
-    let t7 = Pair::pair(&r_pub_key.pk.add(&proof_c.g)?, &r_pub_key.h_cap)?.pow(&params.r_prime_prime)?
-        .mul(&Pair::pair(&r_pub_key.htilde, &r_pub_key.h_cap)?.pow(&params.m_prime.mod_neg()?)?)?
-        .mul(&Pair::pair(&r_pub_key.htilde, &proof_c.s)?.pow(&params.r)?)?;
-    let t8 = Pair::pair(&r_pub_key.htilde, &r_pub_key.u)?.pow(&params.r)?
-        .mul(&Pair::pair(&r_pub_key.g.neg()?, &r_pub_key.h_cap)?.pow(&params.r_prime_prime_prime)?)?;
+    let t7 = Pair::multi_pair(&[(&PointG1::multi_mul(&[r_pub_key.pk.add(&proof_c.g)?, r_pub_key.htilde],
+                                                     &[params.r_prime_prime.clone(), params.m_prime.mod_neg()?])?,
+                                 &r_pub_key.h_cap),
+                                (&r_pub_key.htilde.mul(&params.r)?, &proof_c.s)])?;
+    let t8 = Pair::multi_pair(&[(&r_pub_key.htilde.mul(&params.r)?, &r_pub_key.u),
+                                (&r_pub_key.g.neg()?.mul(&params.r_prime_prime_prime)?, &r_pub_key.h_cap)])?;
 
     let non_revoc_proof_tau_list = NonRevocProofTauList {
         t1,
@@ -584,6 +740,7 @@ macro_rules! hashset {
 mod tests {
     use super::*;
     use cl::{issuer, prover};
+    use pair::{Pair, PointG1, PointG2};
 
     #[test]
     fn encode_attribute_works() {
@@ -622,7 +779,7 @@ mod tests {
 
     #[test]
     fn four_squares_works() {
-        let res = four_squares(107 as i32);
+        let res = four_squares(&BigNumber::from_u32(107).unwrap());
         let res_data = res.unwrap();
 
         assert_eq!("9".to_string(), res_data.get("0").unwrap().to_dec().unwrap());
@@ -630,7 +787,7 @@ mod tests {
         assert_eq!("1".to_string(), res_data.get("2").unwrap().to_dec().unwrap());
         assert_eq!("0".to_string(), res_data.get("3").unwrap().to_dec().unwrap());
 
-        let res = four_squares(112 as i32);
+        let res = four_squares(&BigNumber::from_u32(112).unwrap());
         let res_data = res.unwrap();
 
         assert_eq!("10".to_string(), res_data.get("0").unwrap().to_dec().unwrap());
@@ -639,7 +796,7 @@ mod tests {
         assert_eq!("2".to_string(), res_data.get("3").unwrap().to_dec().unwrap());
 
 
-        let res = four_squares(253 as i32);
+        let res = four_squares(&BigNumber::from_u32(253).unwrap());
         let res_data = res.unwrap();
 
         assert_eq!("14".to_string(), res_data.get("0").unwrap().to_dec().unwrap());
@@ -647,13 +804,24 @@ mod tests {
         assert_eq!("2".to_string(), res_data.get("2").unwrap().to_dec().unwrap());
         assert_eq!("2".to_string(), res_data.get("3").unwrap().to_dec().unwrap());
 
-        let res = four_squares(1506099439 as i32);
-        let res_data = res.unwrap();
+        for delta in &["1506099439", "4294967296", "2417851639229258349412352", "9223372036854775807", "340282366920938463463374607431768211457",
+            "6355086599653879826316700099928903465759924565682653297540990486160410136991969646604012568191576052570982028627086748"] {
+            let delta = BigNumber::from_dec(delta).unwrap();
+            let res_data = four_squares(&delta).unwrap();
 
-        assert_eq!("38807".to_string(), res_data.get("0").unwrap().to_dec().unwrap());
-        assert_eq!("337".to_string(), res_data.get("1").unwrap().to_dec().unwrap());
-        assert_eq!("50".to_string(), res_data.get("2").unwrap().to_dec().unwrap());
-        assert_eq!("11".to_string(), res_data.get("3").unwrap().to_dec().unwrap());
+            let mut sum = BigNumber::new().unwrap();
+            for i in 0..4 {
+                let root = res_data.get(&i.to_string()).unwrap();
+                assert!(!root.is_negative());
+                sum = sum.add(&root.sqr(None).unwrap()).unwrap();
+            }
+            assert_eq!(delta, sum);
+        }
+    }
+
+    #[test]
+    fn four_squares_works_for_negative() {
+        assert!(four_squares(&BigNumber::from_dec("-1").unwrap()).is_err());
     }
 
     #[test]
@@ -686,12 +854,77 @@ mod tests {
         */
     }
 
+    fn _non_revoc_proof_c_list() -> NonRevocProofCList {
+        NonRevocProofCList {
+            e: PointG1::new().unwrap(),
+            d: PointG1::new().unwrap(),
+            a: PointG1::new().unwrap(),
+            g: PointG1::new().unwrap(),
+            w: PointG2::new().unwrap(),
+            s: PointG2::new().unwrap(),
+            u: PointG2::new().unwrap()
+        }
+    }
+
+    #[test]
+    fn create_tau_list_expected_values_works() {
+        let r_pub_key = issuer::mocks::credential_revocation_public_key();
+        let rev_reg = issuer::mocks::revocation_registry();
+        let rev_acc_pub_key = issuer::mocks::revocation_key_public();
+        let proof_c = _non_revoc_proof_c_list();
+
+        let tau_list = create_tau_list_expected_values(&r_pub_key, &rev_reg, &rev_acc_pub_key, &proof_c).unwrap();
+
+        let t3 = Pair::pair(&r_pub_key.h0.add(&proof_c.g).unwrap(), &r_pub_key.h_cap).unwrap()
+            .mul(&Pair::pair(&proof_c.a, &r_pub_key.y).unwrap().inverse().unwrap()).unwrap();
+        let t4 = Pair::pair(&proof_c.g, &rev_reg.accum).unwrap()
+            .mul(&Pair::pair(&r_pub_key.g, &proof_c.w).unwrap().mul(&rev_acc_pub_key.z).unwrap().inverse().unwrap()).unwrap();
+        let t7 = Pair::pair(&r_pub_key.pk.add(&proof_c.g).unwrap(), &proof_c.s).unwrap()
+            .mul(&Pair::pair(&r_pub_key.g, &r_pub_key.g_dash).unwrap().inverse().unwrap()).unwrap();
+        let t8 = Pair::pair(&proof_c.g, &r_pub_key.u).unwrap()
+            .mul(&Pair::pair(&r_pub_key.g, &proof_c.u).unwrap().inverse().unwrap()).unwrap();
+
+        assert_eq!(t3.to_bytes().unwrap(), tau_list.t3.to_bytes().unwrap());
+        assert_eq!(t4.to_bytes().unwrap(), tau_list.t4.to_bytes().unwrap());
+        assert_eq!(t7.to_bytes().unwrap(), tau_list.t7.to_bytes().unwrap());
+        assert_eq!(t8.to_bytes().unwrap(), tau_list.t8.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn create_tau_list_values_works() {
+        let r_pub_key = issuer::mocks::credential_revocation_public_key();
+        let rev_reg = issuer::mocks::revocation_registry();
+        let params = NonRevocProofXList::from_list((0..14).map(|_| GroupOrderElement::new().unwrap()).collect());
+        let proof_c = _non_revoc_proof_c_list();
+
+        let tau_list = create_tau_list_values(&r_pub_key, &rev_reg, &params, &proof_c).unwrap();
+
+        let t3 = Pair::pair(&proof_c.a, &r_pub_key.h_cap).unwrap().pow(&params.c).unwrap()
+            .mul(&Pair::pair(&r_pub_key.htilde, &r_pub_key.h_cap).unwrap().pow(&params.r).unwrap()).unwrap()
+            .mul(&Pair::pair(&r_pub_key.htilde, &r_pub_key.y).unwrap().pow(&params.rho).unwrap()
+                .mul(&Pair::pair(&r_pub_key.htilde, &r_pub_key.h_cap).unwrap().pow(&params.m).unwrap()).unwrap()
+                .mul(&Pair::pair(&r_pub_key.h1, &r_pub_key.h_cap).unwrap().pow(&params.m2).unwrap()).unwrap()
+                .mul(&Pair::pair(&r_pub_key.h2, &r_pub_key.h_cap).unwrap().pow(&params.s).unwrap()).unwrap().inverse().unwrap()).unwrap();
+        let t4 = Pair::pair(&r_pub_key.htilde, &rev_reg.accum).unwrap().pow(&params.r).unwrap()
+            .mul(&Pair::pair(&r_pub_key.g.neg().unwrap(), &r_pub_key.h_cap).unwrap().pow(&params.r_prime).unwrap()).unwrap();
+        let t7 = Pair::pair(&r_pub_key.pk.add(&proof_c.g).unwrap(), &r_pub_key.h_cap).unwrap().pow(&params.r_prime_prime).unwrap()
+            .mul(&Pair::pair(&r_pub_key.htilde, &r_pub_key.h_cap).unwrap().pow(&params.m_prime.mod_neg().unwrap()).unwrap()).unwrap()
+            .mul(&Pair::pair(&r_pub_key.htilde, &proof_c.s).unwrap().pow(&params.r).unwrap()).unwrap();
+        let t8 = Pair::pair(&r_pub_key.htilde, &r_pub_key.u).unwrap().pow(&params.r).unwrap()
+            .mul(&Pair::pair(&r_pub_key.g.neg().unwrap(), &r_pub_key.h_cap).unwrap().pow(&params.r_prime_prime_prime).unwrap()).unwrap();
+
+        assert_eq!(t3.to_bytes().unwrap(), tau_list.t3.to_bytes().unwrap());
+        assert_eq!(t4.to_bytes().unwrap(), tau_list.t4.to_bytes().unwrap());
+        assert_eq!(t7.to_bytes().unwrap(), tau_list.t7.to_bytes().unwrap());
+        assert_eq!(t8.to_bytes().unwrap(), tau_list.t8.to_bytes().unwrap());
+    }
+
     #[test]
     fn calc_tge_works() {
         let proof = prover::mocks::ge_proof();
         let pk = issuer::mocks::credential_primary_public_key();
 
-        let res = calc_tge(&pk, &proof.u, &proof.r, &proof.mj, &proof.alpha, &proof.t);
+        let res = calc_tge(&pk, &proof.u, &proof.r, &proof.mj, &proof.alpha, &proof.t, &proof.predicate);
 
         assert!(res.is_ok());
 
@@ -556,13 +556,13 @@ impl Issuer {
 
         let mut r = BTreeMap::new();
         for (key, xr_value) in xr.iter() {
-            r.insert(key.to_string(), s.mod_exp(&xr_value, &n, Some(&mut ctx))?);
+            r.insert(key.to_string(), s.mod_exp_secret(&xr_value, &n, Some(&mut ctx))?);
         }
 
-        let z = s.mod_exp(&xz, &n, Some(&mut ctx))?;
+        let z = s.mod_exp_secret(&xz, &n, Some(&mut ctx))?;
 
-        let rms = s.mod_exp(&gen_x(&p, &q)?, &n, Some(&mut ctx))?;
-        let rctxt = s.mod_exp(&gen_x(&p, &q)?, &n, Some(&mut ctx))?;
+        let rms = s.mod_exp_secret(&gen_x(&p, &q)?, &n, Some(&mut ctx))?;
+        let rctxt = s.mod_exp_secret(&gen_x(&p, &q)?, &n, Some(&mut ctx))?;
 
         let cred_pr_pub_key = CredentialPrimaryPublicKey { n, s, rms, rctxt, r, z };
         let cred_pr_priv_key = CredentialPrimaryPrivateKey { p, q };
@@ -618,11 +618,11 @@ impl Issuer {
             xr_tilda.insert(key.to_string(), gen_x(&cred_pr_priv_key.p, &cred_pr_priv_key.q)?);
         }
 
-        let z_tilda = cred_pr_pub_key.s.mod_exp(&xz_tilda, &cred_pr_pub_key.n, Some(&mut ctx))?;
+        let z_tilda = cred_pr_pub_key.s.mod_exp_secret(&xz_tilda, &cred_pr_pub_key.n, Some(&mut ctx))?;
 
         let mut r_tilda = BTreeMap::new();
         for (key, xr_tilda_value) in xr_tilda.iter() {
-            r_tilda.insert(key.to_string(), cred_pr_pub_key.s.mod_exp(&xr_tilda_value, &cred_pr_pub_key.n, Some(&mut ctx))?);
+            r_tilda.insert(key.to_string(), cred_pr_pub_key.s.mod_exp_secret(&xr_tilda_value, &cred_pr_pub_key.n, Some(&mut ctx))?);
         }
 
         let mut values: Vec<u8> = Vec::new();
@@ -666,10 +666,13 @@ impl Issuer {
         let mut accum = Accumulator::new_inf()?;
 
         if issuance_by_default {
+            // All tails share g_dash base, so their sum is g_dash multiplied by sum of tail exponents
+            let mut exponent = GroupOrderElement::from_bytes(&[0])?;
             for i in 1..max_cred_num + 1 {
                 let index = Issuer::_get_index(max_cred_num, i);
-                accum = accum.add(&Tail::new_tail(index, &cred_rev_pub_key.g_dash, &rev_key_priv.gamma)?)?;
+                exponent = exponent.add_mod(&Tail::exponent(index, &rev_key_priv.gamma)?)?;
             }
+            accum = cred_rev_pub_key.g_dash.mul(&exponent)?;
         };
 
         let rev_reg = RevocationRegistry {
@@ -805,15 +808,16 @@ impl Issuer {
 
         let mut context = BigNumber::new_context()?;
 
+        // Attribute values, v and credential context are known only to issuer and prover
         let mut rx = p_pub_key.s
-            .mod_exp(&v, &p_pub_key.n, Some(&mut context))?;
+            .mod_exp_secret(&v, &p_pub_key.n, Some(&mut context))?;
 
         if blnd_ms.u != BigNumber::from_u32(0)? {
             rx = blnd_ms.u.modulus(&p_pub_key.n, Some(&mut context))?
                 .mul(&rx, Some(&mut context))?;
         }
 
-        rx = p_pub_key.rctxt.mod_exp(&cred_context, &p_pub_key.n, Some(&mut context))?
+        rx = p_pub_key.rctxt.mod_exp_secret(&cred_context, &p_pub_key.n, Some(&mut context))?
             .mul(&rx, Some(&mut context))?;
 
         for (key, value) in &cred_values.attrs_values {
@@ -821,7 +825,7 @@ impl Issuer {
                 .get(key)
                 .ok_or(IndyCryptoError::InvalidStructure(format!("Value by key '{}' not found in pk.r", key)))?;
 
-            rx = pk_r.mod_exp(&value, &p_pub_key.n, Some(&mut context))?
+            rx = pk_r.mod_exp_secret(&value, &p_pub_key.n, Some(&mut context))?
                 .mod_mul(&rx, &p_pub_key.n, Some(&mut context))?;
         }
 
@@ -830,7 +834,7 @@ impl Issuer {
         let n = p_priv_key.p.mul(&p_priv_key.q, Some(&mut context))?;
         let e_inverse = e.inverse(&n, Some(&mut context))?;
 
-        let a = q.mod_exp(&e_inverse, &p_pub_key.n, Some(&mut context))?;
+        let a = q.mod_exp_secret(&e_inverse, &p_pub_key.n, Some(&mut context))?;
 
         trace!("Issuer::_sign_primary_credential: <<< a: {:?}, q: {:?}", a, q);
 
@@ -850,7 +854,7 @@ impl Issuer {
         let n = p_priv_key.p.mul(&p_priv_key.q, Some(&mut ctx))?;
         let r = bn_rand_range(&n)?;
 
-        let a_cap = q.mod_exp(&r, &p_pub_key.n, Some(&mut ctx))?;
+        let a_cap = q.mod_exp_secret(&r, &p_pub_key.n, Some(&mut ctx))?;
 
         let mut values: Vec<u8> = Vec::new();
         values.extend_from_slice(&q.to_bytes()?);
@@ -915,10 +919,9 @@ impl Issuer {
         };
 
         let sigma =
-            r_pub_key.h0.add(&r_pub_key.h1.mul(&m2)?)?
+            r_pub_key.h0.add(&PointG1::multi_mul(&[r_pub_key.h1, r_pub_key.h2], &[m2.clone(), vr_prime_prime.clone()])?)?
                 .add(&ur)?
                 .add(&g_i)?
-                .add(&r_pub_key.h2.mul(&vr_prime_prime)?)?
                 .mul(&r_priv_key.x.add_mod(&c)?.inverse()?)?;
 
 
@@ -1053,6 +1056,25 @@ mod tests {
         Issuer::new_revocation_registry_def(&pub_key, 100, false).unwrap();
     }
 
+    #[test]
+    fn issuer_new_revocation_registry_def_works_for_issuance_by_default() {
+        MockHelper::inject();
+
+        let max_cred_num = 5;
+        let (pub_key, _, _) = Issuer::new_credential_def(&mocks::credential_schema(), true).unwrap();
+        let (_, _, rev_reg, mut rev_tails_generator) = Issuer::new_revocation_registry_def(&pub_key, max_cred_num, true).unwrap();
+        let simple_tail_accessor = SimpleTailsAccessor::new(&mut rev_tails_generator).unwrap();
+
+        let mut accum = Accumulator::new_inf().unwrap();
+        for i in 1..max_cred_num + 1 {
+            simple_tail_accessor.access_tail(Issuer::_get_index(max_cred_num, i), &mut |tail| {
+                accum = accum.add(tail).unwrap();
+            }).unwrap();
+        }
+
+        assert_eq!(accum.to_bytes().unwrap(), rev_reg.accum.to_bytes().unwrap());
+    }
+
     #[test]
     fn sign_primary_credential_works() {
         MockHelper::inject();
@@ -1194,6 +1216,7 @@ pub mod mocks {
         }
     }
 
+    #[cfg(feature = "pair_amcl")]
     pub fn revocation_credential() -> NonRevocationCredentialSignature {
         NonRevocationCredentialSignature {
             sigma: PointG1::from_string("false C8C7213101C60F F625A22E65736C 695A1F398B4787 D087ABB966C5BC 1EA63E37 7895832C96B02C 60C7E086DFA7AF 1518CD71A957F3 C1BED176429FB9 11DD23B3 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD").unwrap(),
@@ -1206,6 +1229,20 @@ pub mod mocks {
         }
     }
 
+    #[cfg(not(feature = "pair_amcl"))]
+    pub fn revocation_credential() -> NonRevocationCredentialSignature {
+        NonRevocationCredentialSignature {
+            sigma: PointG1::from_string("0AABE616152737DAA573D8AF31FC91FC70606F2A3ADE851974E3D3877A62FC0B34AF1644A86F4ED11BED03D5746A3B9B153AE8723DDF043FFF25423A5694BE2CCFCF5C894ECD3A3BCDCB361C48762A5AE507E3E1A865D1D675A8AC0CFC89B829").unwrap(),
+            c: GroupOrderElement::from_string("29609381B8842B737BCA8B25C8A7E3DA33A4AAE9457575CE9CABE62EC4506E9B").unwrap(),
+            vr_prime_prime: GroupOrderElement::from_string("287DA5025007F8EA2A3818A6589F1B8B92220966071AD204B8646435EE154A88").unwrap(),
+            witness_signature: witness_signature(),
+            g_i: PointG1::from_string("01F35480DDF0470567DA46B36B040B79F28A8B5F9D30DFF20544C1A3D83479FFB463596D23FA3B787A7ABB88C015BE24044487136A77244725D506B0175141AAD01EAD881F32E29A87E1D2DDC0E3DD5A300D368EF493C0D1CAB33B723D63A24B").unwrap(),
+            i: 1,
+            m2: GroupOrderElement::from_string("00F04C9FD6B02CF980BB9FAEF217765D58795EE6582710DF0D7219C92BC1A5C4").unwrap()
+        }
+    }
+
+    #[cfg(feature = "pair_amcl")]
     fn witness_signature() -> WitnessSignature {
         WitnessSignature {
             sigma_i: PointG2::from_string("false D75D129A90AC7C E980CE49738692 E81F6656B7EC8B 5CB508713E5514 1C8D263D 277F296ED2870 DD07D7557B996C 3E3A4CBE72B433 CE6A5B3F49DCF0 12760A8D 794C7329844D36 5F061EF8268D0B 6931F242E445A2 941EE07805B105 112CCA EA8F2154379FFC E347F4C23152D6 81B0FD797DECC 99649EAE531C52 306F627 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD 0 0 0 0 0").unwrap(),
@@ -1214,12 +1251,30 @@ pub mod mocks {
         }
     }
 
+    #[cfg(not(feature = "pair_amcl"))]
+    fn witness_signature() -> WitnessSignature {
+        WitnessSignature {
+            sigma_i: PointG2::from_string("0F60FA640B39962E8A866F8E43E0A0D89A39208A3C4898BB73DE549B5EC7849187156BB5F96C6C595408C1B5623E96C309D00AEF49AC5B9F4DEB3A2E3C1BEDE7DE90CCFE78BC04A5334F2F1F2B35F060396110BFEF5539505CF3EC4F083DFA2E19AAF6F2E4EC709258AEAE3C54BA74284B8275AF07B15FB29B93EFA4D2FD34B27A645BA1187B1C20A63D31D0476273C90265B8B68C163224F29536E710D1DCF45C67B505EB5E385F851A009DEB28E7DC18ED3BAC047CA2ADEF49ABCC2E55B52B").unwrap(),
+            u_i: PointG2::from_string("0C9691D99E6F1ADE239547CDADB2975B306622DB83C2D916BBBA8BE8ECA7E55EEFAC25DE39873194506FAEB0D2E15D8D0DD59695C54B1959D1D7E6DC7778C21CF6C1E5A315110E4C4A2BB1F699AB07CC5F238291D8514B9D1ED190EDB6B9D9D817B98304D9E120C3B2FA138BC80D8592F398C25E588645C857B2FFCD2FB96BEBE0CAAD10FB778CD82F1732EF0FFC55BE0B4086183D688925A46EB1B15E9541B810728EB8DD1AA434CE642AACA6BC3674DE9063BFE7CCDC1F6A50BA04FAC6DAA3").unwrap(),
+            g_i: PointG1::from_string("01F35480DDF0470567DA46B36B040B79F28A8B5F9D30DFF20544C1A3D83479FFB463596D23FA3B787A7ABB88C015BE24044487136A77244725D506B0175141AAD01EAD881F32E29A87E1D2DDC0E3DD5A300D368EF493C0D1CAB33B723D63A24B").unwrap()
+        }
+    }
+
+    #[cfg(feature = "pair_amcl")]
     pub fn witness() -> Witness {
         Witness {
             omega: PointG2::from_string("true 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0").unwrap()
         }
     }
 
+    #[cfg(not(feature = "pair_amcl"))]
+    pub fn witness() -> Witness {
+        Witness {
+            omega: PointG2::from_string("400000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").unwrap()
+        }
+    }
+
+    #[cfg(feature = "pair_amcl")]
     pub fn credential_revocation_public_key() -> CredentialRevocationPublicKey {
         CredentialRevocationPublicKey {
             g: PointG1::from_string("false F7061FFC5D86BD FEA559D709EBB4 184F0E83E83C7F 77518EACC28D21 1B2D4E76 86D88DDE8770D0 5034DD68624C0 CA409B38BD8B6A EC15B842470D5B 2188CB11 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD").unwrap(),
@@ -1237,6 +1292,23 @@ pub mod mocks {
         }
     }
 
+    #[cfg(not(feature = "pair_amcl"))]
+    pub fn credential_revocation_public_key() -> CredentialRevocationPublicKey {
+        CredentialRevocationPublicKey {
+            g: PointG1::from_string("0B377F5867B0F85110881209F6C3B094BA73C94660126E6E84A8A53A2C7CFE55019027FBAA3A9FCF78DE91FB2A2D9FBC077C3DC099787F889CD099BEE265762CFB75925FF8FE69C934A922C0E3DCD7B92355CCE92949CD736256F3DC4B3915CC").unwrap(),
+            g_dash: PointG2::from_string("0BA4B3EF9689EC27B8477A3E7246C7D065547363A73C2DDF19AD8BEC5677F36213CE21D046E6129EF6D9EB5D113502A310733B1DAE74BAEC7431BFA9EA9C064B0C9331EE47FC9558E0C4EDA0DD95343E237CF71209617145251851D1A1B3244413461A82940C97560608908AD0C018551A67D036E8390CDEB525C96108A1BE944F716610EBCE9842B1D38B1BD6012F450772F64DEC9E61CFA52F46001D1F1E90D7419AE8950129B7519BC8963E084B534B35100467F84C3BCAF22C9EC20FCC3A").unwrap(),
+            h: PointG1::from_string("15F78220F380892473E9BE5CD8133DF7AC68E498382C8F0A99596688CD90D9C41A5F3E1F57F54C4BDDFE3BAABD34366B08F5975FE6C76FA1D91BE3F621D055AFAF8F9916E2CF83CE3554E67FD094775776BF745860F62868B251515DA88557AB").unwrap(),
+            h0: PointG1::from_string("0320686EF631BF2AE2F4131A4CDECD1C005222B584491D1D4CE6439E3AFE156A6F5D961A3CDE378FB11AA854FB65B984144FDC45D80E802031D414849CFC49F57FB0689AE4D898C398BE479B4840CB2A95CC9E5CB56D43F41896B4B3DCFB4DEA").unwrap(),
+            h1: PointG1::from_string("0F824D7C11216A33493397A30591BADEDA2CF6739D2526123675BB2B78644F095EE84344D4C793CC69E98A0B029263D202E4E17C10474C647DC70EEF9358AD8E401FFF1B94C87071F4FD4F0C9D21DE80F2417514B200FC882ECBB98902F5C828").unwrap(),
+            h2: PointG1::from_string("0137ED0963DC21CBDA8E2F60A3E43A44DEF39CDF38B75DFF1B8A6549E3F6BE7168B750C63B6CBBB6C0FB2CF15CDD007F061696DBFF423FE5C277450FB98550D56B80775CCE5679BB812841CAACB447A1FA35BAB77E025AC5374DFE3AA67638EE").unwrap(),
+            htilde: PointG1::from_string("0E61F60435BF72128FBECDD974D33D8F7CD6676C1FF721F6760DBD945AB6CD66F21872CD162D38F5DD18C6F84FF7795D06D687078C35B042BE252E05E37FDADC4EF3593C6D9658AB97D833C0CE406CDCFAD8437839D05F02D6F04EC99B6C3019").unwrap(),
+            h_cap: PointG2::from_string("1477587124FAF7B2171C99E8DF437D8760B8B7A774BA054EC858743D687F2E19716A6CB6B114EC4283F4FE106C8CB9BA002FB74746F49F30AFB8C6D8CA25753C89696FC186AB0C15E2469FB1342DBBCB889FADC64EFC2C0EC8074A2C06BA23CF03275CE470D2AEFAF9277059BC21AB292C44EB5C8EF63FB5679B09DB7D8884388124B38288CFAD6E838BF8F203EC82CB0365254D45A2AD801915B7B09EE8671124DC2B4C76A3524EBE98F3FB62A6717D6F4B7EDD7625505823DAA4BBEA67787F").unwrap(),
+            u: PointG2::from_string("099D5EB61BB3A3D990BBD60AC0765E0CC65738AC9F0C3A2A6D867714B929810C6F4AF702E805239A8E710C688A773517077826CA09A0800A83205FE03BF002DA758D53B2CF27C5E77014CE2015E5800BA2D247E2A568BEE6B295F74585AA4FB506FBC0A0182B66979C4B89309EF99329803F54C5501273EB53E42900DC9DEB5AD9D90D6B2BCCBA532FDB94D3167BBF7E0A64F8A7678A143B76E84233EA4BB06920D25A2B1840B41A22573D99C05F6BEC8F2A12B8474BD40C2210066274AD942E").unwrap(),
+            pk: PointG1::from_string("12F127EFF920F3B2632856C0B6720AB34C0E5EDC03F4FCEEAB6D6A8D50D10186537B7EF63A31C1F666047837B732FA20086F77CB07FC5630DC31EA08ABC593FD7B223D15B1DB99E335AFE7E85090F5DD080CE5927EC79A42E7707E92B5B213F8").unwrap(),
+            y: PointG2::from_string("0CE4318FE3EE5DC0F832108C0D75B56F8C31E4DB65A41E6D225C3B634544481EA6C338326A44348394BE7DCDA2656C900D7076D30126A7F3B4D144F205B49920F0AFD53281867504165135478FA40E114BE481E3216EA65693507780D3697CEB0F75FBA43C1DD3C1EA85E2CC3247A9E5B543C0C085EC731D16ACAD32E83B9EB4E3E3E89DDD53B0CF7689DFA1E6A8E0A00AA95B7107186328D53E5876D2A6B4EA71E53E85EBC0A3E9B6CDF0FF16AE705495D6BACDAF2D925A61F032B6F6AE9FD4").unwrap()
+        }
+    }
+
     pub fn credential_revocation_private_key() -> CredentialRevocationPrivateKey {
         CredentialRevocationPrivateKey {
             x: GroupOrderElement::new().unwrap(),
@@ -1244,16 +1316,30 @@ pub mod mocks {
         }
     }
 
+    #[cfg(feature = "pair_amcl")]
     pub fn revocation_key_public() -> RevocationKeyPublic {
         RevocationKeyPublic {
             z: Pair::from_string("B0C52EBB799E8 6FC6F7D6883390 BC4244EDBC1787 FDEA974C84C1F1 234FA3A6 F411BCC525581F B238C8B10BBACB 8536CC797D203D DEFEAA1B1DBC5B 736EAC 529F008C0398B9 CD0B30B71A1F14 2D332E37CEBF1B A3D9B3319DCDAD CA1AAD2 E5B506C98D6F95 575329E5789B3B CA3A9AB8CED863 BB16612D7EDFC9 241D0C39 810C5FA05825E3 C8A863BA7721CD DCCCB939E4BC22 1817F872AA9906 E423204 C38DCA6D9C80D6 5DE52EA7CFE23E FB41FA284C112E E438D18C192C1D 88A018F EF8569C86B3916 119FE81D359A09 6D5A0088955ED3 6904F412A28BD4 11F6C539 29AD474B03EE99 D0353A66812CA7 C9763FC9EEB4A3 217160B2B8982E 10983B69 7F67C0FCFD4244 45C9665E75EC5B 4A23D9F0D1182F 3A8C685A922F6 20A176A9 883FF71EB14569 5030243F2B2B79 95A67EF0922D07 A6D74310BFE00A F8BBB21 476E55B2836798 16B49B2120D6EB 68EABD968A44DE E8DF358500A99A 15A3F96B 28749CC7A07F60 F82B17A0CA933F EE4166241C77F2 9BE2BB4B802250 19F0D85E").unwrap(),
         }
     }
 
+    #[cfg(not(feature = "pair_amcl"))]
+    pub fn revocation_key_public() -> RevocationKeyPublic {
+        RevocationKeyPublic {
+            z: Pair::from_string("01688769B1E6CEA018E27DFC5CDED6DF53BCC5E7CAB7961E0D6B794800E584D3ADA3889FB766B791F78938433EAABD180931FC8D739160EB5613F0ACC6F1FE098AD4499F8228637DCE4B399E98C38E28D8661CAAE990936FED47DA8155B64FF0099913CBBB56ED13688A328F0E04F0875844AEAF01CBFBDE18C5745501E618521CC283899E59F7F24E466FF3DCBBFAFA07B951CED5998E6AF6048FA27705BFA479B23E197FED469F09B81208A3F0104A0D4774D9FE8AC7EF8EE411EE067085FB11E822E746918A927A84047489147FAE9549C1E124E24A647FC4EC5A58F7A6C30CAB05DC8C60EBEF9C40FB83C8F517AF16083DA55BEAFC5574C6E5588DFEAC3939121930B6BA7E6942F3D28A70592AD77D69E14CA08AB0D82112A58BED1A5D750E041F8F081AF931FD6CEACE9A71DB56708B0DC5D66036199A886467B27048B6B3FF99B7EEFEA9857D9E90C764B6799B11A029DC34269AF37C7295871A7A9848AFE7C613B70886D64BC37D7B724C146268F792A01E2C1C46583D4865B955FCA713F23F17069A8ABA7229DB4592F017CE28F2C026153EA6A767F0B3B94193120A43E2D8FD31206C7314BBDE2351751334077A96A12E8BDBBF34B218BAF47B8299A18C96EBE1BC3BEBF4601F5D0A3357817F4A626CB44E848F655974B21E7A1BDE02544E4789DFBB151FD379687F09A4FCA623987430236B999754120C764C571A7D888F991BA9512C9CCD392A2E8460C604D30ADDE52AB1936C48AEFDE06AF9390B103E939020920C74EAD25515D01AB025B463A06C29EA7F06702E634722362C").unwrap()
+        }
+    }
+
+    #[cfg(feature = "pair_amcl")]
     fn accumulator() -> Accumulator {
         PointG2::from_string("false 1348A2A978E0DB 34007FF6AF40CE 6D0587A6FB0664 5C7BE100A9A5F0 195FD169 A8C3298C4E3638 F93A75199C097D F3659F1FB6AE4A A03EC27AEB629 2435D86 4DA6C9C1917365 866CCF7C293373 216DF40B2F9E81 19F44DEEC2C748 170C3B8A DDEA4569FCEEC7 1685AB7B80F94F 5BB29412B2822D 3FE85A96139673 109B08B8 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD 0 0 0 0 0").unwrap()
     }
 
+    #[cfg(not(feature = "pair_amcl"))]
+    fn accumulator() -> Accumulator {
+        PointG2::from_string("01F9FA68E5EC4BD2D972465DFA3308421AA0A7E1C134F47740D7B0A668EA379B6E5E283C352ABF4D9659CCF6371894DE19D3BCA3A6B4E5F996ACEED46F1A7CA79069BA4F4EB3555E897BA7363D43BC7EB19F002DC92B6659715A70F0D304097A036A81473C8A487CA6A6DFD0FF93E4B2C41B83C0B525ED57533E0A22EC73FA27AA682BEA0E992DA6BDFD48A6A7F03221097F79FFEDA2ECF1154B6BC8AA3A33F8F66309B98BA61C766BEE80A7D1590E5C9E57E0FD957F626CECB7D019C645BBC4").unwrap()
+    }
+
     pub fn revocation_registry() -> RevocationRegistry {
         RevocationRegistry {
             accum: accumulator()
@@ -12,8 +12,12 @@ use errors::IndyCryptoError;
 use pair::*;
 use utils::json::{JsonEncodable, JsonDecodable};
 
+use serde::de::{Deserializer, Visitor, Error as DError};
+use serde::ser::{Serializer, Error as SError};
+
 use std::collections::{BTreeMap, HashMap, HashSet};
-use std::hash::Hash;
+use std::fmt;
+use std::hash::{Hash, Hasher};
 
 /// Creates random nonce
 ///
@@ -59,11 +63,12 @@ impl CredentialSchemaBuilder {
 }
 
 /// Values of attributes from `Claim Schema` (must be integers).
-#[derive(Debug)]
 pub struct CredentialValues {
     attrs_values: HashMap<String, BigNumber>
 }
 
+impl_secret_debug!(CredentialValues secret { attrs_values });
+
 impl CredentialValues {
     pub fn clone(&self) -> Result<CredentialValues, IndyCryptoError> {
         Ok(CredentialValues {
@@ -73,11 +78,12 @@ impl CredentialValues {
 }
 
 /// A Builder of `Claim Values`.
-#[derive(Debug)]
 pub struct CredentialValuesBuilder {
     attrs_values: HashMap<String, BigNumber> /* attr_name -> int representation of value */
 }
 
+impl_secret_debug!(CredentialValuesBuilder secret { attrs_values });
+
 impl CredentialValuesBuilder {
     pub fn new() -> Result<CredentialValuesBuilder, IndyCryptoError> {
         Ok(CredentialValuesBuilder {
@@ -137,12 +143,14 @@ impl<'a> JsonDecodable<'a> for CredentialPublicKey {}
 
 /// `Issuer Private Key`: contains 2 internal parts.
 /// One for signing primary credentials and second for signing non-revocation credentials.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Deserialize, Serialize)]
 pub struct CredentialPrivateKey {
     p_key: CredentialPrimaryPrivateKey,
     r_key: Option<CredentialRevocationPrivateKey>,
 }
 
+impl_secret_debug!(CredentialPrivateKey secret { p_key, r_key });
+
 impl JsonEncodable for CredentialPrivateKey {}
 
 impl<'a> JsonDecodable<'a> for CredentialPrivateKey {}
@@ -172,19 +180,22 @@ impl CredentialPrimaryPublicKey {
 }
 
 /// Issuer's "Private Key" used for signing Claim's attributes' values (primary credential)
-#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[derive(PartialEq, Deserialize, Serialize)]
 pub struct CredentialPrimaryPrivateKey {
     p: BigNumber,
     q: BigNumber
 }
 
+impl_secret_debug!(CredentialPrimaryPrivateKey secret { p, q });
+
 /// `Primary Public Key Metadata` required for building of Proof Correctness of `Issuer Public Key`
-#[derive(Debug)]
 pub struct CredentialPrimaryPublicKeyMetadata {
     xz: BigNumber,
     xr: BTreeMap<String, BigNumber>
 }
 
+impl_secret_debug!(CredentialPrimaryPublicKeyMetadata secret { xz, xr });
+
 /// Proof of `Issuer Public Key` correctness
 #[derive(Debug, PartialEq, Deserialize, Serialize)]
 pub struct CredentialKeyCorrectnessProof {
@@ -214,12 +225,14 @@ pub struct CredentialRevocationPublicKey {
 }
 
 /// `Revocation Private Key` is used for signing Claim.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Deserialize, Serialize)]
 pub struct CredentialRevocationPrivateKey {
     x: GroupOrderElement,
     sk: GroupOrderElement
 }
 
+impl_secret_debug!(CredentialRevocationPrivateKey secret { x, sk });
+
 pub type Accumulator = PointG2;
 
 /// `Revocation Registry` contains accumulator.
@@ -299,11 +312,13 @@ impl JsonEncodable for RevocationKeyPublic {}
 impl<'a> JsonDecodable<'a> for RevocationKeyPublic {}
 
 /// `Revocation Key Private` Accumulator primate key.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Deserialize, Serialize)]
 pub struct RevocationKeyPrivate {
     gamma: GroupOrderElement
 }
 
+impl_secret_debug!(RevocationKeyPrivate secret { gamma });
+
 impl JsonEncodable for RevocationKeyPrivate {}
 
 impl<'a> JsonDecodable<'a> for RevocationKeyPrivate {}
@@ -312,23 +327,32 @@ impl<'a> JsonDecodable<'a> for RevocationKeyPrivate {}
 pub type Tail = PointG2;
 
 impl Tail {
-    fn new_tail(index: u32, g_dash: &PointG2, gamma: &GroupOrderElement) -> Result<Tail, IndyCryptoError> {
+    fn new_tail(index: u32, g_dash: &FixedBasePointG2, gamma: &GroupOrderElement) -> Result<Tail, IndyCryptoError> {
+        Ok(g_dash.mul(&Tail::exponent(index, gamma)?)?)
+    }
+
+    /// gamma ^ index, so tail is g_dash ^ (gamma ^ index)
+    fn exponent(index: u32, gamma: &GroupOrderElement) -> Result<GroupOrderElement, IndyCryptoError> {
         let i_bytes = helpers::transform_u32_to_array_of_u8(index);
-        let mut pow = GroupOrderElement::from_bytes(&i_bytes)?;
-        pow = gamma.pow_mod(&pow)?;
-        Ok(g_dash.mul(&pow)?)
+        let pow = GroupOrderElement::from_bytes(&i_bytes)?;
+        gamma.pow_mod(&pow)
     }
 }
 
 /// Generator of `Tail's`.
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct RevocationTailsGenerator {
     size: u32,
     current_index: u32,
     g_dash: PointG2,
-    gamma: GroupOrderElement
+    gamma: GroupOrderElement,
+    /* precomputed g_dash multiples, built on first use */
+    #[serde(skip)]
+    g_dash_table: Option<FixedBasePointG2>
 }
 
+impl_secret_debug!(RevocationTailsGenerator { size, current_index, g_dash } secret { gamma });
+
 impl RevocationTailsGenerator {
     fn new(max_cred_num: u32, gamma: GroupOrderElement, g_dash: PointG2) -> Self {
         RevocationTailsGenerator {
@@ -336,6 +360,7 @@ impl RevocationTailsGenerator {
             current_index: 0,
             gamma,
             g_dash,
+            g_dash_table: None
         }
     }
 
@@ -348,7 +373,11 @@ impl RevocationTailsGenerator {
             return Ok(None);
         }
 
-        let tail = Tail::new_tail(self.current_index, &self.g_dash, &self.gamma)?;
+        if self.g_dash_table.is_none() {
+            self.g_dash_table = Some(FixedBasePointG2::new(&self.g_dash)?);
+        }
+
+        let tail = Tail::new_tail(self.current_index, self.g_dash_table.as_ref().unwrap(), &self.gamma)?;
 
         self.current_index += 1;
 
@@ -526,11 +555,13 @@ pub struct WitnessSignature {
 /// Prover blinds master secret, generating `BlindedMasterSecret` and `MasterSecretBlindingData` (blinding factors)
 /// and sends the `BlindedMasterSecret` to Issuer who then encodes it credential creation.
 /// The blinding factors are used by Prover for post processing of issued credentials.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Deserialize, Serialize)]
 pub struct MasterSecret {
     ms: BigNumber,
 }
 
+impl_secret_debug!(MasterSecret secret { ms });
+
 impl MasterSecret {
     pub fn clone(&self) -> Result<MasterSecret, IndyCryptoError> {
         Ok(MasterSecret { ms: self.ms.clone()? })
@@ -554,28 +585,33 @@ impl<'a> JsonDecodable<'a> for BlindedMasterSecret {}
 
 /// `Master Secret Blinding Data` used by Prover for post processing of credentials received from Issuer.
 /// TODO: Should be renamed `MasterSecretBlindingFactors`
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Deserialize, Serialize)]
 pub struct MasterSecretBlindingData {
     v_prime: BigNumber,
     vr_prime: Option<GroupOrderElement>
 }
 
+impl_secret_debug!(MasterSecretBlindingData secret { v_prime, vr_prime });
+
 impl JsonEncodable for MasterSecretBlindingData {}
 
 impl<'a> JsonDecodable<'a> for MasterSecretBlindingData {}
 
-#[derive(Eq, PartialEq, Debug)]
+#[derive(Eq, PartialEq)]
 pub struct PrimaryBlindedMasterSecretData {
     u: BigNumber,
     v_prime: BigNumber,
 }
 
-#[derive(Debug)]
+impl_secret_debug!(PrimaryBlindedMasterSecretData { u } secret { v_prime });
+
 pub struct RevocationBlindedMasterSecretData {
     ur: PointG1,
     vr_prime: GroupOrderElement,
 }
 
+impl_secret_debug!(RevocationBlindedMasterSecretData { ur } secret { vr_prime });
+
 #[derive(Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub struct BlindedMasterSecretCorrectnessProof {
     c: BigNumber,
@@ -588,11 +624,24 @@ impl JsonEncodable for BlindedMasterSecretCorrectnessProof {}
 impl<'a> JsonDecodable<'a> for BlindedMasterSecretCorrectnessProof {}
 
 /// “Sub Proof Request” - input to create a Proof for a credential;
-/// Contains attributes to be revealed and predicates.
-#[derive(Debug, Clone)]
+/// Contains attributes to be revealed, predicates and equalities of hidden attributes.
+#[derive(Debug)]
 pub struct SubProofRequest {
     revealed_attrs: HashSet<String>,
     predicates: HashSet<Predicate>,
+    attr_equalities: HashMap<String, String>, /* attr_name -> link */
+}
+
+impl SubProofRequest {
+    pub fn clone(&self) -> Result<SubProofRequest, IndyCryptoError> {
+        Ok(SubProofRequest {
+            revealed_attrs: self.revealed_attrs.clone(),
+            predicates: self.predicates.iter()
+                .map(Predicate::clone)
+                .collect::<Result<HashSet<Predicate>, IndyCryptoError>>()?,
+            attr_equalities: self.attr_equalities.clone()
+        })
+    }
 }
 
 /// Builder of “Sub Proof Request”.
@@ -606,7 +655,8 @@ impl SubProofRequestBuilder {
         Ok(SubProofRequestBuilder {
             value: SubProofRequest {
                 revealed_attrs: HashSet::new(),
-                predicates: HashSet::new()
+                predicates: HashSet::new(),
+                attr_equalities: HashMap::new()
             }
         })
     }
@@ -617,38 +667,176 @@ impl SubProofRequestBuilder {
     }
 
     pub fn add_predicate(&mut self, attr_name: &str, p_type: &str, value: i32) -> Result<(), IndyCryptoError> {
+        let value = BigNumber::from_dec(&value.to_string())?;
+        self.add_big_predicate(attr_name, p_type, &value)
+    }
+
+    /// Adds predicate with arbitrary-precision and possibly negative bound.
+    pub fn add_big_predicate(&mut self, attr_name: &str, p_type: &str, value: &BigNumber) -> Result<(), IndyCryptoError> {
         let p_type = match p_type {
             "GE" => PredicateType::GE,
+            "LE" => PredicateType::LE,
+            "LT" => PredicateType::LT,
+            "GT" => PredicateType::GT,
             p_type => return Err(IndyCryptoError::InvalidStructure(format!("Invalid predicate type: {:?}", p_type)))
         };
 
         let predicate = Predicate {
             attr_name: attr_name.to_owned(),
             p_type,
-            value
+            value: _clone_signed(value)?
         };
 
         self.value.predicates.insert(predicate);
         Ok(())
     }
 
+    /// Requests proof that hidden attribute is equal to all other hidden attributes
+    /// added with the same `link` to sub proof requests of the proof, without revealing it.
+    pub fn add_attr_equality(&mut self, attr_name: &str, link: &str) -> Result<(), IndyCryptoError> {
+        if let Some(existing_link) = self.value.attr_equalities.get(attr_name) {
+            if existing_link != link {
+                return Err(IndyCryptoError::InvalidStructure(format!("Attribute '{}' is already linked with '{}'", attr_name, existing_link)));
+            }
+        }
+
+        self.value.attr_equalities.insert(attr_name.to_owned(), link.to_owned());
+        Ok(())
+    }
+
     pub fn finalize(self) -> Result<SubProofRequest, IndyCryptoError> {
         Ok(self.value)
     }
 }
 
 /// Some condition that must be satisfied.
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[derive(Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub struct Predicate {
     attr_name: String,
     p_type: PredicateType,
-    value: i32,
+    #[serde(serialize_with = "_serialize_predicate_value", deserialize_with = "_deserialize_predicate_value")]
+    value: BigNumber,
+}
+
+impl Predicate {
+    pub fn clone(&self) -> Result<Predicate, IndyCryptoError> {
+        Ok(Predicate {
+            attr_name: self.attr_name.clone(),
+            p_type: self.p_type.clone(),
+            value: _clone_signed(&self.value)?
+        })
+    }
+
+    /// Returns difference between attribute value and predicate bound that is non-negative
+    /// if and only if predicate is satisfied: `value - bound` for `GE`, `GT` and `bound - value` for `LE`, `LT`.
+    pub fn get_delta(&self, attr_value: &BigNumber) -> Result<BigNumber, IndyCryptoError> {
+        let mut delta = match self.p_type {
+            PredicateType::GE | PredicateType::GT => attr_value.sub(&self.value)?,
+            PredicateType::LE | PredicateType::LT => self.value.sub(attr_value)?
+        };
+
+        if self.is_strict() {
+            delta.sub_word(1)?;
+        }
+
+        Ok(delta)
+    }
+
+    /// Returns signed bound `b` such that `delta = value - b` for `GE`, `GT` and `delta = -value - b` for `LE`, `LT`.
+    pub fn get_delta_prime(&self) -> Result<BigNumber, IndyCryptoError> {
+        let mut delta_prime = _clone_signed(&self.value)?;
+
+        match self.p_type {
+            PredicateType::GT => { delta_prime.add_word(1)?; }
+            PredicateType::LT => { delta_prime.sub_word(1)?; }
+            PredicateType::GE | PredicateType::LE => {}
+        };
+
+        if self.is_less() {
+            let negative = !delta_prime.is_negative();
+            delta_prime.set_negative(negative)?;
+        }
+
+        Ok(delta_prime)
+    }
+
+    /// Whether predicate bounds attribute value from above, so attribute value enters delta with minus sign.
+    pub fn is_less(&self) -> bool {
+        match self.p_type {
+            PredicateType::GE | PredicateType::GT => false,
+            PredicateType::LE | PredicateType::LT => true
+        }
+    }
+
+    fn is_strict(&self) -> bool {
+        match self.p_type {
+            PredicateType::GT | PredicateType::LT => true,
+            PredicateType::GE | PredicateType::LE => false
+        }
+    }
+}
+
+impl Hash for Predicate {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.attr_name.hash(state);
+        self.p_type.hash(state);
+    }
 }
 
-/// Condition type (Currently GE only).
+/// `BigNumber::clone` copies magnitude only, but predicate bounds may be negative.
+fn _clone_signed(value: &BigNumber) -> Result<BigNumber, IndyCryptoError> {
+    let mut res = value.clone()?;
+    res.set_negative(value.is_negative())?;
+    Ok(res)
+}
+
+/// Predicate values that fit in `i64` are written as JSON integers, as they were before
+/// they became `BigNumber`; only larger values fall back to decimal strings.
+fn _serialize_predicate_value<S>(value: &BigNumber, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+    let dec = value.to_dec().map_err(SError::custom)?;
+
+    match dec.parse::<i64>() {
+        Ok(value) => serializer.serialize_i64(value),
+        Err(_) => serializer.serialize_str(&dec)
+    }
+}
+
+/// Predicate values were serialized as JSON integers before they became `BigNumber`,
+/// so both decimal strings and integers are accepted.
+fn _deserialize_predicate_value<'a, D>(deserializer: D) -> Result<BigNumber, D::Error> where D: Deserializer<'a> {
+    struct PredicateValueVisitor;
+
+    impl<'a> Visitor<'a> for PredicateValueVisitor {
+        type Value = BigNumber;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("expected integer or decimal string")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<BigNumber, E> where E: DError {
+            Ok(BigNumber::from_dec(value).map_err(DError::custom)?)
+        }
+
+        fn visit_i64<E>(self, value: i64) -> Result<BigNumber, E> where E: DError {
+            Ok(BigNumber::from_dec(&value.to_string()).map_err(DError::custom)?)
+        }
+
+        fn visit_u64<E>(self, value: u64) -> Result<BigNumber, E> where E: DError {
+            Ok(BigNumber::from_dec(&value.to_string()).map_err(DError::custom)?)
+        }
+    }
+
+    deserializer.deserialize_any(PredicateValueVisitor)
+}
+
+/// Condition type: attribute value is greater or equal (`GE`), less or equal (`LE`),
+/// less (`LT`) or greater (`GT`) than predicate value.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub enum PredicateType {
-    GE
+    GE,
+    LE,
+    LT,
+    GT
 }
 
 /// Proof is complex crypto structure created by prover over multiple credentials that allows to prove that prover:
@@ -764,7 +952,7 @@ impl NonRevocInitProof {
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
 pub struct PrimaryEqualInitProof {
     a_prime: BigNumber,
     t: BigNumber,
@@ -778,6 +966,8 @@ pub struct PrimaryEqualInitProof {
     m2: BigNumber
 }
 
+impl_secret_debug!(PrimaryEqualInitProof { a_prime, t } secret { e_tilde, e_prime, v_tilde, v_prime, m_tilde, m1_tilde, m2_tilde, m2 });
+
 impl PrimaryEqualInitProof {
     pub fn as_list(&self) -> Result<Vec<Vec<u8>>, IndyCryptoError> {
         Ok(vec![self.a_prime.to_bytes()?])
@@ -788,7 +978,7 @@ impl PrimaryEqualInitProof {
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
 pub struct PrimaryPredicateGEInitProof {
     c_list: Vec<BigNumber>,
     tau_list: Vec<BigNumber>,
@@ -801,6 +991,8 @@ pub struct PrimaryPredicateGEInitProof {
     t: HashMap<String, BigNumber>
 }
 
+impl_secret_debug!(PrimaryPredicateGEInitProof { c_list, tau_list, predicate, t } secret { u, u_tilde, r, r_tilde, alpha_tilde });
+
 impl PrimaryPredicateGEInitProof {
     pub fn as_list(&self) -> Result<&Vec<BigNumber>, IndyCryptoError> {
         Ok(&self.c_list)
@@ -811,7 +1003,7 @@ impl PrimaryPredicateGEInitProof {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct NonRevocProofXList {
     rho: GroupOrderElement,
     r: GroupOrderElement,
@@ -829,28 +1021,31 @@ pub struct NonRevocProofXList {
     c: GroupOrderElement
 }
 
+impl_secret_debug!(NonRevocProofXList secret { rho, r, r_prime, r_prime_prime, r_prime_prime_prime, o, o_prime, m, m_prime, t, t_prime, m2, s, c });
+
 impl NonRevocProofXList {
     pub fn as_list(&self) -> Result<Vec<GroupOrderElement>, IndyCryptoError> {
-        Ok(vec![self.rho, self.o, self.c, self.o_prime, self.m, self.m_prime, self.t, self.t_prime,
-                self.m2, self.s, self.r, self.r_prime, self.r_prime_prime, self.r_prime_prime_prime])
+        Ok(vec![self.rho.clone(), self.o.clone(), self.c.clone(), self.o_prime.clone(), self.m.clone(), self.m_prime.clone(),
+                self.t.clone(), self.t_prime.clone(), self.m2.clone(), self.s.clone(), self.r.clone(), self.r_prime.clone(),
+                self.r_prime_prime.clone(), self.r_prime_prime_prime.clone()])
     }
 
     pub fn from_list(seq: Vec<GroupOrderElement>) -> NonRevocProofXList {
         NonRevocProofXList {
-            rho: seq[0],
-            r: seq[10],
-            r_prime: seq[11],
-            r_prime_prime: seq[12],
-            r_prime_prime_prime: seq[13],
-            o: seq[1],
-            o_prime: seq[3],
-            m: seq[4],
-            m_prime: seq[5],
-            t: seq[6],
-            t_prime: seq[7],
-            m2: seq[8],
-            s: seq[9],
-            c: seq[2]
+            rho: seq[0].clone(),
+            r: seq[10].clone(),
+            r_prime: seq[11].clone(),
+            r_prime_prime: seq[12].clone(),
+            r_prime_prime_prime: seq[13].clone(),
+            o: seq[1].clone(),
+            o_prime: seq[3].clone(),
+            m: seq[4].clone(),
+            m_prime: seq[5].clone(),
+            t: seq[6].clone(),
+            t_prime: seq[7].clone(),
+            m2: seq[8].clone(),
+            s: seq[9].clone(),
+            c: seq[2].clone()
         }
     }
 }
@@ -1143,4 +1338,94 @@ mod test {
                                              Some(&rev_reg)).unwrap();
         assert_eq!(true, proof_verifier.verify(&proof, &proof_request_nonce).unwrap());
     }
+
+    fn _predicate(p_type: &str, value: &str) -> Predicate {
+        let mut sub_proof_request_builder = SubProofRequestBuilder::new().unwrap();
+        sub_proof_request_builder.add_big_predicate("age", p_type, &BigNumber::from_dec(value).unwrap()).unwrap();
+        sub_proof_request_builder.finalize().unwrap().predicates.into_iter().next().unwrap()
+    }
+
+    #[test]
+    fn predicate_get_delta_works() {
+        let delta = |p_type: &str, value: &str, attr_value: &str| {
+            _predicate(p_type, value).get_delta(&BigNumber::from_dec(attr_value).unwrap()).unwrap().to_dec().unwrap()
+        };
+
+        assert_eq!("10", delta("GE", "18", "28"));
+        assert_eq!("9", delta("GT", "18", "28"));
+        assert_eq!("37", delta("LE", "65", "28"));
+        assert_eq!("36", delta("LT", "65", "28"));
+        assert_eq!("-1", delta("LT", "28", "28"));
+        assert_eq!("0", delta("LE", "28", "28"));
+        assert_eq!("29", delta("GT", "-2", "28"));
+        assert_eq!("4294967297", delta("LE", "2147483649", "-2147483648"));
+        assert_eq!("100000000000000000000000000000", delta("GE", "-1", "99999999999999999999999999999"));
+    }
+
+    #[test]
+    fn predicate_get_delta_prime_works() {
+        let delta_prime = |p_type: &str, value: &str| {
+            _predicate(p_type, value).get_delta_prime().unwrap().to_dec().unwrap()
+        };
+
+        assert!(_predicate("LT", "65").is_less());
+        assert_eq!("-64", delta_prime("LT", "65"));
+        assert_eq!("-65", delta_prime("LE", "65"));
+        assert_eq!("66", delta_prime("GT", "65"));
+        assert_eq!("-7", delta_prime("GE", "-7"));
+        assert_eq!("7", delta_prime("LE", "-7"));
+        assert_eq!("8", delta_prime("LT", "-7"));
+        assert_eq!("0", delta_prime("GT", "-1"));
+    }
+
+    #[test]
+    fn predicate_clone_works_for_negative_value() {
+        let predicate = _predicate("GE", "-18");
+        assert_eq!(predicate, predicate.clone().unwrap());
+    }
+
+    #[test]
+    fn predicate_deserialize_works_for_integer_and_string_value() {
+        let expected = _predicate("GE", "-18");
+
+        let predicate: Predicate = serde_json::from_str(r#"{"attr_name":"age","p_type":"GE","value":-18}"#).unwrap();
+        assert_eq!(expected, predicate);
+
+        let predicate: Predicate = serde_json::from_str(r#"{"attr_name":"age","p_type":"GE","value":"-18"}"#).unwrap();
+        assert_eq!(expected, predicate);
+
+        let big = _predicate("LE", "18446744073709551616");
+        assert_eq!(big, serde_json::from_str(&serde_json::to_string(&big).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn predicate_serialize_works_for_integer_value() {
+        let json = r#"{"attr_name":"age","p_type":"GE","value":18}"#;
+        let predicate: Predicate = serde_json::from_str(json).unwrap();
+        assert_eq!(json, serde_json::to_string(&predicate).unwrap());
+
+        let json = r#"{"attr_name":"age","p_type":"LE","value":-18}"#;
+        let predicate: Predicate = serde_json::from_str(json).unwrap();
+        assert_eq!(json, serde_json::to_string(&predicate).unwrap());
+
+        let big = _predicate("LE", "18446744073709551616");
+        assert_eq!(r#"{"attr_name":"age","p_type":"LE","value":"18446744073709551616"}"#, serde_json::to_string(&big).unwrap());
+    }
+
+    #[test]
+    fn sub_proof_request_builder_add_predicate_works_for_invalid_type() {
+        let mut sub_proof_request_builder = SubProofRequestBuilder::new().unwrap();
+        assert!(sub_proof_request_builder.add_predicate("age", "NE", 18).is_err());
+    }
+
+    #[test]
+    fn sub_proof_request_builder_add_attr_equality_works() {
+        let mut sub_proof_request_builder = SubProofRequestBuilder::new().unwrap();
+        sub_proof_request_builder.add_attr_equality("name", "name_link").unwrap();
+        sub_proof_request_builder.add_attr_equality("name", "name_link").unwrap();
+        assert!(sub_proof_request_builder.add_attr_equality("name", "other_link").is_err());
+
+        let sub_proof_request = sub_proof_request_builder.finalize().unwrap();
+        assert_eq!("name_link", sub_proof_request.attr_equalities["name"]);
+    }
 }
@@ -5,6 +5,7 @@ use errors::IndyCryptoError;
 use pair::*;
 use super::helpers::*;
 use utils::commitment::{get_pedersen_commitment, get_exponentiated_generators};
+use utils::secret::Secret;
 
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::iter::FromIterator;
@@ -175,9 +176,9 @@ impl Prover {
                                                    &credential_pub_key.p_key,
                                                    nonce)?;
 
-        if let (&mut Some(ref mut non_revocation_cred), Some(ref vr_prime), &Some(ref r_key),
+        if let (&mut Some(ref mut non_revocation_cred), &Some(ref vr_prime), &Some(ref r_key),
             Some(ref r_key_pub), Some(ref r_reg), Some(ref witness)) = (&mut credential_signature.r_credential,
-                                                                        master_secret_blinding_data.vr_prime,
+                                                                        &master_secret_blinding_data.vr_prime,
                                                                         &credential_pub_key.r_key,
                                                                         rev_key_pub,
                                                                         rev_reg,
@@ -206,6 +207,7 @@ impl Prover {
     pub fn new_proof_builder() -> Result<ProofBuilder, IndyCryptoError> {
         Ok(ProofBuilder {
             m1_tilde: bn_rand(LARGE_M2_TILDE)?,
+            linked_m_tilde: HashMap::new(),
             init_proofs: HashMap::new(),
             c_list: Vec::new(),
             tau_list: Vec::new()
@@ -328,7 +330,7 @@ impl Prover {
 
     fn _process_primary_credential(p_cred: &mut PrimaryCredentialSignature,
                                    v_prime: &BigNumber) -> Result<(), IndyCryptoError> {
-        trace!("Prover::_process_primary_credential: >>> p_cred: {:?}, v_prime: {:?}", p_cred, v_prime);
+        trace!("Prover::_process_primary_credential: >>> p_cred: {:?}, v_prime: {:?}", p_cred, Secret(v_prime));
 
         p_cred.v = v_prime.add(&p_cred.v)?;
 
@@ -344,7 +346,7 @@ impl Prover {
                                           rev_reg: &RevocationRegistry,
                                           witness: &Witness) -> Result<(), IndyCryptoError> {
         trace!("Prover::_process_non_revocation_credential: >>> r_cred: {:?}, vr_prime: {:?}, cred_rev_pub_key: {:?}, rev_reg: {:?}, rev_key_pub: {:?}",
-               r_cred, vr_prime, cred_rev_pub_key, rev_reg, rev_key_pub);
+               r_cred, Secret(vr_prime), cred_rev_pub_key, rev_reg, rev_key_pub);
 
         let r_cnxt_m2 = BigNumber::from_bytes(&r_cred.m2.to_bytes()?)?;
         r_cred.vr_prime_prime = vr_prime.add_mod(&r_cred.vr_prime_prime)?;
@@ -387,7 +389,7 @@ impl Prover {
 
         let q = p_pub_key.z.mod_div(&rx, &p_pub_key.n)?;
 
-        let expected_q = p_cred_sig.a.mod_exp(&p_cred_sig.e, &p_pub_key.n, Some(&mut ctx))?;
+        let expected_q = p_cred_sig.a.mod_exp_secret(&p_cred_sig.e, &p_pub_key.n, Some(&mut ctx))?;
 
         if !q.eq(&expected_q) {
             return Err(IndyCryptoError::InvalidStructure(format!("Invalid Signature correctness proof")));
@@ -397,7 +399,7 @@ impl Prover {
             &signature_correctness_proof.se.mul(&p_cred_sig.e, Some(&mut ctx))?
         )?;
 
-        let a_cap = p_cred_sig.a.mod_exp(&degree, &p_pub_key.n, Some(&mut ctx))?;
+        let a_cap = p_cred_sig.a.mod_exp_secret(&degree, &p_pub_key.n, Some(&mut ctx))?;
 
         let mut values: Vec<u8> = Vec::new();
         values.extend_from_slice(&q.to_bytes()?);
@@ -445,8 +447,7 @@ impl Prover {
         let pair_h1 = Pair::pair(&r_cred.sigma, &cred_rev_pub_key.y.add(&cred_rev_pub_key.h_cap.mul(&r_cred.c)?)?)?;
         let pair_h2 = Pair::pair(
             &cred_rev_pub_key.h0
-                .add(&cred_rev_pub_key.h1.mul(&m2)?)?
-                .add(&cred_rev_pub_key.h2.mul(&r_cred.vr_prime_prime)?)?
+                .add(&PointG1::multi_mul(&[cred_rev_pub_key.h1, cred_rev_pub_key.h2], &[m2, r_cred.vr_prime_prime.clone()])?)?
                 .add(&r_cred.g_i)?,
             &cred_rev_pub_key.h_cap
         )?;
@@ -461,14 +462,16 @@ impl Prover {
     }
 }
 
-#[derive(Debug)]
 pub struct ProofBuilder {
     pub m1_tilde: BigNumber,
+    pub linked_m_tilde: HashMap<String, BigNumber>, /* link -> m_tilde shared by equal hidden attributes */
     pub init_proofs: HashMap<String, InitProof>,
     pub c_list: Vec<Vec<u8>>,
     pub tau_list: Vec<Vec<u8>>,
 }
 
+impl_secret_debug!(ProofBuilder { init_proofs, c_list, tau_list } secret { m1_tilde, linked_m_tilde });
+
 impl ProofBuilder {
     /// Adds sub proof request to proof builder which will be used fo building of proof.
     /// Part of proof request related to a particular schema-key.
@@ -476,7 +479,7 @@ impl ProofBuilder {
     /// # Arguments
     /// * `proof_builder` - Proof builder.
     /// * `key_id` - Unique credential identifier.
-    /// * `sub_proof_request` -Requested attributes and predicates.
+    /// * `sub_proof_request` -Requested attributes, predicates and attribute equalities shared with other sub proof requests.
     /// * `credential_schema` - Credential schema.
     /// * `credential_signature` - Credential signature.
     /// * `credential_values` - Credential values.
@@ -554,6 +557,13 @@ impl ProofBuilder {
                key_id, credential_signature, credential_values, credential_pub_key, rev_reg, sub_proof_request, credential_schema);
 
         ProofBuilder::_check_add_sub_proof_request_params_consistency(credential_values, sub_proof_request, credential_schema)?;
+        ProofBuilder::_check_attr_equalities(&self.init_proofs, credential_values, sub_proof_request)?;
+
+        for link in sub_proof_request.attr_equalities.values() {
+            if !self.linked_m_tilde.contains_key(link) {
+                self.linked_m_tilde.insert(link.clone(), bn_rand(LARGE_MVECT)?);
+            }
+        }
 
         let mut non_revoc_init_proof = None;
         let mut m2_tilde: Option<BigNumber> = None;
@@ -579,7 +589,8 @@ impl ProofBuilder {
                                                                    &credential_schema,
                                                                    &sub_proof_request,
                                                                    &self.m1_tilde,
-                                                                   m2_tilde)?;
+                                                                   m2_tilde,
+                                                                   &self.linked_m_tilde)?;
 
         self.c_list.extend_from_slice(&primary_init_proof.as_c_list()?);
         self.tau_list.extend_from_slice(&primary_init_proof.as_tau_list()?);
@@ -588,7 +599,7 @@ impl ProofBuilder {
             primary_init_proof,
             non_revoc_init_proof,
             credential_values: credential_values.clone()?,
-            sub_proof_request: sub_proof_request.clone(),
+            sub_proof_request: sub_proof_request.clone()?,
             credential_schema: credential_schema.clone()
         };
         self.init_proofs.insert(key_id.to_owned(), init_proof);
@@ -729,22 +740,61 @@ impl ProofBuilder {
             return Err(IndyCryptoError::InvalidStructure(format!("Credential doesn't contain attribute requested in predicate")));
         }
 
+        let equal_attrs = HashSet::from_iter(sub_proof_request.attr_equalities.keys().cloned());
+
+        if equal_attrs.difference(&cred_attrs).count() != 0 {
+            return Err(IndyCryptoError::InvalidStructure(format!("Credential doesn't contain attribute requested in equality")));
+        }
+
+        if equal_attrs.intersection(&sub_proof_request.revealed_attrs).count() != 0 {
+            return Err(IndyCryptoError::InvalidStructure(format!("Attribute requested in equality is revealed")));
+        }
+
         trace!("ProofBuilder::_check_add_sub_proof_request_params_consistency: <<<");
 
         Ok(())
     }
 
+    fn _check_attr_equalities(init_proofs: &HashMap<String, InitProof>,
+                              cred_values: &CredentialValues,
+                              sub_proof_request: &SubProofRequest) -> Result<(), IndyCryptoError> {
+        trace!("ProofBuilder::_check_attr_equalities: >>> init_proofs: {:?}, cred_values: {:?}, sub_proof_request: {:?}",
+               init_proofs, cred_values, sub_proof_request);
+
+        let mut linked_values: HashMap<&String, &BigNumber> = HashMap::new();
+
+        let requests = init_proofs.values()
+            .map(|init_proof| (&init_proof.credential_values, &init_proof.sub_proof_request))
+            .chain(Some((cred_values, sub_proof_request)));
+
+        for (cred_values, sub_proof_request) in requests {
+            for (attr, link) in sub_proof_request.attr_equalities.iter() {
+                let value = cred_values.attrs_values.get(attr)
+                    .ok_or(IndyCryptoError::InvalidStructure(format!("Value by key '{}' not found in cred_values", attr)))?;
+
+                if *linked_values.entry(link).or_insert(value) != value {
+                    return Err(IndyCryptoError::InvalidStructure(format!("Attributes linked with '{}' are not equal", link)));
+                }
+            }
+        }
+
+        trace!("ProofBuilder::_check_attr_equalities: <<<");
+
+        Ok(())
+    }
+
     fn _init_primary_proof(issuer_pub_key: &CredentialPrimaryPublicKey,
                            c1: &PrimaryCredentialSignature,
                            cred_values: &CredentialValues,
                            cred_schema: &CredentialSchema,
                            sub_proof_request: &SubProofRequest,
                            m1_t: &BigNumber,
-                           m2_t: Option<BigNumber>) -> Result<PrimaryInitProof, IndyCryptoError> {
-        trace!("ProofBuilder::_init_primary_proof: >>> issuer_pub_key: {:?}, c1: {:?}, cred_values: {:?}, cred_schema: {:?}, sub_proof_request: {:?}, m1_t: {:?}, m2_t: {:?}",
-               issuer_pub_key, c1, cred_values, cred_schema, sub_proof_request, m1_t, m2_t);
+                           m2_t: Option<BigNumber>,
+                           linked_m_tilde: &HashMap<String, BigNumber>) -> Result<PrimaryInitProof, IndyCryptoError> {
+        trace!("ProofBuilder::_init_primary_proof: >>> issuer_pub_key: {:?}, c1: {:?}, cred_values: {:?}, cred_schema: {:?}, sub_proof_request: {:?}, m1_t: {:?}, m2_t: {:?}, \
+        linked_m_tilde: {:?}", issuer_pub_key, c1, cred_values, cred_schema, sub_proof_request, Secret(m1_t), Secret(&m2_t), Secret(linked_m_tilde));
 
-        let eq_proof = ProofBuilder::_init_eq_proof(&issuer_pub_key, c1, cred_schema, sub_proof_request, m1_t, m2_t)?;
+        let eq_proof = ProofBuilder::_init_eq_proof(&issuer_pub_key, c1, cred_schema, sub_proof_request, m1_t, m2_t, linked_m_tilde)?;
 
         let mut ge_proofs: Vec<PrimaryPredicateGEInitProof> = Vec::new();
         for predicate in sub_proof_request.predicates.iter() {
@@ -792,9 +842,10 @@ impl ProofBuilder {
                       cred_schema: &CredentialSchema,
                       sub_proof_request: &SubProofRequest,
                       m1_tilde: &BigNumber,
-                      m2_t: Option<BigNumber>) -> Result<PrimaryEqualInitProof, IndyCryptoError> {
-        trace!("ProofBuilder::_init_eq_proof: >>> credr_pub_key: {:?}, c1: {:?}, cred_schema: {:?}, sub_proof_request: {:?}, m1_tilde: {:?}, m2_t: {:?}",
-               credr_pub_key, c1, cred_schema, sub_proof_request, m1_tilde, m2_t);
+                      m2_t: Option<BigNumber>,
+                      linked_m_tilde: &HashMap<String, BigNumber>) -> Result<PrimaryEqualInitProof, IndyCryptoError> {
+        trace!("ProofBuilder::_init_eq_proof: >>> credr_pub_key: {:?}, c1: {:?}, cred_schema: {:?}, sub_proof_request: {:?}, m1_tilde: {:?}, m2_t: {:?}, \
+        linked_m_tilde: {:?}", credr_pub_key, c1, cred_schema, sub_proof_request, Secret(m1_tilde), Secret(&m2_t), Secret(linked_m_tilde));
 
         let mut ctx = BigNumber::new_context()?;
 
@@ -810,10 +861,17 @@ impl ProofBuilder {
                 .cloned()
                 .collect::<HashSet<String>>();
 
-        let m_tilde = get_mtilde(&unrevealed_attrs)?;
+        let mut m_tilde = get_mtilde(&unrevealed_attrs)?;
+
+        // Equal hidden attributes share randomness, so their m^ in the proof are equal too
+        for (attr, link) in sub_proof_request.attr_equalities.iter() {
+            let cur_m_tilde = linked_m_tilde.get(link)
+                .ok_or(IndyCryptoError::InvalidStructure(format!("Value by key '{}' not found in linked_m_tilde", link)))?;
+            m_tilde.insert(attr.clone(), cur_m_tilde.clone()?);
+        }
 
         let a_prime = credr_pub_key.s
-            .mod_exp(&r, &credr_pub_key.n, Some(&mut ctx))?
+            .mod_exp_secret(&r, &credr_pub_key.n, Some(&mut ctx))?
             .mod_mul(&c1.a, &credr_pub_key.n, Some(&mut ctx))?;
 
         let v_prime = c1.v.sub(
@@ -849,24 +907,26 @@ impl ProofBuilder {
                       cred_values: &CredentialValues,
                       predicate: &Predicate) -> Result<PrimaryPredicateGEInitProof, IndyCryptoError> {
         trace!("ProofBuilder::_init_ge_proof: >>> p_pub_key: {:?}, m_tilde: {:?}, cred_values: {:?}, predicate: {:?}",
-               p_pub_key, m_tilde, cred_values, predicate);
+               p_pub_key, Secret(m_tilde), cred_values, predicate);
 
         let mut ctx = BigNumber::new_context()?;
-        let (k, value) = (&predicate.attr_name, predicate.value);
+        let k = &predicate.attr_name;
 
         let attr_value = cred_values.attrs_values.get(k.as_str())
-            .ok_or(IndyCryptoError::InvalidStructure(format!("Value by key '{}' not found in cred_values", k)))?
-            .to_dec()?
-            .parse::<i32>()
-            .map_err(|_| IndyCryptoError::InvalidStructure(format!("Value by key '{}' has invalid format", k)))?;
+            .ok_or(IndyCryptoError::InvalidStructure(format!("Value by key '{}' not found in cred_values", k)))?;
+
+        // Issuer signs absolute values of attributes, so proof over negative one can't be verified
+        if attr_value.is_negative() {
+            return Err(IndyCryptoError::InvalidStructure(format!("Value by key '{}' is negative, predicates are supported for non-negative values only", k)));
+        }
 
-        let delta: i32 = attr_value - value;
+        let delta = predicate.get_delta(attr_value)?;
 
-        if delta < 0 {
+        if delta.is_negative() {
             return Err(IndyCryptoError::InvalidStructure("Predicate is not satisfied".to_string()));
         }
 
-        let u = four_squares(delta)?;
+        let u = four_squares(&delta)?;
 
         let mut r: HashMap<String, BigNumber> = HashMap::new();
         let mut t: HashMap<String, BigNumber> = HashMap::new();
@@ -887,7 +947,7 @@ impl ProofBuilder {
 
         let r_delta = bn_rand(LARGE_VPRIME)?;
 
-        let t_delta = get_pedersen_commitment(&p_pub_key.z, &BigNumber::from_dec(&delta.to_string())?,
+        let t_delta = get_pedersen_commitment(&p_pub_key.z, &delta,
                                               &p_pub_key.s, &r_delta, &p_pub_key.n, &mut ctx)?;
 
         r.insert("DELTA".to_string(), r_delta);
@@ -908,7 +968,7 @@ impl ProofBuilder {
         let mj = m_tilde.get(k.as_str())
             .ok_or(IndyCryptoError::InvalidStructure(format!("Value by key '{}' not found in eq_proof.mtilde", k)))?;
 
-        let tau_list = calc_tge(&p_pub_key, &u_tilde, &r_tilde, &mj, &alpha_tilde, &t)?;
+        let tau_list = calc_tge(&p_pub_key, &u_tilde, &r_tilde, &mj, &alpha_tilde, &t, &predicate)?;
 
         let primary_predicate_ge_init_proof = PrimaryPredicateGEInitProof {
             c_list,
@@ -918,7 +978,7 @@ impl ProofBuilder {
             r,
             r_tilde,
             alpha_tilde,
-            predicate: predicate.clone(),
+            predicate: predicate.clone()?,
             t
         };
 
@@ -1053,7 +1113,7 @@ impl ProofBuilder {
             mj: eq_proof.m[&init_proof.predicate.attr_name].clone()?,
             alpha,
             t: clone_bignum_map(&init_proof.t)?,
-            predicate: init_proof.predicate.clone()
+            predicate: init_proof.predicate.clone()?
         };
 
         trace!("ProofBuilder::_finalize_ge_proof: <<< primary_predicate_ge_proof: {:?}", primary_predicate_ge_proof);
@@ -1114,8 +1174,8 @@ impl ProofBuilder {
             t,
             t_prime,
             m2,
-            s: r_cred.vr_prime_prime,
-            c: r_cred.c
+            s: r_cred.vr_prime_prime.clone(),
+            c: r_cred.c.clone()
         };
 
         trace!("ProofBuilder::_gen_c_list_params: <<< non_revoc_proof_x_list: {:?}", non_revoc_proof_x_list);
@@ -1129,17 +1189,9 @@ impl ProofBuilder {
                              witness: &Witness) -> Result<NonRevocProofCList, IndyCryptoError> {
         trace!("ProofBuilder::_create_c_list_values: >>> r_cred: {:?}, r_pub_key: {:?}", r_cred, r_pub_key);
 
-        let e = r_pub_key.h
-            .mul(&params.rho)?
-            .add(
-                &r_pub_key.htilde.mul(&params.o)?
-            )?;
+        let e = PointG1::multi_mul(&[r_pub_key.h, r_pub_key.htilde], &[params.rho.clone(), params.o.clone()])?;
 
-        let d = r_pub_key.g
-            .mul(&params.r)?
-            .add(
-                &r_pub_key.htilde.mul(&params.o_prime)?
-            )?;
+        let d = PointG1::multi_mul(&[r_pub_key.g, r_pub_key.htilde], &[params.r.clone(), params.o_prime.clone()])?;
 
         let a = r_cred.sigma
             .add(
@@ -1326,7 +1378,8 @@ mod tests {
                                                          &credential_schema,
                                                          &sub_proof_request,
                                                          &m1_t,
-                                                         None).unwrap();
+                                                         None,
+                                                         &HashMap::new()).unwrap();
 
         assert_eq!(mocks::primary_equal_init_proof(), init_eq_proof);
     }
@@ -1365,7 +1418,8 @@ mod tests {
                                                            &credential_schema,
                                                            &sub_proof_request,
                                                            &m1_t,
-                                                           None).unwrap();
+                                                           None,
+                                                           &HashMap::new()).unwrap();
         assert_eq!(mocks::primary_init_proof(), init_proof);
     }
 
@@ -1860,7 +1914,7 @@ pub mod mocks {
         Predicate {
             attr_name: "age".to_owned(),
             p_type: PredicateType::GE,
-            value: 18
+            value: BigNumber::from_u32(18).unwrap()
         }
     }
 }
\ No newline at end of file
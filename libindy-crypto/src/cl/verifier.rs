@@ -63,7 +63,7 @@ impl ProofVerifier {
     /// * `credential_schema` - Credential schema.
     /// * `credential_pub_key` - Credential public key.
     /// * `rev_reg_pub` - Revocation registry public key.
-    /// * `sub_proof_request` - Requested attributes and predicates instance pointer.
+    /// * `sub_proof_request` - Requested attributes, predicates and attribute equalities instance pointer.
     ///
     /// #Example
     /// ```
@@ -100,7 +100,7 @@ impl ProofVerifier {
 
         self.credentials.insert(key_id.to_string(), VerifiableCredential {
             pub_key: credential_pub_key.clone()?,
-            sub_proof_request: sub_proof_request.clone(),
+            sub_proof_request: sub_proof_request.clone()?,
             credential_schema: credential_schema.clone(),
             rev_key_pub: rev_key_pub.map(Clone::clone),
             rev_reg: rev_reg.map(Clone::clone)
@@ -254,6 +254,16 @@ impl ProofVerifier {
             return Err(IndyCryptoError::InvalidStructure(format!("Claim doesn't contain attribute requested in predicate")));
         }
 
+        let equal_attrs = HashSet::from_iter(sub_proof_request.attr_equalities.keys().cloned());
+
+        if equal_attrs.difference(&cred_schema.attrs).count() != 0 {
+            return Err(IndyCryptoError::InvalidStructure(format!("Claim doesn't contain attribute requested in equality")));
+        }
+
+        if equal_attrs.intersection(&sub_proof_request.revealed_attrs).count() != 0 {
+            return Err(IndyCryptoError::InvalidStructure(format!("Attribute requested in equality is revealed")));
+        }
+
         trace!("ProofVerifier::_check_add_sub_proof_request_params_consistency: <<<");
 
         Ok(())
@@ -263,6 +273,8 @@ impl ProofVerifier {
                                         proof: &Proof) -> Result<(), IndyCryptoError> {
         trace!("ProofVerifier::_check_verify_params_consistency: >>> credentials: {:?}, proof: {:?}", credentials, proof);
 
+        let mut linked_m: HashMap<&String, &BigNumber> = HashMap::new();
+
         for (key_id, credential) in credentials {
             let proof_for_credential = proof.proofs.get(key_id.as_str()).
                 ok_or(IndyCryptoError::AnoncredsProofRejected(format!("Proof not found")))?;
@@ -276,11 +288,21 @@ impl ProofVerifier {
             let proof_predicates =
                 proof_for_credential.primary_proof.ge_proofs.iter()
                     .map(|ge_proof| ge_proof.predicate.clone())
-                    .collect::<HashSet<Predicate>>();
+                    .collect::<Result<HashSet<Predicate>, IndyCryptoError>>()?;
 
             if proof_predicates != credential.sub_proof_request.predicates {
                 return Err(IndyCryptoError::AnoncredsProofRejected(format!("Proof predicates not correspond to requested predicates")));
             }
+
+            // Equal hidden attributes are proven with shared m_tilde, so they must have equal m^
+            for (attr, link) in credential.sub_proof_request.attr_equalities.iter() {
+                let cur_m = proof_for_credential.primary_proof.eq_proof.m.get(attr)
+                    .ok_or(IndyCryptoError::AnoncredsProofRejected(format!("Value by key '{}' not found in proof.m", attr)))?;
+
+                if *linked_m.entry(link).or_insert(cur_m) != cur_m {
+                    return Err(IndyCryptoError::AnoncredsProofRejected(format!("Proof attributes linked with '{}' not equal", link)));
+                }
+            }
         }
 
         trace!("ProofVerifier::_check_verify_params_consistency: <<<");
@@ -366,7 +388,7 @@ impl ProofVerifier {
 
         let mut ctx = BigNumber::new_context()?;
         let mut tau_list = calc_tge(&p_pub_key, &proof.u, &proof.r, &proof.mj,
-                                    &proof.alpha, &proof.t)?;
+                                    &proof.alpha, &proof.t, &proof.predicate)?;
 
         for i in 0..ITERATION {
             let cur_t = proof.t.get(&i.to_string())
@@ -381,10 +403,19 @@ impl ProofVerifier {
         let delta = proof.t.get("DELTA")
             .ok_or(IndyCryptoError::AnoncredsProofRejected(format!("Value by key '{}' not found in proof.t", "DELTA")))?;
 
-        tau_list[ITERATION] = p_pub_key.z
-            .mod_exp(
-                &BigNumber::from_dec(&proof.predicate.value.to_string())?,
-                &p_pub_key.n, Some(&mut ctx))?
+        let delta_prime = proof.predicate.get_delta_prime()?;
+
+        let mut delta_prime_abs = delta_prime.clone()?;
+        delta_prime_abs.set_negative(false)?;
+
+        let mut z_delta_prime = p_pub_key.z
+            .mod_exp(&delta_prime_abs, &p_pub_key.n, Some(&mut ctx))?;
+
+        if delta_prime.is_negative() {
+            z_delta_prime = z_delta_prime.inverse(&p_pub_key.n, Some(&mut ctx))?;
+        }
+
+        tau_list[ITERATION] = z_delta_prime
             .mul(&delta, Some(&mut ctx))?
             .mod_exp(&c_hash, &p_pub_key.n, Some(&mut ctx))?
             .inverse(&p_pub_key.n, Some(&mut ctx))?
@@ -22,6 +22,7 @@ pub enum IndyCryptoError {
     InvalidParam9(String),
     InvalidState(String),
     InvalidStructure(String),
+    InvalidGroupElement(String),
     IOError(io::Error),
     AnoncredsRevocationAccumulatorIsFull(String),
     AnoncredsInvalidRevocationAccumulatorIndex(String),
@@ -43,6 +44,7 @@ impl fmt::Display for IndyCryptoError {
             IndyCryptoError::InvalidParam9(ref description) => write!(f, "Invalid param 4: {}", description),
             IndyCryptoError::InvalidState(ref description) => write!(f, "Invalid library state: {}", description),
             IndyCryptoError::InvalidStructure(ref description) => write!(f, "Invalid structure: {}", description),
+            IndyCryptoError::InvalidGroupElement(ref description) => write!(f, "Invalid group element: {}", description),
             IndyCryptoError::IOError(ref err) => err.fmt(f),
             IndyCryptoError::AnoncredsRevocationAccumulatorIsFull(ref description) => write!(f, "Revocation accumulator is full: {}", description),
             IndyCryptoError::AnoncredsInvalidRevocationAccumulatorIndex(ref description) => write!(f, "Invalid revocation accumulator index: {}", description),
@@ -66,6 +68,7 @@ impl Error for IndyCryptoError {
             IndyCryptoError::InvalidParam9(ref description) => description,
             IndyCryptoError::InvalidState(ref description) => description,
             IndyCryptoError::InvalidStructure(ref description) => description,
+            IndyCryptoError::InvalidGroupElement(ref description) => description,
             IndyCryptoError::IOError(ref err) => err.description(),
             IndyCryptoError::AnoncredsRevocationAccumulatorIsFull(ref description) => description,
             IndyCryptoError::AnoncredsInvalidRevocationAccumulatorIndex(ref description) => description,
@@ -86,7 +89,8 @@ impl Error for IndyCryptoError {
             IndyCryptoError::InvalidParam8(_) |
             IndyCryptoError::InvalidParam9(_) |
             IndyCryptoError::InvalidState(_) |
-            IndyCryptoError::InvalidStructure(_) => None,
+            IndyCryptoError::InvalidStructure(_) |
+            IndyCryptoError::InvalidGroupElement(_) => None,
             IndyCryptoError::IOError(ref err) => Some(err),
             IndyCryptoError::AnoncredsRevocationAccumulatorIsFull(_) => None,
             IndyCryptoError::AnoncredsInvalidRevocationAccumulatorIndex(_) => None,
@@ -110,6 +114,7 @@ impl ToErrorCode for IndyCryptoError {
             IndyCryptoError::InvalidParam9(_) => ErrorCode::CommonInvalidParam9,
             IndyCryptoError::InvalidState(_) => ErrorCode::CommonInvalidState,
             IndyCryptoError::InvalidStructure(_) => ErrorCode::CommonInvalidStructure,
+            IndyCryptoError::InvalidGroupElement(_) => ErrorCode::CommonInvalidGroupElement,
             IndyCryptoError::IOError(_) => ErrorCode::CommonIOError,
             IndyCryptoError::AnoncredsRevocationAccumulatorIsFull(_) => ErrorCode::AnoncredsRevocationAccumulatorIsFull,
             IndyCryptoError::AnoncredsInvalidRevocationAccumulatorIndex(_) => ErrorCode::AnoncredsInvalidRevocationAccumulatorIndex,
@@ -1,7 +1,15 @@
 use bls::*;
+use bls::curve;
+use bls::hd::KeyDerivation;
+use errors::IndyCryptoError;
+use pair::{DefaultEngine, PairingEngine};
 
 use ffi::ErrorCode;
 use errors::ToErrorCode;
+use utils::ctypes::CTypesUtils;
+use utils::secret::Secret;
+use libc::c_char;
+use std::fmt;
 use std::os::raw::c_void;
 use std::slice;
 
@@ -101,6 +109,71 @@ pub extern fn indy_crypto_bls_generator_as_bytes(gen: *const c_void,
     res
 }
 
+/// Creates and returns generator from compressed bytes representation.
+///
+/// Note: Generator instance deallocation must be performed by calling indy_crypto_bls_generator_free
+///
+/// # Arguments
+/// * `bytes` - Compressed bytes buffer pointer
+/// * `bytes_len` - Compressed bytes buffer len
+/// * `gen_p` - Reference that will contain generator instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_generator_from_compressed_bytes(bytes: *const u8, bytes_len: usize,
+                                                              gen_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_generator_from_compressed_bytes: >>> bytes: {:?}, bytes_len: {:?}, gen_p: {:?}", bytes, bytes_len, gen_p);
+
+    check_useful_c_byte_array!(bytes, bytes_len,
+                               ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(gen_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_generator_from_compressed_bytes: bytes: {:?}", bytes);
+
+    let res = match Generator::from_compressed_bytes(bytes) {
+        Ok(gen) => {
+            trace!("indy_crypto_bls_generator_from_compressed_bytes: gen: {:?}", gen);
+            unsafe {
+                *gen_p = Box::into_raw(Box::new(gen)) as *const c_void;
+                trace!("indy_crypto_bls_generator_from_compressed_bytes: *gen_p: {:?}", *gen_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_generator_from_compressed_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Returns compressed bytes representation of generator.
+///
+/// Note: Returned buffer lifetime is the same as generator instance.
+///
+/// # Arguments
+/// * `gen` - Generator instance pointer
+/// * `bytes_p` - Pointer that will contains compressed bytes buffer
+/// * `bytes_len_p` - Pointer that will contains compressed bytes buffer len
+#[no_mangle]
+pub extern fn indy_crypto_bls_generator_as_compressed_bytes(gen: *const c_void,
+                                                            bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_generator_as_compressed_bytes: >>> gen: {:?}, bytes_p: {:?}, bytes_len_p: {:?}", gen, bytes_p, bytes_len_p);
+
+    check_useful_c_reference!(gen, Generator, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(bytes_p, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(bytes_len_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_generator_as_compressed_bytes: gen: {:?}", gen);
+
+    unsafe {
+        *bytes_p = gen.as_compressed_bytes().as_ptr();
+        *bytes_len_p = gen.as_compressed_bytes().len();
+    };
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_generator_as_compressed_bytes: <<< res: {:?}", res);
+    res
+}
+
 /// Deallocates generator instance.
 ///
 /// # Arguments
@@ -135,7 +208,7 @@ pub extern fn indy_crypto_bls_sign_key_new(seed: *const u8,
     check_useful_opt_c_byte_array!(seed, seed_len,
                                    ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
 
-    trace!("indy_crypto_bls_sign_key_new: seed: {:?}", seed);
+    trace!("indy_crypto_bls_sign_key_new: seed: {:?}", Secret(&seed));
 
     let res = match SignKey::new(seed) {
         Ok(sign_key) => {
@@ -170,7 +243,7 @@ pub extern fn indy_crypto_bls_sign_key_from_bytes(bytes: *const u8, bytes_len: u
                                ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
     check_useful_c_ptr!(sign_key_p, ErrorCode::CommonInvalidParam1);
 
-    trace!("indy_crypto_bls_sign_key_from_bytes: bytes: {:?}", bytes);
+    trace!("indy_crypto_bls_sign_key_from_bytes: bytes: {:?}", Secret(&bytes));
 
     let res = match SignKey::from_bytes(bytes) {
         Ok(sign_key) => {
@@ -218,6 +291,109 @@ pub extern fn indy_crypto_bls_sign_key_as_bytes(sign_key: *const c_void,
     res
 }
 
+/// Derives and returns master sign key (root of the hierarchical deterministic derivation tree) from seed.
+///
+/// Note: Sign key instance deallocation must be performed by calling indy_crypto_bls_sign_key_free.
+///
+/// # Arguments
+/// * `seed` - Seed buffer pointer (at least 32 bytes)
+/// * `seed_len` - Seed buffer len
+/// * `sign_key_p` - Reference that will contain sign key instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_sign_key_derive_master(seed: *const u8,
+                                                     seed_len: usize,
+                                                     sign_key_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_sign_key_derive_master: >>> seed: {:?}, seed_len: {:?}, sign_key_p: {:?}", seed, seed_len, sign_key_p);
+
+    check_useful_c_byte_array!(seed, seed_len,
+                               ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(sign_key_p, ErrorCode::CommonInvalidParam3);
+
+    let res = match KeyDerivation::<DefaultEngine>::derive_master_sign_key(seed) {
+        Ok(sign_key) => {
+            unsafe {
+                *sign_key_p = Box::into_raw(Box::new(sign_key)) as *const c_void;
+                trace!("indy_crypto_bls_sign_key_derive_master: *sign_key_p: {:?}", *sign_key_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_sign_key_derive_master: <<< res: {:?}", res);
+    res
+}
+
+/// Derives and returns child sign key with given index from parent sign key.
+///
+/// Note: Sign key instance deallocation must be performed by calling indy_crypto_bls_sign_key_free.
+///
+/// # Arguments
+/// * `parent` - Parent sign key instance pointer
+/// * `index` - Index of the child
+/// * `sign_key_p` - Reference that will contain sign key instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_sign_key_derive_child(parent: *const c_void,
+                                                    index: u32,
+                                                    sign_key_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_sign_key_derive_child: >>> parent: {:?}, index: {:?}, sign_key_p: {:?}", parent, index, sign_key_p);
+
+    check_useful_c_reference!(parent, SignKey, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(sign_key_p, ErrorCode::CommonInvalidParam3);
+
+    let res = match KeyDerivation::derive_child_sign_key(parent, index) {
+        Ok(sign_key) => {
+            unsafe {
+                *sign_key_p = Box::into_raw(Box::new(sign_key)) as *const c_void;
+                trace!("indy_crypto_bls_sign_key_derive_child: *sign_key_p: {:?}", *sign_key_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_sign_key_derive_child: <<< res: {:?}", res);
+    res
+}
+
+/// Derives and returns sign key for derivation path (like "m/12381/3600/0/0") from seed.
+///
+/// Note: Sign key instance deallocation must be performed by calling indy_crypto_bls_sign_key_free.
+///
+/// # Arguments
+/// * `seed` - Seed buffer pointer (at least 32 bytes)
+/// * `seed_len` - Seed buffer len
+/// * `path` - Derivation path
+/// * `sign_key_p` - Reference that will contain sign key instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_sign_key_derive_path(seed: *const u8,
+                                                   seed_len: usize,
+                                                   path: *const c_char,
+                                                   sign_key_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_sign_key_derive_path: >>> seed: {:?}, seed_len: {:?}, path: {:?}, sign_key_p: {:?}", seed, seed_len, path, sign_key_p);
+
+    check_useful_c_byte_array!(seed, seed_len,
+                               ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(path, ErrorCode::CommonInvalidParam3);
+    check_useful_c_ptr!(sign_key_p, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_crypto_bls_sign_key_derive_path: path: {:?}", path);
+
+    let res = match KeyDerivation::<DefaultEngine>::derive_sign_key(seed, &path) {
+        Ok(sign_key) => {
+            unsafe {
+                *sign_key_p = Box::into_raw(Box::new(sign_key)) as *const c_void;
+                trace!("indy_crypto_bls_sign_key_derive_path: *sign_key_p: {:?}", *sign_key_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_sign_key_derive_path: <<< res: {:?}", res);
+    res
+}
+
 /// Deallocates sign key instance.
 ///
 /// # Arguments
@@ -335,6 +511,71 @@ pub extern fn indy_crypto_bls_ver_key_as_bytes(ver_key: *const c_void,
     res
 }
 
+/// Creates and returns verification key from compressed bytes representation.
+///
+/// Note: Verification key instance deallocation must be performed by calling indy_crypto_bls_ver_key_free
+///
+/// # Arguments
+/// * `bytes` - Compressed bytes buffer pointer
+/// * `bytes_len` - Compressed bytes buffer len
+/// * `ver_key_p` - Reference that will contain verification key instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_ver_key_from_compressed_bytes(bytes: *const u8, bytes_len: usize,
+                                                            ver_key_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_ver_key_from_compressed_bytes: >>> bytes: {:?}, bytes_len: {:?}, ver_key_p: {:?}", bytes, bytes_len, ver_key_p);
+
+    check_useful_c_byte_array!(bytes, bytes_len,
+                               ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(ver_key_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_ver_key_from_compressed_bytes: bytes: {:?}", bytes);
+
+    let res = match VerKey::from_compressed_bytes(bytes) {
+        Ok(ver_key) => {
+            trace!("indy_crypto_bls_ver_key_from_compressed_bytes: ver_key: {:?}", ver_key);
+            unsafe {
+                *ver_key_p = Box::into_raw(Box::new(ver_key)) as *const c_void;
+                trace!("indy_crypto_bls_ver_key_from_compressed_bytes: *ver_key_p: {:?}", *ver_key_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_ver_key_from_compressed_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Returns compressed bytes representation of verification key.
+///
+/// Note: Returned buffer lifetime is the same as verification key instance.
+///
+/// # Arguments
+/// * `ver_key` - Verification key instance pointer
+/// * `bytes_p` - Pointer that will contains compressed bytes buffer
+/// * `bytes_len_p` - Pointer that will contains compressed bytes buffer len
+#[no_mangle]
+pub extern fn indy_crypto_bls_ver_key_as_compressed_bytes(ver_key: *const c_void,
+                                                          bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_ver_key_as_compressed_bytes: >>> ver_key: {:?}, bytes_p: {:?}, bytes_len_p: {:?}", ver_key, bytes_p, bytes_len_p);
+
+    check_useful_c_reference!(ver_key, VerKey, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(bytes_p, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(bytes_len_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_ver_key_as_compressed_bytes: ver_key: {:?}", ver_key);
+
+    unsafe {
+        *bytes_p = ver_key.as_compressed_bytes().as_ptr();
+        *bytes_len_p = ver_key.as_compressed_bytes().len();
+    };
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_ver_key_as_compressed_bytes: <<< res: {:?}", res);
+    res
+}
+
 /// Deallocates verification key instance.
 ///
 /// # Arguments
@@ -417,6 +658,71 @@ pub extern fn indy_crypto_bls_signature_as_bytes(signature: *const c_void,
     res
 }
 
+/// Creates and returns signature from compressed bytes representation.
+///
+/// Note: Signature instance deallocation must be performed by calling indy_crypto_bls_signature_free
+///
+/// # Arguments
+/// * `bytes` - Compressed bytes buffer pointer
+/// * `bytes_len` - Compressed bytes buffer len
+/// * `signature_p` - Reference that will contain signature instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_signature_from_compressed_bytes(bytes: *const u8, bytes_len: usize,
+                                                              signature_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_signature_from_compressed_bytes: >>> bytes: {:?}, bytes_len: {:?}, signature_p: {:?}", bytes, bytes_len, signature_p);
+
+    check_useful_c_byte_array!(bytes, bytes_len,
+                               ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(signature_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_signature_from_compressed_bytes: bytes: {:?}", bytes);
+
+    let res = match Signature::from_compressed_bytes(bytes) {
+        Ok(signature) => {
+            trace!("indy_crypto_bls_signature_from_compressed_bytes: signature: {:?}", signature);
+            unsafe {
+                *signature_p = Box::into_raw(Box::new(signature)) as *const c_void;
+                trace!("indy_crypto_bls_signature_from_compressed_bytes: *signature_p: {:?}", *signature_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_signature_from_compressed_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Returns compressed bytes representation of signature.
+///
+/// Note: Returned buffer lifetime is the same as signature instance.
+///
+/// # Arguments
+/// * `signature` - Signature instance pointer
+/// * `bytes_p` - Pointer that will contains compressed bytes buffer
+/// * `bytes_len_p` - Pointer that will contains compressed bytes buffer len
+#[no_mangle]
+pub extern fn indy_crypto_bls_signature_as_compressed_bytes(signature: *const c_void,
+                                                            bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_signature_as_compressed_bytes: >>> signature: {:?}, bytes_p: {:?}, bytes_len_p: {:?}", signature, bytes_p, bytes_len_p);
+
+    check_useful_c_reference!(signature, Signature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(bytes_p, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(bytes_len_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_signature_as_compressed_bytes: signature: {:?}", signature);
+
+    unsafe {
+        *bytes_p = signature.as_compressed_bytes().as_ptr();
+        *bytes_len_p = signature.as_compressed_bytes().len();
+    };
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_signature_as_compressed_bytes: <<< res: {:?}", res);
+    res
+}
+
 /// Deallocates signature instance.
 ///
 /// # Arguments
@@ -535,6 +841,71 @@ pub extern fn indy_crypto_bls_multi_signature_as_bytes(multi_sig: *const c_void,
     res
 }
 
+/// Creates and returns multi signature from compressed bytes representation.
+///
+/// Note: Multi signature instance deallocation must be performed by calling indy_crypto_bls_multi_signature_free
+///
+/// # Arguments
+/// * `bytes` - Compressed bytes buffer pointer
+/// * `bytes_len` - Compressed bytes buffer len
+/// * `multi_sig_p` - Reference that will contain multi signature instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_multi_signature_from_compressed_bytes(bytes: *const u8, bytes_len: usize,
+                                                                    multi_sig_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_multi_signature_from_compressed_bytes: >>> bytes: {:?}, bytes_len: {:?}, multi_sig_p: {:?}", bytes, bytes_len, multi_sig_p);
+
+    check_useful_c_byte_array!(bytes, bytes_len,
+                               ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(multi_sig_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_multi_signature_from_compressed_bytes: bytes: {:?}", bytes);
+
+    let res = match MultiSignature::from_compressed_bytes(bytes) {
+        Ok(multi_sig) => {
+            trace!("indy_crypto_bls_multi_signature_from_compressed_bytes: multi_sig: {:?}", multi_sig);
+            unsafe {
+                *multi_sig_p = Box::into_raw(Box::new(multi_sig)) as *const c_void;
+                trace!("indy_crypto_bls_multi_signature_from_compressed_bytes: *multi_sig_p: {:?}", *multi_sig_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_multi_signature_from_compressed_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Returns compressed bytes representation of multi signature.
+///
+/// Note: Returned buffer lifetime is the same as multi signature instance.
+///
+/// # Arguments
+/// * `multi_sig` - Multi signature instance pointer
+/// * `bytes_p` - Pointer that will contains compressed bytes buffer
+/// * `bytes_len_p` - Pointer that will contains compressed bytes buffer len
+#[no_mangle]
+pub extern fn indy_crypto_bls_multi_signature_as_compressed_bytes(multi_sig: *const c_void,
+                                                                  bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_multi_signature_as_compressed_bytes: >>> multi_sig: {:?}, bytes_p: {:?}, bytes_len_p: {:?}", multi_sig, bytes_p, bytes_len_p);
+
+    check_useful_c_reference!(multi_sig, MultiSignature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(bytes_p, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(bytes_len_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_multi_signature_as_compressed_bytes: multi_sig: {:?}", multi_sig);
+
+    unsafe {
+        *bytes_p = multi_sig.as_compressed_bytes().as_ptr();
+        *bytes_len_p = multi_sig.as_compressed_bytes().len();
+    };
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_multi_signature_as_compressed_bytes: <<< res: {:?}", res);
+    res
+}
+
 /// Deallocates multi signature instance.
 ///
 /// # Arguments
@@ -552,264 +923,2810 @@ pub extern fn indy_crypto_bls_multi_signature_free(multi_sig: *const c_void) ->
     res
 }
 
-/// Signs the message and returns signature.
+/// Creates and returns multi verification key for provided list of verification keys.
 ///
-/// Note: allocated buffer referenced by (signature_p, signature_len_p) must be
-/// deallocated by calling indy_crypto_bls_free_array.
+/// Note: Multi verification key instance deallocation must be performed by calling indy_crypto_bls_multi_ver_key_free.
 ///
 /// # Arguments
-///
-/// * `message` - Message to sign buffer pointer
-/// * `message_len` - Message to sign buffer len
-/// * `sign_key` - Pointer to Sign Key instance
-/// * `signature_p` - Reference that will contain Signture Instance pointer
+/// * `ver_keys` - Verification key instance pointers array
+/// * `ver_keys_len` - Verification key instance pointers array len
+/// * `multi_ver_key_p` - Reference that will contain multi verification key instance pointer
 #[no_mangle]
-pub extern fn indy_crypto_bls_sign(message: *const u8,
-                                   message_len: usize,
-                                   sign_key: *const c_void,
-                                   signature_p: *mut *const c_void) -> ErrorCode {
-    trace!("indy_crypto_bls_sign: >>> message: {:?}, message_len: {:?}, sign_key: {:?}, signature_p: {:?}", message, message_len, sign_key, signature_p);
+pub extern fn indy_crypto_bls_multi_ver_key_new(ver_keys: *const *const c_void,
+                                                ver_keys_len: usize,
+                                                multi_ver_key_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_multi_ver_key_new: >>> ver_keys: {:?}, ver_keys_len: {:?}, multi_ver_key_p: {:?}", ver_keys, ver_keys_len, multi_ver_key_p);
 
-    check_useful_c_byte_array!(message, message_len,
-                               ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
-    check_useful_c_reference!(sign_key, SignKey, ErrorCode::CommonInvalidParam3);
-    check_useful_c_ptr!(signature_p, ErrorCode::CommonInvalidParam5);
+    check_useful_c_reference_array!(ver_keys, ver_keys_len, VerKey, ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(multi_ver_key_p, ErrorCode::CommonInvalidParam3);
 
-    trace!("indy_crypto_bls_sign: message: {:?}, sign_key: {:?}", message, sign_key);
+    trace!("indy_crypto_bls_multi_ver_key_new: ver_keys: {:?}", ver_keys);
 
-    let res = match Bls::sign(message, sign_key) {
-        Ok(signature) => {
+    let res = match MultiVerKey::new(&ver_keys) {
+        Ok(multi_ver_key) => {
+            trace!("indy_crypto_bls_multi_ver_key_new: multi_ver_key: {:?}", multi_ver_key);
             unsafe {
-                trace!("indy_crypto_bls_sign: signature: {:?}", signature);
-                *signature_p = Box::into_raw(Box::new(signature)) as *const c_void;
-                trace!("indy_crypto_bls_sign: *signature_p: {:?}", *signature_p);
+                *multi_ver_key_p = Box::into_raw(Box::new(multi_ver_key)) as *const c_void;
+                trace!("indy_crypto_bls_multi_ver_key_new: *multi_ver_key_p: {:?}", *multi_ver_key_p);
             }
             ErrorCode::Success
         }
         Err(err) => err.to_error_code()
     };
 
-    trace!("indy_crypto_bls_sign: <<< res: {:?}", res);
+    trace!("indy_crypto_bls_multi_ver_key_new: <<< res: {:?}", res);
     res
 }
 
-/// Verifies the message signature and returns true - if signature valid or false otherwise.
+/// Creates and returns multi verification key from bytes representation.
+///
+/// Note: Multi verification key instance deallocation must be performed by calling indy_crypto_bls_multi_ver_key_free
 ///
 /// # Arguments
+/// * `bytes` - Bytes buffer pointer
+/// * `bytes_len` - Bytes buffer len
+/// * `multi_ver_key_p` - Reference that will contain multi verification key instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_multi_ver_key_from_bytes(bytes: *const u8, bytes_len: usize,
+                                                       multi_ver_key_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_multi_ver_key_from_bytes: >>> bytes: {:?}, bytes_len: {:?}, multi_ver_key_p: {:?}", bytes, bytes_len, multi_ver_key_p);
+
+    check_useful_c_byte_array!(bytes, bytes_len,
+                               ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(multi_ver_key_p, ErrorCode::CommonInvalidParam1);
+
+    trace!("indy_crypto_bls_multi_ver_key_from_bytes: bytes: {:?}", bytes);
+
+    let res = match MultiVerKey::from_bytes(bytes) {
+        Ok(multi_ver_key) => {
+            trace!("indy_crypto_bls_multi_ver_key_from_bytes: multi_ver_key: {:?}", multi_ver_key);
+            unsafe {
+                *multi_ver_key_p = Box::into_raw(Box::new(multi_ver_key)) as *const c_void;
+                trace!("indy_crypto_bls_multi_ver_key_from_bytes: *multi_ver_key_p: {:?}", *multi_ver_key_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_multi_ver_key_from_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Returns bytes representation of multi verification key.
 ///
-/// * `signature` - Signature instance pointer
-/// * `message` - Message to verify buffer pointer
-/// * `message_len` - Message to verify buffer len
-/// * `ver_key` - Verification key instance pinter
-/// * `gen` - Generator instance pointer
-/// * `valid_p` - Reference that will be filled with true - if signature valid or false otherwise.
+/// Note: Returned buffer lifetime is the same as multi verification key instance.
+///
+/// # Arguments
+/// * `multi_ver_key` - Multi verification key instance pointer
+/// * `bytes_p` - Pointer that will contains bytes buffer
+/// * `bytes_len_p` - Pointer that will contains bytes buffer len
 #[no_mangle]
-pub extern fn indy_crypto_bsl_verify(signature: *const c_void,
-                                     message: *const u8,
-                                     message_len: usize,
-                                     ver_key: *const c_void,
-                                     gen: *const c_void,
-                                     valid_p: *mut bool) -> ErrorCode {
-    trace!("indy_crypto_bsl_verify: >>> signature: {:?}, message: {:?}, message_len: {:?}, ver_key: {:?}, gen: {:?}, valid_p: {:?}", signature, message, message_len, ver_key, gen, valid_p);
+pub extern fn indy_crypto_bls_multi_ver_key_as_bytes(multi_ver_key: *const c_void,
+                                                     bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_multi_ver_key_as_bytes: >>> multi_ver_key: {:?}, bytes_p: {:?}, bytes_len_p: {:?}", multi_ver_key, bytes_p, bytes_len_p);
 
-    check_useful_c_reference!(signature, Signature, ErrorCode::CommonInvalidParam1);
-    check_useful_c_byte_array!(message, message_len,
-                               ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
-    check_useful_c_reference!(ver_key, VerKey, ErrorCode::CommonInvalidParam4);
-    check_useful_c_reference!(gen, Generator, ErrorCode::CommonInvalidParam5);
-    check_useful_c_ptr!(valid_p, ErrorCode::CommonInvalidParam6);
+    check_useful_c_ptr!(multi_ver_key, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(bytes_p, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(bytes_len_p, ErrorCode::CommonInvalidParam3);
 
-    trace!("indy_crypto_bsl_verify: signature: {:?}, message: {:?}, ver_key: {:?}, gen: {:?}", signature, message, ver_key, gen);
+    let multi_ver_key = unsafe { &*(multi_ver_key as *const MultiVerKey) };
+    trace!("indy_crypto_bls_multi_ver_key_as_bytes: multi_ver_key: {:?}", multi_ver_key);
 
-    let res = match Bls::verify(signature, message, ver_key, gen) {
-        Ok(valid) => {
-            trace!("indy_crypto_bsl_verify: valid: {:?}", valid);
-            unsafe { *valid_p = valid; }
+    unsafe {
+        *bytes_p = multi_ver_key.as_bytes().as_ptr();
+        *bytes_len_p = multi_ver_key.as_bytes().len();
+    };
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_multi_ver_key_as_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns multi verification key from compressed bytes representation.
+///
+/// Note: Multi verification key instance deallocation must be performed by calling indy_crypto_bls_multi_ver_key_free
+///
+/// # Arguments
+/// * `bytes` - Compressed bytes buffer pointer
+/// * `bytes_len` - Compressed bytes buffer len
+/// * `multi_ver_key_p` - Reference that will contain multi verification key instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_multi_ver_key_from_compressed_bytes(bytes: *const u8, bytes_len: usize,
+                                                                  multi_ver_key_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_multi_ver_key_from_compressed_bytes: >>> bytes: {:?}, bytes_len: {:?}, multi_ver_key_p: {:?}", bytes, bytes_len, multi_ver_key_p);
+
+    check_useful_c_byte_array!(bytes, bytes_len,
+                               ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(multi_ver_key_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_multi_ver_key_from_compressed_bytes: bytes: {:?}", bytes);
+
+    let res = match MultiVerKey::from_compressed_bytes(bytes) {
+        Ok(multi_ver_key) => {
+            trace!("indy_crypto_bls_multi_ver_key_from_compressed_bytes: multi_ver_key: {:?}", multi_ver_key);
+            unsafe {
+                *multi_ver_key_p = Box::into_raw(Box::new(multi_ver_key)) as *const c_void;
+                trace!("indy_crypto_bls_multi_ver_key_from_compressed_bytes: *multi_ver_key_p: {:?}", *multi_ver_key_p);
+            }
             ErrorCode::Success
         }
         Err(err) => err.to_error_code()
     };
 
-    trace!("indy_crypto_bls_sign: <<< res: {:?}", res);
+    trace!("indy_crypto_bls_multi_ver_key_from_compressed_bytes: <<< res: {:?}", res);
     res
 }
 
-/// Verifies the message multi signature and returns true - if signature valid or false otherwise.
+/// Returns compressed bytes representation of multi verification key.
+///
+/// Note: Returned buffer lifetime is the same as multi verification key instance.
 ///
 /// # Arguments
+/// * `multi_ver_key` - Multi verification key instance pointer
+/// * `bytes_p` - Pointer that will contains compressed bytes buffer
+/// * `bytes_len_p` - Pointer that will contains compressed bytes buffer len
+#[no_mangle]
+pub extern fn indy_crypto_bls_multi_ver_key_as_compressed_bytes(multi_ver_key: *const c_void,
+                                                                bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_multi_ver_key_as_compressed_bytes: >>> multi_ver_key: {:?}, bytes_p: {:?}, bytes_len_p: {:?}", multi_ver_key, bytes_p, bytes_len_p);
+
+    check_useful_c_reference!(multi_ver_key, MultiVerKey, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(bytes_p, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(bytes_len_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_multi_ver_key_as_compressed_bytes: multi_ver_key: {:?}", multi_ver_key);
+
+    unsafe {
+        *bytes_p = multi_ver_key.as_compressed_bytes().as_ptr();
+        *bytes_len_p = multi_ver_key.as_compressed_bytes().len();
+    };
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_multi_ver_key_as_compressed_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Adds verification key to multi verification key.
 ///
-/// * `multi_sig` - Multi signature instance pointer
-/// * `message` - Message to verify buffer pointer
-/// * `message_len` - Message to verify buffer len
-/// * `ver_keys` - Verification key instance pointers array
-/// * `ver_keys_len` - Verification keys instance pointers array len
-/// * `gen` - Generator point instance
-/// * `valid_p` - Reference that will be filled with true - if signature valid or false otherwise.
+/// # Arguments
+/// * `multi_ver_key` - Multi verification key instance pointer
+/// * `ver_key` - Verification key instance pointer
 #[no_mangle]
-pub extern fn indy_crypto_bls_verify_multi_sig(multi_sig: *const c_void,
-                                               message: *const u8,
-                                               message_len: usize,
-                                               ver_keys: *const *const c_void,
-                                               ver_keys_len: usize,
-                                               gen: *const c_void,
-                                               valid_p: *mut bool) -> ErrorCode {
-    trace!("indy_crypto_bls_verify_multi_sig: >>> multi_sig: {:?}, message: {:?}, message_len: {:?}, ver_keys: {:?}, ver_keys_len: {:?}, gen: {:?}, valid_p: {:?}", multi_sig, message, message_len, ver_keys, ver_keys_len, gen, valid_p);
+pub extern fn indy_crypto_bls_multi_ver_key_add(multi_ver_key: *const c_void,
+                                                ver_key: *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_multi_ver_key_add: >>> multi_ver_key: {:?}, ver_key: {:?}", multi_ver_key, ver_key);
 
-    check_useful_c_reference!(multi_sig, MultiSignature, ErrorCode::CommonInvalidParam1);
-    check_useful_c_byte_array!(message, message_len, ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
-    check_useful_c_reference_array!(ver_keys, ver_keys_len, VerKey, ErrorCode::CommonInvalidParam4, ErrorCode::CommonInvalidParam5);
-    check_useful_c_reference!(gen, Generator, ErrorCode::CommonInvalidParam6);
-    check_useful_c_ptr!(valid_p, ErrorCode::CommonInvalidParam7);
+    check_useful_mut_c_reference!(multi_ver_key, MultiVerKey, ErrorCode::CommonInvalidParam1);
+    check_useful_c_reference!(ver_key, VerKey, ErrorCode::CommonInvalidParam2);
 
-    trace!("indy_crypto_bls_verify_multi_sig: multi_sig: {:?}, message: {:?}, ver_keys: {:?}, gen: {:?}", multi_sig, message, ver_keys, gen);
+    trace!("indy_crypto_bls_multi_ver_key_add: multi_ver_key: {:?}, ver_key: {:?}", multi_ver_key, ver_key);
 
-    let res = match Bls::verify_multi_sig(multi_sig, message, &ver_keys, gen) {
-        Ok(valid) => {
-            trace!("indy_crypto_bls_verify_multi_sig: valid: {:?}", valid);
-            unsafe { *valid_p = valid; }
+    let res = match multi_ver_key.add(ver_key) {
+        Ok(()) => ErrorCode::Success,
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_multi_ver_key_add: <<< res: {:?}", res);
+    res
+}
+
+/// Removes verification key from multi verification key.
+///
+/// # Arguments
+/// * `multi_ver_key` - Multi verification key instance pointer
+/// * `ver_key` - Verification key instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_multi_ver_key_remove(multi_ver_key: *const c_void,
+                                                   ver_key: *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_multi_ver_key_remove: >>> multi_ver_key: {:?}, ver_key: {:?}", multi_ver_key, ver_key);
+
+    check_useful_mut_c_reference!(multi_ver_key, MultiVerKey, ErrorCode::CommonInvalidParam1);
+    check_useful_c_reference!(ver_key, VerKey, ErrorCode::CommonInvalidParam2);
+
+    trace!("indy_crypto_bls_multi_ver_key_remove: multi_ver_key: {:?}, ver_key: {:?}", multi_ver_key, ver_key);
+
+    let res = match multi_ver_key.remove(ver_key) {
+        Ok(()) => ErrorCode::Success,
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_multi_ver_key_remove: <<< res: {:?}", res);
+    res
+}
+
+/// Deallocates multi verification key instance.
+///
+/// # Arguments
+/// * `multi_ver_key` - Multi verification key instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_multi_ver_key_free(multi_ver_key: *const c_void) -> ErrorCode {
+    check_useful_c_ptr!(multi_ver_key, ErrorCode::CommonInvalidParam1);
+
+    trace!("indy_crypto_bls_multi_ver_key_free: >>> multi_ver_key: {:?}", multi_ver_key);
+
+    unsafe { Box::from_raw(multi_ver_key as *mut MultiVerKey); }
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_multi_ver_key_free: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns bitmap multi signature for provided list of signatures of the validators set participants.
+///
+/// Note: Bitmap multi signature instance deallocation must be performed by calling indy_crypto_bls_bitmap_multi_signature_free.
+///
+/// # Arguments
+/// * `signatures` - Signature instance pointers array
+/// * `signatures_len` - Signature instance pointers array len
+/// * `indexes` - Indexes of signers in validators set array (must have the same len as signatures array)
+/// * `indexes_len` - Indexes array len
+/// * `participants_count` - Size of validators set
+/// * `multi_sig_p` - Reference that will contain bitmap multi signature instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_bitmap_multi_signature_new(signatures: *const *const c_void,
+                                                         signatures_len: usize,
+                                                         indexes: *const u32,
+                                                         indexes_len: usize,
+                                                         participants_count: u32,
+                                                         multi_sig_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_bitmap_multi_signature_new: >>> signatures: {:?}, signatures_len: {:?}, indexes: {:?}, indexes_len: {:?}, participants_count: {:?}, multi_sig_p: {:?}",
+           signatures, signatures_len, indexes, indexes_len, participants_count, multi_sig_p);
+
+    check_useful_c_reference_array!(signatures, signatures_len, Signature, ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_byte_array!(indexes, indexes_len, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
+    check_useful_c_ptr!(multi_sig_p, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_crypto_bls_bitmap_multi_signature_new: signatures: {:?}, indexes: {:?}", signatures, indexes);
+
+    let res = match BitmapMultiSignature::new(&signatures, indexes, participants_count) {
+        Ok(multi_sig) => {
+            trace!("indy_crypto_bls_bitmap_multi_signature_new: multi_sig: {:?}", multi_sig);
+            unsafe {
+                *multi_sig_p = Box::into_raw(Box::new(multi_sig)) as *const c_void;
+                trace!("indy_crypto_bls_bitmap_multi_signature_new: *multi_sig_p: {:?}", *multi_sig_p);
+            }
             ErrorCode::Success
         }
         Err(err) => err.to_error_code()
     };
 
-    trace!("indy_crypto_bls_verify_multi_sig: <<< res: {:?}", res);
+    trace!("indy_crypto_bls_bitmap_multi_signature_new: <<< res: {:?}", res);
     res
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::ptr;
+/// Creates and returns bitmap multi signature from bytes representation.
+///
+/// Note: Bitmap multi signature instance deallocation must be performed by calling indy_crypto_bls_bitmap_multi_signature_free
+///
+/// # Arguments
+/// * `bytes` - Bytes buffer pointer
+/// * `bytes_len` - Bytes buffer len
+/// * `multi_sig_p` - Reference that will contain bitmap multi signature instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_bitmap_multi_signature_from_bytes(bytes: *const u8, bytes_len: usize,
+                                                                multi_sig_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_bitmap_multi_signature_from_bytes: >>> bytes: {:?}, bytes_len: {:?}, multi_sig_p: {:?}", bytes, bytes_len, multi_sig_p);
 
-    #[test]
-    fn indy_crypto_bls_generator_new_works() {
-        let mut gen: *const c_void = ptr::null();
+    check_useful_c_byte_array!(bytes, bytes_len,
+                               ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(multi_sig_p, ErrorCode::CommonInvalidParam1);
 
-        let err_code = indy_crypto_bls_generator_new(&mut gen);
+    trace!("indy_crypto_bls_bitmap_multi_signature_from_bytes: bytes: {:?}", bytes);
+
+    let res = match BitmapMultiSignature::from_bytes(bytes) {
+        Ok(multi_sig) => {
+            trace!("indy_crypto_bls_bitmap_multi_signature_from_bytes: multi_sig: {:?}", multi_sig);
+            unsafe {
+                *multi_sig_p = Box::into_raw(Box::new(multi_sig)) as *const c_void;
+                trace!("indy_crypto_bls_bitmap_multi_signature_from_bytes: *multi_sig_p: {:?}", *multi_sig_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_bitmap_multi_signature_from_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Returns bytes representation of bitmap multi signature.
+///
+/// Note: Returned buffer lifetime is the same as bitmap multi signature instance.
+///
+/// # Arguments
+/// * `multi_sig` - Bitmap multi signature instance pointer
+/// * `bytes_p` - Pointer that will contains bytes buffer
+/// * `bytes_len_p` - Pointer that will contains bytes buffer len
+#[no_mangle]
+pub extern fn indy_crypto_bls_bitmap_multi_signature_as_bytes(multi_sig: *const c_void,
+                                                              bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_bitmap_multi_signature_as_bytes: >>> multi_sig: {:?}, bytes_p: {:?}, bytes_len_p: {:?}", multi_sig, bytes_p, bytes_len_p);
+
+    check_useful_c_ptr!(multi_sig, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(bytes_p, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(bytes_len_p, ErrorCode::CommonInvalidParam3);
+
+    let multi_sig = unsafe { &*(multi_sig as *const BitmapMultiSignature) };
+    trace!("indy_crypto_bls_bitmap_multi_signature_as_bytes: multi_sig: {:?}", multi_sig);
+
+    unsafe {
+        *bytes_p = multi_sig.as_bytes().as_ptr();
+        *bytes_len_p = multi_sig.as_bytes().len();
+    };
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_bitmap_multi_signature_as_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns bitmap multi signature from compressed bytes representation.
+///
+/// Note: Bitmap multi signature instance deallocation must be performed by calling indy_crypto_bls_bitmap_multi_signature_free
+///
+/// # Arguments
+/// * `bytes` - Compressed bytes buffer pointer
+/// * `bytes_len` - Compressed bytes buffer len
+/// * `multi_sig_p` - Reference that will contain bitmap multi signature instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_bitmap_multi_signature_from_compressed_bytes(bytes: *const u8, bytes_len: usize,
+                                                                           multi_sig_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_bitmap_multi_signature_from_compressed_bytes: >>> bytes: {:?}, bytes_len: {:?}, multi_sig_p: {:?}", bytes, bytes_len, multi_sig_p);
+
+    check_useful_c_byte_array!(bytes, bytes_len,
+                               ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(multi_sig_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_bitmap_multi_signature_from_compressed_bytes: bytes: {:?}", bytes);
+
+    let res = match BitmapMultiSignature::from_compressed_bytes(bytes) {
+        Ok(multi_sig) => {
+            trace!("indy_crypto_bls_bitmap_multi_signature_from_compressed_bytes: multi_sig: {:?}", multi_sig);
+            unsafe {
+                *multi_sig_p = Box::into_raw(Box::new(multi_sig)) as *const c_void;
+                trace!("indy_crypto_bls_bitmap_multi_signature_from_compressed_bytes: *multi_sig_p: {:?}", *multi_sig_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_bitmap_multi_signature_from_compressed_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Returns compressed bytes representation of bitmap multi signature.
+///
+/// Note: Returned buffer lifetime is the same as bitmap multi signature instance.
+///
+/// # Arguments
+/// * `multi_sig` - Bitmap multi signature instance pointer
+/// * `bytes_p` - Pointer that will contains compressed bytes buffer
+/// * `bytes_len_p` - Pointer that will contains compressed bytes buffer len
+#[no_mangle]
+pub extern fn indy_crypto_bls_bitmap_multi_signature_as_compressed_bytes(multi_sig: *const c_void,
+                                                                         bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_bitmap_multi_signature_as_compressed_bytes: >>> multi_sig: {:?}, bytes_p: {:?}, bytes_len_p: {:?}", multi_sig, bytes_p, bytes_len_p);
+
+    check_useful_c_reference!(multi_sig, BitmapMultiSignature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(bytes_p, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(bytes_len_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_bitmap_multi_signature_as_compressed_bytes: multi_sig: {:?}", multi_sig);
+
+    unsafe {
+        *bytes_p = multi_sig.as_compressed_bytes().as_ptr();
+        *bytes_len_p = multi_sig.as_compressed_bytes().len();
+    };
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_bitmap_multi_signature_as_compressed_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Returns participants bitmap of bitmap multi signature.
+///
+/// Note: Bit i of the bitmap is bit (i % 8) of byte (i / 8). Returned buffer lifetime is the same as bitmap multi signature instance.
+///
+/// # Arguments
+/// * `multi_sig` - Bitmap multi signature instance pointer
+/// * `bitmap_p` - Pointer that will contains bitmap buffer
+/// * `bitmap_len_p` - Pointer that will contains bitmap buffer len
+#[no_mangle]
+pub extern fn indy_crypto_bls_bitmap_multi_signature_as_bitmap(multi_sig: *const c_void,
+                                                               bitmap_p: *mut *const u8, bitmap_len_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_bitmap_multi_signature_as_bitmap: >>> multi_sig: {:?}, bitmap_p: {:?}, bitmap_len_p: {:?}", multi_sig, bitmap_p, bitmap_len_p);
+
+    check_useful_c_reference!(multi_sig, BitmapMultiSignature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(bitmap_p, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(bitmap_len_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_bitmap_multi_signature_as_bitmap: multi_sig: {:?}", multi_sig);
+
+    unsafe {
+        *bitmap_p = multi_sig.as_bitmap().as_ptr();
+        *bitmap_len_p = multi_sig.as_bitmap().len();
+    };
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_bitmap_multi_signature_as_bitmap: <<< res: {:?}", res);
+    res
+}
+
+/// Adds signature of validators set participant to bitmap multi signature.
+///
+/// # Arguments
+/// * `multi_sig` - Bitmap multi signature instance pointer
+/// * `signature` - Signature instance pointer
+/// * `index` - Index of signer in validators set
+#[no_mangle]
+pub extern fn indy_crypto_bls_bitmap_multi_signature_add(multi_sig: *const c_void,
+                                                         signature: *const c_void,
+                                                         index: u32) -> ErrorCode {
+    trace!("indy_crypto_bls_bitmap_multi_signature_add: >>> multi_sig: {:?}, signature: {:?}, index: {:?}", multi_sig, signature, index);
+
+    check_useful_mut_c_reference!(multi_sig, BitmapMultiSignature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_reference!(signature, Signature, ErrorCode::CommonInvalidParam2);
+
+    trace!("indy_crypto_bls_bitmap_multi_signature_add: multi_sig: {:?}, signature: {:?}", multi_sig, signature);
+
+    let res = match multi_sig.add(signature, index) {
+        Ok(()) => ErrorCode::Success,
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_bitmap_multi_signature_add: <<< res: {:?}", res);
+    res
+}
+
+/// Merges other bitmap multi signature over the same validators set and disjoint participants into bitmap multi signature.
+///
+/// # Arguments
+/// * `multi_sig` - Bitmap multi signature instance pointer
+/// * `other` - Other bitmap multi signature instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_bitmap_multi_signature_merge(multi_sig: *const c_void,
+                                                           other: *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_bitmap_multi_signature_merge: >>> multi_sig: {:?}, other: {:?}", multi_sig, other);
+
+    check_useful_mut_c_reference!(multi_sig, BitmapMultiSignature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_reference!(other, BitmapMultiSignature, ErrorCode::CommonInvalidParam2);
+
+    trace!("indy_crypto_bls_bitmap_multi_signature_merge: multi_sig: {:?}, other: {:?}", multi_sig, other);
+
+    let res = match multi_sig.merge(other) {
+        Ok(()) => ErrorCode::Success,
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_bitmap_multi_signature_merge: <<< res: {:?}", res);
+    res
+}
+
+/// Deallocates bitmap multi signature instance.
+///
+/// # Arguments
+/// * `multi_sig` - Bitmap multi signature instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_bitmap_multi_signature_free(multi_sig: *const c_void) -> ErrorCode {
+    check_useful_c_ptr!(multi_sig, ErrorCode::CommonInvalidParam1);
+
+    trace!("indy_crypto_bls_bitmap_multi_signature_free: >>> multi_sig: {:?}", multi_sig);
+
+    unsafe { Box::from_raw(multi_sig as *mut BitmapMultiSignature); }
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_bitmap_multi_signature_free: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns aggregate signature for provided list of signatures over distinct messages.
+///
+/// Note: Aggregate signature instance deallocation must be performed by calling indy_crypto_bls_aggregate_signature_free.
+///
+/// # Arguments
+/// * `signatures` - Signature instance pointers array
+/// * `signatures_len` - Signature instance pointers array len
+/// * `aggregate_sig_p` - Reference that will contain aggregate signature instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_aggregate_signature_new(signatures: *const *const c_void,
+                                                      signatures_len: usize,
+                                                      aggregate_sig_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_aggregate_signature_new: >>> signatures: {:?}, signatures_len: {:?}, aggregate_sig_p: {:?}", signatures, signatures_len, aggregate_sig_p);
+
+    check_useful_c_reference_array!(signatures, signatures_len, Signature, ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(aggregate_sig_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_aggregate_signature_new: signatures: {:?}", signatures);
+
+    let res = match AggregateSignature::new(&signatures) {
+        Ok(aggregate_sig) => {
+            trace!("indy_crypto_bls_aggregate_signature_new: aggregate_sig: {:?}", aggregate_sig);
+            unsafe {
+                *aggregate_sig_p = Box::into_raw(Box::new(aggregate_sig)) as *const c_void;
+                trace!("indy_crypto_bls_aggregate_signature_new: *aggregate_sig_p: {:?}", *aggregate_sig_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_aggregate_signature_new: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns aggregate signature from bytes representation.
+///
+/// Note: Aggregate signature instance deallocation must be performed by calling indy_crypto_bls_aggregate_signature_free
+///
+/// # Arguments
+/// * `bytes` - Bytes buffer pointer
+/// * `bytes_len` - Bytes buffer len
+/// * `aggregate_sig_p` - Reference that will contain aggregate signature instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_aggregate_signature_from_bytes(bytes: *const u8, bytes_len: usize,
+                                                             aggregate_sig_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_aggregate_signature_from_bytes: >>> bytes: {:?}, bytes_len: {:?}, aggregate_sig_p: {:?}", bytes, bytes_len, aggregate_sig_p);
+
+    check_useful_c_byte_array!(bytes, bytes_len,
+                               ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(aggregate_sig_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_aggregate_signature_from_bytes: bytes: {:?}", bytes);
+
+    let res = match AggregateSignature::from_bytes(bytes) {
+        Ok(aggregate_sig) => {
+            trace!("indy_crypto_bls_aggregate_signature_from_bytes: aggregate_sig: {:?}", aggregate_sig);
+            unsafe {
+                *aggregate_sig_p = Box::into_raw(Box::new(aggregate_sig)) as *const c_void;
+                trace!("indy_crypto_bls_aggregate_signature_from_bytes: *aggregate_sig_p: {:?}", *aggregate_sig_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_aggregate_signature_from_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Returns bytes representation of aggregate signature.
+///
+/// Note: Returned buffer lifetime is the same as aggregate signature instance.
+///
+/// # Arguments
+/// * `aggregate_sig` - Aggregate signature instance pointer
+/// * `bytes_p` - Pointer that will contains bytes buffer
+/// * `bytes_len_p` - Pointer that will contains bytes buffer len
+#[no_mangle]
+pub extern fn indy_crypto_bls_aggregate_signature_as_bytes(aggregate_sig: *const c_void,
+                                                           bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_aggregate_signature_as_bytes: >>> aggregate_sig: {:?}, bytes_p: {:?}, bytes_len_p: {:?}", aggregate_sig, bytes_p, bytes_len_p);
+
+    check_useful_c_reference!(aggregate_sig, AggregateSignature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(bytes_p, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(bytes_len_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_aggregate_signature_as_bytes: aggregate_sig: {:?}", aggregate_sig);
+
+    unsafe {
+        *bytes_p = aggregate_sig.as_bytes().as_ptr();
+        *bytes_len_p = aggregate_sig.as_bytes().len();
+    };
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_aggregate_signature_as_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns aggregate signature from compressed bytes representation.
+///
+/// Note: Aggregate signature instance deallocation must be performed by calling indy_crypto_bls_aggregate_signature_free
+///
+/// # Arguments
+/// * `bytes` - Compressed bytes buffer pointer
+/// * `bytes_len` - Compressed bytes buffer len
+/// * `aggregate_sig_p` - Reference that will contain aggregate signature instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_aggregate_signature_from_compressed_bytes(bytes: *const u8, bytes_len: usize,
+                                                                        aggregate_sig_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_aggregate_signature_from_compressed_bytes: >>> bytes: {:?}, bytes_len: {:?}, aggregate_sig_p: {:?}", bytes, bytes_len, aggregate_sig_p);
+
+    check_useful_c_byte_array!(bytes, bytes_len,
+                               ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(aggregate_sig_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_aggregate_signature_from_compressed_bytes: bytes: {:?}", bytes);
+
+    let res = match AggregateSignature::from_compressed_bytes(bytes) {
+        Ok(aggregate_sig) => {
+            trace!("indy_crypto_bls_aggregate_signature_from_compressed_bytes: aggregate_sig: {:?}", aggregate_sig);
+            unsafe {
+                *aggregate_sig_p = Box::into_raw(Box::new(aggregate_sig)) as *const c_void;
+                trace!("indy_crypto_bls_aggregate_signature_from_compressed_bytes: *aggregate_sig_p: {:?}", *aggregate_sig_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_aggregate_signature_from_compressed_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Returns compressed bytes representation of aggregate signature.
+///
+/// Note: Returned buffer lifetime is the same as aggregate signature instance.
+///
+/// # Arguments
+/// * `aggregate_sig` - Aggregate signature instance pointer
+/// * `bytes_p` - Pointer that will contains compressed bytes buffer
+/// * `bytes_len_p` - Pointer that will contains compressed bytes buffer len
+#[no_mangle]
+pub extern fn indy_crypto_bls_aggregate_signature_as_compressed_bytes(aggregate_sig: *const c_void,
+                                                                      bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_aggregate_signature_as_compressed_bytes: >>> aggregate_sig: {:?}, bytes_p: {:?}, bytes_len_p: {:?}", aggregate_sig, bytes_p, bytes_len_p);
+
+    check_useful_c_reference!(aggregate_sig, AggregateSignature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(bytes_p, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(bytes_len_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_aggregate_signature_as_compressed_bytes: aggregate_sig: {:?}", aggregate_sig);
+
+    unsafe {
+        *bytes_p = aggregate_sig.as_compressed_bytes().as_ptr();
+        *bytes_len_p = aggregate_sig.as_compressed_bytes().len();
+    };
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_aggregate_signature_as_compressed_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Deallocates aggregate signature instance.
+///
+/// # Arguments
+/// * `aggregate_sig` - Aggregate signature instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_aggregate_signature_free(aggregate_sig: *const c_void) -> ErrorCode {
+    check_useful_c_ptr!(aggregate_sig, ErrorCode::CommonInvalidParam1);
+
+    trace!("indy_crypto_bls_aggregate_signature_free: >>> aggregate_sig: {:?}", aggregate_sig);
+
+    unsafe { Box::from_raw(aggregate_sig as *mut AggregateSignature); }
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_aggregate_signature_free: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns proof of possession for provided ver key and sign key.
+///
+/// Note: Proof of possession instance deallocation must be performed by calling indy_crypto_bls_pop_free.
+///
+/// # Arguments
+/// * `ver_key` - Verification key instance pointer
+/// * `sign_key` - Sign key instance pointer
+/// * `pop_p` - Reference that will contain proof of possession instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_pop_new(ver_key: *const c_void,
+                                      sign_key: *const c_void,
+                                      pop_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_pop_new: >>> ver_key: {:?}, sign_key: {:?}, pop_p: {:?}", ver_key, sign_key, pop_p);
+
+    check_useful_c_reference!(ver_key, VerKey, ErrorCode::CommonInvalidParam1);
+    check_useful_c_reference!(sign_key, SignKey, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(pop_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_pop_new: ver_key: {:?}, sign_key: {:?}", ver_key, sign_key);
+
+    let res = match ProofOfPossession::new(ver_key, sign_key) {
+        Ok(pop) => {
+            trace!("indy_crypto_bls_pop_new: pop: {:?}", pop);
+            unsafe {
+                *pop_p = Box::into_raw(Box::new(pop)) as *const c_void;
+                trace!("indy_crypto_bls_pop_new: *pop_p: {:?}", *pop_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_pop_new: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns proof of possession from bytes representation.
+///
+/// Note: Proof of possession instance deallocation must be performed by calling indy_crypto_bls_pop_free
+///
+/// # Arguments
+/// * `bytes` - Bytes buffer pointer
+/// * `bytes_len` - Bytes buffer len
+/// * `pop_p` - Reference that will contain proof of possession instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_pop_from_bytes(bytes: *const u8, bytes_len: usize,
+                                             pop_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_pop_from_bytes: >>> bytes: {:?}, bytes_len: {:?}, pop_p: {:?}", bytes, bytes_len, pop_p);
+
+    check_useful_c_byte_array!(bytes, bytes_len,
+                               ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(pop_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_pop_from_bytes: bytes: {:?}", bytes);
+
+    let res = match ProofOfPossession::from_bytes(bytes) {
+        Ok(pop) => {
+            trace!("indy_crypto_bls_pop_from_bytes: pop: {:?}", pop);
+            unsafe {
+                *pop_p = Box::into_raw(Box::new(pop)) as *const c_void;
+                trace!("indy_crypto_bls_pop_from_bytes: *pop_p: {:?}", *pop_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_pop_from_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Returns bytes representation of proof of possession.
+///
+/// Note: Returned buffer lifetime is the same as proof of possession instance.
+///
+/// # Arguments
+/// * `pop` - Proof of possession instance pointer
+/// * `bytes_p` - Pointer that will contains bytes buffer
+/// * `bytes_len_p` - Pointer that will contains bytes buffer len
+#[no_mangle]
+pub extern fn indy_crypto_bls_pop_as_bytes(pop: *const c_void,
+                                           bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_pop_as_bytes: >>> pop: {:?}, bytes_p: {:?}, bytes_len_p: {:?}", pop, bytes_p, bytes_len_p);
+
+    check_useful_c_reference!(pop, ProofOfPossession, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(bytes_p, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(bytes_len_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_pop_as_bytes: pop: {:?}", pop);
+
+    unsafe {
+        *bytes_p = pop.as_bytes().as_ptr();
+        *bytes_len_p = pop.as_bytes().len();
+    };
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_pop_as_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns proof of possession from compressed bytes representation.
+///
+/// Note: Proof of possession instance deallocation must be performed by calling indy_crypto_bls_pop_free
+///
+/// # Arguments
+/// * `bytes` - Compressed bytes buffer pointer
+/// * `bytes_len` - Compressed bytes buffer len
+/// * `pop_p` - Reference that will contain proof of possession instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_pop_from_compressed_bytes(bytes: *const u8, bytes_len: usize,
+                                                        pop_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_pop_from_compressed_bytes: >>> bytes: {:?}, bytes_len: {:?}, pop_p: {:?}", bytes, bytes_len, pop_p);
+
+    check_useful_c_byte_array!(bytes, bytes_len,
+                               ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(pop_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_pop_from_compressed_bytes: bytes: {:?}", bytes);
+
+    let res = match ProofOfPossession::from_compressed_bytes(bytes) {
+        Ok(pop) => {
+            trace!("indy_crypto_bls_pop_from_compressed_bytes: pop: {:?}", pop);
+            unsafe {
+                *pop_p = Box::into_raw(Box::new(pop)) as *const c_void;
+                trace!("indy_crypto_bls_pop_from_compressed_bytes: *pop_p: {:?}", *pop_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_pop_from_compressed_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Returns compressed bytes representation of proof of possession.
+///
+/// Note: Returned buffer lifetime is the same as proof of possession instance.
+///
+/// # Arguments
+/// * `pop` - Proof of possession instance pointer
+/// * `bytes_p` - Pointer that will contains compressed bytes buffer
+/// * `bytes_len_p` - Pointer that will contains compressed bytes buffer len
+#[no_mangle]
+pub extern fn indy_crypto_bls_pop_as_compressed_bytes(pop: *const c_void,
+                                                      bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_pop_as_compressed_bytes: >>> pop: {:?}, bytes_p: {:?}, bytes_len_p: {:?}", pop, bytes_p, bytes_len_p);
+
+    check_useful_c_reference!(pop, ProofOfPossession, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(bytes_p, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(bytes_len_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_pop_as_compressed_bytes: pop: {:?}", pop);
+
+    unsafe {
+        *bytes_p = pop.as_compressed_bytes().as_ptr();
+        *bytes_len_p = pop.as_compressed_bytes().len();
+    };
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_pop_as_compressed_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Deallocates proof of possession instance.
+///
+/// # Arguments
+/// * `pop` - Proof of possession instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_pop_free(pop: *const c_void) -> ErrorCode {
+    check_useful_c_ptr!(pop, ErrorCode::CommonInvalidParam1);
+
+    trace!("indy_crypto_bls_pop_free: >>> pop: {:?}", pop);
+
+    unsafe { Box::from_raw(pop as *mut ProofOfPossession); }
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_pop_free: <<< res: {:?}", res);
+    res
+}
+
+/// Signs the message and returns signature.
+///
+/// Note: allocated buffer referenced by (signature_p, signature_len_p) must be
+/// deallocated by calling indy_crypto_bls_free_array.
+///
+/// # Arguments
+///
+/// * `message` - Message to sign buffer pointer
+/// * `message_len` - Message to sign buffer len
+/// * `sign_key` - Pointer to Sign Key instance
+/// * `signature_p` - Reference that will contain Signture Instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_sign(message: *const u8,
+                                   message_len: usize,
+                                   sign_key: *const c_void,
+                                   signature_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_sign: >>> message: {:?}, message_len: {:?}, sign_key: {:?}, signature_p: {:?}", message, message_len, sign_key, signature_p);
+
+    check_useful_c_byte_array!(message, message_len,
+                               ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_reference!(sign_key, SignKey, ErrorCode::CommonInvalidParam3);
+    check_useful_c_ptr!(signature_p, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_crypto_bls_sign: message: {:?}, sign_key: {:?}", message, sign_key);
+
+    let res = match Bls::sign(message, sign_key) {
+        Ok(signature) => {
+            unsafe {
+                trace!("indy_crypto_bls_sign: signature: {:?}", signature);
+                *signature_p = Box::into_raw(Box::new(signature)) as *const c_void;
+                trace!("indy_crypto_bls_sign: *signature_p: {:?}", *signature_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_sign: <<< res: {:?}", res);
+    res
+}
+
+/// Verifies the message signature and returns true - if signature valid or false otherwise.
+///
+/// # Arguments
+///
+/// * `signature` - Signature instance pointer
+/// * `message` - Message to verify buffer pointer
+/// * `message_len` - Message to verify buffer len
+/// * `ver_key` - Verification key instance pinter
+/// * `gen` - Generator instance pointer
+/// * `valid_p` - Reference that will be filled with true - if signature valid or false otherwise.
+#[no_mangle]
+pub extern fn indy_crypto_bsl_verify(signature: *const c_void,
+                                     message: *const u8,
+                                     message_len: usize,
+                                     ver_key: *const c_void,
+                                     gen: *const c_void,
+                                     valid_p: *mut bool) -> ErrorCode {
+    trace!("indy_crypto_bsl_verify: >>> signature: {:?}, message: {:?}, message_len: {:?}, ver_key: {:?}, gen: {:?}, valid_p: {:?}", signature, message, message_len, ver_key, gen, valid_p);
+
+    check_useful_c_reference!(signature, Signature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_byte_array!(message, message_len,
+                               ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_c_reference!(ver_key, VerKey, ErrorCode::CommonInvalidParam4);
+    check_useful_c_reference!(gen, Generator, ErrorCode::CommonInvalidParam5);
+    check_useful_c_ptr!(valid_p, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_crypto_bsl_verify: signature: {:?}, message: {:?}, ver_key: {:?}, gen: {:?}", signature, message, ver_key, gen);
+
+    let res = match Bls::verify(signature, message, ver_key, gen) {
+        Ok(valid) => {
+            trace!("indy_crypto_bsl_verify: valid: {:?}", valid);
+            unsafe { *valid_p = valid; }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_sign: <<< res: {:?}", res);
+    res
+}
+
+/// Verifies the message multi signature and returns true - if signature valid or false otherwise.
+///
+/// # Arguments
+///
+/// * `multi_sig` - Multi signature instance pointer
+/// * `message` - Message to verify buffer pointer
+/// * `message_len` - Message to verify buffer len
+/// * `ver_keys` - Verification key instance pointers array
+/// * `ver_keys_len` - Verification keys instance pointers array len
+/// * `gen` - Generator point instance
+/// * `valid_p` - Reference that will be filled with true - if signature valid or false otherwise.
+#[no_mangle]
+pub extern fn indy_crypto_bls_verify_multi_sig(multi_sig: *const c_void,
+                                               message: *const u8,
+                                               message_len: usize,
+                                               ver_keys: *const *const c_void,
+                                               ver_keys_len: usize,
+                                               gen: *const c_void,
+                                               valid_p: *mut bool) -> ErrorCode {
+    trace!("indy_crypto_bls_verify_multi_sig: >>> multi_sig: {:?}, message: {:?}, message_len: {:?}, ver_keys: {:?}, ver_keys_len: {:?}, gen: {:?}, valid_p: {:?}", multi_sig, message, message_len, ver_keys, ver_keys_len, gen, valid_p);
+
+    check_useful_c_reference!(multi_sig, MultiSignature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_byte_array!(message, message_len, ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_c_reference_array!(ver_keys, ver_keys_len, VerKey, ErrorCode::CommonInvalidParam4, ErrorCode::CommonInvalidParam5);
+    check_useful_c_reference!(gen, Generator, ErrorCode::CommonInvalidParam6);
+    check_useful_c_ptr!(valid_p, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_crypto_bls_verify_multi_sig: multi_sig: {:?}, message: {:?}, ver_keys: {:?}, gen: {:?}", multi_sig, message, ver_keys, gen);
+
+    let res = match Bls::verify_multi_sig(multi_sig, message, &ver_keys, gen) {
+        Ok(valid) => {
+            trace!("indy_crypto_bls_verify_multi_sig: valid: {:?}", valid);
+            unsafe { *valid_p = valid; }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_verify_multi_sig: <<< res: {:?}", res);
+    res
+}
+
+/// Verifies the message multi signature against multi verification key and returns true - if signature valid or false otherwise.
+///
+/// # Arguments
+///
+/// * `multi_sig` - Multi signature instance pointer
+/// * `message` - Message to verify buffer pointer
+/// * `message_len` - Message to verify buffer len
+/// * `multi_ver_key` - Multi verification key instance pointer
+/// * `gen` - Generator point instance
+/// * `valid_p` - Reference that will be filled with true - if signature valid or false otherwise.
+#[no_mangle]
+pub extern fn indy_crypto_bls_verify_multi_sig_for_multi_ver_key(multi_sig: *const c_void,
+                                                                 message: *const u8,
+                                                                 message_len: usize,
+                                                                 multi_ver_key: *const c_void,
+                                                                 gen: *const c_void,
+                                                                 valid_p: *mut bool) -> ErrorCode {
+    trace!("indy_crypto_bls_verify_multi_sig_for_multi_ver_key: >>> multi_sig: {:?}, message: {:?}, message_len: {:?}, multi_ver_key: {:?}, gen: {:?}, valid_p: {:?}", multi_sig, message, message_len, multi_ver_key, gen, valid_p);
+
+    check_useful_c_reference!(multi_sig, MultiSignature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_byte_array!(message, message_len, ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_c_reference!(multi_ver_key, MultiVerKey, ErrorCode::CommonInvalidParam4);
+    check_useful_c_reference!(gen, Generator, ErrorCode::CommonInvalidParam5);
+    check_useful_c_ptr!(valid_p, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_crypto_bls_verify_multi_sig_for_multi_ver_key: multi_sig: {:?}, message: {:?}, multi_ver_key: {:?}, gen: {:?}", multi_sig, message, multi_ver_key, gen);
+
+    let res = match Bls::verify_multi_sig_for_multi_ver_key(multi_sig, message, multi_ver_key, gen) {
+        Ok(valid) => {
+            trace!("indy_crypto_bls_verify_multi_sig_for_multi_ver_key: valid: {:?}", valid);
+            unsafe { *valid_p = valid; }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_verify_multi_sig_for_multi_ver_key: <<< res: {:?}", res);
+    res
+}
+
+/// Verifies bitmap multi signature against validators set and returns true - if signature is valid and
+/// has at least `min_signers` participants or false otherwise.
+///
+/// # Arguments
+///
+/// * `multi_sig` - Bitmap multi signature instance pointer
+/// * `message` - Message to verify buffer pointer
+/// * `message_len` - Message to verify buffer len
+/// * `ver_keys` - Verification key instance pointers array of the whole validators set in bitmap order
+/// * `ver_keys_len` - Verification keys instance pointers array len
+/// * `min_signers` - Minimal number of participants required
+/// * `gen` - Generator point instance
+/// * `valid_p` - Reference that will be filled with true - if signature valid or false otherwise.
+#[no_mangle]
+pub extern fn indy_crypto_bls_verify_with_quorum(multi_sig: *const c_void,
+                                                 message: *const u8,
+                                                 message_len: usize,
+                                                 ver_keys: *const *const c_void,
+                                                 ver_keys_len: usize,
+                                                 min_signers: u32,
+                                                 gen: *const c_void,
+                                                 valid_p: *mut bool) -> ErrorCode {
+    trace!("indy_crypto_bls_verify_with_quorum: >>> multi_sig: {:?}, message: {:?}, message_len: {:?}, ver_keys: {:?}, ver_keys_len: {:?}, min_signers: {:?}, \
+            gen: {:?}, valid_p: {:?}", multi_sig, message, message_len, ver_keys, ver_keys_len, min_signers, gen, valid_p);
+
+    check_useful_c_reference!(multi_sig, BitmapMultiSignature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_byte_array!(message, message_len, ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_c_reference_array!(ver_keys, ver_keys_len, VerKey, ErrorCode::CommonInvalidParam4, ErrorCode::CommonInvalidParam5);
+    check_useful_c_reference!(gen, Generator, ErrorCode::CommonInvalidParam7);
+    check_useful_c_ptr!(valid_p, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_crypto_bls_verify_with_quorum: multi_sig: {:?}, message: {:?}, ver_keys: {:?}, min_signers: {:?}, gen: {:?}", multi_sig, message, ver_keys, min_signers, gen);
+
+    let res = match Bls::verify_with_quorum(multi_sig, message, &ver_keys, min_signers, gen) {
+        Ok(valid) => {
+            trace!("indy_crypto_bls_verify_with_quorum: valid: {:?}", valid);
+            unsafe { *valid_p = valid; }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_verify_with_quorum: <<< res: {:?}", res);
+    res
+}
+
+/// Verifies the aggregate signature over distinct messages and returns true - if signature valid or false otherwise.
+///
+/// Note: Duplicate messages are rejected with CommonInvalidStructure error.
+///
+/// # Arguments
+///
+/// * `aggregate_sig` - Aggregate signature instance pointer
+/// * `messages` - Message buffer pointers array
+/// * `message_lens` - Message buffer lens array (must have the same len as messages array)
+/// * `messages_len` - Message buffer pointers array len
+/// * `ver_keys` - Verification key instance pointers array
+/// * `ver_keys_len` - Verification keys instance pointers array len
+/// * `gen` - Generator point instance
+/// * `valid_p` - Reference that will be filled with true - if signature valid or false otherwise.
+#[no_mangle]
+pub extern fn indy_crypto_bls_verify_aggregate(aggregate_sig: *const c_void,
+                                               messages: *const *const u8,
+                                               message_lens: *const usize,
+                                               messages_len: usize,
+                                               ver_keys: *const *const c_void,
+                                               ver_keys_len: usize,
+                                               gen: *const c_void,
+                                               valid_p: *mut bool) -> ErrorCode {
+    trace!("indy_crypto_bls_verify_aggregate: >>> aggregate_sig: {:?}, messages: {:?}, message_lens: {:?}, messages_len: {:?}, ver_keys: {:?}, ver_keys_len: {:?}, \
+            gen: {:?}, valid_p: {:?}", aggregate_sig, messages, message_lens, messages_len, ver_keys, ver_keys_len, gen, valid_p);
+
+    check_useful_c_reference!(aggregate_sig, AggregateSignature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_byte_array_array!(messages, message_lens, messages_len,
+                                     ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
+    check_useful_c_reference_array!(ver_keys, ver_keys_len, VerKey, ErrorCode::CommonInvalidParam5, ErrorCode::CommonInvalidParam6);
+    check_useful_c_reference!(gen, Generator, ErrorCode::CommonInvalidParam7);
+    check_useful_c_ptr!(valid_p, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_crypto_bls_verify_aggregate: aggregate_sig: {:?}, messages: {:?}, ver_keys: {:?}, gen: {:?}", aggregate_sig, messages, ver_keys, gen);
+
+    let res = match Bls::verify_aggregate(aggregate_sig, &messages, &ver_keys, gen) {
+        Ok(valid) => {
+            trace!("indy_crypto_bls_verify_aggregate: valid: {:?}", valid);
+            unsafe { *valid_p = valid; }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_verify_aggregate: <<< res: {:?}", res);
+    res
+}
+
+/// Verifies many independent message signatures at once.
+///
+/// # Arguments
+///
+/// * `signatures` - Signature instance pointers array
+/// * `signatures_len` - Signature instance pointers array len
+/// * `messages` - Message buffer pointers array
+/// * `message_lens` - Message buffer lens array (must have the same len as messages array)
+/// * `messages_len` - Message buffer pointers array len
+/// * `ver_keys` - Verification key instance pointers array
+/// * `ver_keys_len` - Verification keys instance pointers array len
+/// * `gen` - Generator point instance
+/// * `valid_p` - Reference that will be filled with true - if all signatures valid or false otherwise.
+/// * `invalid_idx_p` - Reference that will be filled with index of the first invalid signature if batch rejected.
+#[no_mangle]
+pub extern fn indy_crypto_bls_verify_batch(signatures: *const *const c_void,
+                                           signatures_len: usize,
+                                           messages: *const *const u8,
+                                           message_lens: *const usize,
+                                           messages_len: usize,
+                                           ver_keys: *const *const c_void,
+                                           ver_keys_len: usize,
+                                           gen: *const c_void,
+                                           valid_p: *mut bool,
+                                           invalid_idx_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_verify_batch: >>> signatures: {:?}, signatures_len: {:?}, messages: {:?}, message_lens: {:?}, messages_len: {:?}, \
+            ver_keys: {:?}, ver_keys_len: {:?}, gen: {:?}, valid_p: {:?}, invalid_idx_p: {:?}",
+           signatures, signatures_len, messages, message_lens, messages_len, ver_keys, ver_keys_len, gen, valid_p, invalid_idx_p);
+
+    check_useful_c_reference_array!(signatures, signatures_len, Signature, ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_byte_array_array!(messages, message_lens, messages_len,
+                                     ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4, ErrorCode::CommonInvalidParam5);
+    check_useful_c_reference_array!(ver_keys, ver_keys_len, VerKey, ErrorCode::CommonInvalidParam6, ErrorCode::CommonInvalidParam7);
+    check_useful_c_reference!(gen, Generator, ErrorCode::CommonInvalidParam8);
+    check_useful_c_ptr!(valid_p, ErrorCode::CommonInvalidParam9);
+    check_useful_c_ptr!(invalid_idx_p, ErrorCode::CommonInvalidParam10);
+
+    trace!("indy_crypto_bls_verify_batch: signatures: {:?}, messages: {:?}, ver_keys: {:?}, gen: {:?}", signatures, messages, ver_keys, gen);
+
+    let res = match Bls::verify_batch(&signatures, &messages, &ver_keys, gen) {
+        Ok(invalid_idx) => {
+            trace!("indy_crypto_bls_verify_batch: invalid_idx: {:?}", invalid_idx);
+            unsafe {
+                *valid_p = invalid_idx.is_none();
+                if let Some(invalid_idx) = invalid_idx {
+                    *invalid_idx_p = invalid_idx;
+                }
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_verify_batch: <<< res: {:?}", res);
+    res
+}
+
+/// Verifies the proof of possession and returns true - if proof valid or false otherwise.
+///
+/// # Arguments
+///
+/// * `pop` - Proof of possession instance pointer
+/// * `ver_key` - Verification key instance pointer
+/// * `gen` - Generator instance pointer
+/// * `valid_p` - Reference that will be filled with true - if proof valid or false otherwise.
+#[no_mangle]
+pub extern fn indy_crypto_bls_verify_pop(pop: *const c_void,
+                                         ver_key: *const c_void,
+                                         gen: *const c_void,
+                                         valid_p: *mut bool) -> ErrorCode {
+    trace!("indy_crypto_bls_verify_pop: >>> pop: {:?}, ver_key: {:?}, gen: {:?}, valid_p: {:?}", pop, ver_key, gen, valid_p);
+
+    check_useful_c_reference!(pop, ProofOfPossession, ErrorCode::CommonInvalidParam1);
+    check_useful_c_reference!(ver_key, VerKey, ErrorCode::CommonInvalidParam2);
+    check_useful_c_reference!(gen, Generator, ErrorCode::CommonInvalidParam3);
+    check_useful_c_ptr!(valid_p, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_crypto_bls_verify_pop: pop: {:?}, ver_key: {:?}, gen: {:?}", pop, ver_key, gen);
+
+    let res = match Bls::verify_pop(pop, ver_key, gen) {
+        Ok(valid) => {
+            trace!("indy_crypto_bls_verify_pop: valid: {:?}", valid);
+            unsafe { *valid_p = valid; }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_verify_pop: <<< res: {:?}", res);
+    res
+}
+
+/// Curve identifier of BN254 curve for `indy_crypto_bls_curve_*` functions. Requires `pair_amcl` feature.
+///
+/// Instances created by `indy_crypto_bls_curve_*` functions are tagged with their curve identifier.
+/// Passing an instance together with another curve identifier fails with `CommonInvalidParam*` of the instance.
+pub const BLS_CURVE_BN254: u32 = 0;
+
+/// Curve identifier of BLS12-381 curve for `indy_crypto_bls_curve_*` functions. Requires `pair_bls12_381` feature.
+/// Ver keys and signatures use ZCash compressed encoding.
+pub const BLS_CURVE_BLS12_381: u32 = 1;
+
+/// Calls generic function with pairing engine of the curve identifier
+/// or returns `CommonInvalidParam1` if the curve isn't supported by the build.
+macro_rules! match_curve {
+    ($curve:ident, $func:ident($($arg:expr),*)) => {
+        match $curve {
+            #[cfg(feature = "pair_amcl")]
+            BLS_CURVE_BN254 => $func::<::pair::amcl::Bn254>($($arg),*),
+            #[cfg(feature = "pair_bls12_381")]
+            BLS_CURVE_BLS12_381 => $func::<::pair::bls12_381::Bls12381>($($arg),*),
+            _ => ErrorCode::CommonInvalidParam1
+        }
+    }
+}
+
+/// Instance returned by `indy_crypto_bls_curve_*` functions tagged with the curve it was created for.
+/// Tag is the first field, so it can be read without knowing the type of the value.
+#[repr(C)]
+#[derive(Debug)]
+struct CurveInstance<T> {
+    curve: u32,
+    value: T
+}
+
+/// Returns `$err` if the instance wasn't created for the curve.
+macro_rules! check_curve_instance {
+    ($curve:ident, $ptr:ident, $err:expr) => {
+        if _curve_of($ptr) != $curve {
+            return $err
+        }
+    }
+}
+
+/// Returns `$err` if any instance of the array wasn't created for the curve.
+macro_rules! check_curve_instance_array {
+    ($curve:ident, $ptrs:ident, $ptrs_len:ident, $err:expr) => {
+        if unsafe { slice::from_raw_parts($ptrs, $ptrs_len) }.iter().any(|ptr| ptr.is_null() || _curve_of(*ptr) != $curve) {
+            return $err
+        }
+    }
+}
+
+/// Creates and returns random generator point of the curve.
+///
+/// Note: Generator instance deallocation must be performed by calling indy_crypto_bls_curve_generator_free
+///
+/// # Arguments
+/// * `curve` - Curve identifier: BLS_CURVE_BN254 or BLS_CURVE_BLS12_381
+/// * `gen_p` - Reference that will contain generator instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_curve_generator_new(curve: u32, gen_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_curve_generator_new: >>> curve: {:?}, gen_p: {:?}", curve, gen_p);
+
+    check_useful_c_ptr!(gen_p, ErrorCode::CommonInvalidParam2);
+
+    let res = match_curve!(curve, _curve_generator_new(curve, false, gen_p));
+
+    trace!("indy_crypto_bls_curve_generator_new: <<< res: {:?}", res);
+    res
+}
+
+/// Returns standard generator point of the curve (base point of G2 group).
+///
+/// Note: Generator instance deallocation must be performed by calling indy_crypto_bls_curve_generator_free
+///
+/// # Arguments
+/// * `curve` - Curve identifier: BLS_CURVE_BN254 or BLS_CURVE_BLS12_381
+/// * `gen_p` - Reference that will contain generator instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_curve_generator_standard(curve: u32, gen_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_curve_generator_standard: >>> curve: {:?}, gen_p: {:?}", curve, gen_p);
+
+    check_useful_c_ptr!(gen_p, ErrorCode::CommonInvalidParam2);
+
+    let res = match_curve!(curve, _curve_generator_new(curve, true, gen_p));
+
+    trace!("indy_crypto_bls_curve_generator_standard: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns generator point of the curve from compressed bytes representation.
+///
+/// Note: Generator instance deallocation must be performed by calling indy_crypto_bls_curve_generator_free
+///
+/// # Arguments
+/// * `curve` - Curve identifier: BLS_CURVE_BN254 or BLS_CURVE_BLS12_381
+/// * `bytes` - Compressed bytes buffer pointer
+/// * `bytes_len` - Compressed bytes buffer len
+/// * `gen_p` - Reference that will contain generator instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_curve_generator_from_compressed_bytes(curve: u32, bytes: *const u8, bytes_len: usize,
+                                                                    gen_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_curve_generator_from_compressed_bytes: >>> curve: {:?}, bytes: {:?}, bytes_len: {:?}, gen_p: {:?}", curve, bytes, bytes_len, gen_p);
+
+    check_useful_c_byte_array!(bytes, bytes_len,
+                               ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_c_ptr!(gen_p, ErrorCode::CommonInvalidParam4);
+
+    let res = match_curve!(curve, _curve_generator_from_compressed_bytes(curve, bytes, gen_p));
+
+    trace!("indy_crypto_bls_curve_generator_from_compressed_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Returns compressed bytes representation of generator point.
+///
+/// Note: Returned buffer lifetime is the same as generator instance.
+///
+/// # Arguments
+/// * `curve` - Curve identifier the generator was created for
+/// * `gen` - Generator instance pointer
+/// * `bytes_p` - Pointer that will contains bytes buffer
+/// * `bytes_len_p` - Pointer that will contains bytes buffer len
+#[no_mangle]
+pub extern fn indy_crypto_bls_curve_generator_as_compressed_bytes(curve: u32, gen: *const c_void,
+                                                                  bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_curve_generator_as_compressed_bytes: >>> curve: {:?}, gen: {:?}, bytes_p: {:?}, bytes_len_p: {:?}", curve, gen, bytes_p, bytes_len_p);
+
+    check_useful_c_ptr!(gen, ErrorCode::CommonInvalidParam2);
+    check_curve_instance!(curve, gen, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(bytes_p, ErrorCode::CommonInvalidParam3);
+    check_useful_c_ptr!(bytes_len_p, ErrorCode::CommonInvalidParam4);
+
+    let res = match_curve!(curve, _curve_generator_as_compressed_bytes(gen, bytes_p, bytes_len_p));
+
+    trace!("indy_crypto_bls_curve_generator_as_compressed_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Deallocates generator instance.
+///
+/// # Arguments
+/// * `curve` - Curve identifier the generator was created for
+/// * `gen` - Generator instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_curve_generator_free(curve: u32, gen: *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_curve_generator_free: >>> curve: {:?}, gen: {:?}", curve, gen);
+
+    check_useful_c_ptr!(gen, ErrorCode::CommonInvalidParam2);
+    check_curve_instance!(curve, gen, ErrorCode::CommonInvalidParam2);
+
+    let res = match_curve!(curve, _curve_generator_free(gen));
+
+    trace!("indy_crypto_bls_curve_generator_free: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns random (or seeded from seed) sign key of the curve.
+///
+/// Note: Sign key instance deallocation must be performed by calling indy_crypto_bls_curve_sign_key_free.
+///
+/// # Arguments
+/// * `curve` - Curve identifier: BLS_CURVE_BN254 or BLS_CURVE_BLS12_381
+/// * `seed` - Seed buffer pointer. For random generation null must be passed.
+/// * `seed_len` - Seed buffer len.
+/// * `sign_key_p` - Reference that will contain sign key instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_curve_sign_key_new(curve: u32,
+                                                 seed: *const u8,
+                                                 seed_len: usize,
+                                                 sign_key_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_curve_sign_key_new: >>> curve: {:?}, seed: {:?}, seed_len: {:?}, sign_key_p: {:?}", curve, seed, seed_len, sign_key_p);
+
+    check_useful_opt_c_byte_array!(seed, seed_len,
+                                   ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_c_ptr!(sign_key_p, ErrorCode::CommonInvalidParam4);
+
+    let res = match_curve!(curve, _curve_sign_key_new(curve, seed, sign_key_p));
+
+    trace!("indy_crypto_bls_curve_sign_key_new: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns sign key of the curve from bytes representation.
+///
+/// Note: Sign key instance deallocation must be performed by calling indy_crypto_bls_curve_sign_key_free.
+///
+/// # Arguments
+/// * `curve` - Curve identifier: BLS_CURVE_BN254 or BLS_CURVE_BLS12_381
+/// * `bytes` - Bytes buffer pointer
+/// * `bytes_len` - Bytes buffer len
+/// * `sign_key_p` - Reference that will contain sign key instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_curve_sign_key_from_bytes(curve: u32, bytes: *const u8, bytes_len: usize,
+                                                        sign_key_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_curve_sign_key_from_bytes: >>> curve: {:?}, bytes: {:?}, bytes_len: {:?}, sign_key_p: {:?}", curve, bytes, bytes_len, sign_key_p);
+
+    check_useful_c_byte_array!(bytes, bytes_len,
+                               ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_c_ptr!(sign_key_p, ErrorCode::CommonInvalidParam4);
+
+    let res = match_curve!(curve, _curve_sign_key_from_bytes(curve, bytes, sign_key_p));
+
+    trace!("indy_crypto_bls_curve_sign_key_from_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Returns bytes representation of sign key.
+///
+/// Note: Returned buffer lifetime is the same as sign key instance.
+///
+/// # Arguments
+/// * `curve` - Curve identifier the sign key was created for
+/// * `sign_key` - Sign key instance pointer
+/// * `bytes_p` - Pointer that will contains bytes buffer
+/// * `bytes_len_p` - Pointer that will contains bytes buffer len
+#[no_mangle]
+pub extern fn indy_crypto_bls_curve_sign_key_as_bytes(curve: u32, sign_key: *const c_void,
+                                                      bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_curve_sign_key_as_bytes: >>> curve: {:?}, sign_key: {:?}, bytes_p: {:?}, bytes_len_p: {:?}", curve, sign_key, bytes_p, bytes_len_p);
+
+    check_useful_c_ptr!(sign_key, ErrorCode::CommonInvalidParam2);
+    check_curve_instance!(curve, sign_key, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(bytes_p, ErrorCode::CommonInvalidParam3);
+    check_useful_c_ptr!(bytes_len_p, ErrorCode::CommonInvalidParam4);
+
+    let res = match_curve!(curve, _curve_sign_key_as_bytes(sign_key, bytes_p, bytes_len_p));
+
+    trace!("indy_crypto_bls_curve_sign_key_as_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Deallocates sign key instance.
+///
+/// # Arguments
+/// * `curve` - Curve identifier the sign key was created for
+/// * `sign_key` - Sign key instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_curve_sign_key_free(curve: u32, sign_key: *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_curve_sign_key_free: >>> curve: {:?}, sign_key: {:?}", curve, sign_key);
+
+    check_useful_c_ptr!(sign_key, ErrorCode::CommonInvalidParam2);
+    check_curve_instance!(curve, sign_key, ErrorCode::CommonInvalidParam2);
+
+    let res = match_curve!(curve, _curve_sign_key_free(sign_key));
+
+    trace!("indy_crypto_bls_curve_sign_key_free: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns ver key of the curve that corresponds to sign key.
+///
+/// Note: Ver key instance deallocation must be performed by calling indy_crypto_bls_curve_ver_key_free.
+///
+/// # Arguments
+/// * `curve` - Curve identifier: BLS_CURVE_BN254 or BLS_CURVE_BLS12_381
+/// * `gen` - Generator instance pointer
+/// * `sign_key` - Sign key instance pointer
+/// * `ver_key_p` - Reference that will contain ver key instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_curve_ver_key_new(curve: u32,
+                                                gen: *const c_void,
+                                                sign_key: *const c_void,
+                                                ver_key_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_curve_ver_key_new: >>> curve: {:?}, gen: {:?}, sign_key: {:?}, ver_key_p: {:?}", curve, gen, sign_key, ver_key_p);
+
+    check_useful_c_ptr!(gen, ErrorCode::CommonInvalidParam2);
+    check_curve_instance!(curve, gen, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(sign_key, ErrorCode::CommonInvalidParam3);
+    check_curve_instance!(curve, sign_key, ErrorCode::CommonInvalidParam3);
+    check_useful_c_ptr!(ver_key_p, ErrorCode::CommonInvalidParam4);
+
+    let res = match_curve!(curve, _curve_ver_key_new(curve, gen, sign_key, ver_key_p));
+
+    trace!("indy_crypto_bls_curve_ver_key_new: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns ver key of the curve from compressed bytes representation.
+///
+/// Note: Ver key instance deallocation must be performed by calling indy_crypto_bls_curve_ver_key_free.
+///
+/// # Arguments
+/// * `curve` - Curve identifier: BLS_CURVE_BN254 or BLS_CURVE_BLS12_381
+/// * `bytes` - Compressed bytes buffer pointer
+/// * `bytes_len` - Compressed bytes buffer len
+/// * `ver_key_p` - Reference that will contain ver key instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_curve_ver_key_from_compressed_bytes(curve: u32, bytes: *const u8, bytes_len: usize,
+                                                                  ver_key_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_curve_ver_key_from_compressed_bytes: >>> curve: {:?}, bytes: {:?}, bytes_len: {:?}, ver_key_p: {:?}", curve, bytes, bytes_len, ver_key_p);
+
+    check_useful_c_byte_array!(bytes, bytes_len,
+                               ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_c_ptr!(ver_key_p, ErrorCode::CommonInvalidParam4);
+
+    let res = match_curve!(curve, _curve_ver_key_from_compressed_bytes(curve, bytes, ver_key_p));
+
+    trace!("indy_crypto_bls_curve_ver_key_from_compressed_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Returns compressed bytes representation of ver key.
+///
+/// Note: Returned buffer lifetime is the same as ver key instance.
+///
+/// # Arguments
+/// * `curve` - Curve identifier the ver key was created for
+/// * `ver_key` - Ver key instance pointer
+/// * `bytes_p` - Pointer that will contains bytes buffer
+/// * `bytes_len_p` - Pointer that will contains bytes buffer len
+#[no_mangle]
+pub extern fn indy_crypto_bls_curve_ver_key_as_compressed_bytes(curve: u32, ver_key: *const c_void,
+                                                                bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_curve_ver_key_as_compressed_bytes: >>> curve: {:?}, ver_key: {:?}, bytes_p: {:?}, bytes_len_p: {:?}", curve, ver_key, bytes_p, bytes_len_p);
+
+    check_useful_c_ptr!(ver_key, ErrorCode::CommonInvalidParam2);
+    check_curve_instance!(curve, ver_key, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(bytes_p, ErrorCode::CommonInvalidParam3);
+    check_useful_c_ptr!(bytes_len_p, ErrorCode::CommonInvalidParam4);
+
+    let res = match_curve!(curve, _curve_ver_key_as_compressed_bytes(ver_key, bytes_p, bytes_len_p));
+
+    trace!("indy_crypto_bls_curve_ver_key_as_compressed_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Deallocates ver key instance.
+///
+/// # Arguments
+/// * `curve` - Curve identifier the ver key was created for
+/// * `ver_key` - Ver key instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_curve_ver_key_free(curve: u32, ver_key: *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_curve_ver_key_free: >>> curve: {:?}, ver_key: {:?}", curve, ver_key);
+
+    check_useful_c_ptr!(ver_key, ErrorCode::CommonInvalidParam2);
+    check_curve_instance!(curve, ver_key, ErrorCode::CommonInvalidParam2);
+
+    let res = match_curve!(curve, _curve_ver_key_free(ver_key));
+
+    trace!("indy_crypto_bls_curve_ver_key_free: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns signature of the curve from compressed bytes representation.
+///
+/// Note: Signature instance deallocation must be performed by calling indy_crypto_bls_curve_signature_free.
+///
+/// # Arguments
+/// * `curve` - Curve identifier: BLS_CURVE_BN254 or BLS_CURVE_BLS12_381
+/// * `bytes` - Compressed bytes buffer pointer
+/// * `bytes_len` - Compressed bytes buffer len
+/// * `signature_p` - Reference that will contain signature instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_curve_signature_from_compressed_bytes(curve: u32, bytes: *const u8, bytes_len: usize,
+                                                                    signature_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_curve_signature_from_compressed_bytes: >>> curve: {:?}, bytes: {:?}, bytes_len: {:?}, signature_p: {:?}", curve, bytes, bytes_len, signature_p);
+
+    check_useful_c_byte_array!(bytes, bytes_len,
+                               ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_c_ptr!(signature_p, ErrorCode::CommonInvalidParam4);
+
+    let res = match_curve!(curve, _curve_signature_from_compressed_bytes(curve, bytes, signature_p));
+
+    trace!("indy_crypto_bls_curve_signature_from_compressed_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Returns compressed bytes representation of signature.
+///
+/// Note: Returned buffer lifetime is the same as signature instance.
+///
+/// # Arguments
+/// * `curve` - Curve identifier the signature was created for
+/// * `signature` - Signature instance pointer
+/// * `bytes_p` - Pointer that will contains bytes buffer
+/// * `bytes_len_p` - Pointer that will contains bytes buffer len
+#[no_mangle]
+pub extern fn indy_crypto_bls_curve_signature_as_compressed_bytes(curve: u32, signature: *const c_void,
+                                                                  bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_curve_signature_as_compressed_bytes: >>> curve: {:?}, signature: {:?}, bytes_p: {:?}, bytes_len_p: {:?}", curve, signature, bytes_p, bytes_len_p);
+
+    check_useful_c_ptr!(signature, ErrorCode::CommonInvalidParam2);
+    check_curve_instance!(curve, signature, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(bytes_p, ErrorCode::CommonInvalidParam3);
+    check_useful_c_ptr!(bytes_len_p, ErrorCode::CommonInvalidParam4);
+
+    let res = match_curve!(curve, _curve_signature_as_compressed_bytes(signature, bytes_p, bytes_len_p));
+
+    trace!("indy_crypto_bls_curve_signature_as_compressed_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Deallocates signature instance.
+///
+/// # Arguments
+/// * `curve` - Curve identifier the signature was created for
+/// * `signature` - Signature instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_curve_signature_free(curve: u32, signature: *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_curve_signature_free: >>> curve: {:?}, signature: {:?}", curve, signature);
+
+    check_useful_c_ptr!(signature, ErrorCode::CommonInvalidParam2);
+    check_curve_instance!(curve, signature, ErrorCode::CommonInvalidParam2);
+
+    let res = match_curve!(curve, _curve_signature_free(signature));
+
+    trace!("indy_crypto_bls_curve_signature_free: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns multi signature of the curve for provided list of signatures.
+///
+/// Note: Multi signature instance deallocation must be performed by calling indy_crypto_bls_curve_multi_signature_free.
+///
+/// # Arguments
+/// * `curve` - Curve identifier: BLS_CURVE_BN254 or BLS_CURVE_BLS12_381
+/// * `signatures` - Signature instance pointers array
+/// * `signatures_len` - Signature instance pointers array len
+/// * `multi_sig_p` - Reference that will contain multi signature instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_curve_multi_signature_new(curve: u32,
+                                                        signatures: *const *const c_void,
+                                                        signatures_len: usize,
+                                                        multi_sig_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_curve_multi_signature_new: >>> curve: {:?}, signatures: {:?}, signatures_len: {:?}, multi_sig_p: {:?}", curve, signatures, signatures_len, multi_sig_p);
+
+    check_useful_c_ptr!(signatures, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(multi_sig_p, ErrorCode::CommonInvalidParam4);
+
+    if signatures_len == 0 {
+        return ErrorCode::CommonInvalidParam3;
+    }
+
+    check_curve_instance_array!(curve, signatures, signatures_len, ErrorCode::CommonInvalidParam2);
+
+    let res = match_curve!(curve, _curve_multi_signature_new(curve, signatures, signatures_len, multi_sig_p));
+
+    trace!("indy_crypto_bls_curve_multi_signature_new: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns multi signature of the curve from compressed bytes representation.
+///
+/// Note: Multi signature instance deallocation must be performed by calling indy_crypto_bls_curve_multi_signature_free.
+///
+/// # Arguments
+/// * `curve` - Curve identifier: BLS_CURVE_BN254 or BLS_CURVE_BLS12_381
+/// * `bytes` - Compressed bytes buffer pointer
+/// * `bytes_len` - Compressed bytes buffer len
+/// * `multi_sig_p` - Reference that will contain multi signature instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_curve_multi_signature_from_compressed_bytes(curve: u32, bytes: *const u8, bytes_len: usize,
+                                                                          multi_sig_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_curve_multi_signature_from_compressed_bytes: >>> curve: {:?}, bytes: {:?}, bytes_len: {:?}, multi_sig_p: {:?}", curve, bytes, bytes_len, multi_sig_p);
+
+    check_useful_c_byte_array!(bytes, bytes_len,
+                               ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_c_ptr!(multi_sig_p, ErrorCode::CommonInvalidParam4);
+
+    let res = match_curve!(curve, _curve_multi_signature_from_compressed_bytes(curve, bytes, multi_sig_p));
+
+    trace!("indy_crypto_bls_curve_multi_signature_from_compressed_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Returns compressed bytes representation of multi signature.
+///
+/// Note: Returned buffer lifetime is the same as multi signature instance.
+///
+/// # Arguments
+/// * `curve` - Curve identifier the multi signature was created for
+/// * `multi_sig` - Multi signature instance pointer
+/// * `bytes_p` - Pointer that will contains bytes buffer
+/// * `bytes_len_p` - Pointer that will contains bytes buffer len
+#[no_mangle]
+pub extern fn indy_crypto_bls_curve_multi_signature_as_compressed_bytes(curve: u32, multi_sig: *const c_void,
+                                                                        bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_curve_multi_signature_as_compressed_bytes: >>> curve: {:?}, multi_sig: {:?}, bytes_p: {:?}, bytes_len_p: {:?}", curve, multi_sig, bytes_p, bytes_len_p);
+
+    check_useful_c_ptr!(multi_sig, ErrorCode::CommonInvalidParam2);
+    check_curve_instance!(curve, multi_sig, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(bytes_p, ErrorCode::CommonInvalidParam3);
+    check_useful_c_ptr!(bytes_len_p, ErrorCode::CommonInvalidParam4);
+
+    let res = match_curve!(curve, _curve_multi_signature_as_compressed_bytes(multi_sig, bytes_p, bytes_len_p));
+
+    trace!("indy_crypto_bls_curve_multi_signature_as_compressed_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Deallocates multi signature instance.
+///
+/// # Arguments
+/// * `curve` - Curve identifier the multi signature was created for
+/// * `multi_sig` - Multi signature instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_curve_multi_signature_free(curve: u32, multi_sig: *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_curve_multi_signature_free: >>> curve: {:?}, multi_sig: {:?}", curve, multi_sig);
+
+    check_useful_c_ptr!(multi_sig, ErrorCode::CommonInvalidParam2);
+    check_curve_instance!(curve, multi_sig, ErrorCode::CommonInvalidParam2);
+
+    let res = match_curve!(curve, _curve_multi_signature_free(multi_sig));
+
+    trace!("indy_crypto_bls_curve_multi_signature_free: <<< res: {:?}", res);
+    res
+}
+
+/// Signs the message with legacy ciphersuite (the same as indy_crypto_bls_sign) and returns signature.
+///
+/// Note: Signature instance deallocation must be performed by calling indy_crypto_bls_curve_signature_free.
+///
+/// # Arguments
+/// * `curve` - Curve identifier the sign key was created for
+/// * `message` - Message to sign buffer pointer
+/// * `message_len` - Message to sign buffer len
+/// * `sign_key` - Sign key instance pointer
+/// * `signature_p` - Reference that will contain signature instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_curve_sign(curve: u32,
+                                         message: *const u8,
+                                         message_len: usize,
+                                         sign_key: *const c_void,
+                                         signature_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_curve_sign: >>> curve: {:?}, message: {:?}, message_len: {:?}, sign_key: {:?}, signature_p: {:?}", curve, message, message_len, sign_key, signature_p);
+
+    check_useful_c_byte_array!(message, message_len,
+                               ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_c_ptr!(sign_key, ErrorCode::CommonInvalidParam4);
+    check_curve_instance!(curve, sign_key, ErrorCode::CommonInvalidParam4);
+    check_useful_c_ptr!(signature_p, ErrorCode::CommonInvalidParam5);
+
+    let res = match_curve!(curve, _curve_sign(curve, message, sign_key, signature_p));
+
+    trace!("indy_crypto_bls_curve_sign: <<< res: {:?}", res);
+    res
+}
+
+/// Verifies the message signature created with legacy ciphersuite (the same as indy_crypto_bls_verify)
+/// and returns true - if signature valid or false otherwise.
+///
+/// # Arguments
+/// * `curve` - Curve identifier the instances were created for
+/// * `signature` - Signature instance pointer
+/// * `message` - Message to verify buffer pointer
+/// * `message_len` - Message to verify buffer len
+/// * `ver_key` - Ver key instance pointer
+/// * `gen` - Generator instance pointer
+/// * `valid_p` - Reference that will be filled with true - if signature valid or false otherwise.
+#[no_mangle]
+pub extern fn indy_crypto_bls_curve_verify(curve: u32,
+                                           signature: *const c_void,
+                                           message: *const u8,
+                                           message_len: usize,
+                                           ver_key: *const c_void,
+                                           gen: *const c_void,
+                                           valid_p: *mut bool) -> ErrorCode {
+    trace!("indy_crypto_bls_curve_verify: >>> curve: {:?}, signature: {:?}, message: {:?}, message_len: {:?}, ver_key: {:?}, gen: {:?}, valid_p: {:?}",
+           curve, signature, message, message_len, ver_key, gen, valid_p);
+
+    check_useful_c_ptr!(signature, ErrorCode::CommonInvalidParam2);
+    check_curve_instance!(curve, signature, ErrorCode::CommonInvalidParam2);
+    check_useful_c_byte_array!(message, message_len,
+                               ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
+    check_useful_c_ptr!(ver_key, ErrorCode::CommonInvalidParam5);
+    check_curve_instance!(curve, ver_key, ErrorCode::CommonInvalidParam5);
+    check_useful_c_ptr!(gen, ErrorCode::CommonInvalidParam6);
+    check_curve_instance!(curve, gen, ErrorCode::CommonInvalidParam6);
+    check_useful_c_ptr!(valid_p, ErrorCode::CommonInvalidParam7);
+
+    let res = match_curve!(curve, _curve_verify(signature, message, ver_key, gen, valid_p));
+
+    trace!("indy_crypto_bls_curve_verify: <<< res: {:?}", res);
+    res
+}
+
+/// Verifies the message multi signature created with legacy ciphersuite (the same as indy_crypto_bls_verify_multi_sig)
+/// and returns true - if signature valid or false otherwise.
+///
+/// # Arguments
+/// * `curve` - Curve identifier the instances were created for
+/// * `multi_sig` - Multi signature instance pointer
+/// * `message` - Message to verify buffer pointer
+/// * `message_len` - Message to verify buffer len
+/// * `ver_keys` - Ver key instance pointers array
+/// * `ver_keys_len` - Ver key instance pointers array len
+/// * `gen` - Generator instance pointer
+/// * `valid_p` - Reference that will be filled with true - if signature valid or false otherwise.
+#[no_mangle]
+pub extern fn indy_crypto_bls_curve_verify_multi_sig(curve: u32,
+                                                     multi_sig: *const c_void,
+                                                     message: *const u8,
+                                                     message_len: usize,
+                                                     ver_keys: *const *const c_void,
+                                                     ver_keys_len: usize,
+                                                     gen: *const c_void,
+                                                     valid_p: *mut bool) -> ErrorCode {
+    trace!("indy_crypto_bls_curve_verify_multi_sig: >>> curve: {:?}, multi_sig: {:?}, message: {:?}, message_len: {:?}, ver_keys: {:?}, ver_keys_len: {:?}, gen: {:?}, valid_p: {:?}",
+           curve, multi_sig, message, message_len, ver_keys, ver_keys_len, gen, valid_p);
+
+    check_useful_c_ptr!(multi_sig, ErrorCode::CommonInvalidParam2);
+    check_curve_instance!(curve, multi_sig, ErrorCode::CommonInvalidParam2);
+    check_useful_c_byte_array!(message, message_len,
+                               ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
+    check_useful_c_ptr!(ver_keys, ErrorCode::CommonInvalidParam5);
+    check_useful_c_ptr!(gen, ErrorCode::CommonInvalidParam7);
+    check_curve_instance!(curve, gen, ErrorCode::CommonInvalidParam7);
+    check_useful_c_ptr!(valid_p, ErrorCode::CommonInvalidParam8);
+
+    if ver_keys_len == 0 {
+        return ErrorCode::CommonInvalidParam6;
+    }
+
+    check_curve_instance_array!(curve, ver_keys, ver_keys_len, ErrorCode::CommonInvalidParam5);
+
+    let res = match_curve!(curve, _curve_verify_multi_sig(multi_sig, message, ver_keys, ver_keys_len, gen, valid_p));
+
+    trace!("indy_crypto_bls_curve_verify_multi_sig: <<< res: {:?}", res);
+    res
+}
+
+fn _curve_of(instance: *const c_void) -> u32 {
+    unsafe { (*(instance as *const CurveInstance<()>)).curve }
+}
+
+fn _curve_instance<'a, T>(instance: *const c_void) -> &'a T {
+    unsafe { &(*(instance as *const CurveInstance<T>)).value }
+}
+
+fn _curve_instance_free<T>(instance: *const c_void) -> ErrorCode {
+    unsafe { Box::from_raw(instance as *mut CurveInstance<T>); }
+    ErrorCode::Success
+}
+
+fn _curve_box_result<T: fmt::Debug>(curve: u32, result: Result<T, IndyCryptoError>, p: *mut *const c_void) -> ErrorCode {
+    match result {
+        Ok(value) => {
+            let instance = CurveInstance { curve, value };
+            trace!("indy_crypto_bls_curve: instance: {:?}", instance);
+            unsafe { *p = Box::into_raw(Box::new(instance)) as *const c_void; }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    }
+}
+
+fn _curve_set_bytes(bytes: &[u8], bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    unsafe {
+        *bytes_p = bytes.as_ptr();
+        *bytes_len_p = bytes.len();
+    }
+    ErrorCode::Success
+}
+
+fn _curve_set_valid(result: Result<bool, IndyCryptoError>, valid_p: *mut bool) -> ErrorCode {
+    match result {
+        Ok(valid) => {
+            trace!("indy_crypto_bls_curve: valid: {:?}", valid);
+            unsafe { *valid_p = valid; }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    }
+}
+
+fn _curve_generator_new<E: PairingEngine + fmt::Debug>(curve: u32, standard: bool, gen_p: *mut *const c_void) -> ErrorCode {
+    let gen = if standard { curve::Generator::<E>::standard() } else { curve::Generator::<E>::new() };
+    _curve_box_result(curve, gen, gen_p)
+}
+
+fn _curve_generator_from_compressed_bytes<E: PairingEngine + fmt::Debug>(curve: u32, bytes: &[u8], gen_p: *mut *const c_void) -> ErrorCode {
+    _curve_box_result(curve, curve::Generator::<E>::from_compressed_bytes(bytes), gen_p)
+}
+
+fn _curve_generator_as_compressed_bytes<E: PairingEngine>(gen: *const c_void, bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    let gen = _curve_instance::<curve::Generator<E>>(gen);
+    _curve_set_bytes(gen.as_compressed_bytes(), bytes_p, bytes_len_p)
+}
+
+fn _curve_generator_free<E: PairingEngine>(gen: *const c_void) -> ErrorCode {
+    _curve_instance_free::<curve::Generator<E>>(gen)
+}
+
+fn _curve_sign_key_new<E: PairingEngine + fmt::Debug>(curve: u32, seed: Option<&[u8]>, sign_key_p: *mut *const c_void) -> ErrorCode {
+    _curve_box_result(curve, curve::SignKey::<E>::new(seed), sign_key_p)
+}
+
+fn _curve_sign_key_from_bytes<E: PairingEngine + fmt::Debug>(curve: u32, bytes: &[u8], sign_key_p: *mut *const c_void) -> ErrorCode {
+    _curve_box_result(curve, curve::SignKey::<E>::from_bytes(bytes), sign_key_p)
+}
+
+fn _curve_sign_key_as_bytes<E: PairingEngine>(sign_key: *const c_void, bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    let sign_key = _curve_instance::<curve::SignKey<E>>(sign_key);
+    _curve_set_bytes(sign_key.as_bytes(), bytes_p, bytes_len_p)
+}
+
+fn _curve_sign_key_free<E: PairingEngine>(sign_key: *const c_void) -> ErrorCode {
+    _curve_instance_free::<curve::SignKey<E>>(sign_key)
+}
+
+fn _curve_ver_key_new<E: PairingEngine + fmt::Debug>(curve: u32, gen: *const c_void, sign_key: *const c_void, ver_key_p: *mut *const c_void) -> ErrorCode {
+    let gen = _curve_instance::<curve::Generator<E>>(gen);
+    let sign_key = _curve_instance::<curve::SignKey<E>>(sign_key);
+    _curve_box_result(curve, curve::VerKey::new(gen, sign_key), ver_key_p)
+}
+
+fn _curve_ver_key_from_compressed_bytes<E: PairingEngine + fmt::Debug>(curve: u32, bytes: &[u8], ver_key_p: *mut *const c_void) -> ErrorCode {
+    _curve_box_result(curve, curve::VerKey::<E>::from_compressed_bytes(bytes), ver_key_p)
+}
+
+fn _curve_ver_key_as_compressed_bytes<E: PairingEngine>(ver_key: *const c_void, bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    let ver_key = _curve_instance::<curve::VerKey<E>>(ver_key);
+    _curve_set_bytes(ver_key.as_compressed_bytes(), bytes_p, bytes_len_p)
+}
+
+fn _curve_ver_key_free<E: PairingEngine>(ver_key: *const c_void) -> ErrorCode {
+    _curve_instance_free::<curve::VerKey<E>>(ver_key)
+}
+
+fn _curve_signature_from_compressed_bytes<E: PairingEngine + fmt::Debug>(curve: u32, bytes: &[u8], signature_p: *mut *const c_void) -> ErrorCode {
+    _curve_box_result(curve, curve::Signature::<E>::from_compressed_bytes(bytes), signature_p)
+}
+
+fn _curve_signature_as_compressed_bytes<E: PairingEngine>(signature: *const c_void, bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    let signature = _curve_instance::<curve::Signature<E>>(signature);
+    _curve_set_bytes(signature.as_compressed_bytes(), bytes_p, bytes_len_p)
+}
+
+fn _curve_signature_free<E: PairingEngine>(signature: *const c_void) -> ErrorCode {
+    _curve_instance_free::<curve::Signature<E>>(signature)
+}
+
+fn _curve_multi_signature_new<E: PairingEngine + fmt::Debug>(curve: u32, signatures: *const *const c_void, signatures_len: usize,
+                                                             multi_sig_p: *mut *const c_void) -> ErrorCode {
+    let signatures: Vec<&curve::Signature<E>> =
+        unsafe { slice::from_raw_parts(signatures, signatures_len) }
+            .iter()
+            .map(|ptr| _curve_instance::<curve::Signature<E>>(*ptr))
+            .collect();
+
+    _curve_box_result(curve, curve::MultiSignature::new(&signatures), multi_sig_p)
+}
+
+fn _curve_multi_signature_from_compressed_bytes<E: PairingEngine + fmt::Debug>(curve: u32, bytes: &[u8], multi_sig_p: *mut *const c_void) -> ErrorCode {
+    _curve_box_result(curve, curve::MultiSignature::<E>::from_compressed_bytes(bytes), multi_sig_p)
+}
+
+fn _curve_multi_signature_as_compressed_bytes<E: PairingEngine>(multi_sig: *const c_void, bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    let multi_sig = _curve_instance::<curve::MultiSignature<E>>(multi_sig);
+    _curve_set_bytes(multi_sig.as_compressed_bytes(), bytes_p, bytes_len_p)
+}
+
+fn _curve_multi_signature_free<E: PairingEngine>(multi_sig: *const c_void) -> ErrorCode {
+    _curve_instance_free::<curve::MultiSignature<E>>(multi_sig)
+}
+
+fn _curve_sign<E: PairingEngine + fmt::Debug>(curve: u32, message: &[u8], sign_key: *const c_void, signature_p: *mut *const c_void) -> ErrorCode {
+    let sign_key = _curve_instance::<curve::SignKey<E>>(sign_key);
+    _curve_box_result(curve, curve::Bls::sign(message, sign_key), signature_p)
+}
+
+fn _curve_verify<E: PairingEngine>(signature: *const c_void, message: &[u8], ver_key: *const c_void, gen: *const c_void,
+                                   valid_p: *mut bool) -> ErrorCode {
+    let signature = _curve_instance::<curve::Signature<E>>(signature);
+    let ver_key = _curve_instance::<curve::VerKey<E>>(ver_key);
+    let gen = _curve_instance::<curve::Generator<E>>(gen);
+    _curve_set_valid(curve::Bls::verify(signature, message, ver_key, gen), valid_p)
+}
+
+fn _curve_verify_multi_sig<E: PairingEngine>(multi_sig: *const c_void, message: &[u8], ver_keys: *const *const c_void, ver_keys_len: usize,
+                                             gen: *const c_void, valid_p: *mut bool) -> ErrorCode {
+    let multi_sig = _curve_instance::<curve::MultiSignature<E>>(multi_sig);
+    let ver_keys: Vec<&curve::VerKey<E>> =
+        unsafe { slice::from_raw_parts(ver_keys, ver_keys_len) }
+            .iter()
+            .map(|ptr| _curve_instance::<curve::VerKey<E>>(*ptr))
+            .collect();
+    let gen = _curve_instance::<curve::Generator<E>>(gen);
+    _curve_set_valid(curve::Bls::verify_multi_sig(multi_sig, message, &ver_keys, gen), valid_p)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+    use std::ptr;
+
+    #[test]
+    fn indy_crypto_bls_generator_new_works() {
+        let mut gen: *const c_void = ptr::null();
+
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!gen.is_null());
+
+        let err_code = indy_crypto_bls_generator_free(gen);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_generator_as_bytes_works() {
+        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut bytes: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_generator_as_bytes(gen, &mut bytes, &mut bytes_len);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!gen.is_null());
+        assert!(bytes_len > 0);
+
+        let err_code = indy_crypto_bls_generator_free(gen);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_generator_from_bytes_works() {
+        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut bytes: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_generator_as_bytes(gen, &mut bytes, &mut bytes_len);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut gen2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_generator_from_bytes(bytes, bytes_len, &mut gen2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_generator_free(gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_generator_free(gen2);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_generator_free_works() {
+        let mut gen: *const c_void = ptr::null();
+
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!gen.is_null());
+
+        let err_code = indy_crypto_bls_generator_free(gen);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_sign_key_new_works() {
+        let mut sign_key: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!sign_key.is_null());
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_sign_key_new_works_for_seed() {
+        let mut sign_key: *const c_void = ptr::null();
+
+        let seed_v = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10,
+                          11, 2, 3, 4, 5, 6, 7, 8, 9, 10,
+                          21, 2, 3, 4, 5, 6, 7, 8, 9, 10, 31, 32];
+        let seed = seed_v.as_ptr();
+        let seed_len = seed_v.len();
+
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!sign_key.is_null());
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_sign_key_as_bytes_works() {
+        let mut sign_key: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut bytes: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_as_bytes(sign_key, &mut bytes, &mut bytes_len);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!bytes.is_null());
+        assert!(bytes_len > 0);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_sign_key_from_bytes_works() {
+        let mut sign_key: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut bytes: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_as_bytes(sign_key, &mut bytes, &mut bytes_len);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut sign_key2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign_key_from_bytes(bytes, bytes_len, &mut sign_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_sign_key_free_works() {
+        let mut sign_key: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_sign_key_derive_works() {
+        let seed_v = vec![1u8; 32];
+        let seed = seed_v.as_ptr();
+        let seed_len = seed_v.len();
+
+        let mut master: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign_key_derive_master(seed, seed_len, &mut master);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut child: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign_key_derive_child(master, 7, &mut child);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let path = CString::new("m/7").unwrap();
+
+        let mut sign_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign_key_derive_path(seed, seed_len, path.as_ptr(), &mut sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut child_bytes: *const u8 = ptr::null();
+        let mut child_bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_as_bytes(child, &mut child_bytes, &mut child_bytes_len);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut bytes: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_as_bytes(sign_key, &mut bytes, &mut bytes_len);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        assert_eq!(unsafe { slice::from_raw_parts(child_bytes, child_bytes_len) },
+                   unsafe { slice::from_raw_parts(bytes, bytes_len) });
+
+        let err_code = indy_crypto_bls_sign_key_free(master);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(child);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_sign_key_derive_path_works_for_invalid_path() {
+        let seed_v = vec![1u8; 32];
+        let path = CString::new("m/a").unwrap();
+
+        let mut sign_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign_key_derive_path(seed_v.as_ptr(), seed_v.len(), path.as_ptr(), &mut sign_key);
+        assert_eq!(err_code, ErrorCode::CommonInvalidStructure);
+    }
+
+    #[test]
+    fn indy_crypto_bls_ver_key_new_works() {
+        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut sign_key: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut ver_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key, &mut ver_key);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!ver_key.is_null());
+
+        let err_code = indy_crypto_bls_generator_free(gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_ver_key_free(ver_key);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_ver_key_as_bytes_works() {
+        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut sign_key: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut ver_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key, &mut ver_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut bytes: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_ver_key_as_bytes(ver_key, &mut bytes, &mut bytes_len);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!bytes.is_null());
+        assert!(bytes_len > 0);
+
+        let err_code = indy_crypto_bls_generator_free(gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_ver_key_free(ver_key);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_ver_key_from_bytes_works() {
+        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut sign_key: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut ver_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key, &mut ver_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut bytes: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_ver_key_as_bytes(ver_key, &mut bytes, &mut bytes_len);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!bytes.is_null());
+        assert!(bytes_len > 0);
+
+        let mut ver_key2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_from_bytes(bytes, bytes_len, &mut ver_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_generator_free(gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_ver_key_free(ver_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_ver_key_free(ver_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_ver_key_from_compressed_bytes_works() {
+        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut sign_key: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut ver_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key, &mut ver_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut bytes: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_ver_key_as_compressed_bytes(ver_key, &mut bytes, &mut bytes_len);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!bytes.is_null());
+        assert!(bytes_len > 0);
+
+        let mut ver_key2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_from_compressed_bytes(bytes, bytes_len, &mut ver_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_generator_free(gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_ver_key_free(ver_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_ver_key_free(ver_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_ver_key_free_works() {
+        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut sign_key: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut ver_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key, &mut ver_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_generator_free(gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_ver_key_free(ver_key);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_signature_as_bytes_works() {
+        let mut sign_key: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let message_v = vec![1, 2, 3, 4, 5];
+        let message = message_v.as_ptr();
+        let message_len = message_v.len();
+
+        let mut signature: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key, &mut signature);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut bytes: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_signature_as_bytes(signature, &mut bytes, &mut bytes_len);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!bytes.is_null());
+        assert!(bytes_len > 0);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_signature_free(signature);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_signature_from_bytes_works() {
+        let mut sign_key: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let message_v = vec![1, 2, 3, 4, 5];
+        let message = message_v.as_ptr();
+        let message_len = message_v.len();
+
+        let mut signature: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key, &mut signature);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut bytes: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_signature_as_bytes(signature, &mut bytes, &mut bytes_len);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!bytes.is_null());
+        assert!(bytes_len > 0);
+
+        let mut signature2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_signature_from_bytes(bytes, bytes_len, &mut signature2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_signature_free(signature);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_signature_free(signature2);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_signature_from_compressed_bytes_works() {
+        let mut sign_key: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let message_v = vec![1, 2, 3, 4, 5];
+        let message = message_v.as_ptr();
+        let message_len = message_v.len();
+
+        let mut signature: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key, &mut signature);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut bytes: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_signature_as_compressed_bytes(signature, &mut bytes, &mut bytes_len);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!bytes.is_null());
+        assert!(bytes_len > 0);
+
+        let mut signature2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_signature_from_compressed_bytes(bytes, bytes_len, &mut signature2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_signature_free(signature);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_signature_free(signature2);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_signature_free_works() {
+        let mut sign_key: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let message_v = vec![1, 2, 3, 4, 5];
+        let message = message_v.as_ptr();
+        let message_len = message_v.len();
+
+        let mut signature: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key, &mut signature);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_signature_free(signature);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_multi_signature_new_works() {
+        let mut sign_key1: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut sign_key2: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let message_v = vec![1, 2, 3, 4, 5];
+        let message = message_v.as_ptr();
+        let message_len = message_v.len();
+
+        let mut signature1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key1, &mut signature1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut signature2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key2, &mut signature2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let signatures = [signature1, signature2];
+
+        let mut multi_sig: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_multi_signature_new(signatures.as_ptr(), signatures.len(), &mut multi_sig);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!multi_sig.is_null());
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_signature_free(signature1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_signature_free(signature2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_multi_signature_free(multi_sig);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_multi_signature_as_bytes_works() {
+        let mut sign_key1: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut sign_key2: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let message_v = vec![1, 2, 3, 4, 5];
+        let message = message_v.as_ptr();
+        let message_len = message_v.len();
+
+        let mut signature1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key1, &mut signature1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut signature2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key2, &mut signature2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let signatures = [signature1, signature2];
+
+        let mut multi_sig: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_multi_signature_new(signatures.as_ptr(), signatures.len(), &mut multi_sig);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!multi_sig.is_null());
+
+        let mut bytes: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_multi_signature_as_bytes(multi_sig, &mut bytes, &mut bytes_len);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!bytes.is_null());
+        assert!(bytes_len > 0);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_signature_free(signature1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_signature_free(signature2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_multi_signature_free(multi_sig);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_multi_signature_from_bytes_works() {
+        let mut sign_key1: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut sign_key2: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let message_v = vec![1, 2, 3, 4, 5];
+        let message = message_v.as_ptr();
+        let message_len = message_v.len();
+
+        let mut signature1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key1, &mut signature1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut signature2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key2, &mut signature2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let signatures = [signature1, signature2];
+
+        let mut multi_sig: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_multi_signature_new(signatures.as_ptr(), signatures.len(), &mut multi_sig);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut bytes: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_multi_signature_as_bytes(multi_sig, &mut bytes, &mut bytes_len);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!bytes.is_null());
+        assert!(bytes_len > 0);
+
+        let mut multi_sig2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_multi_signature_from_bytes(bytes, bytes_len, &mut multi_sig2);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!multi_sig2.is_null());
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_signature_free(signature1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_signature_free(signature2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_multi_signature_free(multi_sig);
         assert_eq!(err_code, ErrorCode::Success);
-        assert!(!gen.is_null());
 
-        let err_code = indy_crypto_bls_generator_free(gen);
+        let err_code = indy_crypto_bls_multi_signature_free(multi_sig2);
         assert_eq!(err_code, ErrorCode::Success);
     }
 
     #[test]
-    fn indy_crypto_bls_generator_as_bytes_works() {
-        let mut gen: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_generator_new(&mut gen);
+    fn indy_crypto_bls_multi_signature_free_works() {
+        let mut sign_key1: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key1);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut bytes: *const u8 = ptr::null();
-        let mut bytes_len: usize = 0;
-        let err_code = indy_crypto_bls_generator_as_bytes(gen, &mut bytes, &mut bytes_len);
+        let mut sign_key2: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key2);
         assert_eq!(err_code, ErrorCode::Success);
-        assert!(!gen.is_null());
-        assert!(bytes_len > 0);
 
-        let err_code = indy_crypto_bls_generator_free(gen);
-        assert_eq!(err_code, ErrorCode::Success);
-    }
+        let message_v = vec![1, 2, 3, 4, 5];
+        let message = message_v.as_ptr();
+        let message_len = message_v.len();
 
-    #[test]
-    fn indy_crypto_bls_generator_from_bytes_works() {
-        let mut gen: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        let mut signature1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key1, &mut signature1);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut bytes: *const u8 = ptr::null();
-        let mut bytes_len: usize = 0;
-        let err_code = indy_crypto_bls_generator_as_bytes(gen, &mut bytes, &mut bytes_len);
+        let mut signature2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key2, &mut signature2);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut gen2: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_generator_from_bytes(bytes, bytes_len, &mut gen2);
+        let signatures = [signature1, signature2];
+
+        let mut multi_sig: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_multi_signature_new(signatures.as_ptr(), signatures.len(), &mut multi_sig);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_generator_free(gen);
+        let err_code = indy_crypto_bls_sign_key_free(sign_key1);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_generator_free(gen2);
+        let err_code = indy_crypto_bls_sign_key_free(sign_key2);
         assert_eq!(err_code, ErrorCode::Success);
-    }
 
-    #[test]
-    fn indy_crypto_bls_generator_free_works() {
-        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_signature_free(signature1);
+        assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        let err_code = indy_crypto_bls_signature_free(signature2);
         assert_eq!(err_code, ErrorCode::Success);
-        assert!(!gen.is_null());
 
-        let err_code = indy_crypto_bls_generator_free(gen);
+        let err_code = indy_crypto_bls_multi_signature_free(multi_sig);
         assert_eq!(err_code, ErrorCode::Success);
     }
 
+
     #[test]
-    fn indy_crypto_bls_sign_key_new_works() {
+    fn indy_crypto_bsl_verify_works() {
+        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
         let mut sign_key: *const c_void = ptr::null();
         let seed: *const u8 = ptr::null();
         let seed_len: usize = 0;
-
         let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
         assert_eq!(err_code, ErrorCode::Success);
-        assert!(!sign_key.is_null());
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        let mut ver_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key, &mut ver_key);
         assert_eq!(err_code, ErrorCode::Success);
-    }
 
-    #[test]
-    fn indy_crypto_bls_sign_key_new_works_for_seed() {
-        let mut sign_key: *const c_void = ptr::null();
+        let message_v = vec![1, 2, 3, 4, 5];
+        let message = message_v.as_ptr();
+        let message_len = message_v.len();
 
-        let seed_v = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10,
-                          11, 2, 3, 4, 5, 6, 7, 8, 9, 10,
-                          21, 2, 3, 4, 5, 6, 7, 8, 9, 10, 31, 32];
-        let seed = seed_v.as_ptr();
-        let seed_len = seed_v.len();
+        let mut signature: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key, &mut signature);
+        assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        let mut valid = false;
+
+        let err_code = indy_crypto_bsl_verify(signature,
+                                              message, message_len,
+                                              ver_key,
+                                              gen, &mut valid);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(valid);
+
+        let err_code = indy_crypto_bls_generator_free(gen);
         assert_eq!(err_code, ErrorCode::Success);
-        assert!(!sign_key.is_null());
 
         let err_code = indy_crypto_bls_sign_key_free(sign_key);
         assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_ver_key_free(ver_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_signature_free(signature);
+        assert_eq!(err_code, ErrorCode::Success);
     }
 
     #[test]
-    fn indy_crypto_bls_sign_key_as_bytes_works() {
+    fn indy_crypto_bsl_verify_works_for_invalid() {
+        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
         let mut sign_key: *const c_void = ptr::null();
         let seed: *const u8 = ptr::null();
         let seed_len: usize = 0;
         let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut bytes: *const u8 = ptr::null();
-        let mut bytes_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_as_bytes(sign_key, &mut bytes, &mut bytes_len);
+        let mut sign_key2: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key2);
         assert_eq!(err_code, ErrorCode::Success);
-        assert!(!bytes.is_null());
-        assert!(bytes_len > 0);
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        let mut ver_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key2, &mut ver_key);
         assert_eq!(err_code, ErrorCode::Success);
-    }
 
-    #[test]
-    fn indy_crypto_bls_sign_key_from_bytes_works() {
-        let mut sign_key: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        let message_v = vec![1, 2, 3, 4, 5];
+        let message = message_v.as_ptr();
+        let message_len = message_v.len();
+
+        let mut signature: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key, &mut signature);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut bytes: *const u8 = ptr::null();
-        let mut bytes_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_as_bytes(sign_key, &mut bytes, &mut bytes_len);
+        let mut valid = false;
+
+        let err_code = indy_crypto_bsl_verify(signature,
+                                              message, message_len,
+                                              ver_key,
+                                              gen, &mut valid);
         assert_eq!(err_code, ErrorCode::Success);
+        assert!(!valid);
 
-        let mut sign_key2: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_sign_key_from_bytes(bytes, bytes_len, &mut sign_key2);
+        let err_code = indy_crypto_bls_generator_free(gen);
         assert_eq!(err_code, ErrorCode::Success);
 
         let err_code = indy_crypto_bls_sign_key_free(sign_key);
@@ -817,182 +3734,242 @@ mod tests {
 
         let err_code = indy_crypto_bls_sign_key_free(sign_key2);
         assert_eq!(err_code, ErrorCode::Success);
-    }
 
-    #[test]
-    fn indy_crypto_bls_sign_key_free_works() {
-        let mut sign_key: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        let err_code = indy_crypto_bls_ver_key_free(ver_key);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        let err_code = indy_crypto_bls_signature_free(signature);
         assert_eq!(err_code, ErrorCode::Success);
     }
 
     #[test]
-    fn indy_crypto_bls_ver_key_new_works() {
+    fn indy_crypto_bls_verify_multi_sig_works() {
         let mut gen: *const c_void = ptr::null();
         let err_code = indy_crypto_bls_generator_new(&mut gen);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut sign_key: *const c_void = ptr::null();
+        let mut sign_key1: *const c_void = ptr::null();
         let seed: *const u8 = ptr::null();
         let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key1);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut ver_key: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key, &mut ver_key);
+        let mut sign_key2: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let message_v = vec![1, 2, 3, 4, 5];
+        let message = message_v.as_ptr();
+        let message_len = message_v.len();
+
+        let mut signature1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key1, &mut signature1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut signature2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key2, &mut signature2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let signatures = [signature1, signature2];
+
+        let mut multi_sig: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_multi_signature_new(signatures.as_ptr(), signatures.len(), &mut multi_sig);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut ver_key1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key1, &mut ver_key1);
         assert_eq!(err_code, ErrorCode::Success);
-        assert!(!ver_key.is_null());
+
+        let mut ver_key2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key2, &mut ver_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let ver_keys = [ver_key1, ver_key2];
+        let mut valid = false;
+
+        let err_code = indy_crypto_bls_verify_multi_sig(multi_sig,
+                                                        message, message_len,
+                                                        ver_keys.as_ptr(), ver_keys.len(),
+                                                        gen,
+                                                        &mut valid);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(valid);
 
         let err_code = indy_crypto_bls_generator_free(gen);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        let err_code = indy_crypto_bls_sign_key_free(sign_key1);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_ver_key_free(ver_key);
+        let err_code = indy_crypto_bls_sign_key_free(sign_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_ver_key_free(ver_key1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_ver_key_free(ver_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_signature_free(signature1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_signature_free(signature2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_multi_signature_free(multi_sig);
         assert_eq!(err_code, ErrorCode::Success);
     }
 
     #[test]
-    fn indy_crypto_bls_ver_key_as_bytes_works() {
+    fn indy_crypto_bls_multi_ver_key_from_bytes_works() {
         let mut gen: *const c_void = ptr::null();
         let err_code = indy_crypto_bls_generator_new(&mut gen);
         assert_eq!(err_code, ErrorCode::Success);
 
         let mut sign_key: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        let err_code = indy_crypto_bls_sign_key_new(ptr::null(), 0, &mut sign_key);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut ver_key: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key, &mut ver_key);
+        let mut ver_key1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key, &mut ver_key1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut ver_key2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key, &mut ver_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let ver_keys = [ver_key1, ver_key2];
+
+        let mut multi_ver_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_multi_ver_key_new(ver_keys.as_ptr(), ver_keys.len(), &mut multi_ver_key);
         assert_eq!(err_code, ErrorCode::Success);
 
         let mut bytes: *const u8 = ptr::null();
         let mut bytes_len: usize = 0;
-        let err_code = indy_crypto_bls_ver_key_as_bytes(ver_key, &mut bytes, &mut bytes_len);
+        let err_code = indy_crypto_bls_multi_ver_key_as_bytes(multi_ver_key, &mut bytes, &mut bytes_len);
         assert_eq!(err_code, ErrorCode::Success);
-        assert!(!bytes.is_null());
-        assert!(bytes_len > 0);
+
+        let mut multi_ver_key2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_multi_ver_key_from_bytes(bytes, bytes_len, &mut multi_ver_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_multi_ver_key_remove(multi_ver_key2, ver_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_multi_ver_key_remove(multi_ver_key2, ver_key1);
+        assert_eq!(err_code, ErrorCode::CommonInvalidStructure);
 
         let err_code = indy_crypto_bls_generator_free(gen);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_ver_key_free(ver_key1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_ver_key_free(ver_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_multi_ver_key_free(multi_ver_key);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_ver_key_free(ver_key);
+        let err_code = indy_crypto_bls_multi_ver_key_free(multi_ver_key2);
         assert_eq!(err_code, ErrorCode::Success);
     }
 
     #[test]
-    fn indy_crypto_bls_ver_key_from_bytes_works() {
+    fn indy_crypto_bls_verify_multi_sig_for_multi_ver_key_works() {
         let mut gen: *const c_void = ptr::null();
         let err_code = indy_crypto_bls_generator_new(&mut gen);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut sign_key: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        let mut sign_key1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign_key_new(ptr::null(), 0, &mut sign_key1);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut ver_key: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key, &mut ver_key);
+        let mut sign_key2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign_key_new(ptr::null(), 0, &mut sign_key2);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut bytes: *const u8 = ptr::null();
-        let mut bytes_len: usize = 0;
-        let err_code = indy_crypto_bls_ver_key_as_bytes(ver_key, &mut bytes, &mut bytes_len);
+        let mut ver_key1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key1, &mut ver_key1);
         assert_eq!(err_code, ErrorCode::Success);
-        assert!(!bytes.is_null());
-        assert!(bytes_len > 0);
 
         let mut ver_key2: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_ver_key_from_bytes(bytes, bytes_len, &mut ver_key2);
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key2, &mut ver_key2);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_generator_free(gen);
-        assert_eq!(err_code, ErrorCode::Success);
+        let message_v = vec![1, 2, 3, 4, 5];
+        let message = message_v.as_ptr();
+        let message_len = message_v.len();
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        let mut signature1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key1, &mut signature1);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_ver_key_free(ver_key);
+        let mut signature2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key2, &mut signature2);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_ver_key_free(ver_key2);
+        let signatures = [signature1, signature2];
+
+        let mut multi_sig: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_multi_signature_new(signatures.as_ptr(), signatures.len(), &mut multi_sig);
         assert_eq!(err_code, ErrorCode::Success);
-    }
 
-    #[test]
-    fn indy_crypto_bls_ver_key_free_works() {
-        let mut gen: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        let ver_keys = [ver_key1];
+
+        let mut multi_ver_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_multi_ver_key_new(ver_keys.as_ptr(), ver_keys.len(), &mut multi_ver_key);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut sign_key: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        let err_code = indy_crypto_bls_multi_ver_key_add(multi_ver_key, ver_key2);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut ver_key: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key, &mut ver_key);
+        let mut valid = false;
+        let err_code = indy_crypto_bls_verify_multi_sig_for_multi_ver_key(multi_sig,
+                                                                          message, message_len,
+                                                                          multi_ver_key,
+                                                                          gen,
+                                                                          &mut valid);
         assert_eq!(err_code, ErrorCode::Success);
+        assert!(valid);
 
         let err_code = indy_crypto_bls_generator_free(gen);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        let err_code = indy_crypto_bls_sign_key_free(sign_key1);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_ver_key_free(ver_key);
+        let err_code = indy_crypto_bls_sign_key_free(sign_key2);
         assert_eq!(err_code, ErrorCode::Success);
-    }
 
-    #[test]
-    fn indy_crypto_bls_signature_as_bytes_works() {
-        let mut sign_key: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        let err_code = indy_crypto_bls_ver_key_free(ver_key1);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let message_v = vec![1, 2, 3, 4, 5];
-        let message = message_v.as_ptr();
-        let message_len = message_v.len();
+        let err_code = indy_crypto_bls_ver_key_free(ver_key2);
+        assert_eq!(err_code, ErrorCode::Success);
 
-        let mut signature: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_sign(message, message_len, sign_key, &mut signature);
+        let err_code = indy_crypto_bls_signature_free(signature1);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut bytes: *const u8 = ptr::null();
-        let mut bytes_len: usize = 0;
-        let err_code = indy_crypto_bls_signature_as_bytes(signature, &mut bytes, &mut bytes_len);
+        let err_code = indy_crypto_bls_signature_free(signature2);
         assert_eq!(err_code, ErrorCode::Success);
-        assert!(!bytes.is_null());
-        assert!(bytes_len > 0);
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        let err_code = indy_crypto_bls_multi_signature_free(multi_sig);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_signature_free(signature);
+        let err_code = indy_crypto_bls_multi_ver_key_free(multi_ver_key);
         assert_eq!(err_code, ErrorCode::Success);
     }
 
     #[test]
-    fn indy_crypto_bls_signature_from_bytes_works() {
+    fn indy_crypto_bls_bitmap_multi_signature_from_bytes_works() {
         let mut sign_key: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        let err_code = indy_crypto_bls_sign_key_new(ptr::null(), 0, &mut sign_key);
         assert_eq!(err_code, ErrorCode::Success);
 
         let message_v = vec![1, 2, 3, 4, 5];
@@ -1003,15 +3980,38 @@ mod tests {
         let err_code = indy_crypto_bls_sign(message, message_len, sign_key, &mut signature);
         assert_eq!(err_code, ErrorCode::Success);
 
+        let signatures = [signature];
+        let indexes = [9u32];
+
+        let mut multi_sig: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_bitmap_multi_signature_new(signatures.as_ptr(), signatures.len(),
+                                                                  indexes.as_ptr(), indexes.len(),
+                                                                  10,
+                                                                  &mut multi_sig);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut bitmap: *const u8 = ptr::null();
+        let mut bitmap_len: usize = 0;
+        let err_code = indy_crypto_bls_bitmap_multi_signature_as_bitmap(multi_sig, &mut bitmap, &mut bitmap_len);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert_eq!(&[0, 0b0000_0010], unsafe { slice::from_raw_parts(bitmap, bitmap_len) });
+
         let mut bytes: *const u8 = ptr::null();
         let mut bytes_len: usize = 0;
-        let err_code = indy_crypto_bls_signature_as_bytes(signature, &mut bytes, &mut bytes_len);
+        let err_code = indy_crypto_bls_bitmap_multi_signature_as_bytes(multi_sig, &mut bytes, &mut bytes_len);
         assert_eq!(err_code, ErrorCode::Success);
-        assert!(!bytes.is_null());
-        assert!(bytes_len > 0);
 
-        let mut signature2: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_signature_from_bytes(bytes, bytes_len, &mut signature2);
+        let mut multi_sig2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_bitmap_multi_signature_from_bytes(bytes, bytes_len, &mut multi_sig2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut compressed_bytes: *const u8 = ptr::null();
+        let mut compressed_bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_bitmap_multi_signature_as_compressed_bytes(multi_sig, &mut compressed_bytes, &mut compressed_bytes_len);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut multi_sig3: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_bitmap_multi_signature_from_compressed_bytes(compressed_bytes, compressed_bytes_len, &mut multi_sig3);
         assert_eq!(err_code, ErrorCode::Success);
 
         let err_code = indy_crypto_bls_sign_key_free(sign_key);
@@ -1020,45 +4020,44 @@ mod tests {
         let err_code = indy_crypto_bls_signature_free(signature);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_signature_free(signature2);
+        let err_code = indy_crypto_bls_bitmap_multi_signature_free(multi_sig);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_bitmap_multi_signature_free(multi_sig2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_bitmap_multi_signature_free(multi_sig3);
         assert_eq!(err_code, ErrorCode::Success);
     }
 
     #[test]
-    fn indy_crypto_bls_signature_free_works() {
-        let mut sign_key: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+    fn indy_crypto_bls_verify_with_quorum_works() {
+        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let message_v = vec![1, 2, 3, 4, 5];
-        let message = message_v.as_ptr();
-        let message_len = message_v.len();
+        let mut sign_key1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign_key_new(ptr::null(), 0, &mut sign_key1);
+        assert_eq!(err_code, ErrorCode::Success);
 
-        let mut signature: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_sign(message, message_len, sign_key, &mut signature);
+        let mut sign_key2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign_key_new(ptr::null(), 0, &mut sign_key2);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        let mut sign_key3: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign_key_new(ptr::null(), 0, &mut sign_key3);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_signature_free(signature);
+        let mut ver_key1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key1, &mut ver_key1);
         assert_eq!(err_code, ErrorCode::Success);
-    }
 
-    #[test]
-    fn indy_crypto_bls_multi_signature_new_works() {
-        let mut sign_key1: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key1);
+        let mut ver_key2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key2, &mut ver_key2);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut sign_key2: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key2);
+        let mut ver_key3: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key3, &mut ver_key3);
         assert_eq!(err_code, ErrorCode::Success);
 
         let message_v = vec![1, 2, 3, 4, 5];
@@ -1069,16 +4068,60 @@ mod tests {
         let err_code = indy_crypto_bls_sign(message, message_len, sign_key1, &mut signature1);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut signature2: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_sign(message, message_len, sign_key2, &mut signature2);
+        let mut signature3: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key3, &mut signature3);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let signatures = [signature1, signature2];
+        let signatures = [signature1];
+        let indexes = [0u32];
 
         let mut multi_sig: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_multi_signature_new(signatures.as_ptr(), signatures.len(), &mut multi_sig);
+        let err_code = indy_crypto_bls_bitmap_multi_signature_new(signatures.as_ptr(), signatures.len(),
+                                                                  indexes.as_ptr(), indexes.len(),
+                                                                  3,
+                                                                  &mut multi_sig);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let signatures = [signature3];
+        let indexes = [2u32];
+
+        let mut multi_sig2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_bitmap_multi_signature_new(signatures.as_ptr(), signatures.len(),
+                                                                  indexes.as_ptr(), indexes.len(),
+                                                                  3,
+                                                                  &mut multi_sig2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let ver_keys = [ver_key1, ver_key2, ver_key3];
+
+        let mut valid = true;
+        let err_code = indy_crypto_bls_verify_with_quorum(multi_sig,
+                                                          message, message_len,
+                                                          ver_keys.as_ptr(), ver_keys.len(),
+                                                          2,
+                                                          gen,
+                                                          &mut valid);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!valid);
+
+        let err_code = indy_crypto_bls_bitmap_multi_signature_merge(multi_sig, multi_sig2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_bitmap_multi_signature_add(multi_sig, signature3, 2);
+        assert_eq!(err_code, ErrorCode::CommonInvalidStructure);
+
+        let mut valid = false;
+        let err_code = indy_crypto_bls_verify_with_quorum(multi_sig,
+                                                          message, message_len,
+                                                          ver_keys.as_ptr(), ver_keys.len(),
+                                                          2,
+                                                          gen,
+                                                          &mut valid);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(valid);
+
+        let err_code = indy_crypto_bls_generator_free(gen);
         assert_eq!(err_code, ErrorCode::Success);
-        assert!(!multi_sig.is_null());
 
         let err_code = indy_crypto_bls_sign_key_free(sign_key1);
         assert_eq!(err_code, ErrorCode::Success);
@@ -1086,18 +4129,37 @@ mod tests {
         let err_code = indy_crypto_bls_sign_key_free(sign_key2);
         assert_eq!(err_code, ErrorCode::Success);
 
+        let err_code = indy_crypto_bls_sign_key_free(sign_key3);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_ver_key_free(ver_key1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_ver_key_free(ver_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_ver_key_free(ver_key3);
+        assert_eq!(err_code, ErrorCode::Success);
+
         let err_code = indy_crypto_bls_signature_free(signature1);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_signature_free(signature2);
+        let err_code = indy_crypto_bls_signature_free(signature3);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_multi_signature_free(multi_sig);
+        let err_code = indy_crypto_bls_bitmap_multi_signature_free(multi_sig);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_bitmap_multi_signature_free(multi_sig2);
         assert_eq!(err_code, ErrorCode::Success);
     }
 
     #[test]
-    fn indy_crypto_bls_multi_signature_as_bytes_works() {
+    fn indy_crypto_bls_verify_multi_sig_works_for_invalid() {
+        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
         let mut sign_key1: *const c_void = ptr::null();
         let seed: *const u8 = ptr::null();
         let seed_len: usize = 0;
@@ -1110,6 +4172,12 @@ mod tests {
         let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key2);
         assert_eq!(err_code, ErrorCode::Success);
 
+        let mut sign_key3: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key3);
+        assert_eq!(err_code, ErrorCode::Success);
+
         let message_v = vec![1, 2, 3, 4, 5];
         let message = message_v.as_ptr();
         let message_len = message_v.len();
@@ -1127,145 +4195,137 @@ mod tests {
         let mut multi_sig: *const c_void = ptr::null();
         let err_code = indy_crypto_bls_multi_signature_new(signatures.as_ptr(), signatures.len(), &mut multi_sig);
         assert_eq!(err_code, ErrorCode::Success);
-        assert!(!multi_sig.is_null());
 
-        let mut bytes: *const u8 = ptr::null();
-        let mut bytes_len: usize = 0;
-        let err_code = indy_crypto_bls_multi_signature_as_bytes(multi_sig, &mut bytes, &mut bytes_len);
+        let mut ver_key1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key1, &mut ver_key1);
         assert_eq!(err_code, ErrorCode::Success);
-        assert!(!bytes.is_null());
-        assert!(bytes_len > 0);
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key1);
+        let mut ver_key2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key3, &mut ver_key2);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key2);
-        assert_eq!(err_code, ErrorCode::Success);
+        let ver_keys = [ver_key1, ver_key2];
+        let mut valid = false;
 
-        let err_code = indy_crypto_bls_signature_free(signature1);
+        let err_code = indy_crypto_bls_verify_multi_sig(multi_sig,
+                                                        message, message_len,
+                                                        ver_keys.as_ptr(), ver_keys.len(),
+                                                        gen,
+                                                        &mut valid);
         assert_eq!(err_code, ErrorCode::Success);
+        assert!(!valid);
 
-        let err_code = indy_crypto_bls_signature_free(signature2);
+        let err_code = indy_crypto_bls_generator_free(gen);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_multi_signature_free(multi_sig);
+        let err_code = indy_crypto_bls_sign_key_free(sign_key1);
         assert_eq!(err_code, ErrorCode::Success);
-    }
 
-    #[test]
-    fn indy_crypto_bls_multi_signature_from_bytes_works() {
-        let mut sign_key1: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key1);
+        let err_code = indy_crypto_bls_sign_key_free(sign_key2);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut sign_key2: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key2);
+        let err_code = indy_crypto_bls_sign_key_free(sign_key3);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let message_v = vec![1, 2, 3, 4, 5];
-        let message = message_v.as_ptr();
-        let message_len = message_v.len();
-
-        let mut signature1: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_sign(message, message_len, sign_key1, &mut signature1);
+        let err_code = indy_crypto_bls_ver_key_free(ver_key1);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut signature2: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_sign(message, message_len, sign_key2, &mut signature2);
+        let err_code = indy_crypto_bls_ver_key_free(ver_key2);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let signatures = [signature1, signature2];
+        let err_code = indy_crypto_bls_signature_free(signature1);
+        assert_eq!(err_code, ErrorCode::Success);
 
-        let mut multi_sig: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_multi_signature_new(signatures.as_ptr(), signatures.len(), &mut multi_sig);
+        let err_code = indy_crypto_bls_signature_free(signature2);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut bytes: *const u8 = ptr::null();
-        let mut bytes_len: usize = 0;
-        let err_code = indy_crypto_bls_multi_signature_as_bytes(multi_sig, &mut bytes, &mut bytes_len);
+        let err_code = indy_crypto_bls_multi_signature_free(multi_sig);
         assert_eq!(err_code, ErrorCode::Success);
-        assert!(!bytes.is_null());
-        assert!(bytes_len > 0);
+    }
 
-        let mut multi_sig2: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_multi_signature_from_bytes(bytes, bytes_len, &mut multi_sig2);
+    #[test]
+    fn indy_crypto_bls_pop_new_works() {
+        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut sign_key: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
         assert_eq!(err_code, ErrorCode::Success);
-        assert!(!multi_sig2.is_null());
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key1);
+        let mut ver_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key, &mut ver_key);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key2);
+        let mut pop: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_pop_new(ver_key, sign_key, &mut pop);
         assert_eq!(err_code, ErrorCode::Success);
+        assert!(!pop.is_null());
 
-        let err_code = indy_crypto_bls_signature_free(signature1);
+        let err_code = indy_crypto_bls_generator_free(gen);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_signature_free(signature2);
+        let err_code = indy_crypto_bls_sign_key_free(sign_key);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_multi_signature_free(multi_sig);
+        let err_code = indy_crypto_bls_ver_key_free(ver_key);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_multi_signature_free(multi_sig2);
+        let err_code = indy_crypto_bls_pop_free(pop);
         assert_eq!(err_code, ErrorCode::Success);
     }
 
     #[test]
-    fn indy_crypto_bls_multi_signature_free_works() {
-        let mut sign_key1: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key1);
+    fn indy_crypto_bls_pop_from_bytes_works() {
+        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut sign_key2: *const c_void = ptr::null();
+        let mut sign_key: *const c_void = ptr::null();
         let seed: *const u8 = ptr::null();
         let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key2);
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let message_v = vec![1, 2, 3, 4, 5];
-        let message = message_v.as_ptr();
-        let message_len = message_v.len();
-
-        let mut signature1: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_sign(message, message_len, sign_key1, &mut signature1);
+        let mut ver_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key, &mut ver_key);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut signature2: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_sign(message, message_len, sign_key2, &mut signature2);
+        let mut pop: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_pop_new(ver_key, sign_key, &mut pop);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let signatures = [signature1, signature2];
+        let mut bytes: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_pop_as_bytes(pop, &mut bytes, &mut bytes_len);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!bytes.is_null());
+        assert!(bytes_len > 0);
 
-        let mut multi_sig: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_multi_signature_new(signatures.as_ptr(), signatures.len(), &mut multi_sig);
+        let mut pop2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_pop_from_bytes(bytes, bytes_len, &mut pop2);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key1);
+        let err_code = indy_crypto_bls_generator_free(gen);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key2);
+        let err_code = indy_crypto_bls_sign_key_free(sign_key);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_signature_free(signature1);
+        let err_code = indy_crypto_bls_ver_key_free(ver_key);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_signature_free(signature2);
+        let err_code = indy_crypto_bls_pop_free(pop);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_multi_signature_free(multi_sig);
+        let err_code = indy_crypto_bls_pop_free(pop2);
         assert_eq!(err_code, ErrorCode::Success);
     }
 
-
     #[test]
-    fn indy_crypto_bsl_verify_works() {
+    fn indy_crypto_bls_verify_pop_works() {
         let mut gen: *const c_void = ptr::null();
         let err_code = indy_crypto_bls_generator_new(&mut gen);
         assert_eq!(err_code, ErrorCode::Success);
@@ -1280,20 +4340,12 @@ mod tests {
         let err_code = indy_crypto_bls_ver_key_new(gen, sign_key, &mut ver_key);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let message_v = vec![1, 2, 3, 4, 5];
-        let message = message_v.as_ptr();
-        let message_len = message_v.len();
-
-        let mut signature: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_sign(message, message_len, sign_key, &mut signature);
+        let mut pop: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_pop_new(ver_key, sign_key, &mut pop);
         assert_eq!(err_code, ErrorCode::Success);
 
         let mut valid = false;
-
-        let err_code = indy_crypto_bsl_verify(signature,
-                                              message, message_len,
-                                              ver_key,
-                                              gen, &mut valid);
+        let err_code = indy_crypto_bls_verify_pop(pop, ver_key, gen, &mut valid);
         assert_eq!(err_code, ErrorCode::Success);
         assert!(valid);
 
@@ -1306,20 +4358,20 @@ mod tests {
         let err_code = indy_crypto_bls_ver_key_free(ver_key);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_signature_free(signature);
+        let err_code = indy_crypto_bls_pop_free(pop);
         assert_eq!(err_code, ErrorCode::Success);
     }
 
     #[test]
-    fn indy_crypto_bsl_verify_works_for_invalid() {
+    fn indy_crypto_bls_verify_batch_works() {
         let mut gen: *const c_void = ptr::null();
         let err_code = indy_crypto_bls_generator_new(&mut gen);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut sign_key: *const c_void = ptr::null();
+        let mut sign_key1: *const c_void = ptr::null();
         let seed: *const u8 = ptr::null();
         let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key1);
         assert_eq!(err_code, ErrorCode::Success);
 
         let mut sign_key2: *const c_void = ptr::null();
@@ -1328,45 +4380,120 @@ mod tests {
         let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key2);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut ver_key: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key2, &mut ver_key);
+        let message1_v = vec![1, 2, 3, 4, 5];
+        let message2_v = vec![6, 7, 8, 9, 10, 11];
+
+        let mut signature1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message1_v.as_ptr(), message1_v.len(), sign_key1, &mut signature1);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let message_v = vec![1, 2, 3, 4, 5];
-        let message = message_v.as_ptr();
-        let message_len = message_v.len();
+        let mut signature2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message2_v.as_ptr(), message2_v.len(), sign_key2, &mut signature2);
+        assert_eq!(err_code, ErrorCode::Success);
 
-        let mut signature: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_sign(message, message_len, sign_key, &mut signature);
+        let mut ver_key1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key1, &mut ver_key1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut ver_key2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key2, &mut ver_key2);
         assert_eq!(err_code, ErrorCode::Success);
 
+        let signatures = [signature1, signature2];
+        let messages = [message1_v.as_ptr(), message2_v.as_ptr()];
+        let message_lens = [message1_v.len(), message2_v.len()];
+        let ver_keys = [ver_key1, ver_key2];
         let mut valid = false;
+        let mut invalid_idx: usize = 0;
 
-        let err_code = indy_crypto_bsl_verify(signature,
-                                              message, message_len,
-                                              ver_key,
-                                              gen, &mut valid);
+        let err_code = indy_crypto_bls_verify_batch(signatures.as_ptr(), signatures.len(),
+                                                    messages.as_ptr(), message_lens.as_ptr(), messages.len(),
+                                                    ver_keys.as_ptr(), ver_keys.len(),
+                                                    gen,
+                                                    &mut valid,
+                                                    &mut invalid_idx);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(valid);
+
+        let ver_keys = [ver_key2, ver_key2];
+
+        let err_code = indy_crypto_bls_verify_batch(signatures.as_ptr(), signatures.len(),
+                                                    messages.as_ptr(), message_lens.as_ptr(), messages.len(),
+                                                    ver_keys.as_ptr(), ver_keys.len(),
+                                                    gen,
+                                                    &mut valid,
+                                                    &mut invalid_idx);
         assert_eq!(err_code, ErrorCode::Success);
         assert!(!valid);
+        assert_eq!(invalid_idx, 0);
 
         let err_code = indy_crypto_bls_generator_free(gen);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        let err_code = indy_crypto_bls_sign_key_free(sign_key1);
         assert_eq!(err_code, ErrorCode::Success);
 
         let err_code = indy_crypto_bls_sign_key_free(sign_key2);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_ver_key_free(ver_key);
+        let err_code = indy_crypto_bls_ver_key_free(ver_key1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_ver_key_free(ver_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_signature_free(signature1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_signature_free(signature2);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_aggregate_signature_from_bytes_works() {
+        let mut sign_key: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let message_v = vec![1, 2, 3, 4, 5];
+
+        let mut signature: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message_v.as_ptr(), message_v.len(), sign_key, &mut signature);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let signatures = [signature];
+
+        let mut aggregate_sig: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_aggregate_signature_new(signatures.as_ptr(), signatures.len(), &mut aggregate_sig);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut bytes: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_aggregate_signature_as_bytes(aggregate_sig, &mut bytes, &mut bytes_len);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(bytes_len > 0);
+
+        let mut aggregate_sig2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_aggregate_signature_from_bytes(bytes, bytes_len, &mut aggregate_sig2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key);
         assert_eq!(err_code, ErrorCode::Success);
 
         let err_code = indy_crypto_bls_signature_free(signature);
         assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_aggregate_signature_free(aggregate_sig);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_aggregate_signature_free(aggregate_sig2);
+        assert_eq!(err_code, ErrorCode::Success);
     }
 
     #[test]
-    fn indy_crypto_bls_verify_multi_sig_works() {
+    fn indy_crypto_bls_verify_aggregate_works() {
         let mut gen: *const c_void = ptr::null();
         let err_code = indy_crypto_bls_generator_new(&mut gen);
         assert_eq!(err_code, ErrorCode::Success);
@@ -1383,22 +4510,21 @@ mod tests {
         let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key2);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let message_v = vec![1, 2, 3, 4, 5];
-        let message = message_v.as_ptr();
-        let message_len = message_v.len();
+        let message1_v = vec![1, 2, 3, 4, 5];
+        let message2_v = vec![6, 7, 8, 9, 10, 11];
 
         let mut signature1: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_sign(message, message_len, sign_key1, &mut signature1);
+        let err_code = indy_crypto_bls_sign(message1_v.as_ptr(), message1_v.len(), sign_key1, &mut signature1);
         assert_eq!(err_code, ErrorCode::Success);
 
         let mut signature2: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_sign(message, message_len, sign_key2, &mut signature2);
+        let err_code = indy_crypto_bls_sign(message2_v.as_ptr(), message2_v.len(), sign_key2, &mut signature2);
         assert_eq!(err_code, ErrorCode::Success);
 
         let signatures = [signature1, signature2];
 
-        let mut multi_sig: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_multi_signature_new(signatures.as_ptr(), signatures.len(), &mut multi_sig);
+        let mut aggregate_sig: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_aggregate_signature_new(signatures.as_ptr(), signatures.len(), &mut aggregate_sig);
         assert_eq!(err_code, ErrorCode::Success);
 
         let mut ver_key1: *const c_void = ptr::null();
@@ -1409,17 +4535,29 @@ mod tests {
         let err_code = indy_crypto_bls_ver_key_new(gen, sign_key2, &mut ver_key2);
         assert_eq!(err_code, ErrorCode::Success);
 
+        let messages = [message1_v.as_ptr(), message2_v.as_ptr()];
+        let message_lens = [message1_v.len(), message2_v.len()];
         let ver_keys = [ver_key1, ver_key2];
         let mut valid = false;
 
-        let err_code = indy_crypto_bls_verify_multi_sig(multi_sig,
-                                                        message, message_len,
+        let err_code = indy_crypto_bls_verify_aggregate(aggregate_sig,
+                                                        messages.as_ptr(), message_lens.as_ptr(), messages.len(),
                                                         ver_keys.as_ptr(), ver_keys.len(),
                                                         gen,
                                                         &mut valid);
         assert_eq!(err_code, ErrorCode::Success);
         assert!(valid);
 
+        let messages = [message1_v.as_ptr(), message1_v.as_ptr()];
+        let message_lens = [message1_v.len(), message1_v.len()];
+
+        let err_code = indy_crypto_bls_verify_aggregate(aggregate_sig,
+                                                        messages.as_ptr(), message_lens.as_ptr(), messages.len(),
+                                                        ver_keys.as_ptr(), ver_keys.len(),
+                                                        gen,
+                                                        &mut valid);
+        assert_eq!(err_code, ErrorCode::CommonInvalidStructure);
+
         let err_code = indy_crypto_bls_generator_free(gen);
         assert_eq!(err_code, ErrorCode::Success);
 
@@ -1441,96 +4579,177 @@ mod tests {
         let err_code = indy_crypto_bls_signature_free(signature2);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_multi_signature_free(multi_sig);
+        let err_code = indy_crypto_bls_aggregate_signature_free(aggregate_sig);
         assert_eq!(err_code, ErrorCode::Success);
     }
 
-    #[test]
-    fn indy_crypto_bls_verify_multi_sig_works_for_invalid() {
+    fn _curve_sign_verify_works(curve: u32, ver_key_len: usize, signature_len: usize) {
         let mut gen: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        let err_code = indy_crypto_bls_curve_generator_standard(curve, &mut gen);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut sign_key1: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key1);
+        let mut sign_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_curve_sign_key_new(curve, ptr::null(), 0, &mut sign_key);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut sign_key2: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key2);
+        let mut ver_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_curve_ver_key_new(curve, gen, sign_key, &mut ver_key);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut sign_key3: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key3);
+        let mut bytes: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_curve_ver_key_as_compressed_bytes(curve, ver_key, &mut bytes, &mut bytes_len);
         assert_eq!(err_code, ErrorCode::Success);
+        assert_eq!(ver_key_len, bytes_len);
 
-        let message_v = vec![1, 2, 3, 4, 5];
-        let message = message_v.as_ptr();
-        let message_len = message_v.len();
+        let message = vec![1, 2, 3, 4, 5];
+        let mut signature: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_curve_sign(curve, message.as_ptr(), message.len(), sign_key, &mut signature);
+        assert_eq!(err_code, ErrorCode::Success);
 
-        let mut signature1: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_sign(message, message_len, sign_key1, &mut signature1);
+        let err_code = indy_crypto_bls_curve_signature_as_compressed_bytes(curve, signature, &mut bytes, &mut bytes_len);
         assert_eq!(err_code, ErrorCode::Success);
+        assert_eq!(signature_len, bytes_len);
 
-        let mut signature2: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_sign(message, message_len, sign_key2, &mut signature2);
+        let mut signature_copy: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_curve_signature_from_compressed_bytes(curve, bytes, bytes_len, &mut signature_copy);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let signatures = [signature1, signature2];
+        let mut valid = false;
+        let err_code = indy_crypto_bls_curve_verify(curve, signature_copy, message.as_ptr(), message.len(), ver_key, gen, &mut valid);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(valid);
 
+        let signatures = [signature, signature_copy];
         let mut multi_sig: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_multi_signature_new(signatures.as_ptr(), signatures.len(), &mut multi_sig);
+        let err_code = indy_crypto_bls_curve_multi_signature_new(curve, signatures.as_ptr(), signatures.len(), &mut multi_sig);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut ver_key1: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key1, &mut ver_key1);
+        let ver_keys = [ver_key, ver_key];
+        let mut valid = false;
+        let err_code = indy_crypto_bls_curve_verify_multi_sig(curve, multi_sig, message.as_ptr(), message.len(),
+                                                              ver_keys.as_ptr(), ver_keys.len(), gen, &mut valid);
         assert_eq!(err_code, ErrorCode::Success);
+        assert!(valid);
 
-        let mut ver_key2: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key3, &mut ver_key2);
+        assert_eq!(ErrorCode::Success, indy_crypto_bls_curve_multi_signature_free(curve, multi_sig));
+        assert_eq!(ErrorCode::Success, indy_crypto_bls_curve_signature_free(curve, signature_copy));
+        assert_eq!(ErrorCode::Success, indy_crypto_bls_curve_signature_free(curve, signature));
+        assert_eq!(ErrorCode::Success, indy_crypto_bls_curve_ver_key_free(curve, ver_key));
+        assert_eq!(ErrorCode::Success, indy_crypto_bls_curve_sign_key_free(curve, sign_key));
+        assert_eq!(ErrorCode::Success, indy_crypto_bls_curve_generator_free(curve, gen));
+    }
+
+    #[test]
+    #[cfg(feature = "pair_amcl")]
+    fn indy_crypto_bls_curve_sign_verify_works_for_bn254() {
+        _curve_sign_verify_works(BLS_CURVE_BN254, 64, 32);
+    }
+
+    #[test]
+    #[cfg(feature = "pair_bls12_381")]
+    fn indy_crypto_bls_curve_sign_verify_works_for_bls12_381() {
+        _curve_sign_verify_works(BLS_CURVE_BLS12_381, 96, 48);
+    }
+
+    #[test]
+    #[cfg(feature = "pair_amcl")]
+    fn indy_crypto_bls_curve_sign_works_for_verify_of_default_curve() {
+        let mut sign_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign_key_new(ptr::null(), 0, &mut sign_key);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let ver_keys = [ver_key1, ver_key2];
-        let mut valid = false;
+        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_verify_multi_sig(multi_sig,
-                                                        message, message_len,
-                                                        ver_keys.as_ptr(), ver_keys.len(),
-                                                        gen,
-                                                        &mut valid);
+        let mut ver_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key, &mut ver_key);
         assert_eq!(err_code, ErrorCode::Success);
-        assert!(!valid);
 
-        let err_code = indy_crypto_bls_generator_free(gen);
+        let mut bytes: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_as_bytes(sign_key, &mut bytes, &mut bytes_len);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key1);
+        let mut curve_sign_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_curve_sign_key_from_bytes(BLS_CURVE_BN254, bytes, bytes_len, &mut curve_sign_key);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key2);
+        let message = vec![1, 2, 3, 4, 5];
+        let mut curve_signature: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_curve_sign(BLS_CURVE_BN254, message.as_ptr(), message.len(), curve_sign_key, &mut curve_signature);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key3);
+        let err_code = indy_crypto_bls_curve_signature_as_compressed_bytes(BLS_CURVE_BN254, curve_signature, &mut bytes, &mut bytes_len);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_ver_key_free(ver_key1);
+        let mut signature: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_signature_from_compressed_bytes(bytes, bytes_len, &mut signature);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_ver_key_free(ver_key2);
+        let mut valid = false;
+        let err_code = indy_crypto_bsl_verify(signature, message.as_ptr(), message.len(), ver_key, gen, &mut valid);
         assert_eq!(err_code, ErrorCode::Success);
+        assert!(valid);
 
-        let err_code = indy_crypto_bls_signature_free(signature1);
+        assert_eq!(ErrorCode::Success, indy_crypto_bls_signature_free(signature));
+        assert_eq!(ErrorCode::Success, indy_crypto_bls_curve_signature_free(BLS_CURVE_BN254, curve_signature));
+        assert_eq!(ErrorCode::Success, indy_crypto_bls_curve_sign_key_free(BLS_CURVE_BN254, curve_sign_key));
+        assert_eq!(ErrorCode::Success, indy_crypto_bls_ver_key_free(ver_key));
+        assert_eq!(ErrorCode::Success, indy_crypto_bls_generator_free(gen));
+        assert_eq!(ErrorCode::Success, indy_crypto_bls_sign_key_free(sign_key));
+    }
+
+    #[test]
+    fn indy_crypto_bls_curve_generator_new_works_for_unknown_curve() {
+        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_curve_generator_new(7, &mut gen);
+        assert_eq!(err_code, ErrorCode::CommonInvalidParam1);
+        assert!(gen.is_null());
+    }
+
+    #[test]
+    #[cfg(all(feature = "pair_amcl", feature = "pair_bls12_381"))]
+    fn indy_crypto_bls_curve_works_for_instances_of_other_curve() {
+        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_curve_generator_standard(BLS_CURVE_BN254, &mut gen);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_signature_free(signature2);
+        let mut sign_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_curve_sign_key_new(BLS_CURVE_BN254, ptr::null(), 0, &mut sign_key);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_multi_signature_free(multi_sig);
+        let mut ver_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_curve_ver_key_new(BLS_CURVE_BLS12_381, gen, sign_key, &mut ver_key);
+        assert_eq!(err_code, ErrorCode::CommonInvalidParam2);
+        assert!(ver_key.is_null());
+
+        let mut bls_gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_curve_generator_standard(BLS_CURVE_BLS12_381, &mut bls_gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_curve_ver_key_new(BLS_CURVE_BLS12_381, bls_gen, sign_key, &mut ver_key);
+        assert_eq!(err_code, ErrorCode::CommonInvalidParam3);
+        assert!(ver_key.is_null());
+
+        let message = vec![1, 2, 3, 4, 5];
+        let mut signature: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_curve_sign(BLS_CURVE_BN254, message.as_ptr(), message.len(), sign_key, &mut signature);
         assert_eq!(err_code, ErrorCode::Success);
+
+        let signatures = [signature];
+        let mut multi_sig: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_curve_multi_signature_new(BLS_CURVE_BLS12_381, signatures.as_ptr(), signatures.len(), &mut multi_sig);
+        assert_eq!(err_code, ErrorCode::CommonInvalidParam2);
+        assert!(multi_sig.is_null());
+
+        assert_eq!(ErrorCode::CommonInvalidParam2, indy_crypto_bls_curve_signature_free(BLS_CURVE_BLS12_381, signature));
+        assert_eq!(ErrorCode::CommonInvalidParam2, indy_crypto_bls_curve_generator_free(BLS_CURVE_BN254, bls_gen));
+
+        assert_eq!(ErrorCode::Success, indy_crypto_bls_curve_signature_free(BLS_CURVE_BN254, signature));
+        assert_eq!(ErrorCode::Success, indy_crypto_bls_curve_generator_free(BLS_CURVE_BLS12_381, bls_gen));
+        assert_eq!(ErrorCode::Success, indy_crypto_bls_curve_sign_key_free(BLS_CURVE_BN254, sign_key));
+        assert_eq!(ErrorCode::Success, indy_crypto_bls_curve_generator_free(BLS_CURVE_BN254, gen));
     }
-}
\ No newline at end of file
+}
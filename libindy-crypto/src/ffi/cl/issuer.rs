@@ -5,6 +5,7 @@ use ffi::ErrorCode;
 use ffi::cl::{FFITailTake, FFITailPut, FFITailsAccessor};
 use utils::ctypes::CTypesUtils;
 use utils::json::{JsonEncodable, JsonDecodable};
+use utils::secret::Secret;
 use libc::c_char;
 
 use std::os::raw::c_void;
@@ -165,7 +166,7 @@ pub extern fn indy_crypto_cl_credential_private_key_to_json(credential_priv_key:
 
     let res = match credential_priv_key.to_json() {
         Ok(credential_priv_key_json) => {
-            trace!("indy_crypto_cl_credential_private_key_to_json: credential_priv_key_json: {:?}", credential_priv_key_json);
+            trace!("indy_crypto_cl_credential_private_key_to_json: credential_priv_key_json: {:?}", Secret(&credential_priv_key_json));
             unsafe {
                 let credential_priv_key_json = CTypesUtils::string_to_cstring(credential_priv_key_json);
                 *credential_priv_key_json_p = credential_priv_key_json.into_raw();
@@ -196,7 +197,7 @@ pub extern fn indy_crypto_cl_issuer_private_key_from_json(credential_priv_key_js
     check_useful_c_str!(credential_priv_key_json, ErrorCode::CommonInvalidParam1);
     check_useful_c_ptr!(credential_priv_key_p, ErrorCode::CommonInvalidParam2);
 
-    trace!("indy_crypto_cl_issuer_private_key_from_json: entity: credential_priv_key_json: {:?}", credential_priv_key_json);
+    trace!("indy_crypto_cl_issuer_private_key_from_json: entity: credential_priv_key_json: {:?}", Secret(&credential_priv_key_json));
 
     let res = match CredentialPrivateKey::from_json(&credential_priv_key_json) {
         Ok(credential_priv_key) => {
@@ -486,7 +487,7 @@ pub extern fn indy_crypto_cl_revocation_key_private_to_json(rev_key_priv: *const
 
     let res = match rev_key_priv.to_json() {
         Ok(rev_key_priv_json) => {
-            trace!("indy_crypto_cl_revocation_key_private_to_json: rev_key_priv_json: {:?}", rev_key_priv_json);
+            trace!("indy_crypto_cl_revocation_key_private_to_json: rev_key_priv_json: {:?}", Secret(&rev_key_priv_json));
             unsafe {
                 let rev_reg_def_priv_json = CTypesUtils::string_to_cstring(rev_key_priv_json);
                 *rev_key_priv_json_p = rev_reg_def_priv_json.into_raw();
@@ -518,7 +519,7 @@ pub extern fn indy_crypto_cl_revocation_key_private_from_json(rev_key_priv_json:
     check_useful_c_str!(rev_key_priv_json, ErrorCode::CommonInvalidParam1);
     check_useful_c_ptr!(rev_key_priv_p, ErrorCode::CommonInvalidParam2);
 
-    trace!("indy_crypto_cl_revocation_key_private_from_json: entity: rev_key_priv_json: {:?}", rev_key_priv_json);
+    trace!("indy_crypto_cl_revocation_key_private_from_json: entity: rev_key_priv_json: {:?}", Secret(&rev_key_priv_json));
 
     let res = match RevocationKeyPrivate::from_json(&rev_key_priv_json) {
         Ok(rev_key_priv) => {
@@ -660,7 +661,7 @@ pub extern fn indy_crypto_cl_revocation_tails_generator_to_json(rev_tails_genera
 
     let res = match rev_tails_generator.to_json() {
         Ok(rev_tails_generator_json) => {
-            trace!("indy_crypto_cl_revocation_tails_generator_to_json: rev_tails_generator_json: {:?}", rev_tails_generator_json);
+            trace!("indy_crypto_cl_revocation_tails_generator_to_json: rev_tails_generator_json: {:?}", Secret(&rev_tails_generator_json));
             unsafe {
                 let rev_tails_generator_json = CTypesUtils::string_to_cstring(rev_tails_generator_json);
                 *rev_tails_generator_json_p = rev_tails_generator_json.into_raw();
@@ -692,7 +693,7 @@ pub extern fn indy_crypto_cl_revocation_tails_generator_from_json(rev_tails_gene
     check_useful_c_str!(rev_tails_generator_json, ErrorCode::CommonInvalidParam1);
     check_useful_c_ptr!(rev_tails_generator_p, ErrorCode::CommonInvalidParam2);
 
-    trace!("indy_crypto_cl_revocation_tails_generator_from_json: entity: rev_tails_generator_json: {:?}", rev_tails_generator_json);
+    trace!("indy_crypto_cl_revocation_tails_generator_from_json: entity: rev_tails_generator_json: {:?}", Secret(&rev_tails_generator_json));
 
     let res = match RevocationTailsGenerator::from_json(&rev_tails_generator_json) {
         Ok(rev_tails_generator) => {
@@ -1,3 +1,4 @@
+use bn::BigNumber;
 use cl::*;
 use cl::issuer::Issuer;
 use cl::verifier::Verifier;
@@ -439,7 +440,7 @@ pub extern fn indy_crypto_cl_sub_proof_request_builder_add_revealed_attr(sub_pro
 /// # Arguments
 /// * `sub_proof_request_builder` - Reference that contains sub proof request builder instance pointer.
 /// * `attr_name` - Related attribute
-/// * `p_type` - Predicate type (Currently `GE` only).
+/// * `p_type` - Predicate type: `GE`, `LE`, `LT` or `GT`.
 /// * `value` - Requested value.
 #[no_mangle]
 pub extern fn indy_crypto_cl_sub_proof_request_builder_add_predicate(sub_proof_request_builder: *const c_void,
@@ -465,6 +466,70 @@ pub extern fn indy_crypto_cl_sub_proof_request_builder_add_predicate(sub_proof_r
     res
 }
 
+/// Adds predicate with arbitrary-precision bound to sub proof request.
+///
+/// # Arguments
+/// * `sub_proof_request_builder` - Reference that contains sub proof request builder instance pointer.
+/// * `attr_name` - Related attribute
+/// * `p_type` - Predicate type: `GE`, `LE`, `LT` or `GT`.
+/// * `dec_value` - Requested value. Decimal BigNum representation as null terminated string, may be negative.
+#[no_mangle]
+pub extern fn indy_crypto_cl_sub_proof_request_builder_add_big_predicate(sub_proof_request_builder: *const c_void,
+                                                                         attr_name: *const c_char,
+                                                                         p_type: *const c_char,
+                                                                         dec_value: *const c_char) -> ErrorCode {
+    trace!("indy_crypto_cl_sub_proof_request_builder_add_big_predicate: >>> sub_proof_request_builder: {:?}, attr_name: {:?}, p_type: {:?}, dec_value: {:?}",
+           sub_proof_request_builder, attr_name, p_type, dec_value);
+
+    check_useful_mut_c_reference!(sub_proof_request_builder, SubProofRequestBuilder, ErrorCode::CommonInvalidParam1);
+    check_useful_c_str!(attr_name, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(p_type, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(dec_value, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_crypto_cl_sub_proof_request_builder_add_big_predicate: entities: >>> sub_proof_request_builder: {:?}, attr_name: {:?}, p_type: {:?}, dec_value: {:?}",
+           sub_proof_request_builder, attr_name, p_type, dec_value);
+
+    let res = match BigNumber::from_dec(&dec_value)
+        .and_then(|value| sub_proof_request_builder.add_big_predicate(&attr_name, &p_type, &value)) {
+        Ok(_) => ErrorCode::Success,
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_sub_proof_request_builder_add_big_predicate: <<< res: {:?}", res);
+    res
+}
+
+/// Adds equality of hidden attribute to sub proof request.
+///
+/// Hidden attributes added with the same link to sub proof requests of the proof must be equal.
+///
+/// # Arguments
+/// * `sub_proof_request_builder` - Reference that contains sub proof request builder instance pointer.
+/// * `attr_name` - Related attribute
+/// * `link` - Name that links equal attributes as null terminated string.
+#[no_mangle]
+pub extern fn indy_crypto_cl_sub_proof_request_builder_add_attr_equality(sub_proof_request_builder: *const c_void,
+                                                                         attr_name: *const c_char,
+                                                                         link: *const c_char) -> ErrorCode {
+    trace!("indy_crypto_cl_sub_proof_request_builder_add_attr_equality: >>> sub_proof_request_builder: {:?}, attr_name: {:?}, link: {:?}",
+           sub_proof_request_builder, attr_name, link);
+
+    check_useful_mut_c_reference!(sub_proof_request_builder, SubProofRequestBuilder, ErrorCode::CommonInvalidParam1);
+    check_useful_c_str!(attr_name, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(link, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_cl_sub_proof_request_builder_add_attr_equality: entities: >>> sub_proof_request_builder: {:?}, attr_name: {:?}, link: {:?}",
+           sub_proof_request_builder, attr_name, link);
+
+    let res = match sub_proof_request_builder.add_attr_equality(&attr_name, &link) {
+        Ok(_) => ErrorCode::Success,
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_sub_proof_request_builder_add_attr_equality: <<< res: {:?}", res);
+    res
+}
+
 /// Deallocates sub proof request builder and returns sub proof request entity instead.
 ///
 /// Note: Sub proof request instance deallocation must be performed by
@@ -816,6 +881,38 @@ mod tests {
         _free_sub_proof_request_builder(sub_proof_request_builder);
     }
 
+    #[test]
+    fn indy_crypto_cl_sub_proof_request_builder_add_big_predicate_works() {
+        let sub_proof_request_builder = _sub_proof_request_builder();
+
+        let attr_name = CString::new("timestamp").unwrap();
+        let p_type = CString::new("LT").unwrap();
+        let dec_value = CString::new("18446744073709551616").unwrap();
+
+        let err_code = indy_crypto_cl_sub_proof_request_builder_add_big_predicate(sub_proof_request_builder, attr_name.as_ptr(), p_type.as_ptr(), dec_value.as_ptr());
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let dec_value = CString::new("not a number").unwrap();
+        let err_code = indy_crypto_cl_sub_proof_request_builder_add_big_predicate(sub_proof_request_builder, attr_name.as_ptr(), p_type.as_ptr(), dec_value.as_ptr());
+        assert_ne!(err_code, ErrorCode::Success);
+
+        _free_sub_proof_request_builder(sub_proof_request_builder);
+    }
+
+    #[test]
+    fn indy_crypto_cl_sub_proof_request_builder_add_attr_equality_works() {
+        let sub_proof_request_builder = _sub_proof_request_builder();
+
+        let attr_name = CString::new("name").unwrap();
+        let link = CString::new("name_link").unwrap();
+
+        let err_code = indy_crypto_cl_sub_proof_request_builder_add_attr_equality(sub_proof_request_builder, attr_name.as_ptr(), link.as_ptr());
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!sub_proof_request_builder.is_null());
+
+        _free_sub_proof_request_builder(sub_proof_request_builder);
+    }
+
     #[test]
     fn indy_crypto_cl_sub_proof_request_builder_finalize_works() {
         let sub_proof_request_builder = _sub_proof_request_builder();
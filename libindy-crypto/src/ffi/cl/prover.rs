@@ -4,6 +4,7 @@ use errors::ToErrorCode;
 use ffi::ErrorCode;
 use utils::ctypes::CTypesUtils;
 use utils::json::{JsonEncodable, JsonDecodable};
+use utils::secret::Secret;
 
 use libc::c_char;
 
@@ -55,7 +56,7 @@ pub extern fn indy_crypto_cl_master_secret_to_json(master_secret: *const c_void,
 
     let res = match master_secret.to_json() {
         Ok(master_secret_json) => {
-            trace!("indy_crypto_cl_master_secret_to_json: master_secret_json: {:?}", master_secret_json);
+            trace!("indy_crypto_cl_master_secret_to_json: master_secret_json: {:?}", Secret(&master_secret_json));
             unsafe {
                 let master_secret_json = CTypesUtils::string_to_cstring(master_secret_json);
                 *master_secret_json_p = master_secret_json.into_raw();
@@ -86,7 +87,7 @@ pub extern fn indy_crypto_cl_master_secret_from_json(master_secret_json: *const
     check_useful_c_str!(master_secret_json, ErrorCode::CommonInvalidParam1);
     check_useful_c_ptr!(master_secret_p, ErrorCode::CommonInvalidParam2);
 
-    trace!("indy_crypto_cl_master_secret_from_json: entity: master_secret_json: {:?}", master_secret_json);
+    trace!("indy_crypto_cl_master_secret_from_json: entity: master_secret_json: {:?}", Secret(&master_secret_json));
 
     let res = match MasterSecret::from_json(&master_secret_json) {
         Ok(master_secret) => {
@@ -264,8 +265,8 @@ pub extern fn indy_crypto_cl_blinded_master_secret_free(blinded_master_secret: *
 
     check_useful_c_ptr!(blinded_master_secret, ErrorCode::CommonInvalidParam1);
 
-    let blinded_master_secret = unsafe { Box::from_raw(blinded_master_secret as *mut MasterSecret); };
-    trace!("indy_crypto_cl_master_secret_free: entity: blinded_master_secret: {:?}", blinded_master_secret);
+    let blinded_master_secret = unsafe { Box::from_raw(blinded_master_secret as *mut BlindedMasterSecret); };
+    trace!("indy_crypto_cl_blinded_master_secret_free: entity: blinded_master_secret: {:?}", blinded_master_secret);
 
     let res = ErrorCode::Success;
 
@@ -290,7 +291,7 @@ pub extern fn indy_crypto_cl_master_secret_blinding_data_to_json(master_secret_b
 
     let res = match master_secret_blinding_data.to_json() {
         Ok(master_secret_blinding_data_json) => {
-            trace!("indy_crypto_cl_master_secret_blinding_data_to_json: master_secret_blinding_data_json: {:?}", master_secret_blinding_data_json);
+            trace!("indy_crypto_cl_master_secret_blinding_data_to_json: master_secret_blinding_data_json: {:?}", Secret(&master_secret_blinding_data_json));
             unsafe {
                 let master_secret_blinding_data_json = CTypesUtils::string_to_cstring(master_secret_blinding_data_json);
                 *master_secret_blinding_data_json_p = master_secret_blinding_data_json.into_raw();
@@ -321,7 +322,7 @@ pub extern fn indy_crypto_cl_master_secret_blinding_data_from_json(master_secret
     check_useful_c_str!(master_secret_blinding_data_json, ErrorCode::CommonInvalidParam1);
     check_useful_c_ptr!(master_secret_blinding_data_p, ErrorCode::CommonInvalidParam2);
 
-    trace!("indy_crypto_cl_master_secret_blinding_data_from_json: entity: master_secret_blinding_data_json: {:?}", master_secret_blinding_data_json);
+    trace!("indy_crypto_cl_master_secret_blinding_data_from_json: entity: master_secret_blinding_data_json: {:?}", Secret(&master_secret_blinding_data_json));
 
     let res = match MasterSecretBlindingData::from_json(&master_secret_blinding_data_json) {
         Ok(master_secret_blinding_data) => {
@@ -1044,7 +1045,7 @@ mod tests {
                                                                                         master_secret_blinding_nonce,
                                                                                         credential_issuance_nonce,
                                                                                         credential_pub_key,
-                                                                                        credential_pub_key);
+                                                                                        credential_priv_key);
         _process_credential_signature(credential_signature,
                                       signature_correctness_proof,
                                       master_secret_blinding_data,
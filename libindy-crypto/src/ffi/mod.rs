@@ -67,6 +67,10 @@ pub enum ErrorCode
 
     // Proof rejected
     AnoncredsProofRejected = 118,
+
+    // Group element (curve point) passed by library caller doesn't belong to the curve,
+    // to the prime-order subgroup or is unexpected infinity
+    CommonInvalidGroupElement = 119,
 }
 
 #[no_mangle]
@@ -1,4 +1,15 @@
+#[cfg(feature = "pair_amcl")]
 extern crate amcl;
+
+#[cfg(feature = "pair_bls12_381")]
+extern crate ark_bls12_381;
+#[cfg(feature = "pair_bls12_381")]
+extern crate ark_ec;
+#[cfg(feature = "pair_bls12_381")]
+extern crate ark_ff;
+#[cfg(feature = "pair_bls12_381")]
+extern crate ark_serialize;
+
 extern crate env_logger;
 #[macro_use]
 extern crate log;
@@ -23,23 +34,45 @@ extern crate serde_json;
 #[cfg(feature = "bn_openssl")]
 extern crate openssl;
 
+#[cfg(feature = "bn_openssl")]
+extern crate openssl_sys;
+
+#[cfg(feature = "bn_openssl")]
+extern crate foreign_types;
+
 #[cfg(feature = "bn_openssl")]
 extern crate int_traits;
 
+#[cfg(feature = "bn_rust")]
+extern crate num_bigint;
+
+#[cfg(feature = "bn_rust")]
+extern crate num_integer;
+
+#[cfg(feature = "bn_rust")]
+extern crate num_traits;
+
 extern crate libc;
 
 extern crate time;
 
+extern crate zeroize;
+
 pub mod cl;
 pub mod bls;
 
+#[cfg(not(any(feature = "bn_openssl", feature = "bn_rust")))]
+compile_error!("At least one big number backend must be enabled: bn_openssl or bn_rust");
+
 #[cfg(feature = "bn_openssl")]
 #[path = "bn/openssl.rs"]
 pub mod bn;
 
+#[cfg(all(feature = "bn_rust", not(feature = "bn_openssl")))]
+#[path = "bn/rust.rs"]
+pub mod bn;
+
 pub mod errors;
 pub mod ffi;
 
-#[cfg(feature = "pair_amcl")]
-#[path = "pair/amcl.rs"]
 pub mod pair;
\ No newline at end of file
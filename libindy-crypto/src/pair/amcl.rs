@@ -3,6 +3,10 @@ use errors::IndyCryptoError;
 use amcl::big::BIG;
 
 use amcl::rom::{
+    CURVE_B,
+    CURVE_BNX,
+    CURVE_FRA,
+    CURVE_FRB,
     CURVE_GX,
     CURVE_GY,
     CURVE_ORDER,
@@ -10,24 +14,30 @@ use amcl::rom::{
     CURVE_PYA,
     CURVE_PXB,
     CURVE_PYB,
-    MODBYTES
+    MODBYTES,
+    MODULUS
 };
 
 use amcl::ecp::ECP;
 use amcl::ecp2::ECP2;
+use amcl::fp::FP;
 use amcl::fp12::FP12;
 use amcl::fp2::FP2;
+use amcl::fp4::FP4;
 use amcl::pair::{ate, g1mul, g2mul, gtpow, fexp};
 use amcl::rand::RAND;
 
 use rand::os::OsRng;
 use rand::Rng;
 
+use zeroize::Zeroize;
+
+use pair::expand_message_xmd;
+
 #[cfg(feature = "serialization")]
 use serde::ser::{Serialize, Serializer, Error as SError};
 #[cfg(feature = "serialization")]
 use serde::de::{Deserialize, Deserializer, Visitor, Error as DError};
-#[cfg(feature = "serialization")]
 use std::fmt;
 
 fn random_mod_order() -> Result<BIG, IndyCryptoError> {
@@ -40,6 +50,210 @@ fn random_mod_order() -> Result<BIG, IndyCryptoError> {
     Ok(BIG::randomnum(&BIG::new_ints(&CURVE_ORDER), &mut rng))
 }
 
+/// Length of field element representation in hash_to_field: ceil((ceil(log2(p)) + k) / 8) with k = 128
+const HASH_TO_FIELD_LEN: usize = 48;
+
+/// Flags stored in two most significant bits of compressed point representation
+/// (they are always zero in x-coordinate as field modulus is 254 bits long).
+const COMPRESSED_Y_FLAG: u8 = 0x80;
+const COMPRESSED_INFINITY_FLAG: u8 = 0x40;
+
+/// Prefix byte of uncompressed PointG1 bytes representation
+const UNCOMPRESSED_PREFIX: u8 = 0x04;
+
+/// Checks that all MODBYTES long big-endian chunks of bytes are canonical field elements (less than field modulus).
+fn check_field_elements(b: &[u8], name: &str) -> Result<(), IndyCryptoError> {
+    let p = BIG::new_ints(&MODULUS);
+
+    for chunk in b.chunks(MODBYTES) {
+        if BIG::comp(&BIG::frombytes(chunk), &p) >= 0 {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("{} bytes representation contains non-canonical field element", name)));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that unused tail of fixed size bytes representation is zero.
+fn check_padding(b: &[u8], name: &str) -> Result<(), IndyCryptoError> {
+    if b.iter().any(|&b| b != 0) {
+        return Err(IndyCryptoError::InvalidStructure(
+            format!("{} bytes representation has non-zero padding", name)));
+    }
+
+    Ok(())
+}
+
+fn hash_to_field(message: &[u8], dst: &[u8], count: usize) -> Result<Vec<FP>, IndyCryptoError> {
+    let uniform_bytes = expand_message_xmd(message, dst, count * HASH_TO_FIELD_LEN)?;
+    Ok(uniform_bytes.chunks(HASH_TO_FIELD_LEN).map(fp_from_wide_bytes).collect())
+}
+
+/// Reduces big-endian bytes (up to 2 * (MODBYTES - 1)) modulo field prime.
+fn fp_from_wide_bytes(b: &[u8]) -> FP {
+    // b = hi * 2^(8 * lo_len) + lo, both hi and lo are less than field prime
+    let lo_len = b.len() / 2;
+    let mut hi = FP::new_big(&big_from_bytes(&b[..b.len() - lo_len]));
+    let lo = FP::new_big(&big_from_bytes(&b[b.len() - lo_len..]));
+
+    let mut shift = vec![0u8; MODBYTES];
+    shift[MODBYTES - lo_len - 1] = 1;
+    hi.mul(&mut FP::new_big(&BIG::frombytes(&shift)));
+    hi.add(&lo);
+    hi.reduce();
+    hi
+}
+
+fn big_from_bytes(b: &[u8]) -> BIG {
+    let mut vec = vec![0u8; MODBYTES - b.len()];
+    vec.extend_from_slice(b);
+    BIG::frombytes(&vec)
+}
+
+fn big_is_odd(b: &BIG) -> isize {
+    let mut b = *b;
+    b.rmod(&BIG::new_ints(&MODULUS));
+    b.parity()
+}
+
+/// Splits compressed representation to flags and x-coordinate bytes
+fn split_compressed_flags(b: &[u8]) -> Result<(u8, Vec<u8>), IndyCryptoError> {
+    let flags = b[0] & (COMPRESSED_Y_FLAG | COMPRESSED_INFINITY_FLAG);
+    let mut x = b.to_vec();
+    x[0] &= !(COMPRESSED_Y_FLAG | COMPRESSED_INFINITY_FLAG);
+
+    if flags & COMPRESSED_INFINITY_FLAG != 0 && (flags != COMPRESSED_INFINITY_FLAG || x.iter().any(|b| *b != 0)) {
+        return Err(IndyCryptoError::InvalidStructure(
+            "Invalid compressed representation of infinity point".to_string()));
+    }
+
+    Ok((flags, x))
+}
+
+fn big_from_canonical_bytes(b: &[u8]) -> Result<BIG, IndyCryptoError> {
+    let big = BIG::frombytes(b);
+    if BIG::comp(&big, &BIG::new_ints(&MODULUS)) >= 0 {
+        return Err(IndyCryptoError::InvalidStructure(
+            "Invalid field element in compressed representation".to_string()));
+    }
+    Ok(big)
+}
+
+fn fp_is_square(x: &FP) -> bool {
+    let mut x = *x;
+    x.iszilch() || x.jacobi() == 1
+}
+
+fn fp_sgn0(x: &FP) -> isize {
+    let mut x = *x;
+    let mut b = x.redc();
+    b.rmod(&BIG::new_ints(&MODULUS));
+    b.parity()
+}
+
+/// Inversion that maps 0 to 0 (inv0 in IETF hash-to-curve)
+fn fp_inv0(x: &FP) -> FP {
+    let mut x = *x;
+    let mut e = BIG::new_ints(&MODULUS);
+    e.dec(2);
+    e.norm();
+    x.pow(&mut e)
+}
+
+/// Right-hand side of curve equation: x^3 + B (A is 0 for BN curves)
+fn curve_rhs(x: &FP) -> FP {
+    let mut r = *x;
+    r.sqr();
+    r.mul(&mut x.clone());
+    r.add(&FP::new_big(&BIG::new_ints(&CURVE_B)));
+    r.reduce();
+    r
+}
+
+/// Shallue-van de Woestijne map as defined in IETF hash-to-curve (RFC 9380, section 6.6.1)
+/// for curve y^2 = x^3 + B. Z = -1 is chosen with the find_z_svdw procedure from the specification.
+fn map_to_curve_svdw(u: &FP) -> ECP {
+    let one = FP::new_int(1);
+
+    let mut z = one;
+    z.neg();
+
+    // Constants
+    let c1 = curve_rhs(&z);
+
+    let mut c2 = z;
+    c2.neg();
+    c2.div2();
+
+    let mut three_z2 = z;
+    three_z2.sqr();
+    three_z2.imul(3);
+
+    let mut c3 = c1;
+    c3.neg();
+    c3.mul(&mut three_z2.clone());
+    let mut c3 = c3.sqrt();
+    let mut neg_c3 = c3;
+    neg_c3.neg();
+    let sgn0_c3 = fp_sgn0(&c3);
+    c3.cmove(&neg_c3, sgn0_c3);
+
+    let mut c4 = c1;
+    c4.imul(4);
+    c4.neg();
+    c4.mul(&mut fp_inv0(&three_z2));
+
+    // Map
+    let mut tv1 = *u;
+    tv1.sqr();
+    tv1.mul(&mut c1.clone());
+    let mut tv2 = one;
+    tv2.add(&tv1);
+    let mut tv1_neg = tv1;
+    tv1_neg.neg();
+    tv1 = one;
+    tv1.add(&tv1_neg);
+    let mut tv3 = tv1;
+    tv3.mul(&mut tv2.clone());
+    tv3 = fp_inv0(&tv3);
+    let mut tv4 = *u;
+    tv4.mul(&mut tv1);
+    tv4.mul(&mut tv3.clone());
+    tv4.mul(&mut c3);
+
+    let mut x1 = c2;
+    x1.sub(&tv4);
+    x1.reduce();
+    let e1 = fp_is_square(&curve_rhs(&x1));
+
+    let mut x2 = c2;
+    x2.add(&tv4);
+    x2.reduce();
+    let e2 = fp_is_square(&curve_rhs(&x2)) && !e1;
+
+    let mut x3 = tv2;
+    x3.sqr();
+    x3.mul(&mut tv3);
+    x3.sqr();
+    x3.mul(&mut c4);
+    x3.add(&z);
+    x3.reduce();
+
+    let mut x = x3;
+    x.cmove(&x1, e1 as isize);
+    x.cmove(&x2, e2 as isize);
+
+    let mut y = curve_rhs(&x).sqrt();
+    let mut neg_y = y;
+    neg_y.neg();
+    neg_y.reduce();
+    let e3 = (fp_sgn0(u) != fp_sgn0(&y)) as isize;
+    y.cmove(&neg_y, e3);
+
+    ECP::new_bigs(&x.redc(), &y.redc())
+}
+
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub struct PointG1 {
     point: ECP
@@ -47,6 +261,7 @@ pub struct PointG1 {
 
 impl PointG1 {
     pub const BYTES_REPR_SIZE: usize = MODBYTES * 4;
+    pub const COMPRESSED_BYTES_REPR_SIZE: usize = MODBYTES;
 
     /// Creates new random PointG1
     pub fn new() -> Result<PointG1, IndyCryptoError> {
@@ -86,6 +301,15 @@ impl PointG1 {
         })
     }
 
+    /// PointG1 ^ GroupOrderElement * PointG1 ^ GroupOrderElement * ...
+    /// Runs in time independent of scalar values, so scalars may be secret.
+    pub fn multi_mul(points: &[PointG1], scalars: &[GroupOrderElement]) -> Result<PointG1, IndyCryptoError> {
+        let points: Vec<ECP> = points.iter().map(|p| p.point).collect();
+        Ok(PointG1 {
+            point: multi_scalar_mul(&points, scalars)?
+        })
+    }
+
     /// PointG1 * PointG1
     pub fn add(&self, q: &PointG1) -> Result<PointG1, IndyCryptoError> {
         let mut r = self.point;
@@ -119,12 +343,31 @@ impl PointG1 {
         Ok(self.point.to_hex())
     }
 
+    /// Restores point from string representation.
+    /// Fails if point doesn't belong to the curve. Infinity is accepted only if explicitly marked.
     pub fn from_string(str: &str) -> Result<PointG1, IndyCryptoError> {
+        let point = PointG1::from_string_unchecked(str)?;
+
+        if point.is_inf()? && str.split_whitespace().next() != Some("true") {
+            return Err(IndyCryptoError::InvalidGroupElement(
+                "PointG1 string representation is empty".to_string()));
+        }
+
+        point._check()?;
+
+        Ok(point)
+    }
+
+    /// Restores point from string representation without any validation.
+    /// Must be used only for trusted data (i.e. produced by this library and stored locally).
+    pub fn from_string_unchecked(str: &str) -> Result<PointG1, IndyCryptoError> {
         Ok(PointG1 {
             point: ECP::from_hex(str.to_string())
         })
     }
 
+    /// Returns bytes representation: 0x04, affine x and y coordinates (big-endian, MODBYTES each)
+    /// followed by zero padding up to BYTES_REPR_SIZE.
     pub fn to_bytes(&self) -> Result<Vec<u8>, IndyCryptoError> {
         let mut r = self.point;
         let mut vec = vec![0u8; Self::BYTES_REPR_SIZE];
@@ -132,7 +375,32 @@ impl PointG1 {
         Ok(vec)
     }
 
+    /// Restores point from bytes representation.
+    /// Fails if representation isn't canonical, point doesn't belong to the curve or is infinity
+    /// (AMCL maps any invalid coordinates to infinity, so it can't be distinguished from garbage).
     pub fn from_bytes(b: &[u8]) -> Result<PointG1, IndyCryptoError> {
+        let point = PointG1::from_bytes_unchecked(b)?;
+
+        if b[0] != UNCOMPRESSED_PREFIX {
+            return Err(IndyCryptoError::InvalidStructure(
+                "PointG1 bytes representation has invalid prefix".to_string()));
+        }
+
+        check_padding(&b[1 + 2 * MODBYTES..], "PointG1")?;
+
+        if point.is_inf()? {
+            return Err(IndyCryptoError::InvalidGroupElement(
+                "PointG1 bytes representation doesn't correspond to curve point".to_string()));
+        }
+
+        point._check()?;
+
+        Ok(point)
+    }
+
+    /// Restores point from bytes representation without any validation.
+    /// Must be used only for trusted data (i.e. produced by this library and stored locally).
+    pub fn from_bytes_unchecked(b: &[u8]) -> Result<PointG1, IndyCryptoError> {
         if b.len() != Self::BYTES_REPR_SIZE {
             return Err(IndyCryptoError::InvalidStructure(
                 "Invalid len of bytes representation".to_string()));
@@ -144,6 +412,51 @@ impl PointG1 {
         )
     }
 
+    /// Returns compressed representation: x-coordinate (big-endian) with y parity and infinity flags
+    /// in two most significant bits.
+    pub fn to_compressed_bytes(&self) -> Result<Vec<u8>, IndyCryptoError> {
+        let mut r = self.point;
+        let mut vec = vec![0u8; Self::COMPRESSED_BYTES_REPR_SIZE];
+
+        if r.is_infinity() {
+            vec[0] = COMPRESSED_INFINITY_FLAG;
+            return Ok(vec);
+        }
+
+        r.getx().tobytes(&mut vec);
+        if big_is_odd(&r.gety()) == 1 {
+            vec[0] |= COMPRESSED_Y_FLAG;
+        }
+
+        Ok(vec)
+    }
+
+    /// Restores point from compressed representation. Fails if x-coordinate doesn't belong to the curve.
+    pub fn from_compressed_bytes(b: &[u8]) -> Result<PointG1, IndyCryptoError> {
+        if b.len() != Self::COMPRESSED_BYTES_REPR_SIZE {
+            return Err(IndyCryptoError::InvalidStructure(
+                "Invalid len of compressed bytes representation".to_string()));
+        }
+
+        let (flags, x) = split_compressed_flags(b)?;
+        if flags & COMPRESSED_INFINITY_FLAG != 0 {
+            return PointG1::new_inf();
+        }
+
+        let x = big_from_canonical_bytes(&x)?;
+        let mut point = ECP::new_bigint(&x, (flags & COMPRESSED_Y_FLAG != 0) as isize);
+
+        if point.is_infinity() {
+            return Err(IndyCryptoError::InvalidGroupElement(
+                "Compressed representation doesn't correspond to curve point".to_string()));
+        }
+
+        // Cofactor of G1 is 1 for BN curves, so any curve point belongs to the group
+        Ok(PointG1 {
+            point: point
+        })
+    }
+
     pub fn from_hash(hash: &[u8]) -> Result<PointG1, IndyCryptoError> {
         let mut el = GroupOrderElement::from_bytes(hash)?;
         let mut point = ECP::new_big(&el.bn);
@@ -157,6 +470,46 @@ impl PointG1 {
             point: point
         })
     }
+
+    /// Hashes message to PointG1 following IETF hash-to-curve (RFC 9380) random oracle encoding:
+    /// expand_message_xmd with SHA-256 and Shallue-van de Woestijne map.
+    /// `dst` is domain separation tag chosen by caller.
+    pub fn hash_to_curve(message: &[u8], dst: &[u8]) -> Result<PointG1, IndyCryptoError> {
+        let u = hash_to_field(message, dst, 2)?;
+
+        let mut point = map_to_curve_svdw(&u[0]);
+        let mut q1 = map_to_curve_svdw(&u[1]);
+        point.add(&mut q1);
+
+        // G1 cofactor of BN curves is 1, so clear_cofactor is identity
+        Ok(PointG1 {
+            point: point
+        })
+    }
+
+    /// Checks that point belongs to the curve.
+    /// Cofactor of G1 is 1 for BN curves, so any curve point belongs to the prime-order subgroup.
+    fn _check(&self) -> Result<(), IndyCryptoError> {
+        let mut point = self.point;
+
+        if point.is_infinity() {
+            return Ok(());
+        }
+
+        if point.getpz().iszilch() {
+            return Err(IndyCryptoError::InvalidGroupElement(
+                "PointG1 has zero z-coordinate".to_string()));
+        }
+
+        let mut affine = ECP::new_bigs(&point.getx(), &point.gety());
+
+        if affine.is_infinity() {
+            return Err(IndyCryptoError::InvalidGroupElement(
+                "PointG1 doesn't belong to the curve".to_string()));
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(feature = "serialization")]
@@ -196,9 +549,21 @@ pub struct PointG2 {
 
 impl PointG2 {
     pub const BYTES_REPR_SIZE: usize = MODBYTES * 4;
+    pub const COMPRESSED_BYTES_REPR_SIZE: usize = MODBYTES * 2;
 
     /// Creates new random PointG2
     pub fn new() -> Result<PointG2, IndyCryptoError> {
+        let mut gen_g2 = PointG2::base()?.point;
+
+        let point = g2mul(&mut gen_g2, &mut random_mod_order()?);
+
+        Ok(PointG2 {
+            point: point
+        })
+    }
+
+    /// Returns fixed base point of the group G2 defined by curve parameters
+    pub fn base() -> Result<PointG2, IndyCryptoError> {
         let point_xa = BIG::new_ints(&CURVE_PXA);
         let point_xb = BIG::new_ints(&CURVE_PXB);
         let point_ya = BIG::new_ints(&CURVE_PYA);
@@ -207,12 +572,8 @@ impl PointG2 {
         let point_x = FP2::new_bigs(&point_xa, &point_xb);
         let point_y = FP2::new_bigs(&point_ya, &point_yb);
 
-        let mut gen_g2 = ECP2::new_fp2s(&point_x, &point_y);
-
-        let point = g2mul(&mut gen_g2, &mut random_mod_order()?);
-
         Ok(PointG2 {
-            point: point
+            point: ECP2::new_fp2s(&point_x, &point_y)
         })
     }
 
@@ -226,6 +587,12 @@ impl PointG2 {
         })
     }
 
+    /// Checks infinity
+    pub fn is_inf(&self) -> Result<bool, IndyCryptoError> {
+        let mut r = self.point;
+        Ok(r.is_infinity())
+    }
+
     /// PointG2 * PointG2
     pub fn add(&self, q: &PointG2) -> Result<PointG2, IndyCryptoError> {
         let mut r = self.point;
@@ -248,6 +615,15 @@ impl PointG2 {
         })
     }
 
+    /// 1 / PointG2
+    pub fn neg(&self) -> Result<PointG2, IndyCryptoError> {
+        let mut r = self.point;
+        r.neg();
+        Ok(PointG2 {
+            point: r
+        })
+    }
+
     /// PointG2 ^ GroupOrderElement
     pub fn mul(&self, e: &GroupOrderElement) -> Result<PointG2, IndyCryptoError> {
         let mut r = self.point;
@@ -257,16 +633,45 @@ impl PointG2 {
         })
     }
 
+    /// PointG2 ^ GroupOrderElement * PointG2 ^ GroupOrderElement * ...
+    /// Runs in time independent of scalar values, so scalars may be secret.
+    pub fn multi_mul(points: &[PointG2], scalars: &[GroupOrderElement]) -> Result<PointG2, IndyCryptoError> {
+        let points: Vec<ECP2> = points.iter().map(|p| p.point).collect();
+        Ok(PointG2 {
+            point: multi_scalar_mul(&points, scalars)?
+        })
+    }
+
     pub fn to_string(&self) -> Result<String, IndyCryptoError> {
         Ok(self.point.to_hex())
     }
 
+    /// Restores point from string representation.
+    /// Fails if point doesn't belong to the curve or to the prime-order subgroup.
+    /// Infinity is accepted only if explicitly marked.
     pub fn from_string(str: &str) -> Result<PointG2, IndyCryptoError> {
+        let point = PointG2::from_string_unchecked(str)?;
+
+        if point.is_inf()? && str.split_whitespace().next() != Some("true") {
+            return Err(IndyCryptoError::InvalidGroupElement(
+                "PointG2 string representation is empty".to_string()));
+        }
+
+        point._check()?;
+
+        Ok(point)
+    }
+
+    /// Restores point from string representation without any validation.
+    /// Must be used only for trusted data (i.e. produced by this library and stored locally).
+    pub fn from_string_unchecked(str: &str) -> Result<PointG2, IndyCryptoError> {
         Ok(PointG2 {
             point: ECP2::from_hex(str.to_string())
         })
     }
 
+    /// Returns bytes representation: affine coordinates x = x.a + x.b * i and y = y.a + y.b * i
+    /// as x.a, x.b, y.a, y.b (big-endian, MODBYTES each).
     pub fn to_bytes(&self) -> Result<Vec<u8>, IndyCryptoError> {
         let mut point = self.point;
         let mut vec = vec![0u8; Self::BYTES_REPR_SIZE];
@@ -274,7 +679,27 @@ impl PointG2 {
         Ok(vec)
     }
 
+    /// Restores point from bytes representation.
+    /// Fails if representation isn't canonical, point doesn't belong to the curve or to the prime-order subgroup
+    /// or is infinity (AMCL maps any invalid coordinates to infinity, so it can't be distinguished from garbage).
     pub fn from_bytes(b: &[u8]) -> Result<PointG2, IndyCryptoError> {
+        let point = PointG2::from_bytes_unchecked(b)?;
+
+        check_field_elements(b, "PointG2")?;
+
+        if point.is_inf()? {
+            return Err(IndyCryptoError::InvalidGroupElement(
+                "PointG2 bytes representation doesn't correspond to curve point".to_string()));
+        }
+
+        point._check()?;
+
+        Ok(point)
+    }
+
+    /// Restores point from bytes representation without any validation.
+    /// Must be used only for trusted data (i.e. produced by this library and stored locally).
+    pub fn from_bytes_unchecked(b: &[u8]) -> Result<PointG2, IndyCryptoError> {
         if b.len() != Self::BYTES_REPR_SIZE {
             return Err(IndyCryptoError::InvalidStructure(
                 "Invalid len of bytes representation".to_string()));
@@ -285,6 +710,101 @@ impl PointG2 {
             }
         )
     }
+
+    /// Returns compressed representation: x-coordinate (a and b components, big-endian)
+    /// with y sign and infinity flags in two most significant bits.
+    /// Sign of y is parity of its a component or parity of b component if a is zero.
+    pub fn to_compressed_bytes(&self) -> Result<Vec<u8>, IndyCryptoError> {
+        let mut r = self.point;
+        let mut vec = vec![0u8; Self::COMPRESSED_BYTES_REPR_SIZE];
+
+        if r.is_infinity() {
+            vec[0] = COMPRESSED_INFINITY_FLAG;
+            return Ok(vec);
+        }
+
+        let mut x = r.getx();
+        x.geta().tobytes(&mut vec[..MODBYTES]);
+        x.getb().tobytes(&mut vec[MODBYTES..]);
+
+        if PointG2::_sgn0(&r.gety()) == 1 {
+            vec[0] |= COMPRESSED_Y_FLAG;
+        }
+
+        Ok(vec)
+    }
+
+    /// Restores point from compressed representation. Fails if x-coordinate doesn't belong to the curve.
+    pub fn from_compressed_bytes(b: &[u8]) -> Result<PointG2, IndyCryptoError> {
+        if b.len() != Self::COMPRESSED_BYTES_REPR_SIZE {
+            return Err(IndyCryptoError::InvalidStructure(
+                "Invalid len of compressed bytes representation".to_string()));
+        }
+
+        let (flags, x) = split_compressed_flags(b)?;
+        if flags & COMPRESSED_INFINITY_FLAG != 0 {
+            return PointG2::new_inf();
+        }
+
+        let x = FP2::new_bigs(&big_from_canonical_bytes(&x[..MODBYTES])?,
+                              &big_from_canonical_bytes(&x[MODBYTES..])?);
+        let mut point = ECP2::new_fp2(&x);
+
+        if point.is_infinity() {
+            return Err(IndyCryptoError::InvalidGroupElement(
+                "Compressed representation doesn't correspond to curve point".to_string()));
+        }
+
+        if PointG2::_sgn0(&point.gety()) != (flags & COMPRESSED_Y_FLAG != 0) as isize {
+            point.neg();
+        }
+
+        let point = PointG2 {
+            point: point
+        };
+        point._check()?;
+
+        Ok(point)
+    }
+
+    /// Checks that point belongs to the curve and to the prime-order subgroup.
+    fn _check(&self) -> Result<(), IndyCryptoError> {
+        let mut point = self.point;
+
+        if point.is_infinity() {
+            return Ok(());
+        }
+
+        if point.getpz().iszilch() {
+            return Err(IndyCryptoError::InvalidGroupElement(
+                "PointG2 has zero z-coordinate".to_string()));
+        }
+
+        let mut affine = ECP2::new_fp2s(&point.getx(), &point.gety());
+
+        if affine.is_infinity() {
+            return Err(IndyCryptoError::InvalidGroupElement(
+                "PointG2 doesn't belong to the curve".to_string()));
+        }
+
+        // Twist has non-trivial cofactor, so order of the point must be checked explicitly
+        if !affine.mul(&BIG::new_ints(&CURVE_ORDER)).is_infinity() {
+            return Err(IndyCryptoError::InvalidGroupElement(
+                "PointG2 doesn't belong to the prime-order subgroup".to_string()));
+        }
+
+        Ok(())
+    }
+
+    fn _sgn0(y: &FP2) -> isize {
+        let mut y = *y;
+        let a = y.geta();
+        if a.iszilch() {
+            big_is_odd(&y.getb())
+        } else {
+            big_is_odd(&a)
+        }
+    }
 }
 
 #[cfg(feature = "serialization")]
@@ -317,82 +837,545 @@ impl<'a> Deserialize<'a> for PointG2 {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
-pub struct GroupOrderElement {
-    bn: BIG
+/// PointG2 with precomputed Miller loop line functions.
+/// Makes pairings with fixed G2 points (like BLS generator) cheaper.
+#[derive(Clone)]
+pub struct PreparedPointG2 {
+    // Empty for infinity, as pairing with infinity is unity
+    lines: Vec<Option<LineCoefficients>>
 }
 
-impl GroupOrderElement {
-    pub const BYTES_REPR_SIZE: usize = MODBYTES;
+impl PreparedPointG2 {
+    pub fn new(q: &PointG2) -> Result<PreparedPointG2, IndyCryptoError> {
+        let mut p = q.point;
+        if p.is_infinity() {
+            return Ok(PreparedPointG2 {
+                lines: Vec::new()
+            });
+        }
+        p.affine();
 
-    pub fn new() -> Result<GroupOrderElement, IndyCryptoError> {
-        // returns random element in 0, ..., GroupOrder-1
-        Ok(GroupOrderElement {
-            bn: random_mod_order()?
-        })
-    }
+        let mut n = ate_loop_count();
+        let mut a = p;
+        let mut lines: Vec<Option<LineCoefficients>> = Vec::new();
 
-    pub fn new_from_seed(seed: &[u8]) -> Result<GroupOrderElement, IndyCryptoError> {
-        // returns random element in 0, ..., GroupOrder-1
-        if seed.len() != MODBYTES {
-            return Err(IndyCryptoError::InvalidStructure(
-                format!("Invalid len of seed: expected {}, actual {}", MODBYTES, seed.len())));
+        for i in (1..n.nbits() - 1).rev() {
+            lines.push(line_dbl(&mut a));
+            if n.bit(i) == 1 {
+                lines.push(line_add(&mut a, &mut p));
+            }
+        }
+        lines.push(line_dbl(&mut a));
+        if n.parity() == 1 {
+            lines.push(line_add(&mut a, &mut p));
         }
-        let mut rng = RAND::new();
-        rng.clean();
-        rng.seed(seed.len(), seed);
 
-        Ok(GroupOrderElement {
-            bn: BIG::randomnum(&BIG::new_ints(&CURVE_ORDER), &mut rng)
+        // R-ate fixup for BN curves
+        let mut f = FP2::new_bigs(&BIG::new_ints(&CURVE_FRA), &BIG::new_ints(&CURVE_FRB));
+        let mut k = p;
+        k.frob(&mut f);
+        a.neg();
+        lines.push(line_add(&mut a, &mut k));
+        k.frob(&mut f);
+        k.neg();
+        lines.push(line_add(&mut a, &mut k));
+
+        Ok(PreparedPointG2 {
+            lines
         })
     }
+}
 
-    /// (GroupOrderElement ^ GroupOrderElement) mod GroupOrder
-    pub fn pow_mod(&self, e: &GroupOrderElement) -> Result<GroupOrderElement, IndyCryptoError> {
-        let mut base = self.bn;
-        let mut pow = e.bn;
-        Ok(GroupOrderElement {
-            bn: base.powmod(&mut pow, &BIG::new_ints(&CURVE_ORDER))
-        })
+impl fmt::Debug for PreparedPointG2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PreparedPointG2 {{ lines: {} }}", self.lines.len())
     }
+}
 
-    /// (GroupOrderElement + GroupOrderElement) mod GroupOrder
-    pub fn add_mod(&self, r: &GroupOrderElement) -> Result<GroupOrderElement, IndyCryptoError> {
-        let mut sum = self.bn;
-        sum.add(&r.bn);
-        sum.rmod(&BIG::new_ints(&CURVE_ORDER));
-        Ok(GroupOrderElement {
-            bn: sum
-        })
+/// Coefficients of Miller loop line function that depend on G2 point only.
+/// Line function evaluated at G1 point (x, y) is ((a * y, b), (c * x), 0).
+#[derive(Copy, Clone)]
+struct LineCoefficients {
+    a: FP2,
+    b: FP2,
+    c: FP2
+}
+
+impl LineCoefficients {
+    fn eval(&self, x: &FP, y: &FP) -> FP12 {
+        let mut a = self.a;
+        a.pmul(&mut y.clone());
+        let mut c = self.c;
+        c.pmul(&mut x.clone());
+        FP12::new_fp4s(&FP4::new_fp2s(&a, &self.b), &FP4::new_fp2(&c), &FP4::new())
     }
+}
 
-    /// (GroupOrderElement - GroupOrderElement) mod GroupOrder
-    pub fn sub_mod(&self, r: &GroupOrderElement) -> Result<GroupOrderElement, IndyCryptoError> {
-        //need to use modneg if sub is negative
-        let mut diff = self.bn;
-        diff.sub(&r.bn);
-        let mut zero = BIG::new();
-        zero.zero();
+/// Ate loop count 6x + 2 for BN curves (handled by amcl as 6|x| - 2 followed by conjugation).
+fn ate_loop_count() -> BIG {
+    let mut n = BIG::new_ints(&CURVE_BNX);
+    n.pmul(6);
+    n.dec(2);
+    n.norm();
+    n
+}
 
-        if diff < zero {
-            return Ok(GroupOrderElement {
-                bn: BIG::modneg(&mut diff, &BIG::new_ints(&CURVE_ORDER))
-            });
-        }
+/// Doubles `a` and returns coefficients of the tangent line (amcl linedbl).
+fn line_dbl(a: &mut ECP2) -> Option<LineCoefficients> {
+    let mut p = *a;
+    let mut zz = p.getpz();
+    zz.sqr();
 
-        Ok(GroupOrderElement {
-            bn: diff
-        })
+    if a.dbl() < 0 {
+        return None;
     }
 
-    /// (GroupOrderElement * GroupOrderElement) mod GroupOrder
-    pub fn mul_mod(&self, r: &GroupOrderElement) -> Result<GroupOrderElement, IndyCryptoError> {
-        let mut base = self.bn;
-        let mut r = r.bn;
-        Ok(GroupOrderElement {
-            bn: BIG::modmul(&mut base, &mut r, &BIG::new_ints(&CURVE_ORDER))
-        })
-    }
+    Some(line_dbl_coefficients(&mut p, zz, a.getpz()))
+}
+
+/// Adds `b` to `a` and returns coefficients of the line through them (amcl lineadd).
+fn line_add(a: &mut ECP2, b: &mut ECP2) -> Option<LineCoefficients> {
+    let mut p = *a;
+    let mut zz = p.getpz();
+    zz.sqr();
+
+    let d = a.add(b);
+    if d < 0 {
+        return None;
+    }
+
+    let z3 = a.getpz();
+
+    if d != 0 {
+        return Some(line_dbl_coefficients(&mut p, zz, z3));
+    }
+
+    let mut x = b.getpx();
+    let mut y = b.getpy();
+    let mut t = p.getpz();
+    t.mul(&mut y);
+    zz.mul(&mut t);
+
+    let mut ny = p.getpy();
+    ny.neg();
+    zz.add(&ny);
+    t.mul(&mut p.getpx());
+    x.mul(&mut ny);
+    t.add(&x);
+    zz.neg();
+
+    Some(LineCoefficients {
+        a: z3,
+        b: t,
+        c: zz
+    })
+}
+
+fn line_dbl_coefficients(p: &mut ECP2, mut zz: FP2, mut z3: FP2) -> LineCoefficients {
+    let mut x = p.getpx();
+    let mut y = p.getpy();
+    let mut t = p.getpx();
+    t.sqr();
+    t.imul(3);
+
+    y.sqr();
+    y.dbl();
+    z3.mul(&mut zz);
+
+    x.mul(&mut t);
+    x.sub(&y);
+    t.neg();
+    zz.mul(&mut t);
+
+    LineCoefficients {
+        a: z3,
+        b: x,
+        c: zz
+    }
+}
+
+/// Product of Miller loops of all pairs sharing squarings of the accumulator.
+fn multi_miller_loop(pairs: &[(&PointG1, &PreparedPointG2)]) -> FP12 {
+    let mut points: Vec<(FP, FP, &PreparedPointG2)> = Vec::new();
+    for &(p, q) in pairs {
+        let mut p = p.point;
+        if p.is_infinity() || q.lines.is_empty() {
+            // Pairing with infinity is unity
+            continue;
+        }
+        p.affine();
+        points.push((p.getpx(), p.getpy(), q));
+    }
+
+    let mul_lines = |r: &mut FP12, line: usize| {
+        for &(ref x, ref y, q) in points.iter() {
+            if let Some(ref coefficients) = q.lines[line] {
+                r.smul(&mut coefficients.eval(x, y));
+            }
+        }
+    };
+
+    let mut n = ate_loop_count();
+    let mut r = FP12::new_int(1);
+    let mut line = 0;
+
+    for i in (1..n.nbits() - 1).rev() {
+        mul_lines(&mut r, line);
+        line += 1;
+        if n.bit(i) == 1 {
+            mul_lines(&mut r, line);
+            line += 1;
+        }
+        r.sqr();
+    }
+    mul_lines(&mut r, line);
+    line += 1;
+    if n.parity() == 1 {
+        mul_lines(&mut r, line);
+        line += 1;
+    }
+
+    r.conj();
+    mul_lines(&mut r, line);
+    mul_lines(&mut r, line + 1);
+
+    r
+}
+
+/// PointG1 with precomputed multiples for fast multiplication of fixed base by many scalars.
+#[derive(Clone)]
+pub struct FixedBasePointG1 {
+    table: Vec<Vec<ECP>>
+}
+
+impl FixedBasePointG1 {
+    pub fn new(p: &PointG1) -> Result<FixedBasePointG1, IndyCryptoError> {
+        Ok(FixedBasePointG1 {
+            table: fixed_base_table(&p.point)
+        })
+    }
+
+    /// PointG1 ^ GroupOrderElement
+    pub fn mul(&self, e: &GroupOrderElement) -> Result<PointG1, IndyCryptoError> {
+        Ok(PointG1 {
+            point: fixed_base_mul(&self.table, e)
+        })
+    }
+}
+
+impl fmt::Debug for FixedBasePointG1 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FixedBasePointG1 {{ windows: {} }}", self.table.len())
+    }
+}
+
+/// PointG2 with precomputed multiples for fast multiplication of fixed base by many scalars.
+#[derive(Clone)]
+pub struct FixedBasePointG2 {
+    table: Vec<Vec<ECP2>>
+}
+
+impl FixedBasePointG2 {
+    pub fn new(p: &PointG2) -> Result<FixedBasePointG2, IndyCryptoError> {
+        Ok(FixedBasePointG2 {
+            table: fixed_base_table(&p.point)
+        })
+    }
+
+    /// PointG2 ^ GroupOrderElement
+    pub fn mul(&self, e: &GroupOrderElement) -> Result<PointG2, IndyCryptoError> {
+        Ok(PointG2 {
+            point: fixed_base_mul(&self.table, e)
+        })
+    }
+}
+
+impl fmt::Debug for FixedBasePointG2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FixedBasePointG2 {{ windows: {} }}", self.table.len())
+    }
+}
+
+/// Group operations shared by G1 and G2 points for multi-scalar multiplication.
+trait CurvePoint: Copy {
+    fn infinity() -> Self;
+    fn add_point(&mut self, q: &Self);
+    fn double(&mut self);
+    fn negate(&mut self);
+    fn cmove(&mut self, q: &Self, d: isize);
+}
+
+impl CurvePoint for ECP {
+    fn infinity() -> ECP {
+        let mut p = ECP::new();
+        p.inf();
+        p
+    }
+
+    fn add_point(&mut self, q: &ECP) {
+        self.add(&mut q.clone());
+    }
+
+    fn double(&mut self) {
+        self.dbl();
+    }
+
+    fn negate(&mut self) {
+        self.neg();
+    }
+
+    fn cmove(&mut self, q: &ECP, d: isize) {
+        ECP::cmove(self, q, d);
+    }
+}
+
+impl CurvePoint for ECP2 {
+    fn infinity() -> ECP2 {
+        let mut p = ECP2::new();
+        p.inf();
+        p
+    }
+
+    fn add_point(&mut self, q: &ECP2) {
+        self.add(&mut q.clone());
+    }
+
+    fn double(&mut self) {
+        self.dbl();
+    }
+
+    fn negate(&mut self) {
+        self.neg();
+    }
+
+    fn cmove(&mut self, q: &ECP2, d: isize) {
+        ECP2::cmove(self, q, d);
+    }
+}
+
+/// Window width of signed scalar digits.
+const WINDOW_WIDTH: usize = 4;
+
+/// Number of odd multiples of point required for signed digits of WINDOW_WIDTH bits.
+const TABLE_SIZE: usize = 1 << (WINDOW_WIDTH - 1);
+
+/// Number of signed digits of scalar. It doesn't depend on scalar value,
+/// so all multiplications by secret scalars perform the same sequence of group operations.
+const DIGITS_COUNT: usize = MODBYTES * 8 / WINDOW_WIDTH + 1;
+
+/// Returns 1 if b == c and 0 otherwise without branching.
+fn ct_eq(b: isize, c: isize) -> isize {
+    (((b ^ c) as i32 - 1) >> 31 & 1) as isize
+}
+
+/// Recodes scalar to DIGITS_COUNT odd digits from -2^WINDOW_WIDTH + 1 to 2^WINDOW_WIDTH - 1.
+/// Scalar is made odd first by adding 1 if it is even or 2 if it is odd,
+/// returned flag is 1 if 1 was added and product must be corrected by subtracting of point
+/// and 0 if product must be corrected by subtracting of doubled point.
+fn scalar_digits(e: &GroupOrderElement) -> ([isize; DIGITS_COUNT], isize) {
+    let mut t = e.bn;
+    t.norm();
+
+    let s = t.parity();
+    t.inc(1);
+    t.norm();
+    let ns = t.parity();
+    let mut mt = t;
+    mt.inc(1);
+    mt.norm();
+    t.cmove(&mt, s);
+
+    let mut digits = [0; DIGITS_COUNT];
+    for digit in digits.iter_mut().take(DIGITS_COUNT - 1) {
+        *digit = t.lastbits(WINDOW_WIDTH + 1) - (1 << WINDOW_WIDTH);
+        t.dec(*digit);
+        t.norm();
+        t.fshr(WINDOW_WIDTH);
+    }
+    digits[DIGITS_COUNT - 1] = t.lastbits(WINDOW_WIDTH + 1);
+
+    t.w.zeroize();
+    mt.w.zeroize();
+
+    (digits, ns)
+}
+
+/// Returns table[(|d| - 1) / 2] negated if d is negative. Scans whole table, so doesn't leak d through memory access.
+fn select<P: CurvePoint>(table: &[P], d: isize) -> P {
+    let m = d >> (8 * ::std::mem::size_of::<isize>() - 1);
+    let index = (((d ^ m) - m) - 1) / 2;
+
+    let mut r = table[0];
+    for (i, p) in table.iter().enumerate().skip(1) {
+        r.cmove(p, ct_eq(index, i as isize));
+    }
+
+    let mut neg = r;
+    neg.negate();
+    r.cmove(&neg, m & 1);
+    r
+}
+
+/// table[j] = (2 * j + 1) * p
+fn odd_multiples<P: CurvePoint>(p: &P) -> Vec<P> {
+    let mut double = *p;
+    double.double();
+
+    let mut table = vec![*p];
+    for j in 1..TABLE_SIZE {
+        let mut q = table[j - 1];
+        q.add_point(&double);
+        table.push(q);
+    }
+    table
+}
+
+/// Selects p or 2 * p depending on flag returned by scalar_digits and subtracts it from r.
+fn correct<P: CurvePoint>(r: &mut P, p: &P, ns: isize) {
+    let mut c = *p;
+    c.double();
+    c.cmove(p, ns);
+    c.negate();
+    r.add_point(&c);
+}
+
+/// Interleaves fixed window multiplications of all points sharing doublings (Straus method).
+/// Runs in time independent of scalar values.
+fn multi_scalar_mul<P: CurvePoint>(points: &[P], scalars: &[GroupOrderElement]) -> Result<P, IndyCryptoError> {
+    if points.len() != scalars.len() {
+        return Err(IndyCryptoError::InvalidStructure(
+            format!("Lists of points and scalars have different len: {}, {}", points.len(), scalars.len())));
+    }
+
+    let tables: Vec<Vec<P>> = points.iter().map(odd_multiples).collect();
+    let mut digits: Vec<([isize; DIGITS_COUNT], isize)> = scalars.iter().map(scalar_digits).collect();
+
+    let mut r = P::infinity();
+    for i in (0..DIGITS_COUNT).rev() {
+        if i != DIGITS_COUNT - 1 {
+            for _ in 0..WINDOW_WIDTH {
+                r.double();
+            }
+        }
+        for (table, &(ref d, _)) in tables.iter().zip(digits.iter()) {
+            r.add_point(&select(table, d[i]));
+        }
+    }
+
+    for (p, &(_, ns)) in points.iter().zip(digits.iter()) {
+        correct(&mut r, p, ns);
+    }
+
+    for &mut (ref mut d, _) in digits.iter_mut() {
+        d.zeroize();
+    }
+
+    Ok(r)
+}
+
+/// table[i][j] = (2 * j + 1) * 2^(WINDOW_WIDTH * i) * p for all signed digits of scalar
+fn fixed_base_table<P: CurvePoint>(p: &P) -> Vec<Vec<P>> {
+    let mut table = Vec::new();
+    let mut base = *p;
+    for _ in 0..DIGITS_COUNT {
+        table.push(odd_multiples(&base));
+        for _ in 0..WINDOW_WIDTH {
+            base.double();
+        }
+    }
+    table
+}
+
+/// Runs in time independent of scalar value.
+fn fixed_base_mul<P: CurvePoint>(table: &[Vec<P>], e: &GroupOrderElement) -> P {
+    let (mut digits, ns) = scalar_digits(e);
+
+    let mut r = P::infinity();
+    for (window, d) in table.iter().zip(digits.iter()) {
+        r.add_point(&select(window, *d));
+    }
+    correct(&mut r, &table[0][0], ns);
+
+    digits.zeroize();
+    r
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupOrderElement {
+    bn: BIG
+}
+
+impl GroupOrderElement {
+    pub const BYTES_REPR_SIZE: usize = MODBYTES;
+
+    pub fn new() -> Result<GroupOrderElement, IndyCryptoError> {
+        // returns random element in 0, ..., GroupOrder-1
+        Ok(GroupOrderElement {
+            bn: random_mod_order()?
+        })
+    }
+
+    pub fn new_from_seed(seed: &[u8]) -> Result<GroupOrderElement, IndyCryptoError> {
+        // returns random element in 0, ..., GroupOrder-1
+        if seed.len() != MODBYTES {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("Invalid len of seed: expected {}, actual {}", MODBYTES, seed.len())));
+        }
+        let mut rng = RAND::new();
+        rng.clean();
+        rng.seed(seed.len(), seed);
+
+        Ok(GroupOrderElement {
+            bn: BIG::randomnum(&BIG::new_ints(&CURVE_ORDER), &mut rng)
+        })
+    }
+
+    /// (GroupOrderElement ^ GroupOrderElement) mod GroupOrder
+    pub fn pow_mod(&self, e: &GroupOrderElement) -> Result<GroupOrderElement, IndyCryptoError> {
+        let mut base = self.bn;
+        let mut pow = e.bn;
+        Ok(GroupOrderElement {
+            bn: base.powmod(&mut pow, &BIG::new_ints(&CURVE_ORDER))
+        })
+    }
+
+    /// (GroupOrderElement + GroupOrderElement) mod GroupOrder
+    pub fn add_mod(&self, r: &GroupOrderElement) -> Result<GroupOrderElement, IndyCryptoError> {
+        let mut sum = self.bn;
+        sum.add(&r.bn);
+        sum.rmod(&BIG::new_ints(&CURVE_ORDER));
+        Ok(GroupOrderElement {
+            bn: sum
+        })
+    }
+
+    /// (GroupOrderElement - GroupOrderElement) mod GroupOrder
+    pub fn sub_mod(&self, r: &GroupOrderElement) -> Result<GroupOrderElement, IndyCryptoError> {
+        //need to use modneg if sub is negative
+        let mut diff = self.bn;
+        diff.sub(&r.bn);
+        let mut zero = BIG::new();
+        zero.zero();
+
+        if diff < zero {
+            return Ok(GroupOrderElement {
+                bn: BIG::modneg(&mut diff, &BIG::new_ints(&CURVE_ORDER))
+            });
+        }
+
+        Ok(GroupOrderElement {
+            bn: diff
+        })
+    }
+
+    /// (GroupOrderElement * GroupOrderElement) mod GroupOrder
+    pub fn mul_mod(&self, r: &GroupOrderElement) -> Result<GroupOrderElement, IndyCryptoError> {
+        let mut base = self.bn;
+        let mut r = r.bn;
+        Ok(GroupOrderElement {
+            bn: BIG::modmul(&mut base, &mut r, &BIG::new_ints(&CURVE_ORDER))
+        })
+    }
 
     /// 1 / GroupOrderElement
     pub fn inverse(&self) -> Result<GroupOrderElement, IndyCryptoError> {
@@ -423,6 +1406,7 @@ impl GroupOrderElement {
         })
     }
 
+    /// Returns bytes representation: big-endian number (MODBYTES).
     pub fn to_bytes(&self) -> Result<Vec<u8>, IndyCryptoError> {
         let mut bn = self.bn;
         let mut vec = vec![0u8; Self::BYTES_REPR_SIZE];
@@ -430,6 +1414,7 @@ impl GroupOrderElement {
         Ok(vec)
     }
 
+    /// Restores element from big-endian number up to MODBYTES long (shorter numbers are left-padded with zeros).
     pub fn from_bytes(b: &[u8]) -> Result<GroupOrderElement, IndyCryptoError> {
         if b.len() > Self::BYTES_REPR_SIZE {
             return Err(IndyCryptoError::InvalidStructure(
@@ -453,6 +1438,47 @@ impl GroupOrderElement {
             }
         )
     }
+
+    /// Reduces big-endian bytes (up to 2 * (MODBYTES - 1)) modulo GroupOrder.
+    pub fn from_wide_bytes(b: &[u8]) -> Result<GroupOrderElement, IndyCryptoError> {
+        if b.len() > 2 * (MODBYTES - 1) {
+            return Err(IndyCryptoError::InvalidStructure(
+                "Invalid len of bytes representation".to_string()));
+        }
+
+        // b = hi * 2^(8 * lo_len) + lo
+        let order = BIG::new_ints(&CURVE_ORDER);
+        let lo_len = b.len() / 2;
+        let mut hi = big_from_bytes(&b[..b.len() - lo_len]);
+        let lo = big_from_bytes(&b[b.len() - lo_len..]);
+
+        let mut shift = vec![0u8; MODBYTES];
+        shift[MODBYTES - lo_len - 1] = 1;
+
+        let mut bn = BIG::modmul(&mut hi, &mut BIG::frombytes(&shift), &order);
+        bn.add(&lo);
+        bn.rmod(&order);
+
+        Ok(GroupOrderElement {
+            bn: bn
+        })
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.bn.iszilch()
+    }
+}
+
+impl Zeroize for GroupOrderElement {
+    fn zeroize(&mut self) {
+        self.bn.w.zeroize();
+    }
+}
+
+impl Drop for GroupOrderElement {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
 }
 
 #[cfg(feature = "serialization")]
@@ -504,6 +1530,38 @@ impl Pair {
         })
     }
 
+    /// e(PointG1, PointG2) * e(PointG1, PointG2) * ... with single final exponentiation
+    pub fn multi_pair(pairs: &[(&PointG1, &PointG2)]) -> Result<Pair, IndyCryptoError> {
+        let prepared = pairs
+            .iter()
+            .map(|&(_, q)| PreparedPointG2::new(q))
+            .collect::<Result<Vec<PreparedPointG2>, IndyCryptoError>>()?;
+
+        let pairs: Vec<(&PointG1, &PreparedPointG2)> = pairs
+            .iter()
+            .zip(prepared.iter())
+            .map(|(&(p, _), q)| (p, q))
+            .collect();
+
+        Pair::multi_pair_prepared(&pairs)
+    }
+
+    /// e(PointG1, PreparedPointG2) * e(PointG1, PreparedPointG2) * ... with single final exponentiation
+    pub fn multi_pair_prepared(pairs: &[(&PointG1, &PreparedPointG2)]) -> Result<Pair, IndyCryptoError> {
+        let mut result = fexp(&multi_miller_loop(pairs));
+        result.reduce();
+
+        Ok(Pair {
+            pair: result
+        })
+    }
+
+    /// e() == 1
+    pub fn is_unity(&self) -> Result<bool, IndyCryptoError> {
+        let mut r = self.pair;
+        Ok(r.isunity())
+    }
+
     /// e() * e()
     pub fn mul(&self, b: &Pair) -> Result<Pair, IndyCryptoError> {
         let mut base = self.pair;
@@ -544,12 +1602,57 @@ impl Pair {
         })
     }
 
+    /// Returns bytes representation: 12 coefficients (big-endian, MODBYTES each) in order of extension tower
+    /// FP12 = FP4[w] (a, b, c), FP4 = FP2[s] (a, b), FP2 = FP[i] (a, b) - a.a.a, a.a.b, a.b.a, ..., c.b.b -
+    /// followed by zero padding up to BYTES_REPR_SIZE.
     pub fn to_bytes(&self) -> Result<Vec<u8>, IndyCryptoError> {
         let mut r = self.pair;
         let mut vec = vec![0u8; Self::BYTES_REPR_SIZE];
         r.tobytes(&mut vec);
         Ok(vec)
     }
+
+    /// Restores element from bytes representation.
+    /// Fails if representation isn't canonical or element doesn't belong to the group GT (r-torsion of FP12).
+    pub fn from_bytes(b: &[u8]) -> Result<Pair, IndyCryptoError> {
+        if b.len() != Self::BYTES_REPR_SIZE {
+            return Err(IndyCryptoError::InvalidStructure(
+                "Invalid len of bytes representation".to_string()));
+        }
+
+        check_field_elements(&b[..12 * MODBYTES], "Pair")?;
+        check_padding(&b[12 * MODBYTES..], "Pair")?;
+
+        let pair = Pair {
+            pair: FP12::frombytes(b)
+        };
+
+        pair._check()?;
+
+        Ok(pair)
+    }
+
+    /// Checks that element is unitary (x * conj(x) == 1), so cyclotomic squarings of FP12::pow are valid for it,
+    /// and that x ^ GroupOrder == 1.
+    fn _check(&self) -> Result<(), IndyCryptoError> {
+        let mut conj = self.pair;
+        conj.conj();
+        let mut norm = self.pair;
+        norm.mul(&mut conj);
+
+        if !norm.isunity() {
+            return Err(IndyCryptoError::InvalidGroupElement(
+                "Pair doesn't belong to cyclotomic subgroup".to_string()));
+        }
+
+        let mut pair = self.pair;
+        if !pair.pow(&mut BIG::new_ints(&CURVE_ORDER)).isunity() {
+            return Err(IndyCryptoError::InvalidGroupElement(
+                "Pair doesn't belong to GT".to_string()));
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(feature = "serialization")]
@@ -582,6 +1685,14 @@ impl<'a> Deserialize<'a> for Pair {
     }
 }
 
+/// AMCL BN254 pairing engine.
+/// Serializable only to let types generic over engine derive serde traits.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub struct Bn254;
+
+impl_pairing_engine!(Bn254, "BN254", "BN254G1_XMD:SHA-256_SVDW_RO_");
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -594,6 +1705,35 @@ mod tests {
         assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
     }
 
+    #[test]
+    fn group_order_element_zeroize_works() {
+        let mut element = GroupOrderElement::new().unwrap();
+        element.zeroize();
+        assert!(element.is_zero());
+    }
+
+    #[test]
+    fn group_order_element_from_wide_bytes_works() {
+        let mut bytes = vec![0u8; 16];
+        bytes.extend_from_slice(&GroupOrderElement::new().unwrap().to_bytes().unwrap());
+        let expected = GroupOrderElement::from_bytes(&bytes[16..]).unwrap();
+        let element = GroupOrderElement::from_wide_bytes(&bytes).unwrap();
+        assert_eq!(expected.to_bytes().unwrap(), element.to_bytes().unwrap());
+
+        // 2^384 - 1 mod r
+        let element = GroupOrderElement::from_wide_bytes(&[0xFF; 48]).unwrap();
+        assert_eq!(vec![8, 220, 55, 146, 222, 151, 39, 22, 4, 77, 132, 100, 21, 176, 85, 184, 84, 133, 2, 94, 244, 23, 197, 170, 87, 126, 216, 178, 183, 227, 128, 48], element.to_bytes().unwrap());
+
+        let element = GroupOrderElement::from_wide_bytes(&[0; 48]).unwrap();
+        assert!(element.is_zero());
+    }
+
+    #[test]
+    fn group_order_element_from_wide_bytes_works_for_invalid_len() {
+        let err = GroupOrderElement::from_wide_bytes(&[0; 63]).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+    }
+
     #[test]
     fn pairing_definition_bilinearity() {
         let a = GroupOrderElement::new().unwrap();
@@ -605,6 +1745,366 @@ mod tests {
         assert_eq!(left, right);
     }
 
+    fn _multi_mul_g1(n: usize) {
+        let points: Vec<PointG1> = (0..n).map(|_| PointG1::new().unwrap()).collect();
+        let scalars: Vec<GroupOrderElement> = (0..n).map(|_| GroupOrderElement::new().unwrap()).collect();
+
+        let mut expected = PointG1::new_inf().unwrap();
+        for (p, e) in points.iter().zip(scalars.iter()) {
+            expected = expected.add(&p.mul(e).unwrap()).unwrap();
+        }
+
+        assert_eq!(expected.to_bytes().unwrap(), PointG1::multi_mul(&points, &scalars).unwrap().to_bytes().unwrap());
+    }
+
+    #[test]
+    fn point_g1_multi_mul_works() {
+        _multi_mul_g1(1);
+        _multi_mul_g1(5);
+    }
+
+    #[test]
+    fn point_g1_multi_mul_works_for_many_points() {
+        _multi_mul_g1(40);
+    }
+
+    #[test]
+    fn point_g1_multi_mul_works_for_special_scalars() {
+        let p = PointG1::new().unwrap();
+        let q = PointG1::new().unwrap();
+        let zero = GroupOrderElement::from_bytes(&[0]).unwrap();
+        let one = GroupOrderElement::from_bytes(&[1]).unwrap();
+
+        assert!(PointG1::multi_mul(&[], &[]).unwrap().is_inf().unwrap());
+        assert!(PointG1::multi_mul(&[p, q], &[zero.clone(), zero]).unwrap().is_inf().unwrap());
+        assert_eq!(p.add(&q).unwrap().to_bytes().unwrap(), PointG1::multi_mul(&[p, q], &[one.clone(), one.clone()]).unwrap().to_bytes().unwrap());
+        assert!(PointG1::multi_mul(&[p, p.neg().unwrap()], &[one.clone(), one]).unwrap().is_inf().unwrap());
+    }
+
+    #[test]
+    fn point_g1_multi_mul_works_for_different_len() {
+        let res = PointG1::multi_mul(&[PointG1::new().unwrap()], &[]);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn point_g2_multi_mul_works() {
+        for n in [3, 32].iter() {
+            let points: Vec<PointG2> = (0..*n).map(|_| PointG2::new().unwrap()).collect();
+            let scalars: Vec<GroupOrderElement> = (0..*n).map(|_| GroupOrderElement::new().unwrap()).collect();
+
+            let mut expected = PointG2::new_inf().unwrap();
+            for (p, e) in points.iter().zip(scalars.iter()) {
+                expected = expected.add(&p.mul(e).unwrap()).unwrap();
+            }
+
+            assert_eq!(expected.to_bytes().unwrap(), PointG2::multi_mul(&points, &scalars).unwrap().to_bytes().unwrap());
+        }
+    }
+
+    #[test]
+    fn fixed_base_point_g1_works() {
+        let p = PointG1::new().unwrap();
+        let fixed_base = FixedBasePointG1::new(&p).unwrap();
+
+        for _ in 0..3 {
+            let e = GroupOrderElement::new().unwrap();
+            assert_eq!(p.mul(&e).unwrap().to_bytes().unwrap(), fixed_base.mul(&e).unwrap().to_bytes().unwrap());
+        }
+
+        let max = GroupOrderElement::from_bytes(&[0xFF; MODBYTES]).unwrap();
+        assert_eq!(p.mul(&max).unwrap().to_bytes().unwrap(), fixed_base.mul(&max).unwrap().to_bytes().unwrap());
+        assert!(fixed_base.mul(&GroupOrderElement::from_bytes(&[0]).unwrap()).unwrap().is_inf().unwrap());
+    }
+
+    #[test]
+    fn fixed_base_point_g2_works() {
+        let p = PointG2::new().unwrap();
+        let fixed_base = FixedBasePointG2::new(&p).unwrap();
+
+        for _ in 0..3 {
+            let e = GroupOrderElement::new().unwrap();
+            assert_eq!(p.mul(&e).unwrap().to_bytes().unwrap(), fixed_base.mul(&e).unwrap().to_bytes().unwrap());
+        }
+    }
+
+    #[test]
+    fn multi_pair_works_for_single_pair() {
+        let p = PointG1::new().unwrap();
+        let q = PointG2::new().unwrap();
+        assert_eq!(Pair::pair(&p, &q).unwrap(), Pair::multi_pair(&[(&p, &q)]).unwrap());
+    }
+
+    #[test]
+    fn multi_pair_works() {
+        let p1 = PointG1::new().unwrap();
+        let q1 = PointG2::new().unwrap();
+        let p2 = PointG1::new().unwrap();
+        let q2 = PointG2::new().unwrap();
+        let p3 = PointG1::new().unwrap();
+        let q3 = PointG2::new().unwrap();
+
+        let expected = Pair::pair(&p1, &q1).unwrap()
+            .mul(&Pair::pair(&p2, &q2).unwrap()).unwrap()
+            .mul(&Pair::pair(&p3, &q3).unwrap()).unwrap();
+        let multi_pair = Pair::multi_pair(&[(&p1, &q1), (&p2, &q2), (&p3, &q3)]).unwrap();
+        assert_eq!(expected.to_bytes().unwrap(), multi_pair.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn multi_pair_works_for_bilinearity() {
+        let p = PointG1::new().unwrap();
+        let q = PointG2::new().unwrap();
+        let a = GroupOrderElement::new().unwrap();
+
+        let pair = Pair::multi_pair(&[(&p.mul(&a).unwrap(), &q), (&p.neg().unwrap(), &q.mul(&a).unwrap())]).unwrap();
+        assert!(pair.is_unity().unwrap());
+        assert!(!Pair::pair(&p, &q).unwrap().is_unity().unwrap());
+    }
+
+    #[test]
+    fn multi_pair_works_for_infinity() {
+        let p = PointG1::new().unwrap();
+        let q = PointG2::new().unwrap();
+        let p_inf = PointG1::new_inf().unwrap();
+        let q_inf = PointG2::new_inf().unwrap();
+
+        assert!(Pair::multi_pair(&[]).unwrap().is_unity().unwrap());
+        assert!(Pair::multi_pair(&[(&p_inf, &q), (&p, &q_inf)]).unwrap().is_unity().unwrap());
+        assert_eq!(Pair::pair(&p, &q).unwrap().to_bytes().unwrap(),
+                   Pair::multi_pair(&[(&p, &q), (&p_inf, &q)]).unwrap().to_bytes().unwrap());
+    }
+
+    #[test]
+    fn multi_pair_prepared_works() {
+        let p1 = PointG1::new().unwrap();
+        let q1 = PointG2::new().unwrap();
+        let p2 = PointG1::new().unwrap();
+        let q2 = PointG2::new().unwrap();
+
+        let prepared_q1 = PreparedPointG2::new(&q1).unwrap();
+        let prepared_q2 = PreparedPointG2::new(&q2).unwrap();
+
+        assert_eq!(Pair::multi_pair(&[(&p1, &q1), (&p2, &q2)]).unwrap().to_bytes().unwrap(),
+                   Pair::multi_pair_prepared(&[(&p1, &prepared_q1), (&p2, &prepared_q2)]).unwrap().to_bytes().unwrap());
+    }
+
+    #[test]
+    fn hash_to_curve_works() {
+        let dst = b"INDY_CRYPTO_TEST_DST";
+
+        let point = PointG1::hash_to_curve(b"", dst).unwrap();
+        assert_eq!("03c0c47b96db88fb9e232d2ca02caec3b759b79c86c6776635320d814115e24a20b35eefb4184879bd33612f736d9f80a72cbc89cb408e029065333f85c9953d",
+                   to_hex(&point.to_bytes().unwrap()[1..2 * MODBYTES + 1]));
+
+        let point = PointG1::hash_to_curve(b"abc", dst).unwrap();
+        assert_eq!("09ab39580943ec323b32fc8054db550db48a43b966c39fdcb6fc503d7e963a4d0de46ef7e2a8db9162fb03f85688cc3e7b263b71c8e43b7ecf1af9869615bf1c",
+                   to_hex(&point.to_bytes().unwrap()[1..2 * MODBYTES + 1]));
+    }
+
+    #[test]
+    fn hash_to_curve_works_for_different_dst() {
+        let point1 = PointG1::hash_to_curve(b"abc", b"DST1").unwrap();
+        let point2 = PointG1::hash_to_curve(b"abc", b"DST2").unwrap();
+        assert!(!point1.is_inf().unwrap());
+        assert_ne!(point1.to_bytes().unwrap(), point2.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn point_g1_compressed_bytes_works() {
+        let point = PointG1::new().unwrap();
+        let bytes = point.to_compressed_bytes().unwrap();
+        assert_eq!(PointG1::COMPRESSED_BYTES_REPR_SIZE, bytes.len());
+
+        let restored = PointG1::from_compressed_bytes(&bytes).unwrap();
+        assert_eq!(point.to_bytes().unwrap(), restored.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn point_g1_compressed_bytes_works_for_negated_point() {
+        let point = PointG1::new().unwrap();
+        let neg = point.neg().unwrap();
+
+        let bytes = point.to_compressed_bytes().unwrap();
+        let neg_bytes = neg.to_compressed_bytes().unwrap();
+        assert_eq!(bytes[0] ^ COMPRESSED_Y_FLAG, neg_bytes[0]);
+        assert_eq!(bytes[1..], neg_bytes[1..]);
+
+        let restored = PointG1::from_compressed_bytes(&neg_bytes).unwrap();
+        assert_eq!(neg.to_bytes().unwrap(), restored.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn point_g1_compressed_bytes_works_for_infinity() {
+        let bytes = PointG1::new_inf().unwrap().to_compressed_bytes().unwrap();
+        assert!(PointG1::from_compressed_bytes(&bytes).unwrap().is_inf().unwrap());
+    }
+
+    #[test]
+    fn point_g1_from_compressed_bytes_works_for_invalid() {
+        let mut bytes = PointG1::new().unwrap().to_compressed_bytes().unwrap();
+        assert!(PointG1::from_compressed_bytes(&bytes[1..]).is_err());
+
+        bytes[0] |= COMPRESSED_INFINITY_FLAG;
+        assert!(PointG1::from_compressed_bytes(&bytes).is_err());
+
+        // x = p is not canonical
+        let mut modulus = BIG::new_ints(&MODULUS);
+        let mut bytes = vec![0u8; MODBYTES];
+        modulus.tobytes(&mut bytes);
+        assert!(PointG1::from_compressed_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn point_g2_compressed_bytes_works() {
+        let point = PointG2::new().unwrap();
+        let bytes = point.to_compressed_bytes().unwrap();
+        assert_eq!(PointG2::COMPRESSED_BYTES_REPR_SIZE, bytes.len());
+
+        let restored = PointG2::from_compressed_bytes(&bytes).unwrap();
+        assert_eq!(point.to_bytes().unwrap(), restored.to_bytes().unwrap());
+
+        let neg = PointG2::new_inf().unwrap().sub(&point).unwrap();
+        let restored = PointG2::from_compressed_bytes(&neg.to_compressed_bytes().unwrap()).unwrap();
+        assert_eq!(neg.to_bytes().unwrap(), restored.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn point_g2_compressed_bytes_works_for_infinity() {
+        let bytes = PointG2::new_inf().unwrap().to_compressed_bytes().unwrap();
+        let point = PointG2::from_compressed_bytes(&bytes).unwrap();
+        assert_eq!(PointG2::new_inf().unwrap().to_compressed_bytes().unwrap(), point.to_compressed_bytes().unwrap());
+    }
+
+    /// Returns point of the twist curve that doesn't belong to the prime-order subgroup
+    fn _point_g2_outside_subgroup() -> PointG2 {
+        let mut xa = BIG::new_int(1);
+        loop {
+            let mut point = ECP2::new_fp2(&FP2::new_bigs(&xa, &BIG::new()));
+            if !point.is_infinity() {
+                return PointG2 { point: point };
+            }
+            xa.inc(1);
+        }
+    }
+
+    #[test]
+    fn point_g1_from_bytes_works() {
+        let point = PointG1::new().unwrap();
+        let restored = PointG1::from_bytes(&point.to_bytes().unwrap()).unwrap();
+        assert_eq!(point.to_bytes().unwrap(), restored.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn point_g1_from_bytes_works_for_point_outside_curve() {
+        let mut bytes = PointG1::new().unwrap().to_bytes().unwrap();
+        bytes[MODBYTES * 2] ^= 0x01;
+
+        let err = PointG1::from_bytes(&bytes).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidGroupElement);
+    }
+
+    #[test]
+    fn point_g1_from_bytes_works_for_infinity() {
+        let bytes = PointG1::new_inf().unwrap().to_bytes().unwrap();
+
+        let err = PointG1::from_bytes(&bytes).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidGroupElement);
+
+        assert!(PointG1::from_bytes_unchecked(&bytes).unwrap().is_inf().unwrap());
+    }
+
+    #[test]
+    fn point_g1_from_string_works_for_point_outside_curve() {
+        let point = PointG1::new().unwrap();
+        let mut invalid = point.point;
+        invalid.add(&mut point.point.clone());
+        let string = point.to_string().unwrap();
+        let invalid_string = format!("false {} {} {}",
+                                     invalid.getpx().to_hex(), point.point.getpy().to_hex(), point.point.getpz().to_hex());
+
+        assert!(PointG1::from_string(&string).is_ok());
+
+        let err = PointG1::from_string(&invalid_string).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidGroupElement);
+
+        PointG1::from_string_unchecked(&invalid_string).unwrap();
+    }
+
+    #[test]
+    fn point_g1_from_string_works_for_infinity() {
+        let string = PointG1::new_inf().unwrap().to_string().unwrap();
+        assert!(PointG1::from_string(&string).unwrap().is_inf().unwrap());
+
+        let err = PointG1::from_string("").unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidGroupElement);
+    }
+
+    #[test]
+    fn point_g2_from_bytes_works() {
+        let point = PointG2::new().unwrap();
+        let restored = PointG2::from_bytes(&point.to_bytes().unwrap()).unwrap();
+        assert_eq!(point.to_bytes().unwrap(), restored.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn point_g2_from_bytes_works_for_point_outside_curve() {
+        let mut bytes = PointG2::new().unwrap().to_bytes().unwrap();
+        bytes[MODBYTES * 3] ^= 0x01;
+
+        let err = PointG2::from_bytes(&bytes).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidGroupElement);
+    }
+
+    #[test]
+    fn point_g2_from_bytes_works_for_point_outside_subgroup() {
+        let bytes = _point_g2_outside_subgroup().to_bytes().unwrap();
+
+        let err = PointG2::from_bytes(&bytes).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidGroupElement);
+
+        PointG2::from_bytes_unchecked(&bytes).unwrap();
+    }
+
+    #[test]
+    fn point_g2_from_bytes_works_for_infinity() {
+        let bytes = PointG2::new_inf().unwrap().to_bytes().unwrap();
+
+        let err = PointG2::from_bytes(&bytes).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidGroupElement);
+    }
+
+    #[test]
+    fn point_g2_from_string_works_for_point_outside_subgroup() {
+        let string = _point_g2_outside_subgroup().to_string().unwrap();
+
+        let err = PointG2::from_string(&string).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidGroupElement);
+
+        PointG2::from_string_unchecked(&string).unwrap();
+    }
+
+    #[test]
+    fn point_g2_from_string_works_for_infinity() {
+        let string = PointG2::new_inf().unwrap().to_string().unwrap();
+        assert!(PointG2::from_string(&string).unwrap().is_inf().unwrap());
+
+        let err = PointG2::from_string("").unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidGroupElement);
+    }
+
+    #[test]
+    fn point_g2_from_compressed_bytes_works_for_point_outside_subgroup() {
+        let bytes = _point_g2_outside_subgroup().to_compressed_bytes().unwrap();
+
+        let err = PointG2::from_compressed_bytes(&bytes).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidGroupElement);
+    }
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
     #[test]
     fn point_g1_infinity_test() {
         let p = PointG1::new_inf().unwrap();
@@ -642,6 +2142,116 @@ mod tests {
         let pair3 = pair_result.mul(&pair1.inverse().unwrap()).unwrap();
         assert_eq!(pair2, pair3);
     }
+
+    #[test]
+    fn point_g1_to_bytes_works_for_layout() {
+        let point = PointG1::new().unwrap();
+        let bytes = point.to_bytes().unwrap();
+
+        let mut ecp = point.point;
+        let mut x = vec![0u8; MODBYTES];
+        ecp.getx().tobytes(&mut x);
+        let mut y = vec![0u8; MODBYTES];
+        ecp.gety().tobytes(&mut y);
+
+        assert_eq!(PointG1::BYTES_REPR_SIZE, bytes.len());
+        assert_eq!(UNCOMPRESSED_PREFIX, bytes[0]);
+        assert_eq!(x, &bytes[1..1 + MODBYTES]);
+        assert_eq!(y, &bytes[1 + MODBYTES..1 + 2 * MODBYTES]);
+        assert!(bytes[1 + 2 * MODBYTES..].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn point_g1_from_bytes_works_for_non_canonical_representation() {
+        let bytes = PointG1::new().unwrap().to_bytes().unwrap();
+
+        let mut invalid_prefix = bytes.clone();
+        invalid_prefix[0] = 0x02;
+        let err = PointG1::from_bytes(&invalid_prefix).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+
+        let mut invalid_padding = bytes.clone();
+        invalid_padding[PointG1::BYTES_REPR_SIZE - 1] = 0x01;
+        let err = PointG1::from_bytes(&invalid_padding).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+    }
+
+    #[test]
+    fn point_g2_from_bytes_works_for_non_canonical_representation() {
+        let point = PointG2::new().unwrap();
+        let mut bytes = point.to_bytes().unwrap();
+
+        // x.a + p is the same field element, but it isn't canonical
+        let mut xa = BIG::frombytes(&bytes[..MODBYTES]);
+        xa.add(&BIG::new_ints(&MODULUS));
+        xa.norm();
+        xa.tobytes(&mut bytes[..MODBYTES]);
+
+        assert_eq!(point, PointG2::from_bytes_unchecked(&bytes).unwrap());
+
+        let err = PointG2::from_bytes(&bytes).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+    }
+
+    #[test]
+    fn pair_from_bytes_works() {
+        let pair = Pair::pair(&PointG1::new().unwrap(), &PointG2::new().unwrap()).unwrap();
+        let bytes = pair.to_bytes().unwrap();
+        assert_eq!(Pair::BYTES_REPR_SIZE, bytes.len());
+
+        let restored = Pair::from_bytes(&bytes).unwrap();
+        assert_eq!(bytes, restored.to_bytes().unwrap());
+        assert!(restored.mul(&pair.inverse().unwrap()).unwrap().is_unity().unwrap());
+    }
+
+    #[test]
+    fn pair_from_bytes_works_for_unity() {
+        let unity = Pair { pair: FP12::new_int(1) };
+        let restored = Pair::from_bytes(&unity.to_bytes().unwrap()).unwrap();
+        assert!(restored.is_unity().unwrap());
+    }
+
+    #[test]
+    fn pair_from_bytes_works_for_invalid_len() {
+        let bytes = Pair::pair(&PointG1::new().unwrap(), &PointG2::new().unwrap()).unwrap().to_bytes().unwrap();
+        let err = Pair::from_bytes(&bytes[..12 * MODBYTES]).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+    }
+
+    #[test]
+    fn pair_from_bytes_works_for_non_canonical_representation() {
+        let bytes = Pair::pair(&PointG1::new().unwrap(), &PointG2::new().unwrap()).unwrap().to_bytes().unwrap();
+
+        let mut non_canonical = bytes.clone();
+        BIG::new_ints(&MODULUS).tobytes(&mut non_canonical[..MODBYTES]);
+        let err = Pair::from_bytes(&non_canonical).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+
+        let mut invalid_padding = bytes.clone();
+        invalid_padding[Pair::BYTES_REPR_SIZE - 1] = 0x01;
+        let err = Pair::from_bytes(&invalid_padding).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+    }
+
+    #[test]
+    fn pair_from_bytes_works_for_element_outside_cyclotomic_subgroup() {
+        let two = Pair { pair: FP12::new_int(2) };
+        let err = Pair::from_bytes(&two.to_bytes().unwrap()).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidGroupElement);
+    }
+
+    #[test]
+    fn pair_from_bytes_works_for_element_outside_gt() {
+        // conj(x) / x is unitary for any x, but it is in GT only if x ^ ((p^6 - 1) * r) == 1
+        let mut x = FP12::new_fp4s(&FP4::new_int(2), &FP4::new_int(1), &FP4::new_int(0));
+        let mut unitary = x;
+        unitary.conj();
+        x.inverse();
+        unitary.mul(&mut x);
+
+        let err = Pair::from_bytes(&Pair { pair: unitary }.to_bytes().unwrap()).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidGroupElement);
+    }
 }
 
 #[cfg(feature = "serialization")]
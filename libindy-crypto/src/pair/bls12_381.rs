@@ -0,0 +1,996 @@
+use errors::IndyCryptoError;
+
+use ark_bls12_381::{Bls12_381, Fq, Fq12, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+use ark_bls12_381::g1::Config as G1Config;
+
+use ark_ec::{AffineRepr, CurveGroup, Group, VariableBaseMSM};
+use ark_ec::hashing::curve_maps::wb::WBMap;
+use ark_ec::hashing::map_to_curve_hasher::MapToCurve;
+use ark_ec::pairing::Pairing;
+use ark_ec::scalar_mul::fixed_base::FixedBase;
+
+use ark_ff::{BigInteger, BigInteger384, Field, One, PrimeField, Zero};
+
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+
+use rand::os::OsRng;
+use rand::Rng;
+
+use pair::expand_message_xmd;
+
+use sha2::{Sha512, Digest};
+
+use zeroize::Zeroize;
+
+#[cfg(feature = "serialization")]
+use serde::ser::{Serialize, Serializer, Error as SError};
+#[cfg(feature = "serialization")]
+use serde::de::{Deserialize, Deserializer, Visitor, Error as DError};
+use std::fmt;
+
+/// Len of base field element representation
+const FIELD_BYTES: usize = 48;
+
+/// Len of group order element representation
+const SCALAR_BYTES: usize = 32;
+
+/// Length of field element representation in hash_to_field: ceil((ceil(log2(p)) + k) / 8) with k = 128
+const HASH_TO_FIELD_LEN: usize = 64;
+
+/// Width of windows in precomputed tables of fixed base points
+const FIXED_BASE_WINDOW: usize = 4;
+
+type G2Prepared = <Bls12_381 as Pairing>::G2Prepared;
+
+fn random_mod_order() -> Result<Fr, IndyCryptoError> {
+    // 128 extra bits make bias of reduction negligible
+    let mut seed = vec![0; 2 * SCALAR_BYTES];
+    let mut os_rng = OsRng::new().unwrap();
+    os_rng.fill_bytes(&mut seed.as_mut_slice());
+    Ok(Fr::from_be_bytes_mod_order(&seed))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02X}", b)).collect()
+}
+
+fn from_hex(str: &str) -> Result<Vec<u8>, IndyCryptoError> {
+    if str.len() % 2 != 0 || !str.is_ascii() {
+        return Err(IndyCryptoError::InvalidStructure(
+            "Invalid hex representation".to_string()));
+    }
+
+    (0..str.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&str[i..i + 2], 16)
+            .map_err(|_| IndyCryptoError::InvalidStructure("Invalid hex representation".to_string())))
+        .collect()
+}
+
+/// Restores base field element from big-endian bytes. Fails if value isn't less than field modulus.
+fn fq_from_bytes(b: &[u8]) -> Result<Fq, IndyCryptoError> {
+    let mut limbs = [0u64; 6];
+    for (i, chunk) in b.rchunks(8).enumerate() {
+        limbs[i] = chunk.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64);
+    }
+
+    Fq::from_bigint(BigInteger384::new(limbs))
+        .ok_or(IndyCryptoError::InvalidStructure(
+            "Invalid field element representation".to_string()))
+}
+
+fn hash_to_field(message: &[u8], dst: &[u8], count: usize) -> Result<Vec<Fq>, IndyCryptoError> {
+    let uniform_bytes = expand_message_xmd(message, dst, count * HASH_TO_FIELD_LEN)?;
+    Ok(uniform_bytes.chunks(HASH_TO_FIELD_LEN).map(Fq::from_be_bytes_mod_order).collect())
+}
+
+/// Simplified SWU map to the 11-isogenous curve followed by the isogeny map
+/// as defined in IETF hash-to-curve (RFC 9380, section 6.6.3)
+fn map_to_curve_sswu(u: &Fq) -> Result<G1Projective, IndyCryptoError> {
+    let map = WBMap::<G1Config>::new()
+        .map_err(|err| IndyCryptoError::InvalidState(format!("Invalid map to curve parameters: {:?}", err)))?;
+
+    let point = map.map_to_curve(*u)
+        .map_err(|err| IndyCryptoError::InvalidState(format!("Map to curve failed: {:?}", err)))?;
+
+    Ok(point.into_group())
+}
+
+fn point_from_bytes<P: CanonicalDeserialize>(b: &[u8], len: usize, compress: Compress, validate: Validate) -> Result<P, IndyCryptoError> {
+    if b.len() != len {
+        return Err(IndyCryptoError::InvalidStructure(
+            "Invalid len of bytes representation".to_string()));
+    }
+
+    P::deserialize_with_mode(b, compress, validate)
+        .map_err(|_| IndyCryptoError::InvalidGroupElement(
+            "Bytes representation doesn't correspond to group element".to_string()))
+}
+
+fn point_to_bytes<P: CanonicalSerialize>(p: &P, compress: Compress) -> Result<Vec<u8>, IndyCryptoError> {
+    let mut vec = Vec::new();
+    p.serialize_with_mode(&mut vec, compress)
+        .map_err(|err| IndyCryptoError::InvalidState(format!("Point serialization failed: {:?}", err)))?;
+    Ok(vec)
+}
+
+/// Point of BLS12-381 group G1.
+/// Bytes representations follow ZCash encoding: big-endian coordinates with compression,
+/// infinity and y sign flags in three most significant bits.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct PointG1 {
+    point: G1Projective
+}
+
+impl PointG1 {
+    pub const BYTES_REPR_SIZE: usize = FIELD_BYTES * 2;
+    pub const COMPRESSED_BYTES_REPR_SIZE: usize = FIELD_BYTES;
+
+    /// Creates new random PointG1
+    pub fn new() -> Result<PointG1, IndyCryptoError> {
+        Ok(PointG1 {
+            point: G1Projective::generator() * random_mod_order()?
+        })
+    }
+
+    /// Creates new infinity PointG1
+    pub fn new_inf() -> Result<PointG1, IndyCryptoError> {
+        Ok(PointG1 {
+            point: G1Projective::zero()
+        })
+    }
+
+    /// Checks infinity
+    pub fn is_inf(&self) -> Result<bool, IndyCryptoError> {
+        Ok(self.point.is_zero())
+    }
+
+    /// PointG1 ^ GroupOrderElement
+    pub fn mul(&self, e: &GroupOrderElement) -> Result<PointG1, IndyCryptoError> {
+        Ok(PointG1 {
+            point: self.point * e.bn
+        })
+    }
+
+    /// PointG1 ^ GroupOrderElement * PointG1 ^ GroupOrderElement * ...
+    pub fn multi_mul(points: &[PointG1], scalars: &[GroupOrderElement]) -> Result<PointG1, IndyCryptoError> {
+        let points: Vec<G1Projective> = points.iter().map(|p| p.point).collect();
+        let scalars: Vec<Fr> = scalars.iter().map(|e| e.bn).collect();
+
+        let point = G1Projective::msm(&G1Projective::normalize_batch(&points), &scalars)
+            .map_err(|_| IndyCryptoError::InvalidStructure(
+                format!("Lists of points and scalars have different len: {}, {}", points.len(), scalars.len())))?;
+
+        Ok(PointG1 {
+            point: point
+        })
+    }
+
+    /// PointG1 * PointG1
+    pub fn add(&self, q: &PointG1) -> Result<PointG1, IndyCryptoError> {
+        Ok(PointG1 {
+            point: self.point + q.point
+        })
+    }
+
+    /// PointG1 / PointG1
+    pub fn sub(&self, q: &PointG1) -> Result<PointG1, IndyCryptoError> {
+        Ok(PointG1 {
+            point: self.point - q.point
+        })
+    }
+
+    /// 1 / PointG1
+    pub fn neg(&self) -> Result<PointG1, IndyCryptoError> {
+        Ok(PointG1 {
+            point: -self.point
+        })
+    }
+
+    /// Returns hex of bytes representation
+    pub fn to_string(&self) -> Result<String, IndyCryptoError> {
+        Ok(to_hex(&self.to_bytes()?))
+    }
+
+    /// Restores point from string representation.
+    /// Fails if point doesn't belong to the prime-order subgroup. Infinity is accepted only if explicitly marked.
+    pub fn from_string(str: &str) -> Result<PointG1, IndyCryptoError> {
+        Ok(PointG1 {
+            point: point_from_bytes::<G1Affine>(&from_hex(str)?, Self::BYTES_REPR_SIZE, Compress::No, Validate::Yes)?.into_group()
+        })
+    }
+
+    /// Restores point from string representation without any validation.
+    /// Must be used only for trusted data (i.e. produced by this library and stored locally).
+    pub fn from_string_unchecked(str: &str) -> Result<PointG1, IndyCryptoError> {
+        PointG1::from_bytes_unchecked(&from_hex(str)?)
+    }
+
+    /// Returns ZCash uncompressed representation
+    pub fn to_bytes(&self) -> Result<Vec<u8>, IndyCryptoError> {
+        point_to_bytes(&self.point.into_affine(), Compress::No)
+    }
+
+    /// Restores point from ZCash uncompressed representation.
+    /// Fails if point doesn't belong to the prime-order subgroup or is infinity
+    /// (the same way as bytes representation of AMCL backend).
+    pub fn from_bytes(b: &[u8]) -> Result<PointG1, IndyCryptoError> {
+        let point = point_from_bytes::<G1Affine>(b, Self::BYTES_REPR_SIZE, Compress::No, Validate::Yes)?;
+
+        if point.is_zero() {
+            return Err(IndyCryptoError::InvalidGroupElement(
+                "PointG1 bytes representation doesn't correspond to curve point".to_string()));
+        }
+
+        Ok(PointG1 {
+            point: point.into_group()
+        })
+    }
+
+    /// Restores point from bytes representation without any validation.
+    /// Must be used only for trusted data (i.e. produced by this library and stored locally).
+    pub fn from_bytes_unchecked(b: &[u8]) -> Result<PointG1, IndyCryptoError> {
+        Ok(PointG1 {
+            point: point_from_bytes::<G1Affine>(b, Self::BYTES_REPR_SIZE, Compress::No, Validate::No)?.into_group()
+        })
+    }
+
+    /// Returns ZCash compressed representation
+    pub fn to_compressed_bytes(&self) -> Result<Vec<u8>, IndyCryptoError> {
+        point_to_bytes(&self.point.into_affine(), Compress::Yes)
+    }
+
+    /// Restores point from ZCash compressed representation.
+    /// Fails if point doesn't belong to the prime-order subgroup.
+    pub fn from_compressed_bytes(b: &[u8]) -> Result<PointG1, IndyCryptoError> {
+        Ok(PointG1 {
+            point: point_from_bytes::<G1Affine>(b, Self::COMPRESSED_BYTES_REPR_SIZE, Compress::Yes, Validate::Yes)?.into_group()
+        })
+    }
+
+    /// Maps hash to the group: hash is taken as x-coordinate that is incremented until it belongs to the curve,
+    /// then cofactor is cleared.
+    pub fn from_hash(hash: &[u8]) -> Result<PointG1, IndyCryptoError> {
+        let mut x = Fq::from_be_bytes_mod_order(hash);
+
+        loop {
+            if let Some(point) = G1Affine::get_point_from_x_unchecked(x, false) {
+                return Ok(PointG1 {
+                    point: point.clear_cofactor().into_group()
+                });
+            }
+            x += Fq::one();
+        }
+    }
+
+    /// Hashes message to PointG1 following IETF hash-to-curve (RFC 9380) random oracle encoding
+    /// BLS12381G1_XMD:SHA-256_SSWU_RO_. `dst` is domain separation tag chosen by caller.
+    pub fn hash_to_curve(message: &[u8], dst: &[u8]) -> Result<PointG1, IndyCryptoError> {
+        let u = hash_to_field(message, dst, 2)?;
+
+        let point = map_to_curve_sswu(&u[0])? + map_to_curve_sswu(&u[1])?;
+
+        Ok(PointG1 {
+            point: point.into_affine().clear_cofactor().into_group()
+        })
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl Serialize for PointG1 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        serializer.serialize_newtype_struct("PointG1", &self.to_string().map_err(SError::custom)?)
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl<'a> Deserialize<'a> for PointG1 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'a> {
+        struct PointG1Visitor;
+
+        impl<'a> Visitor<'a> for PointG1Visitor {
+            type Value = PointG1;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("expected PointG1")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<PointG1, E>
+                where E: DError
+            {
+                Ok(PointG1::from_string(value).map_err(DError::custom)?)
+            }
+        }
+
+        deserializer.deserialize_str(PointG1Visitor)
+    }
+}
+
+/// Point of BLS12-381 group G2.
+/// Bytes representations follow ZCash encoding: big-endian coordinates (c1 component first)
+/// with compression, infinity and y sign flags in three most significant bits.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct PointG2 {
+    point: G2Projective
+}
+
+impl PointG2 {
+    pub const BYTES_REPR_SIZE: usize = FIELD_BYTES * 4;
+    pub const COMPRESSED_BYTES_REPR_SIZE: usize = FIELD_BYTES * 2;
+
+    /// Creates new random PointG2
+    pub fn new() -> Result<PointG2, IndyCryptoError> {
+        Ok(PointG2 {
+            point: G2Projective::generator() * random_mod_order()?
+        })
+    }
+
+    /// Returns fixed base point of the group G2 defined by curve parameters
+    pub fn base() -> Result<PointG2, IndyCryptoError> {
+        Ok(PointG2 {
+            point: G2Projective::generator()
+        })
+    }
+
+    /// Creates new infinity PointG2
+    pub fn new_inf() -> Result<PointG2, IndyCryptoError> {
+        Ok(PointG2 {
+            point: G2Projective::zero()
+        })
+    }
+
+    /// Checks infinity
+    pub fn is_inf(&self) -> Result<bool, IndyCryptoError> {
+        Ok(self.point.is_zero())
+    }
+
+    /// PointG2 * PointG2
+    pub fn add(&self, q: &PointG2) -> Result<PointG2, IndyCryptoError> {
+        Ok(PointG2 {
+            point: self.point + q.point
+        })
+    }
+
+    /// PointG2 / PointG2
+    pub fn sub(&self, q: &PointG2) -> Result<PointG2, IndyCryptoError> {
+        Ok(PointG2 {
+            point: self.point - q.point
+        })
+    }
+
+    /// 1 / PointG2
+    pub fn neg(&self) -> Result<PointG2, IndyCryptoError> {
+        Ok(PointG2 {
+            point: -self.point
+        })
+    }
+
+    /// PointG2 ^ GroupOrderElement
+    pub fn mul(&self, e: &GroupOrderElement) -> Result<PointG2, IndyCryptoError> {
+        Ok(PointG2 {
+            point: self.point * e.bn
+        })
+    }
+
+    /// PointG2 ^ GroupOrderElement * PointG2 ^ GroupOrderElement * ...
+    pub fn multi_mul(points: &[PointG2], scalars: &[GroupOrderElement]) -> Result<PointG2, IndyCryptoError> {
+        let points: Vec<G2Projective> = points.iter().map(|p| p.point).collect();
+        let scalars: Vec<Fr> = scalars.iter().map(|e| e.bn).collect();
+
+        let point = G2Projective::msm(&G2Projective::normalize_batch(&points), &scalars)
+            .map_err(|_| IndyCryptoError::InvalidStructure(
+                format!("Lists of points and scalars have different len: {}, {}", points.len(), scalars.len())))?;
+
+        Ok(PointG2 {
+            point: point
+        })
+    }
+
+    /// Returns hex of bytes representation
+    pub fn to_string(&self) -> Result<String, IndyCryptoError> {
+        Ok(to_hex(&self.to_bytes()?))
+    }
+
+    /// Restores point from string representation.
+    /// Fails if point doesn't belong to the prime-order subgroup. Infinity is accepted only if explicitly marked.
+    pub fn from_string(str: &str) -> Result<PointG2, IndyCryptoError> {
+        Ok(PointG2 {
+            point: point_from_bytes::<G2Affine>(&from_hex(str)?, Self::BYTES_REPR_SIZE, Compress::No, Validate::Yes)?.into_group()
+        })
+    }
+
+    /// Restores point from string representation without any validation.
+    /// Must be used only for trusted data (i.e. produced by this library and stored locally).
+    pub fn from_string_unchecked(str: &str) -> Result<PointG2, IndyCryptoError> {
+        PointG2::from_bytes_unchecked(&from_hex(str)?)
+    }
+
+    /// Returns ZCash uncompressed representation
+    pub fn to_bytes(&self) -> Result<Vec<u8>, IndyCryptoError> {
+        point_to_bytes(&self.point.into_affine(), Compress::No)
+    }
+
+    /// Restores point from ZCash uncompressed representation.
+    /// Fails if point doesn't belong to the prime-order subgroup or is infinity
+    /// (the same way as bytes representation of AMCL backend).
+    pub fn from_bytes(b: &[u8]) -> Result<PointG2, IndyCryptoError> {
+        let point = point_from_bytes::<G2Affine>(b, Self::BYTES_REPR_SIZE, Compress::No, Validate::Yes)?;
+
+        if point.is_zero() {
+            return Err(IndyCryptoError::InvalidGroupElement(
+                "PointG2 bytes representation doesn't correspond to curve point".to_string()));
+        }
+
+        Ok(PointG2 {
+            point: point.into_group()
+        })
+    }
+
+    /// Restores point from bytes representation without any validation.
+    /// Must be used only for trusted data (i.e. produced by this library and stored locally).
+    pub fn from_bytes_unchecked(b: &[u8]) -> Result<PointG2, IndyCryptoError> {
+        Ok(PointG2 {
+            point: point_from_bytes::<G2Affine>(b, Self::BYTES_REPR_SIZE, Compress::No, Validate::No)?.into_group()
+        })
+    }
+
+    /// Returns ZCash compressed representation
+    pub fn to_compressed_bytes(&self) -> Result<Vec<u8>, IndyCryptoError> {
+        point_to_bytes(&self.point.into_affine(), Compress::Yes)
+    }
+
+    /// Restores point from ZCash compressed representation.
+    /// Fails if point doesn't belong to the prime-order subgroup.
+    pub fn from_compressed_bytes(b: &[u8]) -> Result<PointG2, IndyCryptoError> {
+        Ok(PointG2 {
+            point: point_from_bytes::<G2Affine>(b, Self::COMPRESSED_BYTES_REPR_SIZE, Compress::Yes, Validate::Yes)?.into_group()
+        })
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl Serialize for PointG2 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        serializer.serialize_newtype_struct("PointG2", &self.to_string().map_err(SError::custom)?)
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl<'a> Deserialize<'a> for PointG2 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'a> {
+        struct PointG2Visitor;
+
+        impl<'a> Visitor<'a> for PointG2Visitor {
+            type Value = PointG2;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("expected PointG2")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<PointG2, E>
+                where E: DError
+            {
+                Ok(PointG2::from_string(value).map_err(DError::custom)?)
+            }
+        }
+
+        deserializer.deserialize_str(PointG2Visitor)
+    }
+}
+
+/// PointG2 with precomputed Miller loop line functions.
+/// Makes pairings with fixed G2 points (like BLS generator) cheaper.
+#[derive(Clone)]
+pub struct PreparedPointG2 {
+    prepared: G2Prepared
+}
+
+impl PreparedPointG2 {
+    pub fn new(q: &PointG2) -> Result<PreparedPointG2, IndyCryptoError> {
+        Ok(PreparedPointG2 {
+            prepared: G2Prepared::from(q.point.into_affine())
+        })
+    }
+}
+
+impl fmt::Debug for PreparedPointG2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PreparedPointG2 {{ lines: {} }}", self.prepared.ell_coeffs.len())
+    }
+}
+
+/// PointG1 with precomputed multiples for fast multiplication of fixed base by many scalars.
+#[derive(Clone)]
+pub struct FixedBasePointG1 {
+    table: Vec<Vec<G1Affine>>
+}
+
+impl FixedBasePointG1 {
+    pub fn new(p: &PointG1) -> Result<FixedBasePointG1, IndyCryptoError> {
+        Ok(FixedBasePointG1 {
+            table: FixedBase::get_window_table(Fr::MODULUS_BIT_SIZE as usize, FIXED_BASE_WINDOW, p.point)
+        })
+    }
+
+    /// PointG1 ^ GroupOrderElement
+    pub fn mul(&self, e: &GroupOrderElement) -> Result<PointG1, IndyCryptoError> {
+        Ok(PointG1 {
+            point: FixedBase::windowed_mul::<G1Projective>(self.table.len(), FIXED_BASE_WINDOW, &self.table, &e.bn)
+        })
+    }
+}
+
+impl fmt::Debug for FixedBasePointG1 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FixedBasePointG1 {{ windows: {} }}", self.table.len())
+    }
+}
+
+/// PointG2 with precomputed multiples for fast multiplication of fixed base by many scalars.
+#[derive(Clone)]
+pub struct FixedBasePointG2 {
+    table: Vec<Vec<G2Affine>>
+}
+
+impl FixedBasePointG2 {
+    pub fn new(p: &PointG2) -> Result<FixedBasePointG2, IndyCryptoError> {
+        Ok(FixedBasePointG2 {
+            table: FixedBase::get_window_table(Fr::MODULUS_BIT_SIZE as usize, FIXED_BASE_WINDOW, p.point)
+        })
+    }
+
+    /// PointG2 ^ GroupOrderElement
+    pub fn mul(&self, e: &GroupOrderElement) -> Result<PointG2, IndyCryptoError> {
+        Ok(PointG2 {
+            point: FixedBase::windowed_mul::<G2Projective>(self.table.len(), FIXED_BASE_WINDOW, &self.table, &e.bn)
+        })
+    }
+}
+
+impl fmt::Debug for FixedBasePointG2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FixedBasePointG2 {{ windows: {} }}", self.table.len())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupOrderElement {
+    bn: Fr
+}
+
+impl GroupOrderElement {
+    pub const BYTES_REPR_SIZE: usize = SCALAR_BYTES;
+
+    pub fn new() -> Result<GroupOrderElement, IndyCryptoError> {
+        // returns random element in 0, ..., GroupOrder-1
+        Ok(GroupOrderElement {
+            bn: random_mod_order()?
+        })
+    }
+
+    /// Returns element derived from seed: SHA-512 digest of seed reduced modulo GroupOrder
+    pub fn new_from_seed(seed: &[u8]) -> Result<GroupOrderElement, IndyCryptoError> {
+        if seed.len() != SCALAR_BYTES {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("Invalid len of seed: expected {}, actual {}", SCALAR_BYTES, seed.len())));
+        }
+
+        let mut hasher = Sha512::default();
+        hasher.input(seed);
+
+        Ok(GroupOrderElement {
+            bn: Fr::from_be_bytes_mod_order(hasher.result().as_slice())
+        })
+    }
+
+    /// (GroupOrderElement ^ GroupOrderElement) mod GroupOrder
+    pub fn pow_mod(&self, e: &GroupOrderElement) -> Result<GroupOrderElement, IndyCryptoError> {
+        Ok(GroupOrderElement {
+            bn: self.bn.pow(e.bn.into_bigint())
+        })
+    }
+
+    /// (GroupOrderElement + GroupOrderElement) mod GroupOrder
+    pub fn add_mod(&self, r: &GroupOrderElement) -> Result<GroupOrderElement, IndyCryptoError> {
+        Ok(GroupOrderElement {
+            bn: self.bn + r.bn
+        })
+    }
+
+    /// (GroupOrderElement - GroupOrderElement) mod GroupOrder
+    pub fn sub_mod(&self, r: &GroupOrderElement) -> Result<GroupOrderElement, IndyCryptoError> {
+        Ok(GroupOrderElement {
+            bn: self.bn - r.bn
+        })
+    }
+
+    /// (GroupOrderElement * GroupOrderElement) mod GroupOrder
+    pub fn mul_mod(&self, r: &GroupOrderElement) -> Result<GroupOrderElement, IndyCryptoError> {
+        Ok(GroupOrderElement {
+            bn: self.bn * r.bn
+        })
+    }
+
+    /// 1 / GroupOrderElement
+    pub fn inverse(&self) -> Result<GroupOrderElement, IndyCryptoError> {
+        let bn = self.bn.inverse()
+            .ok_or(IndyCryptoError::InvalidStructure("Zero GroupOrderElement can't be inverted".to_string()))?;
+
+        Ok(GroupOrderElement {
+            bn: bn
+        })
+    }
+
+    /// - GroupOrderElement mod GroupOrder
+    pub fn mod_neg(&self) -> Result<GroupOrderElement, IndyCryptoError> {
+        Ok(GroupOrderElement {
+            bn: -self.bn
+        })
+    }
+
+    /// Returns hex of bytes representation
+    pub fn to_string(&self) -> Result<String, IndyCryptoError> {
+        Ok(to_hex(&self.to_bytes()?))
+    }
+
+    pub fn from_string(str: &str) -> Result<GroupOrderElement, IndyCryptoError> {
+        GroupOrderElement::from_bytes(&from_hex(str)?)
+    }
+
+    /// Returns big-endian representation
+    pub fn to_bytes(&self) -> Result<Vec<u8>, IndyCryptoError> {
+        Ok(self.bn.into_bigint().to_bytes_be())
+    }
+
+    /// Restores element from big-endian representation (up to BYTES_REPR_SIZE) reduced modulo GroupOrder.
+    pub fn from_bytes(b: &[u8]) -> Result<GroupOrderElement, IndyCryptoError> {
+        if b.len() > Self::BYTES_REPR_SIZE {
+            return Err(IndyCryptoError::InvalidStructure(
+                "Invalid len of bytes representation".to_string()));
+        }
+
+        Ok(GroupOrderElement {
+            bn: Fr::from_be_bytes_mod_order(b)
+        })
+    }
+
+    /// Reduces big-endian bytes (up to 2 * BYTES_REPR_SIZE) modulo GroupOrder.
+    pub fn from_wide_bytes(b: &[u8]) -> Result<GroupOrderElement, IndyCryptoError> {
+        if b.len() > 2 * Self::BYTES_REPR_SIZE {
+            return Err(IndyCryptoError::InvalidStructure(
+                "Invalid len of bytes representation".to_string()));
+        }
+
+        Ok(GroupOrderElement {
+            bn: Fr::from_be_bytes_mod_order(b)
+        })
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.bn.is_zero()
+    }
+}
+
+impl Zeroize for GroupOrderElement {
+    fn zeroize(&mut self) {
+        self.bn.zeroize();
+    }
+}
+
+impl Drop for GroupOrderElement {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl Serialize for GroupOrderElement {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        serializer.serialize_newtype_struct("GroupOrderElement", &self.to_string().map_err(SError::custom)?)
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl<'a> Deserialize<'a> for GroupOrderElement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'a> {
+        struct GroupOrderElementVisitor;
+
+        impl<'a> Visitor<'a> for GroupOrderElementVisitor {
+            type Value = GroupOrderElement;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("expected GroupOrderElement")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<GroupOrderElement, E>
+                where E: DError
+            {
+                Ok(GroupOrderElement::from_string(value).map_err(DError::custom)?)
+            }
+        }
+
+        deserializer.deserialize_str(GroupOrderElementVisitor)
+    }
+}
+
+/// Element of BLS12-381 target group.
+/// Bytes representation is concatenation of 12 big-endian base field coefficients
+/// in order of the extension tower (Fp12 = Fp6[w] (c0, c1), Fp6 = Fp2[v] (c0, c1, c2), Fp2 = Fp[u] (c0, c1)).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Pair {
+    pair: Fq12
+}
+
+impl Pair {
+    pub const BYTES_REPR_SIZE: usize = FIELD_BYTES * 12;
+
+    /// e(PointG1, PointG2)
+    pub fn pair(p: &PointG1, q: &PointG2) -> Result<Pair, IndyCryptoError> {
+        Ok(Pair {
+            pair: Bls12_381::pairing(p.point.into_affine(), q.point.into_affine()).0
+        })
+    }
+
+    /// e(PointG1, PointG2) * e(PointG1, PointG2) * ... with single final exponentiation
+    pub fn multi_pair(pairs: &[(&PointG1, &PointG2)]) -> Result<Pair, IndyCryptoError> {
+        let prepared = pairs
+            .iter()
+            .map(|&(_, q)| PreparedPointG2::new(q))
+            .collect::<Result<Vec<PreparedPointG2>, IndyCryptoError>>()?;
+
+        let pairs: Vec<(&PointG1, &PreparedPointG2)> = pairs
+            .iter()
+            .zip(prepared.iter())
+            .map(|(&(p, _), q)| (p, q))
+            .collect();
+
+        Pair::multi_pair_prepared(&pairs)
+    }
+
+    /// e(PointG1, PreparedPointG2) * e(PointG1, PreparedPointG2) * ... with single final exponentiation
+    pub fn multi_pair_prepared(pairs: &[(&PointG1, &PreparedPointG2)]) -> Result<Pair, IndyCryptoError> {
+        let g1: Vec<G1Affine> = pairs.iter().map(|&(p, _)| p.point.into_affine()).collect();
+        let g2: Vec<G2Prepared> = pairs.iter().map(|&(_, q)| q.prepared.clone()).collect();
+
+        let result = Bls12_381::final_exponentiation(Bls12_381::multi_miller_loop(g1, g2))
+            .ok_or(IndyCryptoError::InvalidState("Final exponentiation of zero".to_string()))?;
+
+        Ok(Pair {
+            pair: result.0
+        })
+    }
+
+    /// e() == 1
+    pub fn is_unity(&self) -> Result<bool, IndyCryptoError> {
+        Ok(self.pair.is_one())
+    }
+
+    /// e() * e()
+    pub fn mul(&self, b: &Pair) -> Result<Pair, IndyCryptoError> {
+        Ok(Pair {
+            pair: self.pair * b.pair
+        })
+    }
+
+    /// e() ^ GroupOrderElement
+    pub fn pow(&self, b: &GroupOrderElement) -> Result<Pair, IndyCryptoError> {
+        Ok(Pair {
+            pair: self.pair.pow(b.bn.into_bigint())
+        })
+    }
+
+    /// 1 / e()
+    pub fn inverse(&self) -> Result<Pair, IndyCryptoError> {
+        // Pairing values belong to cyclotomic subgroup, so inverse is conjugate
+        let mut r = self.pair;
+        r.conjugate_in_place();
+        Ok(Pair {
+            pair: r
+        })
+    }
+
+    /// Returns hex of bytes representation
+    pub fn to_string(&self) -> Result<String, IndyCryptoError> {
+        Ok(to_hex(&self.to_bytes()?))
+    }
+
+    pub fn from_string(str: &str) -> Result<Pair, IndyCryptoError> {
+        Pair::_from_bytes_unchecked(&from_hex(str)?)
+    }
+
+    /// Returns bytes representation: 12 coefficients (big-endian, 48 bytes each)
+    /// in order of the extension tower - c0.c0.c0, c0.c0.c1, c0.c1.c0, ..., c1.c2.c1.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, IndyCryptoError> {
+        Ok(self.pair
+            .to_base_prime_field_elements()
+            .flat_map(|c| c.into_bigint().to_bytes_be())
+            .collect())
+    }
+
+    /// Restores element from bytes representation.
+    /// Fails if representation isn't canonical or element doesn't belong to the group GT (r-torsion of Fq12).
+    pub fn from_bytes(b: &[u8]) -> Result<Pair, IndyCryptoError> {
+        let pair = Pair::_from_bytes_unchecked(b)?;
+
+        if !pair.pair.pow(Fr::MODULUS).is_one() {
+            return Err(IndyCryptoError::InvalidGroupElement(
+                "Pair doesn't belong to GT".to_string()));
+        }
+
+        Ok(pair)
+    }
+
+    fn _from_bytes_unchecked(b: &[u8]) -> Result<Pair, IndyCryptoError> {
+        if b.len() != Self::BYTES_REPR_SIZE {
+            return Err(IndyCryptoError::InvalidStructure(
+                "Invalid len of bytes representation".to_string()));
+        }
+
+        let coefficients = b
+            .chunks(FIELD_BYTES)
+            .map(fq_from_bytes)
+            .collect::<Result<Vec<Fq>, IndyCryptoError>>()?;
+
+        Ok(Pair {
+            pair: Fq12::from_base_prime_field_elems(&coefficients)
+                .ok_or(IndyCryptoError::InvalidStructure("Invalid Pair representation".to_string()))?
+        })
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl Serialize for Pair {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        serializer.serialize_newtype_struct("Pair", &self.to_string().map_err(SError::custom)?)
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl<'a> Deserialize<'a> for Pair {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'a> {
+        struct PairVisitor;
+
+        impl<'a> Visitor<'a> for PairVisitor {
+            type Value = Pair;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("expected Pair")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Pair, E>
+                where E: DError
+            {
+                Ok(Pair::from_string(value).map_err(DError::custom)?)
+            }
+        }
+
+        deserializer.deserialize_str(PairVisitor)
+    }
+}
+
+/// BLS12-381 pairing engine.
+/// Serializable only to let types generic over engine derive serde traits.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub struct Bls12381;
+
+impl_pairing_engine!(Bls12381, "BLS12381", "BLS12381G1_XMD:SHA-256_SSWU_RO_");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_g1_compressed_bytes_works_for_generator() {
+        // ZCash encoding of the standard generator
+        let generator = PointG1 { point: G1Projective::generator() };
+        assert_eq!("97F1D3A73197D7942695638C4FA9AC0FC3688C4F9774B905A14E3A3F171BAC586C55E83FF97A1AEFFB3AF00ADB22C6BB",
+                   to_hex(&generator.to_compressed_bytes().unwrap()));
+    }
+
+    #[test]
+    fn point_g2_compressed_bytes_works_for_generator() {
+        // ZCash encoding of the standard generator
+        let generator = PointG2::base().unwrap();
+        assert_eq!("93E02B6052719F607DACD3A088274F65596BD0D09920B61AB5DA61BBDC7F5049334CF11213945D57E5AC7D055D042B7E\
+                    024AA2B2F08F0A91260805272DC51051C6E47AD4FA403B02B4510B647AE3D1770BAC0326A805BBEFD48056C8C121BDB8",
+                   to_hex(&generator.to_compressed_bytes().unwrap()));
+    }
+
+    #[test]
+    fn hash_to_curve_works() {
+        // Test vectors from RFC 9380, appendix J.9.1
+        let dst = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+
+        let point = PointG1::hash_to_curve(b"", dst).unwrap();
+        assert_eq!("052926ADD2207B76CA4FA57A8734416C8DC95E24501772C814278700EED6D1E4E8CF62D9C09DB0FAC349612B759E79A1\
+                    08BA738453BFED09CB546DBB0783DBB3A5F1F566ED67BB6BE0E8C67E2E81A4CC68EE29813BB7994998F3EAE0C9C6A265",
+                   point.to_string().unwrap());
+
+        let point = PointG1::hash_to_curve(b"abc", dst).unwrap();
+        assert_eq!("03567BC5EF9C690C2AB2ECDF6A96EF1C139CC0B2F284DCA0A9A7943388A49A3AEE664BA5379A7655D3C68900BE2F6903\
+                    0B9C15F3FE6E5CF4211F346271D7B01C8F3B28BE689C8429C85B67AF215533311F0B8DFAAA154FA6B88176C229F2885D",
+                   point.to_string().unwrap());
+    }
+
+    #[test]
+    fn point_g1_from_bytes_works_for_point_outside_subgroup() {
+        // Curve point with x = 4 has order divisible by cofactor
+        let point = G1Affine::get_point_from_x_unchecked(Fq::from(4u64), false).unwrap();
+        assert!(!point.is_in_correct_subgroup_assuming_on_curve());
+
+        let bytes = point_to_bytes(&point, Compress::No).unwrap();
+        assert!(PointG1::from_bytes(&bytes).is_err());
+        assert!(PointG1::from_bytes_unchecked(&bytes).is_ok());
+    }
+
+    #[test]
+    fn point_g1_from_bytes_works_for_infinity() {
+        let bytes = PointG1::new_inf().unwrap().to_bytes().unwrap();
+        assert!(PointG1::from_bytes(&bytes).is_err());
+        assert!(PointG1::from_string(&to_hex(&bytes)).unwrap().is_inf().unwrap());
+    }
+
+    #[test]
+    fn point_g2_from_compressed_bytes_works() {
+        let point = PointG2::new().unwrap();
+        let restored = PointG2::from_compressed_bytes(&point.to_compressed_bytes().unwrap()).unwrap();
+        assert_eq!(point, restored);
+    }
+
+    #[test]
+    fn group_order_element_zeroize_works() {
+        let mut element = GroupOrderElement::new().unwrap();
+        element.zeroize();
+        assert!(element.is_zero());
+    }
+
+    #[test]
+    fn group_order_element_from_wide_bytes_works() {
+        let mut bytes = vec![0u8; 32];
+        bytes.extend_from_slice(&GroupOrderElement::new().unwrap().to_bytes().unwrap());
+        let expected = GroupOrderElement::from_bytes(&bytes[32..]).unwrap();
+        assert_eq!(expected, GroupOrderElement::from_wide_bytes(&bytes).unwrap());
+
+        // 2^512 - 1 mod r
+        let element = GroupOrderElement::from_wide_bytes(&[0xFF; 64]).unwrap();
+        assert_eq!("0748D9D99F59FF1105D314967254398F2B6CEDCB87925C23C999E990F3F29C6C", element.to_string().unwrap());
+
+        assert!(GroupOrderElement::from_wide_bytes(&[0xFF; 65]).is_err());
+    }
+
+    #[test]
+    fn pair_to_string_from_string_works() {
+        let pair = Pair::pair(&PointG1::new().unwrap(), &PointG2::new().unwrap()).unwrap();
+        let restored = Pair::from_string(&pair.to_string().unwrap()).unwrap();
+        assert_eq!(pair, restored);
+        assert_eq!(Pair::BYTES_REPR_SIZE, pair.to_bytes().unwrap().len());
+    }
+
+    #[test]
+    fn pair_inverse_works() {
+        let pair = Pair::pair(&PointG1::new().unwrap(), &PointG2::new().unwrap()).unwrap();
+        assert!(pair.mul(&pair.inverse().unwrap()).unwrap().is_unity().unwrap());
+    }
+
+    #[test]
+    fn pair_from_bytes_works() {
+        let pair = Pair::pair(&PointG1::new().unwrap(), &PointG2::new().unwrap()).unwrap();
+        let restored = Pair::from_bytes(&pair.to_bytes().unwrap()).unwrap();
+        assert_eq!(pair, restored);
+    }
+
+    #[test]
+    fn pair_from_bytes_works_for_element_outside_gt() {
+        let two = Pair { pair: Fq12::from(2u64) };
+        assert!(Pair::from_bytes(&two.to_bytes().unwrap()).is_err());
+        assert_eq!(two, Pair::from_string(&two.to_string().unwrap()).unwrap());
+    }
+
+    #[test]
+    fn pair_from_bytes_works_for_non_canonical_representation() {
+        let mut bytes = Pair::pair(&PointG1::new().unwrap(), &PointG2::new().unwrap()).unwrap().to_bytes().unwrap();
+        bytes[..FIELD_BYTES].copy_from_slice(&Fq::MODULUS.to_bytes_be());
+        assert!(Pair::from_bytes(&bytes).is_err());
+    }
+}
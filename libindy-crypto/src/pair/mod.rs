@@ -0,0 +1,359 @@
+//! Pairing-friendly curves used by `bls` and by the revocation part of `cl`.
+//!
+//! Every backend exposes the same set of types (`PointG1`, `PointG2`, `GroupOrderElement`, `Pair`, ...)
+//! and an engine type implementing `PairingEngine`, so code can be written either against the types
+//! of the default backend re-exported from this module or generically over `PairingEngine`.
+//!
+//! Backends are selected by cargo features:
+//! * `pair_amcl` - AMCL BN254 (default). Used for types re-exported from this module if enabled.
+//! * `pair_bls12_381` - BLS12-381 with ZCash-compatible encodings. Used for types re-exported
+//!   from this module if `pair_amcl` is disabled.
+//!
+//! # Bytes representation
+//!
+//! `to_bytes` results are fixed size, don't depend on `to_string` format of the backend
+//! and are kept stable between releases, so they can be stored and sent instead of JSON.
+//! Field elements are big-endian and left-padded to field size; `from_bytes` rejects
+//! non-canonical field elements (not less than modulus) and values outside of the group.
+//!
+//! AMCL BN254 (field elements are 32 bytes):
+//! * `GroupOrderElement` - 32 bytes.
+//! * `PointG1` - 128 bytes: 0x04, affine x, affine y, 63 zero bytes.
+//! * `PointG2` - 128 bytes: x.a, x.b, y.a, y.b of affine coordinates x = x.a + x.b * i, y = y.a + y.b * i.
+//! * `Pair` - 512 bytes: 12 coefficients in order of AMCL extension tower
+//!   FP12 = FP4[w] (a, b, c), FP4 = FP2[s] (a, b), FP2 = FP[i] (a, b) - a.a.a, a.a.b, a.b.a, ..., c.b.b -
+//!   and 128 zero bytes.
+//!
+//! BLS12-381 (field elements are 48 bytes):
+//! * `GroupOrderElement` - 32 bytes.
+//! * `PointG1` - 96 bytes: ZCash uncompressed encoding (x, y with flags in three most significant bits).
+//! * `PointG2` - 192 bytes: ZCash uncompressed encoding (x.c1, x.c0, y.c1, y.c0 with flags).
+//! * `Pair` - 576 bytes: 12 coefficients in order of extension tower
+//!   Fp12 = Fp6[w] (c0, c1), Fp6 = Fp2[v] (c0, c1, c2), Fp2 = Fp[u] (c0, c1) - c0.c0.c0, c0.c0.c1, ..., c1.c2.c1.
+
+use errors::IndyCryptoError;
+
+use sha2::{Sha256, Digest};
+
+use std::fmt;
+
+#[cfg(not(any(feature = "pair_amcl", feature = "pair_bls12_381")))]
+compile_error!("At least one pairing backend must be enabled: pair_amcl or pair_bls12_381");
+
+/// Implements pairing traits for types of the backend module by delegation to their inherent methods.
+macro_rules! impl_pairing_engine {
+    ($engine:ident, $curve_name:expr, $g1_hash_suite:expr) => {
+        impl_pairing_engine!(@point PointG1, FixedBasePointG1);
+        impl_pairing_engine!(@point PointG2, FixedBasePointG2);
+
+        impl $crate::pair::PairingScalar for GroupOrderElement {
+            fn new() -> Result<Self, IndyCryptoError> { GroupOrderElement::new() }
+            fn new_from_seed(seed: &[u8]) -> Result<Self, IndyCryptoError> { GroupOrderElement::new_from_seed(seed) }
+            fn add_mod(&self, r: &Self) -> Result<Self, IndyCryptoError> { GroupOrderElement::add_mod(self, r) }
+            fn mul_mod(&self, r: &Self) -> Result<Self, IndyCryptoError> { GroupOrderElement::mul_mod(self, r) }
+            fn pow_mod(&self, e: &Self) -> Result<Self, IndyCryptoError> { GroupOrderElement::pow_mod(self, e) }
+            fn inverse(&self) -> Result<Self, IndyCryptoError> { GroupOrderElement::inverse(self) }
+            fn mod_neg(&self) -> Result<Self, IndyCryptoError> { GroupOrderElement::mod_neg(self) }
+            fn is_zero(&self) -> bool { GroupOrderElement::is_zero(self) }
+            fn to_bytes(&self) -> Result<Vec<u8>, IndyCryptoError> { GroupOrderElement::to_bytes(self) }
+            fn from_bytes(b: &[u8]) -> Result<Self, IndyCryptoError> { GroupOrderElement::from_bytes(b) }
+            fn from_wide_bytes(b: &[u8]) -> Result<Self, IndyCryptoError> { GroupOrderElement::from_wide_bytes(b) }
+        }
+
+        impl $crate::pair::PairingTarget for Pair {
+            type Scalar = GroupOrderElement;
+
+            fn mul(&self, b: &Self) -> Result<Self, IndyCryptoError> { Pair::mul(self, b) }
+            fn pow(&self, e: &GroupOrderElement) -> Result<Self, IndyCryptoError> { Pair::pow(self, e) }
+            fn inverse(&self) -> Result<Self, IndyCryptoError> { Pair::inverse(self) }
+            fn is_unity(&self) -> Result<bool, IndyCryptoError> { Pair::is_unity(self) }
+            fn to_bytes(&self) -> Result<Vec<u8>, IndyCryptoError> { Pair::to_bytes(self) }
+            fn from_bytes(b: &[u8]) -> Result<Self, IndyCryptoError> { Pair::from_bytes(b) }
+        }
+
+        impl $crate::pair::PairingEngine for $engine {
+            const CURVE_NAME: &'static str = $curve_name;
+            const G1_HASH_SUITE: &'static str = $g1_hash_suite;
+
+            type Scalar = GroupOrderElement;
+            type G1 = PointG1;
+            type G2 = PointG2;
+            type PreparedG2 = PreparedPointG2;
+            type GT = Pair;
+
+            fn g2_base() -> Result<PointG2, IndyCryptoError> {
+                PointG2::base()
+            }
+
+            fn hash_to_g1(message: &[u8], dst: &[u8]) -> Result<PointG1, IndyCryptoError> {
+                PointG1::hash_to_curve(message, dst)
+            }
+
+            fn g1_from_hash(hash: &[u8]) -> Result<PointG1, IndyCryptoError> {
+                PointG1::from_hash(hash)
+            }
+
+            fn prepare_g2(q: &PointG2) -> Result<PreparedPointG2, IndyCryptoError> {
+                PreparedPointG2::new(q)
+            }
+
+            fn pair(p: &PointG1, q: &PointG2) -> Result<Pair, IndyCryptoError> {
+                Pair::pair(p, q)
+            }
+
+            fn multi_pair(pairs: &[(&PointG1, &PointG2)]) -> Result<Pair, IndyCryptoError> {
+                Pair::multi_pair(pairs)
+            }
+
+            fn multi_pair_prepared(pairs: &[(&PointG1, &PreparedPointG2)]) -> Result<Pair, IndyCryptoError> {
+                Pair::multi_pair_prepared(pairs)
+            }
+        }
+    };
+    (@point $point:ident, $fixed_base:ident) => {
+        impl $crate::pair::PairingPoint for $point {
+            type Scalar = GroupOrderElement;
+            type FixedBase = $fixed_base;
+
+            fn new() -> Result<Self, IndyCryptoError> { $point::new() }
+            fn new_inf() -> Result<Self, IndyCryptoError> { $point::new_inf() }
+            fn is_inf(&self) -> Result<bool, IndyCryptoError> { $point::is_inf(self) }
+            fn add(&self, q: &Self) -> Result<Self, IndyCryptoError> { $point::add(self, q) }
+            fn sub(&self, q: &Self) -> Result<Self, IndyCryptoError> { $point::sub(self, q) }
+            fn neg(&self) -> Result<Self, IndyCryptoError> { $point::neg(self) }
+            fn mul(&self, e: &GroupOrderElement) -> Result<Self, IndyCryptoError> { $point::mul(self, e) }
+            fn multi_mul(points: &[Self], scalars: &[GroupOrderElement]) -> Result<Self, IndyCryptoError> { $point::multi_mul(points, scalars) }
+            fn to_bytes(&self) -> Result<Vec<u8>, IndyCryptoError> { $point::to_bytes(self) }
+            fn from_bytes(b: &[u8]) -> Result<Self, IndyCryptoError> { $point::from_bytes(b) }
+            fn to_compressed_bytes(&self) -> Result<Vec<u8>, IndyCryptoError> { $point::to_compressed_bytes(self) }
+            fn from_compressed_bytes(b: &[u8]) -> Result<Self, IndyCryptoError> { $point::from_compressed_bytes(b) }
+        }
+
+        impl $crate::pair::PairingFixedBase<$point> for $fixed_base {
+            fn new(p: &$point) -> Result<Self, IndyCryptoError> { $fixed_base::new(p) }
+            fn mul(&self, e: &GroupOrderElement) -> Result<$point, IndyCryptoError> { $fixed_base::mul(self, e) }
+        }
+    };
+}
+
+#[cfg(feature = "pair_amcl")]
+pub mod amcl;
+
+#[cfg(feature = "pair_bls12_381")]
+pub mod bls12_381;
+
+#[cfg(feature = "pair_amcl")]
+pub use self::amcl::*;
+
+#[cfg(all(feature = "pair_bls12_381", not(feature = "pair_amcl")))]
+pub use self::bls12_381::*;
+
+/// Engine of the backend whose types are re-exported from this module.
+#[cfg(feature = "pair_amcl")]
+pub type DefaultEngine = self::amcl::Bn254;
+
+/// Engine of the backend whose types are re-exported from this module.
+#[cfg(all(feature = "pair_bls12_381", not(feature = "pair_amcl")))]
+pub type DefaultEngine = self::bls12_381::Bls12381;
+
+/// Element of the scalar field of pairing groups (integer modulo group order).
+pub trait PairingScalar: Sized + Clone + PartialEq + fmt::Debug {
+    /// Creates new random element
+    fn new() -> Result<Self, IndyCryptoError>;
+    /// Creates element deterministically derived from seed
+    fn new_from_seed(seed: &[u8]) -> Result<Self, IndyCryptoError>;
+    fn add_mod(&self, r: &Self) -> Result<Self, IndyCryptoError>;
+    fn mul_mod(&self, r: &Self) -> Result<Self, IndyCryptoError>;
+    fn pow_mod(&self, e: &Self) -> Result<Self, IndyCryptoError>;
+    fn inverse(&self) -> Result<Self, IndyCryptoError>;
+    fn mod_neg(&self) -> Result<Self, IndyCryptoError>;
+    fn is_zero(&self) -> bool;
+    fn to_bytes(&self) -> Result<Vec<u8>, IndyCryptoError>;
+    fn from_bytes(b: &[u8]) -> Result<Self, IndyCryptoError>;
+    /// Reduces big-endian bytes modulo group order
+    fn from_wide_bytes(b: &[u8]) -> Result<Self, IndyCryptoError>;
+}
+
+/// Point of pairing group G1 or G2.
+pub trait PairingPoint: Sized + Copy + PartialEq + fmt::Debug {
+    type Scalar: PairingScalar;
+    /// Point with precomputed multiples
+    type FixedBase: PairingFixedBase<Self>;
+
+    /// Creates new random point
+    fn new() -> Result<Self, IndyCryptoError>;
+    fn new_inf() -> Result<Self, IndyCryptoError>;
+    fn is_inf(&self) -> Result<bool, IndyCryptoError>;
+    fn add(&self, q: &Self) -> Result<Self, IndyCryptoError>;
+    fn sub(&self, q: &Self) -> Result<Self, IndyCryptoError>;
+    fn neg(&self) -> Result<Self, IndyCryptoError>;
+    fn mul(&self, e: &Self::Scalar) -> Result<Self, IndyCryptoError>;
+    fn multi_mul(points: &[Self], scalars: &[Self::Scalar]) -> Result<Self, IndyCryptoError>;
+    fn to_bytes(&self) -> Result<Vec<u8>, IndyCryptoError>;
+    /// Restores point from bytes representation. Fails if point doesn't belong to the group.
+    fn from_bytes(b: &[u8]) -> Result<Self, IndyCryptoError>;
+    fn to_compressed_bytes(&self) -> Result<Vec<u8>, IndyCryptoError>;
+    /// Restores point from compressed representation. Fails if point doesn't belong to the group.
+    fn from_compressed_bytes(b: &[u8]) -> Result<Self, IndyCryptoError>;
+}
+
+/// Point with precomputed multiples for fast multiplication of fixed base by many scalars.
+pub trait PairingFixedBase<P: PairingPoint>: Sized + Clone + fmt::Debug {
+    fn new(p: &P) -> Result<Self, IndyCryptoError>;
+    fn mul(&self, e: &P::Scalar) -> Result<P, IndyCryptoError>;
+}
+
+/// Element of pairing target group GT.
+pub trait PairingTarget: Sized + Copy + PartialEq + fmt::Debug {
+    type Scalar: PairingScalar;
+
+    fn mul(&self, b: &Self) -> Result<Self, IndyCryptoError>;
+    fn pow(&self, e: &Self::Scalar) -> Result<Self, IndyCryptoError>;
+    fn inverse(&self) -> Result<Self, IndyCryptoError>;
+    fn is_unity(&self) -> Result<bool, IndyCryptoError>;
+    fn to_bytes(&self) -> Result<Vec<u8>, IndyCryptoError>;
+    /// Fails if element doesn't belong to GT
+    fn from_bytes(b: &[u8]) -> Result<Self, IndyCryptoError>;
+}
+
+/// Pairing e: G1 x G2 -> GT over one of supported curves.
+pub trait PairingEngine: Sized + Copy + PartialEq + fmt::Debug + 'static {
+    /// Curve name as used in ciphersuite identifiers, e.g. `BN254`
+    const CURVE_NAME: &'static str;
+    /// IETF hash-to-curve suite identifier of `hash_to_g1`, e.g. `BN254G1_XMD:SHA-256_SVDW_RO_`
+    const G1_HASH_SUITE: &'static str;
+
+    type Scalar: PairingScalar;
+    type G1: PairingPoint<Scalar = Self::Scalar>;
+    type G2: PairingPoint<Scalar = Self::Scalar>;
+    /// G2 point with precomputed Miller loop line functions
+    type PreparedG2: Clone + fmt::Debug;
+    type GT: PairingTarget<Scalar = Self::Scalar>;
+
+    /// Returns fixed base point of the group G2 defined by curve parameters
+    fn g2_base() -> Result<Self::G2, IndyCryptoError>;
+    /// Hashes message to G1 following IETF hash-to-curve random oracle encoding with `G1_HASH_SUITE`
+    fn hash_to_g1(message: &[u8], dst: &[u8]) -> Result<Self::G1, IndyCryptoError>;
+    /// Maps 32 bytes hash to G1 with try-and-increment as legacy (pre IETF) BLS signatures do
+    fn g1_from_hash(hash: &[u8]) -> Result<Self::G1, IndyCryptoError>;
+    fn prepare_g2(q: &Self::G2) -> Result<Self::PreparedG2, IndyCryptoError>;
+    /// e(G1, G2)
+    fn pair(p: &Self::G1, q: &Self::G2) -> Result<Self::GT, IndyCryptoError>;
+    /// e(G1, G2) * e(G1, G2) * ... with single final exponentiation
+    fn multi_pair(pairs: &[(&Self::G1, &Self::G2)]) -> Result<Self::GT, IndyCryptoError>;
+    /// e(G1, PreparedG2) * e(G1, PreparedG2) * ... with single final exponentiation
+    fn multi_pair_prepared(pairs: &[(&Self::G1, &Self::PreparedG2)]) -> Result<Self::GT, IndyCryptoError>;
+}
+
+const OVERSIZE_DST_PREFIX: &'static [u8] = b"H2C-OVERSIZE-DST-";
+
+/// expand_message_xmd with SHA-256 as defined in IETF hash-to-curve (RFC 9380, section 5.3.1).
+pub fn expand_message_xmd(message: &[u8], dst: &[u8], len_in_bytes: usize) -> Result<Vec<u8>, IndyCryptoError> {
+    let b_in_bytes = 32;
+    let ell = (len_in_bytes + b_in_bytes - 1) / b_in_bytes;
+
+    if ell > 255 || len_in_bytes > 65535 {
+        return Err(IndyCryptoError::InvalidStructure(
+            format!("Invalid len of expanded message: {}", len_in_bytes)));
+    }
+
+    let mut dst_prime = if dst.len() > 255 {
+        let mut hasher = Sha256::default();
+        hasher.input(OVERSIZE_DST_PREFIX);
+        hasher.input(dst);
+        hasher.result().to_vec()
+    } else {
+        dst.to_vec()
+    };
+    let dst_len = dst_prime.len() as u8;
+    dst_prime.push(dst_len);
+
+    let mut hasher = Sha256::default();
+    hasher.input(&[0u8; 64]);
+    hasher.input(message);
+    hasher.input(&[(len_in_bytes >> 8) as u8, len_in_bytes as u8, 0u8]);
+    hasher.input(&dst_prime);
+    let b_0 = hasher.result().to_vec();
+
+    let mut hasher = Sha256::default();
+    hasher.input(&b_0);
+    hasher.input(&[1u8]);
+    hasher.input(&dst_prime);
+    let mut b_i = hasher.result().to_vec();
+
+    let mut uniform_bytes = b_i.clone();
+    for i in 2..(ell + 1) {
+        let mut hasher = Sha256::default();
+        hasher.input(&b_0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b).collect::<Vec<u8>>());
+        hasher.input(&[i as u8]);
+        hasher.input(&dst_prime);
+        b_i = hasher.result().to_vec();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(len_in_bytes);
+
+    Ok(uniform_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn expand_message_xmd_works() {
+        // Test vectors from RFC 9380, appendix K.1
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+        assert_eq!("68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+                   to_hex(&expand_message_xmd(b"", dst, 0x20).unwrap()));
+        assert_eq!("d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+                   to_hex(&expand_message_xmd(b"abc", dst, 0x20).unwrap()));
+        assert_eq!("af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+                   to_hex(&expand_message_xmd(b"", dst, 0x80).unwrap()));
+    }
+
+    #[test]
+    fn expand_message_xmd_works_for_too_long_output() {
+        assert!(expand_message_xmd(b"abc", b"DST", 256 * 32).is_err());
+    }
+
+
+    fn _pairing_engine_works<E: PairingEngine>() {
+        let p = E::G1::new().unwrap();
+        let q = E::g2_base().unwrap();
+        let a = E::Scalar::new().unwrap();
+        let b = E::Scalar::new().unwrap();
+
+        let left = E::pair(&p.mul(&a).unwrap(), &q.mul(&b).unwrap()).unwrap();
+        let right = E::pair(&p, &q).unwrap().pow(&a.mul_mod(&b).unwrap()).unwrap();
+        assert_eq!(left.to_bytes().unwrap(), right.to_bytes().unwrap());
+        assert_eq!(left, E::GT::from_bytes(&left.to_bytes().unwrap()).unwrap());
+
+        let neg_p = E::G1::new_inf().unwrap().sub(&p).unwrap();
+        let q_prepared = E::prepare_g2(&q).unwrap();
+        assert!(E::multi_pair(&[(&p, &q), (&neg_p, &q)]).unwrap().is_unity().unwrap());
+        assert!(E::multi_pair_prepared(&[(&p, &q_prepared), (&neg_p, &q_prepared)]).unwrap().is_unity().unwrap());
+
+        let h = E::hash_to_g1(b"abc", E::G1_HASH_SUITE.as_bytes()).unwrap();
+        let h_restored = E::G1::from_compressed_bytes(&h.to_compressed_bytes().unwrap()).unwrap();
+        assert_eq!(h.to_bytes().unwrap(), h_restored.to_bytes().unwrap());
+        let q_restored = E::G2::from_bytes(&q.to_bytes().unwrap()).unwrap();
+        assert_eq!(q.to_bytes().unwrap(), q_restored.to_bytes().unwrap());
+    }
+
+    #[cfg(feature = "pair_amcl")]
+    #[test]
+    fn pairing_engine_works_for_bn254() {
+        _pairing_engine_works::<amcl::Bn254>();
+    }
+
+    #[cfg(feature = "pair_bls12_381")]
+    #[test]
+    fn pairing_engine_works_for_bls12_381() {
+        _pairing_engine_works::<bls12_381::Bls12381>();
+    }
+}
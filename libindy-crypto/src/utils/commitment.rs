@@ -4,6 +4,8 @@ use errors::IndyCryptoError;
 
 /// Generate a pedersen commitment to a given number
 ///
+/// Committed values and blinding factors are secret, so exponentiation is done in constant time.
+///
 /// # Arguments
 /// * `gen_1` - first generator
 /// * `m` - exponent of the first generator
@@ -17,8 +19,8 @@ use errors::IndyCryptoError;
 pub fn get_pedersen_commitment(gen_1: &BigNumber, m: &BigNumber,
                                gen_2: &BigNumber, r: &BigNumber,
                                modulus: &BigNumber, ctx: &mut BigNumberContext) -> Result<BigNumber, IndyCryptoError> {
-    let commitment = gen_1.mod_exp(m, modulus, Some(ctx))?
-        .mod_mul(&gen_2.mod_exp(r, modulus, Some(ctx))?,
+    let commitment = gen_1.mod_exp_secret(m, modulus, Some(ctx))?
+        .mod_mul(&gen_2.mod_exp_secret(r, modulus, Some(ctx))?,
                  modulus, Some(ctx))?;
     Ok(commitment)
 }
@@ -26,6 +28,8 @@ pub fn get_pedersen_commitment(gen_1: &BigNumber, m: &BigNumber,
 
 /// Generate a pedersen commitment over `n` values
 ///
+/// Committed values and blinding factors are secret, so exponentiation is done in constant time.
+///
 /// # Arguments
 /// * `to_commit` - a list of 2-tuples where the first element of the tuple is a generator and
 /// the second is the value being committed to, like [(g_1, m_1), (g_2, m_2), (g_3, m_3), ... (g_i, m_i)]
@@ -40,7 +44,7 @@ pub fn get_generalised_pedersen_commitment(to_commit: Vec<(&BigNumber, &BigNumbe
                                gen_2: &BigNumber, r: &BigNumber,
                                modulus: &BigNumber, ctx: &mut BigNumberContext) -> Result<BigNumber, IndyCryptoError> {
     let accumulated = get_exponentiated_generators(to_commit, modulus, ctx)?;
-    let commitment = accumulated.mod_mul(&gen_2.mod_exp(r, modulus, Some(ctx))?,
+    let commitment = accumulated.mod_mul(&gen_2.mod_exp_secret(r, modulus, Some(ctx))?,
                  modulus, Some(ctx))?;
     Ok(commitment)
 }
@@ -48,6 +52,8 @@ pub fn get_generalised_pedersen_commitment(to_commit: Vec<(&BigNumber, &BigNumbe
 
 /// Exponentiate the given generators to corresponding exponents
 ///
+/// Exponents are treated as secret, so exponentiation is done in constant time.
+///
 /// # Arguments
 /// * `to_exponentiate` - a list of 2-tuples where the first element of the tuple is a generator and
 /// the second is the exponent, like [(g_1, e_1), (g_2, e_2), (g_3, e_3), ... (g_i, e_i)]
@@ -69,7 +75,7 @@ pub fn get_exponentiated_generators(to_exponentiate: Vec<(&BigNumber, &BigNumber
     let mut accumulated = BigNumber::from_dec("1")?;
     for &(g, m) in to_exponentiate.iter() {
         accumulated = accumulated.mod_mul(
-            &g.mod_exp(m, modulus, Some(ctx))?, modulus, Some(ctx)
+            &g.mod_exp_secret(m, modulus, Some(ctx))?, modulus, Some(ctx)
         )?;
     }
     Ok(accumulated)
@@ -101,6 +101,40 @@ macro_rules! check_useful_c_reference_array {
     }
 }
 
+macro_rules! check_useful_c_byte_array_array {
+    ($ptrs:ident, $lens:ident, $ptrs_len:ident, $err1:expr, $err2:expr, $err3:expr) => {
+        if $ptrs.is_null() {
+            return $err1
+        }
+
+        if $lens.is_null() {
+            return $err2
+        }
+
+        if $ptrs_len <= 0 {
+            return $err3
+        }
+
+        let $lens: &[usize] = unsafe { slice::from_raw_parts($lens, $ptrs_len) };
+        let $ptrs: &[*const u8] = unsafe { slice::from_raw_parts($ptrs, $ptrs_len) };
+
+        if $ptrs.iter().any(|ptr| ptr.is_null()) {
+            return $err1
+        }
+
+        if $lens.iter().any(|len| *len <= 0) {
+            return $err2
+        }
+
+        let $ptrs: Vec<&[u8]> =
+            $ptrs
+                .iter()
+                .zip($lens.iter())
+                .map(|(ptr, len)| unsafe { slice::from_raw_parts(*ptr, *len) })
+                .collect();
+    }
+}
+
 macro_rules! check_useful_c_ptr {
     ($ptr:ident, $err1:expr) => {
         if $ptr.is_null() {
@@ -1,4 +1,6 @@
 #[macro_use]
 pub mod ctypes;
+#[macro_use]
+pub mod secret;
 pub mod json;
 pub mod commitment;
@@ -0,0 +1,80 @@
+//! Logging policy for secret values.
+//!
+//! Private keys, master secrets, blinding factors and proof randomness are printed as `[redacted]`
+//! by `Debug`, so they never reach `trace!` output. `log_secrets` feature prints them as is
+//! for debugging of the library itself and must never be enabled in production builds.
+
+use std::fmt;
+
+pub const REDACTED: &'static str = "[redacted]";
+
+/// Debug view of secret value that respects logging policy.
+pub struct Secret<'a, T: 'a + ?Sized>(pub &'a T);
+
+impl<'a, T: fmt::Debug + ?Sized> fmt::Debug for Secret<'a, T> {
+    #[cfg(feature = "log_secrets")]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+
+    #[cfg(not(feature = "log_secrets"))]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+/// Implements `Debug` that prints `public` fields as is and `secret` fields according to logging policy.
+macro_rules! impl_secret_debug {
+    ($name:ident { $($field:ident),* } secret { $($secret:ident),* }) => {
+        impl ::std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.debug_struct(stringify!($name))
+                    $(.field(stringify!($field), &self.$field))*
+                    $(.field(stringify!($secret), &::utils::secret::Secret(&self.$secret)))*
+                    .finish()
+            }
+        }
+    };
+    ($name:ident secret { $($secret:ident),* }) => {
+        impl_secret_debug!($name { } secret { $($secret),* });
+    };
+    ($name:ident<$param:ident: $bound:path> { $($field:ident),* } secret { $($secret:ident),* }) => {
+        impl<$param: $bound> ::std::fmt::Debug for $name<$param> {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.debug_struct(stringify!($name))
+                    $(.field(stringify!($field), &self.$field))*
+                    $(.field(stringify!($secret), &::utils::secret::Secret(&self.$secret)))*
+                    .finish()
+            }
+        }
+    };
+    ($name:ident<$param:ident: $bound:path> secret { $($secret:ident),* }) => {
+        impl_secret_debug!($name<$param: $bound> { } secret { $($secret),* });
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Key {
+        index: u32,
+        value: Vec<u8>
+    }
+
+    impl_secret_debug!(Key { index } secret { value });
+
+    #[test]
+    #[cfg(not(feature = "log_secrets"))]
+    fn secret_debug_works() {
+        assert_eq!("[redacted]", format!("{:?}", Secret(&vec![1, 2, 3])));
+        assert_eq!("Key { index: 1, value: [redacted] }", format!("{:?}", Key { index: 1, value: vec![1, 2, 3] }));
+    }
+
+    #[test]
+    #[cfg(feature = "log_secrets")]
+    fn secret_debug_works_for_log_secrets() {
+        assert_eq!("[1, 2, 3]", format!("{:?}", Secret(&vec![1, 2, 3])));
+        assert_eq!("Key { index: 1, value: [1, 2, 3] }", format!("{:?}", Key { index: 1, value: vec![1, 2, 3] }));
+    }
+}
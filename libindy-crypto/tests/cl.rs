@@ -8,6 +8,7 @@ use indy_crypto::cl::issuer::Issuer;
 use indy_crypto::cl::prover::Prover;
 use indy_crypto::cl::verifier::Verifier;
 use indy_crypto::pair::PointG2;
+use indy_crypto::bn::BigNumber;
 use std::collections::HashSet;
 
 pub const PROVER_ID: &'static str = "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW";
@@ -313,6 +314,174 @@ mod test {
         assert!(proof_verifier.verify(&proof, &nonce).unwrap());
     }
 
+    #[test]
+    fn anoncreds_works_for_le_lt_gt_predicates() {
+        // 1. Issuer creates credential schema
+        let credential_schema = helpers::gvt_credential_schema();
+
+        // 2. Issuer creates credential definition
+        let (credential_pub_key, credential_priv_key, credential_key_correctness_proof) =
+            Issuer::new_credential_def(&credential_schema, false).unwrap();
+
+        // 3. Prover creates master secret
+        let master_secret = Prover::new_master_secret().unwrap();
+
+        // 4. Issuer creates nonce used Prover to blind master secret
+        let master_secret_blinding_nonce = new_nonce().unwrap();
+
+        // 5. Prover blinds master secret
+        let (blinded_ms, master_secret_blinding_data, blinded_master_secret_correctness_proof) =
+            Prover::blind_master_secret(&credential_pub_key,
+                                        &credential_key_correctness_proof,
+                                        &master_secret,
+                                        &master_secret_blinding_nonce).unwrap();
+
+        // 6. Prover creates nonce used Issuer to credential issue
+        let credential_issuance_nonce = new_nonce().unwrap();
+
+        // 7. Issuer creates credential values
+        let credential_values = helpers::gvt_credential_values();
+
+        // 8. Issuer signs credential values
+        let (mut credential_signature, signature_correctness_proof) = Issuer::sign_credential(PROVER_ID,
+                                                                                              &blinded_ms,
+                                                                                              &blinded_master_secret_correctness_proof,
+                                                                                              &master_secret_blinding_nonce,
+                                                                                              &credential_issuance_nonce,
+                                                                                              &credential_values,
+                                                                                              &credential_pub_key,
+                                                                                              &credential_priv_key).unwrap();
+
+        // 9. Prover processes credential signature
+        Prover::process_credential_signature(&mut credential_signature,
+                                             &credential_values,
+                                             &signature_correctness_proof,
+                                             &master_secret_blinding_data,
+                                             &master_secret,
+                                             &credential_pub_key,
+                                             &credential_issuance_nonce,
+                                             None, None, None).unwrap();
+
+        // 10. Verifier create sub proof request with predicates of all types (age is 28, height is 175)
+        let mut sub_proof_request_builder = Verifier::new_sub_proof_request_builder().unwrap();
+        sub_proof_request_builder.add_revealed_attr("name").unwrap();
+        sub_proof_request_builder.add_predicate("age", "GE", 28).unwrap();
+        sub_proof_request_builder.add_predicate("age", "LT", 65).unwrap();
+        sub_proof_request_builder.add_predicate("age", "GT", 27).unwrap();
+        sub_proof_request_builder.add_predicate("height", "LE", 175).unwrap();
+        sub_proof_request_builder.add_predicate("height", "GT", -1).unwrap();
+        let sub_proof_request = sub_proof_request_builder.finalize().unwrap();
+
+        // 11. Verifier creates nonce
+        let nonce = new_nonce().unwrap();
+
+        // 12. Prover creates proof
+        let key_id = "issuer_key_id_1";
+        let mut proof_builder = Prover::new_proof_builder().unwrap();
+        proof_builder.add_sub_proof_request(key_id,
+                                            &sub_proof_request,
+                                            &credential_schema,
+                                            &credential_signature,
+                                            &credential_values,
+                                            &credential_pub_key,
+                                            None,
+                                            None).unwrap();
+        let proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
+
+        // 13. Verifier verifies proof
+        let mut proof_verifier = Verifier::new_proof_verifier().unwrap();
+        proof_verifier.add_sub_proof_request(key_id,
+                                             &sub_proof_request,
+                                             &credential_schema,
+                                             &credential_pub_key,
+                                             None,
+                                             None).unwrap();
+        assert!(proof_verifier.verify(&proof, &nonce).unwrap());
+    }
+
+    #[test]
+    fn anoncreds_works_for_big_and_negative_predicates() {
+        // 1. Issuer creates credential schema
+        let credential_schema = helpers::xyz_credential_schema();
+
+        // 2. Issuer creates credential definition
+        let (credential_pub_key, credential_priv_key, credential_key_correctness_proof) =
+            Issuer::new_credential_def(&credential_schema, false).unwrap();
+
+        // 3. Prover creates master secret
+        let master_secret = Prover::new_master_secret().unwrap();
+
+        // 4. Issuer creates nonce used Prover to blind master secret
+        let master_secret_blinding_nonce = new_nonce().unwrap();
+
+        // 5. Prover blinds master secret
+        let (blinded_ms, master_secret_blinding_data, blinded_master_secret_correctness_proof) =
+            Prover::blind_master_secret(&credential_pub_key,
+                                        &credential_key_correctness_proof,
+                                        &master_secret,
+                                        &master_secret_blinding_nonce).unwrap();
+
+        // 6. Prover creates nonce used Issuer to credential issue
+        let credential_issuance_nonce = new_nonce().unwrap();
+
+        // 7. Issuer creates credential values
+        let credential_values = helpers::xyz_credential_values();
+
+        // 8. Issuer signs credential values
+        let (mut credential_signature, signature_correctness_proof) = Issuer::sign_credential(PROVER_ID,
+                                                                                              &blinded_ms,
+                                                                                              &blinded_master_secret_correctness_proof,
+                                                                                              &master_secret_blinding_nonce,
+                                                                                              &credential_issuance_nonce,
+                                                                                              &credential_values,
+                                                                                              &credential_pub_key,
+                                                                                              &credential_priv_key).unwrap();
+
+        // 9. Prover processes credential signature
+        Prover::process_credential_signature(&mut credential_signature,
+                                             &credential_values,
+                                             &signature_correctness_proof,
+                                             &master_secret_blinding_data,
+                                             &master_secret,
+                                             &credential_pub_key,
+                                             &credential_issuance_nonce,
+                                             None, None, None).unwrap();
+
+        // 10. Verifier create sub proof request with bounds past i32 range (status is 51792877103171595686471452153480627530895, period is 8)
+        let mut sub_proof_request_builder = Verifier::new_sub_proof_request_builder().unwrap();
+        sub_proof_request_builder.add_big_predicate("status", "GE", &BigNumber::from_dec("51792877103171595686471452153480627530000").unwrap()).unwrap();
+        sub_proof_request_builder.add_big_predicate("status", "LT", &BigNumber::from_dec("87112285931760246646623899502532662132736").unwrap()).unwrap();
+        sub_proof_request_builder.add_big_predicate("period", "GT", &BigNumber::from_dec("-18446744073709551616").unwrap()).unwrap();
+        sub_proof_request_builder.add_big_predicate("period", "LE", &BigNumber::from_dec("8").unwrap()).unwrap();
+        let sub_proof_request = sub_proof_request_builder.finalize().unwrap();
+
+        // 11. Verifier creates nonce
+        let nonce = new_nonce().unwrap();
+
+        // 12. Prover creates proof
+        let key_id = "issuer_key_id_1";
+        let mut proof_builder = Prover::new_proof_builder().unwrap();
+        proof_builder.add_sub_proof_request(key_id,
+                                            &sub_proof_request,
+                                            &credential_schema,
+                                            &credential_signature,
+                                            &credential_values,
+                                            &credential_pub_key,
+                                            None,
+                                            None).unwrap();
+        let proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
+
+        // 13. Verifier verifies proof
+        let mut proof_verifier = Verifier::new_proof_verifier().unwrap();
+        proof_verifier.add_sub_proof_request(key_id,
+                                             &sub_proof_request,
+                                             &credential_schema,
+                                             &credential_pub_key,
+                                             None,
+                                             None).unwrap();
+        assert!(proof_verifier.verify(&proof, &nonce).unwrap());
+    }
+
     #[test]
     fn anoncreds_works_for_revocation_proof_issuance_on_demand() {
         // 1. Issuer creates credential schema
@@ -641,6 +810,166 @@ mod test {
         assert!(proof_verifier.verify(&proof, &nonce).unwrap());
     }
 
+    #[test]
+    fn anoncreds_works_for_attr_equality_across_credentials() {
+        // 1. Prover creates master secret
+        let master_secret = Prover::new_master_secret().unwrap();
+
+        // 2. Two issuers create and sign GVT credentials with the same name for Prover
+        let credential_schema = helpers::gvt_credential_schema();
+        let credential_values = helpers::gvt_credential_values();
+
+        let (first_credential_pub_key, first_credential_signature) =
+            helpers::issue_credential(&credential_schema, &credential_values, &master_secret);
+        let (second_credential_pub_key, second_credential_signature) =
+            helpers::issue_credential(&credential_schema, &credential_values, &master_secret);
+
+        // 3. Verifier creates sub proof requests with hidden names linked to each other
+        let mut first_sub_proof_request_builder = Verifier::new_sub_proof_request_builder().unwrap();
+        first_sub_proof_request_builder.add_predicate("age", "GE", 18).unwrap();
+        first_sub_proof_request_builder.add_attr_equality("name", "name_link").unwrap();
+        let first_sub_proof_request = first_sub_proof_request_builder.finalize().unwrap();
+
+        let mut second_sub_proof_request_builder = Verifier::new_sub_proof_request_builder().unwrap();
+        second_sub_proof_request_builder.add_revealed_attr("sex").unwrap();
+        second_sub_proof_request_builder.add_attr_equality("name", "name_link").unwrap();
+        let second_sub_proof_request = second_sub_proof_request_builder.finalize().unwrap();
+
+        // 4. Verifier creates nonce
+        let nonce = new_nonce().unwrap();
+
+        // 5. Prover creates proof
+        let mut proof_builder = Prover::new_proof_builder().unwrap();
+        proof_builder.add_sub_proof_request("first_key_id",
+                                            &first_sub_proof_request,
+                                            &credential_schema,
+                                            &first_credential_signature,
+                                            &credential_values,
+                                            &first_credential_pub_key,
+                                            None, None).unwrap();
+        proof_builder.add_sub_proof_request("second_key_id",
+                                            &second_sub_proof_request,
+                                            &credential_schema,
+                                            &second_credential_signature,
+                                            &credential_values,
+                                            &second_credential_pub_key,
+                                            None, None).unwrap();
+        let proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
+
+        // 6. Verifier verifies proof
+        let mut proof_verifier = Verifier::new_proof_verifier().unwrap();
+        proof_verifier.add_sub_proof_request("first_key_id",
+                                             &first_sub_proof_request,
+                                             &credential_schema,
+                                             &first_credential_pub_key,
+                                             None, None).unwrap();
+        proof_verifier.add_sub_proof_request("second_key_id",
+                                             &second_sub_proof_request,
+                                             &credential_schema,
+                                             &second_credential_pub_key,
+                                             None, None).unwrap();
+        assert!(proof_verifier.verify(&proof, &nonce).unwrap());
+    }
+
+    #[test]
+    fn proof_builder_add_sub_proof_works_for_not_equal_linked_attrs() {
+        // 1. Prover creates master secret
+        let master_secret = Prover::new_master_secret().unwrap();
+
+        // 2. Issuers create and sign GVT and XYZ credentials for Prover
+        let gvt_credential_schema = helpers::gvt_credential_schema();
+        let gvt_credential_values = helpers::gvt_credential_values();
+        let (gvt_credential_pub_key, gvt_credential_signature) =
+            helpers::issue_credential(&gvt_credential_schema, &gvt_credential_values, &master_secret);
+
+        let xyz_credential_schema = helpers::xyz_credential_schema();
+        let xyz_credential_values = helpers::xyz_credential_values();
+        let (xyz_credential_pub_key, xyz_credential_signature) =
+            helpers::issue_credential(&xyz_credential_schema, &xyz_credential_values, &master_secret);
+
+        // 3. Verifier links GVT age (28) with XYZ period (8)
+        let mut gvt_sub_proof_request_builder = Verifier::new_sub_proof_request_builder().unwrap();
+        gvt_sub_proof_request_builder.add_attr_equality("age", "link").unwrap();
+        let gvt_sub_proof_request = gvt_sub_proof_request_builder.finalize().unwrap();
+
+        let mut xyz_sub_proof_request_builder = Verifier::new_sub_proof_request_builder().unwrap();
+        xyz_sub_proof_request_builder.add_attr_equality("period", "link").unwrap();
+        let xyz_sub_proof_request = xyz_sub_proof_request_builder.finalize().unwrap();
+
+        // 4. Prover can't create proof for not equal attributes
+        let mut proof_builder = Prover::new_proof_builder().unwrap();
+        proof_builder.add_sub_proof_request("gvt_key_id",
+                                            &gvt_sub_proof_request,
+                                            &gvt_credential_schema,
+                                            &gvt_credential_signature,
+                                            &gvt_credential_values,
+                                            &gvt_credential_pub_key,
+                                            None, None).unwrap();
+        let res = proof_builder.add_sub_proof_request("xyz_key_id",
+                                                      &xyz_sub_proof_request,
+                                                      &xyz_credential_schema,
+                                                      &xyz_credential_signature,
+                                                      &xyz_credential_values,
+                                                      &xyz_credential_pub_key,
+                                                      None, None);
+        assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err().to_error_code());
+    }
+
+    #[test]
+    fn verifier_verify_proof_works_for_proof_without_requested_attr_equality() {
+        // 1. Prover creates master secret
+        let master_secret = Prover::new_master_secret().unwrap();
+
+        // 2. Two issuers create and sign GVT credentials with the same name for Prover
+        let credential_schema = helpers::gvt_credential_schema();
+        let credential_values = helpers::gvt_credential_values();
+
+        let (first_credential_pub_key, first_credential_signature) =
+            helpers::issue_credential(&credential_schema, &credential_values, &master_secret);
+        let (second_credential_pub_key, second_credential_signature) =
+            helpers::issue_credential(&credential_schema, &credential_values, &master_secret);
+
+        // 3. Prover creates proof for sub proof requests without attribute equality
+        let sub_proof_request = Verifier::new_sub_proof_request_builder().unwrap().finalize().unwrap();
+        let nonce = new_nonce().unwrap();
+
+        let mut proof_builder = Prover::new_proof_builder().unwrap();
+        proof_builder.add_sub_proof_request("first_key_id",
+                                            &sub_proof_request,
+                                            &credential_schema,
+                                            &first_credential_signature,
+                                            &credential_values,
+                                            &first_credential_pub_key,
+                                            None, None).unwrap();
+        proof_builder.add_sub_proof_request("second_key_id",
+                                            &sub_proof_request,
+                                            &credential_schema,
+                                            &second_credential_signature,
+                                            &credential_values,
+                                            &second_credential_pub_key,
+                                            None, None).unwrap();
+        let proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
+
+        // 4. Verifier requests names to be equal and rejects proof
+        let mut linked_sub_proof_request_builder = Verifier::new_sub_proof_request_builder().unwrap();
+        linked_sub_proof_request_builder.add_attr_equality("name", "name_link").unwrap();
+        let linked_sub_proof_request = linked_sub_proof_request_builder.finalize().unwrap();
+
+        let mut proof_verifier = Verifier::new_proof_verifier().unwrap();
+        proof_verifier.add_sub_proof_request("first_key_id",
+                                             &linked_sub_proof_request,
+                                             &credential_schema,
+                                             &first_credential_pub_key,
+                                             None, None).unwrap();
+        proof_verifier.add_sub_proof_request("second_key_id",
+                                             &linked_sub_proof_request,
+                                             &credential_schema,
+                                             &second_credential_pub_key,
+                                             None, None).unwrap();
+        let res = proof_verifier.verify(&proof, &nonce);
+        assert_eq!(ErrorCode::AnoncredsProofRejected, res.unwrap_err().to_error_code());
+    }
+
     #[test]
     fn anoncreds_works_for_revocation_proof_for_three_credentials_proving_first() {
         // 1. Issuer creates credential schema
@@ -2775,6 +3104,71 @@ mod test {
         assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err().to_error_code());
     }
 
+    #[test]
+    fn proof_builder_add_sub_proof_works_for_credential_not_satisfied_requested_lt_predicate() {
+        // 1. Issuer creates credential schema
+        let credential_schema = helpers::gvt_credential_schema();
+
+        // 2. Issuer creates credential definition
+        let (credential_pub_key, credential_priv_key, credential_key_correctness_proof) = Issuer::new_credential_def(&credential_schema, false).unwrap();
+
+        // 3. Prover creates master secret
+        let master_secret = Prover::new_master_secret().unwrap();
+
+        // 4. Issuer creates nonce used Prover to blind master secret
+        let master_secret_blinding_nonce = new_nonce().unwrap();
+
+        // 5. Prover blinds master secret
+        let (blinded_master_secret, master_secret_blinding_data, blinded_master_secret_correctness_proof) =
+            Prover::blind_master_secret(&credential_pub_key,
+                                        &credential_key_correctness_proof,
+                                        &master_secret,
+                                        &master_secret_blinding_nonce).unwrap();
+
+        // 6. Prover creates nonce used Issuer to credential issue
+        let credential_issuance_nonce = new_nonce().unwrap();
+
+        // 7. Issuer creates and signs credential values
+        let credential_values = helpers::gvt_credential_values();
+        let (mut credential_signature, signature_correctness_proof) = Issuer::sign_credential(PROVER_ID,
+                                                                                              &blinded_master_secret,
+                                                                                              &blinded_master_secret_correctness_proof,
+                                                                                              &master_secret_blinding_nonce,
+                                                                                              &credential_issuance_nonce,
+                                                                                              &credential_values,
+                                                                                              &credential_pub_key,
+                                                                                              &credential_priv_key).unwrap();
+
+        // 8. Prover processes credential signature
+        Prover::process_credential_signature(&mut credential_signature,
+                                             &credential_values,
+                                             &signature_correctness_proof,
+                                             &master_secret_blinding_data,
+                                             &master_secret,
+                                             &credential_pub_key,
+                                             &credential_issuance_nonce,
+                                             None, None, None).unwrap();
+
+        // 9. Verifier creates sub proof request
+        let mut gvt_sub_proof_request_builder = Verifier::new_sub_proof_request_builder().unwrap();
+        gvt_sub_proof_request_builder.add_revealed_attr("name").unwrap();
+        gvt_sub_proof_request_builder.add_predicate("age", "LT", 28).unwrap();
+        let sub_proof_request = gvt_sub_proof_request_builder.finalize().unwrap();
+
+        // 10. Prover creates proof by credential value not satisfied predicate
+        let mut proof_builder = Prover::new_proof_builder().unwrap();
+
+        let key_id = "key_id";
+        let res = proof_builder.add_sub_proof_request(key_id,
+                                                      &sub_proof_request,
+                                                      &credential_schema,
+                                                      &credential_signature,
+                                                      &credential_values,
+                                                      &credential_pub_key,
+                                                      None, None);
+        assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err().to_error_code());
+    }
+
     #[test]
     fn proof_verifier_add_sub_proof_request_works_for_credential_schema_not_satisfied_to_sub_proof_request() {
         // 1. Issuer creates credential schema
@@ -3180,6 +3574,43 @@ mod helpers {
         credential_values_builder.finalize().unwrap()
     }
 
+    pub fn issue_credential(credential_schema: &CredentialSchema,
+                            credential_values: &CredentialValues,
+                            master_secret: &MasterSecret) -> (CredentialPublicKey, CredentialSignature) {
+        let (credential_pub_key, credential_priv_key, credential_key_correctness_proof) =
+            Issuer::new_credential_def(credential_schema, false).unwrap();
+
+        let master_secret_blinding_nonce = new_nonce().unwrap();
+
+        let (blinded_master_secret, master_secret_blinding_data, blinded_master_secret_correctness_proof) =
+            Prover::blind_master_secret(&credential_pub_key,
+                                        &credential_key_correctness_proof,
+                                        master_secret,
+                                        &master_secret_blinding_nonce).unwrap();
+
+        let credential_issuance_nonce = new_nonce().unwrap();
+
+        let (mut credential_signature, signature_correctness_proof) = Issuer::sign_credential(PROVER_ID,
+                                                                                              &blinded_master_secret,
+                                                                                              &blinded_master_secret_correctness_proof,
+                                                                                              &master_secret_blinding_nonce,
+                                                                                              &credential_issuance_nonce,
+                                                                                              credential_values,
+                                                                                              &credential_pub_key,
+                                                                                              &credential_priv_key).unwrap();
+
+        Prover::process_credential_signature(&mut credential_signature,
+                                             credential_values,
+                                             &signature_correctness_proof,
+                                             &master_secret_blinding_data,
+                                             master_secret,
+                                             &credential_pub_key,
+                                             &credential_issuance_nonce,
+                                             None, None, None).unwrap();
+
+        (credential_pub_key, credential_signature)
+    }
+
     pub fn gvt_sub_proof_request() -> SubProofRequest {
         let mut gvt_sub_proof_request_builder = Verifier::new_sub_proof_request_builder().unwrap();
         gvt_sub_proof_request_builder.add_revealed_attr("name").unwrap();
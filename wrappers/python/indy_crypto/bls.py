@@ -7,11 +7,14 @@ from .lib import do_call
 
 class BlsEntity:
     """
-    Base class for BLS Entities (Generator, SignKey, VerKey, Signature, MultiSignature).
+    Base class for BLS Entities (Generator, SignKey, VerKey, Signature, MultiSignature, MultiVerKey,
+    BitmapMultiSignature, AggregateSignature, ProofOfPossession).
     """
     new_handler = None
     from_bytes_handler = None
     as_bytes_handler = None
+    from_compressed_bytes_handler = None
+    as_compressed_bytes_handler = None
     free_handler = None
 
     def __init__(self, c_instance: c_void_p):
@@ -55,6 +58,41 @@ class BlsEntity:
         logger.debug("BlsEntity.as_bytes: <<< res: %r", res)
         return res
 
+    @classmethod
+    def from_compressed_bytes(cls, xbytes: bytes) -> 'BlsEntity':
+        """
+        Creates and Bls entity from compressed bytes representation.
+        :param xbytes: Compressed bytes representation of Bls entity
+        :return: BLS entity intance
+        """
+        logger = logging.getLogger(__name__)
+        logger.debug("BlsEntity::from_compressed_bytes: >>>")
+
+        c_instance = c_void_p()
+        do_call(cls.from_compressed_bytes_handler, xbytes, len(xbytes), byref(c_instance))
+
+        res = cls(c_instance)
+
+        logger.debug("BlsEntity::from_compressed_bytes: <<< res: %r", res)
+        return res
+
+    def as_compressed_bytes(self) -> bytes:
+        """
+        Returns BLS entity compressed bytes representation.
+        :return: BLS entity compressed bytes representation
+        """
+        logger = logging.getLogger(__name__)
+        logger.debug("BlsEntity.as_compressed_bytes: >>> self: %r", self)
+
+        xbytes = POINTER(c_ubyte)()
+        xbytes_len = c_size_t()
+
+        do_call(self.as_compressed_bytes_handler, self.c_instance, byref(xbytes), byref(xbytes_len))
+        res = bytes(xbytes[:xbytes_len.value])
+
+        logger.debug("BlsEntity.as_compressed_bytes: <<< res: %r", res)
+        return res
+
     def __del__(self):
         logger = logging.getLogger(__name__)
         logger.debug("BlsEntity.__del__: >>> self: %r", self)
@@ -71,6 +109,8 @@ class Generator(BlsEntity):
     new_handler = 'indy_crypto_bls_generator_new'
     from_bytes_handler = 'indy_crypto_bls_generator_from_bytes'
     as_bytes_handler = 'indy_crypto_bls_generator_as_bytes'
+    from_compressed_bytes_handler = 'indy_crypto_bls_generator_from_compressed_bytes'
+    as_compressed_bytes_handler = 'indy_crypto_bls_generator_as_compressed_bytes'
     free_handler = 'indy_crypto_bls_generator_free'
 
     @classmethod
@@ -118,6 +158,60 @@ class SignKey(BlsEntity):
         logger.debug("SignKey::new: <<< res: %r", res)
         return res
 
+    @classmethod
+    def derive_master(cls, seed: bytes) -> 'SignKey':
+        """
+        Derives and returns master BLS sign key (root of the derivation tree) from seed.
+        :param: seed - Seed, at least 32 bytes.
+        :return: BLS sign key
+        """
+        logger = logging.getLogger(__name__)
+        logger.debug("SignKey::derive_master: >>>")
+
+        c_instance = c_void_p()
+        do_call('indy_crypto_bls_sign_key_derive_master', seed, len(seed), byref(c_instance))
+
+        res = cls(c_instance)
+
+        logger.debug("SignKey::derive_master: <<< res: %r", res)
+        return res
+
+    def derive_child(self, index: int) -> 'SignKey':
+        """
+        Derives and returns child BLS sign key with given index.
+        :param: index - Index of the child.
+        :return: BLS sign key
+        """
+        logger = logging.getLogger(__name__)
+        logger.debug("SignKey::derive_child: >>> index: %r", index)
+
+        c_instance = c_void_p()
+        do_call('indy_crypto_bls_sign_key_derive_child', self.c_instance, c_uint32(index), byref(c_instance))
+
+        res = SignKey(c_instance)
+
+        logger.debug("SignKey::derive_child: <<< res: %r", res)
+        return res
+
+    @classmethod
+    def derive_path(cls, seed: bytes, path: str) -> 'SignKey':
+        """
+        Derives and returns BLS sign key for derivation path (like "m/12381/3600/0/0") from seed.
+        :param: seed - Seed, at least 32 bytes.
+        :param: path - Derivation path.
+        :return: BLS sign key
+        """
+        logger = logging.getLogger(__name__)
+        logger.debug("SignKey::derive_path: >>> path: %r", path)
+
+        c_instance = c_void_p()
+        do_call('indy_crypto_bls_sign_key_derive_path', seed, len(seed), path.encode('utf-8'), byref(c_instance))
+
+        res = cls(c_instance)
+
+        logger.debug("SignKey::derive_path: <<< res: %r", res)
+        return res
+
 
 class VerKey(BlsEntity):
     """
@@ -126,6 +220,8 @@ class VerKey(BlsEntity):
     new_handler = 'indy_crypto_bls_ver_key_new'
     from_bytes_handler = 'indy_crypto_bls_ver_key_from_bytes'
     as_bytes_handler = 'indy_crypto_bls_ver_key_as_bytes'
+    from_compressed_bytes_handler = 'indy_crypto_bls_ver_key_from_compressed_bytes'
+    as_compressed_bytes_handler = 'indy_crypto_bls_ver_key_as_compressed_bytes'
     free_handler = 'indy_crypto_bls_ver_key_free'
 
     @classmethod
@@ -155,6 +251,8 @@ class Signature(BlsEntity):
     new_handler = None
     from_bytes_handler = 'indy_crypto_bls_signature_from_bytes'
     as_bytes_handler = 'indy_crypto_bls_signature_as_bytes'
+    from_compressed_bytes_handler = 'indy_crypto_bls_signature_from_compressed_bytes'
+    as_compressed_bytes_handler = 'indy_crypto_bls_signature_as_compressed_bytes'
     free_handler = 'indy_crypto_bls_signature_free'
 
 
@@ -165,6 +263,8 @@ class MultiSignature(BlsEntity):
     new_handler = 'indy_crypto_bls_multi_signature_new'
     from_bytes_handler = 'indy_crypto_bls_multi_signature_from_bytes'
     as_bytes_handler = 'indy_crypto_bls_multi_signature_as_bytes'
+    from_compressed_bytes_handler = 'indy_crypto_bls_multi_signature_from_compressed_bytes'
+    as_compressed_bytes_handler = 'indy_crypto_bls_multi_signature_as_compressed_bytes'
     free_handler = 'indy_crypto_bls_multi_signature_free'
 
     @classmethod
@@ -191,6 +291,217 @@ class MultiSignature(BlsEntity):
         return res
 
 
+class MultiVerKey(BlsEntity):
+    """
+    BLS multi verification key (aggregated verification keys of signers set).
+    """
+    new_handler = 'indy_crypto_bls_multi_ver_key_new'
+    from_bytes_handler = 'indy_crypto_bls_multi_ver_key_from_bytes'
+    as_bytes_handler = 'indy_crypto_bls_multi_ver_key_as_bytes'
+    from_compressed_bytes_handler = 'indy_crypto_bls_multi_ver_key_from_compressed_bytes'
+    as_compressed_bytes_handler = 'indy_crypto_bls_multi_ver_key_as_compressed_bytes'
+    free_handler = 'indy_crypto_bls_multi_ver_key_free'
+
+    @classmethod
+    def new(cls, ver_keys: [VerKey]) -> 'MultiVerKey':
+        """
+        Creates and returns BLS multi verification key that corresponds to the given verification keys list.
+        :param: ver_keys - List of verification keys
+        :return: BLS multi verification key
+        """
+        logger = logging.getLogger(__name__)
+        logger.debug("MultiVerKey::new: >>>")
+
+        # noinspection PyCallingNonCallable,PyTypeChecker
+        ver_key_c_instances = (c_void_p * len(ver_keys))()
+        for i in range(len(ver_keys)):
+            ver_key_c_instances[i] = ver_keys[i].c_instance
+
+        c_instance = c_void_p()
+        do_call(cls.new_handler, ver_key_c_instances, len(ver_keys), byref(c_instance))
+
+        res = cls(c_instance)
+
+        logger.debug("MultiVerKey::new: <<< res: %r", res)
+        return res
+
+    def add(self, ver_key: VerKey):
+        """
+        Adds verification key to the set.
+        :param: ver_key - Verification key to add
+        """
+        logger = logging.getLogger(__name__)
+        logger.debug("MultiVerKey::add: >>> ver_key: %r", ver_key)
+
+        do_call('indy_crypto_bls_multi_ver_key_add', self.c_instance, ver_key.c_instance)
+
+        logger.debug("MultiVerKey::add: <<<")
+
+    def remove(self, ver_key: VerKey):
+        """
+        Removes verification key from the set.
+        :param: ver_key - Verification key to remove
+        """
+        logger = logging.getLogger(__name__)
+        logger.debug("MultiVerKey::remove: >>> ver_key: %r", ver_key)
+
+        do_call('indy_crypto_bls_multi_ver_key_remove', self.c_instance, ver_key.c_instance)
+
+        logger.debug("MultiVerKey::remove: <<<")
+
+
+class BitmapMultiSignature(BlsEntity):
+    """
+    BLS multi signature with bitmap of signers in ordered validators set.
+    """
+    new_handler = 'indy_crypto_bls_bitmap_multi_signature_new'
+    from_bytes_handler = 'indy_crypto_bls_bitmap_multi_signature_from_bytes'
+    as_bytes_handler = 'indy_crypto_bls_bitmap_multi_signature_as_bytes'
+    from_compressed_bytes_handler = 'indy_crypto_bls_bitmap_multi_signature_from_compressed_bytes'
+    as_compressed_bytes_handler = 'indy_crypto_bls_bitmap_multi_signature_as_compressed_bytes'
+    free_handler = 'indy_crypto_bls_bitmap_multi_signature_free'
+
+    @classmethod
+    def new(cls, signatures: [Signature], indexes: [int], participants_count: int) -> 'BitmapMultiSignature':
+        """
+        Creates and returns BLS bitmap multi signature that corresponds to the given signatures list.
+        :param: signatures - List of signatures
+        :param: indexes - List of signers indexes in validators set
+        :param: participants_count - Size of validators set
+        :return: BLS bitmap multi signature
+        """
+        logger = logging.getLogger(__name__)
+        logger.debug("BitmapMultiSignature::new: >>> indexes: %r, participants_count: %r", indexes, participants_count)
+
+        # noinspection PyCallingNonCallable,PyTypeChecker
+        signature_c_instances = (c_void_p * len(signatures))()
+        for i in range(len(signatures)):
+            signature_c_instances[i] = signatures[i].c_instance
+
+        # noinspection PyCallingNonCallable,PyTypeChecker
+        c_indexes = (c_uint32 * len(indexes))(*indexes)
+
+        c_instance = c_void_p()
+        do_call(cls.new_handler,
+                signature_c_instances, c_size_t(len(signatures)),
+                c_indexes, c_size_t(len(indexes)),
+                c_uint32(participants_count),
+                byref(c_instance))
+
+        res = cls(c_instance)
+
+        logger.debug("BitmapMultiSignature::new: <<< res: %r", res)
+        return res
+
+    def as_bitmap(self) -> bytes:
+        """
+        Returns bitmap of signers. Bit i of the bitmap is bit (i % 8) of byte (i // 8).
+        :return: Bitmap of signers
+        """
+        logger = logging.getLogger(__name__)
+        logger.debug("BitmapMultiSignature.as_bitmap: >>> self: %r", self)
+
+        bitmap = POINTER(c_ubyte)()
+        bitmap_len = c_size_t()
+
+        do_call('indy_crypto_bls_bitmap_multi_signature_as_bitmap', self.c_instance, byref(bitmap), byref(bitmap_len))
+        res = bytes(bitmap[:bitmap_len.value])
+
+        logger.debug("BitmapMultiSignature.as_bitmap: <<< res: %r", res)
+        return res
+
+    def add(self, signature: Signature, index: int):
+        """
+        Adds signature of validators set participant.
+        :param: signature - Signature to add
+        :param: index - Signer index in validators set
+        """
+        logger = logging.getLogger(__name__)
+        logger.debug("BitmapMultiSignature::add: >>> signature: %r, index: %r", signature, index)
+
+        do_call('indy_crypto_bls_bitmap_multi_signature_add', self.c_instance, signature.c_instance, c_uint32(index))
+
+        logger.debug("BitmapMultiSignature::add: <<<")
+
+    def merge(self, other: 'BitmapMultiSignature'):
+        """
+        Merges other bitmap multi signature over the same validators set and disjoint signers.
+        :param: other - Bitmap multi signature to merge
+        """
+        logger = logging.getLogger(__name__)
+        logger.debug("BitmapMultiSignature::merge: >>> other: %r", other)
+
+        do_call('indy_crypto_bls_bitmap_multi_signature_merge', self.c_instance, other.c_instance)
+
+        logger.debug("BitmapMultiSignature::merge: <<<")
+
+
+class AggregateSignature(BlsEntity):
+    """
+    BLS aggregate signature over distinct messages.
+    """
+    new_handler = 'indy_crypto_bls_aggregate_signature_new'
+    from_bytes_handler = 'indy_crypto_bls_aggregate_signature_from_bytes'
+    as_bytes_handler = 'indy_crypto_bls_aggregate_signature_as_bytes'
+    from_compressed_bytes_handler = 'indy_crypto_bls_aggregate_signature_from_compressed_bytes'
+    as_compressed_bytes_handler = 'indy_crypto_bls_aggregate_signature_as_compressed_bytes'
+    free_handler = 'indy_crypto_bls_aggregate_signature_free'
+
+    @classmethod
+    def new(cls, signatures: [Signature]) -> 'AggregateSignature':
+        """
+        Creates and returns BLS aggregate signature that corresponds to the given signatures list.
+        :param: signature - List of signatures
+        :return: BLS aggregate signature
+        """
+        logger = logging.getLogger(__name__)
+        logger.debug("AggregateSignature::new: >>>")
+
+        # noinspection PyCallingNonCallable,PyTypeChecker
+        signature_c_instances = (c_void_p * len(signatures))()
+        for i in range(len(signatures)):
+            signature_c_instances[i] = signatures[i].c_instance
+
+        c_instance = c_void_p()
+        do_call(cls.new_handler, signature_c_instances, len(signatures), byref(c_instance))
+
+        res = cls(c_instance)
+
+        logger.debug("AggregateSignature::new: <<< res: %r", res)
+        return res
+
+
+class ProofOfPossession(BlsEntity):
+    """
+    BLS proof of possession.
+    """
+    new_handler = 'indy_crypto_bls_pop_new'
+    from_bytes_handler = 'indy_crypto_bls_pop_from_bytes'
+    as_bytes_handler = 'indy_crypto_bls_pop_as_bytes'
+    from_compressed_bytes_handler = 'indy_crypto_bls_pop_from_compressed_bytes'
+    as_compressed_bytes_handler = 'indy_crypto_bls_pop_as_compressed_bytes'
+    free_handler = 'indy_crypto_bls_pop_free'
+
+    @classmethod
+    def new(cls, ver_key: VerKey, sign_key: SignKey) -> 'ProofOfPossession':
+        """
+        Creates and returns BLS proof of possession that corresponds to the given ver key and sign key.
+        :param: ver_key - Ver Key
+        :param: sign_key - Sign Key
+        :return: BLS proof of possession
+        """
+        logger = logging.getLogger(__name__)
+        logger.debug("ProofOfPossession::new: >>>")
+
+        c_instance = c_void_p()
+        do_call(cls.new_handler, ver_key.c_instance, sign_key.c_instance, byref(c_instance))
+
+        res = cls(c_instance)
+
+        logger.debug("ProofOfPossession::new: <<< res: %r", res)
+        return res
+
+
 class Bls:
     """
     Provides Bls methods.
@@ -281,3 +592,190 @@ class Bls:
 
         logger.debug("Bls::verify_multi_sig: <<< res: %r", res)
         return res
+
+    @staticmethod
+    def verify_multi_sig_for_multi_ver_key(multi_sig: MultiSignature, message: bytes, multi_ver_key: MultiVerKey,
+                                           gen: Generator) -> bool:
+        """
+        Verifies the message multi signature against multi verification key
+        and returns true - if signature valid or false otherwise.
+
+        :param: multi_sig - Multi signature to verify
+        :param: message - Message to verify
+        :param: multi_ver_key - Multi verification key of signers
+        :param: gen - Generator point
+        :return: true if multi signature valid.
+        """
+
+        logger = logging.getLogger(__name__)
+        logger.debug("Bls::verify_multi_sig_for_multi_ver_key: >>> multi_sig: %r, message: %r, multi_ver_key: %r, gen: %r",
+                     multi_sig, message, multi_ver_key, gen)
+
+        valid = c_bool()
+        do_call('indy_crypto_bls_verify_multi_sig_for_multi_ver_key',
+                multi_sig.c_instance,
+                message, len(message),
+                multi_ver_key.c_instance,
+                gen.c_instance,
+                byref(valid))
+
+        res = valid
+
+        logger.debug("Bls::verify_multi_sig_for_multi_ver_key: <<< res: %r", res)
+        return res
+
+    @staticmethod
+    def verify_with_quorum(multi_sig: BitmapMultiSignature, message: bytes, ver_keys: [VerKey], min_signers: int,
+                           gen: Generator) -> bool:
+        """
+        Verifies the message bitmap multi signature against validators set and returns true - if signature valid
+        and has at least min_signers signers or false otherwise.
+
+        :param: multi_sig - Bitmap multi signature to verify
+        :param: message - Message to verify
+        :param: ver_keys - List of verification keys of the whole validators set
+        :param: min_signers - Minimal number of signers
+        :param: gen - Generator point
+        :return: true if bitmap multi signature valid.
+        """
+
+        logger = logging.getLogger(__name__)
+        logger.debug("Bls::verify_with_quorum: >>> multi_sig: %r, message: %r, ver_keys: %r, min_signers: %r, gen: %r",
+                     multi_sig, message, ver_keys, min_signers, gen)
+
+        # noinspection PyCallingNonCallable,PyTypeChecker
+        ver_key_c_instances = (c_void_p * len(ver_keys))()
+        for i in range(len(ver_keys)):
+            ver_key_c_instances[i] = ver_keys[i].c_instance
+
+        valid = c_bool()
+        do_call('indy_crypto_bls_verify_with_quorum',
+                multi_sig.c_instance,
+                message, len(message),
+                ver_key_c_instances, len(ver_keys),
+                c_uint32(min_signers),
+                gen.c_instance,
+                byref(valid))
+
+        res = valid
+
+        logger.debug("Bls::verify_with_quorum: <<< res: %r", res)
+        return res
+
+    @staticmethod
+    def verify_aggregate(aggregate_sig: AggregateSignature, messages: [bytes], ver_keys: [VerKey],
+                         gen: Generator) -> bool:
+        """
+        Verifies the aggregate signature over distinct messages and returns true - if signature valid or false otherwise.
+        Duplicate messages are rejected with error.
+
+        :param: aggregate_sig - Aggregate signature to verify
+        :param: messages - List of signed messages
+        :param: ver_keys - List of verification keys messages correspond to
+        :param: gen - Generator point
+        :return: true if aggregate signature valid.
+        """
+
+        logger = logging.getLogger(__name__)
+        logger.debug("Bls::verify_aggregate: >>> aggregate_sig: %r, messages: %r, ver_keys: %r, gen: %r",
+                     aggregate_sig, messages, ver_keys, gen)
+
+        # noinspection PyCallingNonCallable,PyTypeChecker
+        message_c_instances = (c_char_p * len(messages))()
+        # noinspection PyCallingNonCallable,PyTypeChecker
+        message_lens = (c_size_t * len(messages))()
+        for i in range(len(messages)):
+            message_c_instances[i] = messages[i]
+            message_lens[i] = len(messages[i])
+
+        # noinspection PyCallingNonCallable,PyTypeChecker
+        ver_key_c_instances = (c_void_p * len(ver_keys))()
+        for i in range(len(ver_keys)):
+            ver_key_c_instances[i] = ver_keys[i].c_instance
+
+        valid = c_bool()
+        do_call('indy_crypto_bls_verify_aggregate',
+                aggregate_sig.c_instance,
+                message_c_instances, message_lens, c_size_t(len(messages)),
+                ver_key_c_instances, c_size_t(len(ver_keys)),
+                gen.c_instance,
+                byref(valid))
+
+        res = valid
+
+        logger.debug("Bls::verify_aggregate: <<< res: %r", res)
+        return res
+
+    @staticmethod
+    def verify_batch(signatures: [Signature], messages: [bytes], ver_keys: [VerKey], gen: Generator) -> Optional[int]:
+        """
+        Verifies many independent message signatures at once.
+
+        :param: signatures - List of signatures to verify
+        :param: messages - List of messages signatures correspond to
+        :param: ver_keys - List of verification keys signatures correspond to
+        :param: gen - Generator point
+        :return: None if all signatures valid or index of the first invalid signature otherwise.
+        """
+
+        logger = logging.getLogger(__name__)
+        logger.debug("Bls::verify_batch: >>> signatures: %r, messages: %r, ver_keys: %r, gen: %r",
+                     signatures, messages, ver_keys, gen)
+
+        # noinspection PyCallingNonCallable,PyTypeChecker
+        signature_c_instances = (c_void_p * len(signatures))()
+        for i in range(len(signatures)):
+            signature_c_instances[i] = signatures[i].c_instance
+
+        # noinspection PyCallingNonCallable,PyTypeChecker
+        message_c_instances = (c_char_p * len(messages))()
+        # noinspection PyCallingNonCallable,PyTypeChecker
+        message_lens = (c_size_t * len(messages))()
+        for i in range(len(messages)):
+            message_c_instances[i] = messages[i]
+            message_lens[i] = len(messages[i])
+
+        # noinspection PyCallingNonCallable,PyTypeChecker
+        ver_key_c_instances = (c_void_p * len(ver_keys))()
+        for i in range(len(ver_keys)):
+            ver_key_c_instances[i] = ver_keys[i].c_instance
+
+        valid = c_bool()
+        invalid_idx = c_size_t()
+        do_call('indy_crypto_bls_verify_batch',
+                signature_c_instances, c_size_t(len(signatures)),
+                message_c_instances, message_lens, c_size_t(len(messages)),
+                ver_key_c_instances, c_size_t(len(ver_keys)),
+                gen.c_instance,
+                byref(valid),
+                byref(invalid_idx))
+
+        res = None if valid else invalid_idx.value
+
+        logger.debug("Bls::verify_batch: <<< res: %r", res)
+        return res
+
+    @staticmethod
+    def verify_pop(pop: ProofOfPossession, ver_key: VerKey, gen: Generator) -> bool:
+        """
+        Verifies the proof of possession and returns true - if proof valid or false otherwise.
+
+        :param: pop - Proof of possession
+        :param: ver_key - Verification key
+        :param: gen - Generator point
+        :return: true if proof of possession valid
+        """
+
+        logger = logging.getLogger(__name__)
+        logger.debug("Bls::verify_pop: >>> pop: %r, ver_key: %r, gen: %r", pop, ver_key, gen)
+
+        valid = c_bool()
+        do_call('indy_crypto_bls_verify_pop',
+                pop.c_instance,
+                ver_key.c_instance,
+                gen.c_instance,
+                byref(valid))
+
+        res = valid
+        logger.debug("Bls::verify_pop: <<< res: %r", res)
+        return res
@@ -48,6 +48,10 @@ class ErrorCode(IntEnum):
     # Object (group, key, point, and etc...) passed by library caller has invalid structure
     CommonInvalidStructure = 113,
 
+    # Group element (curve point) passed by library caller doesn't belong to the curve,
+    # to the prime-order subgroup or is unexpected infinity
+    CommonInvalidGroupElement = 119,
+
 
 class IndyCryptoError(Exception):
     #error_code: ErrorCode
@@ -1,4 +1,5 @@
-from indy_crypto.bls import Bls, Generator, SignKey, VerKey, Signature, MultiSignature
+from indy_crypto.bls import Bls, Generator, SignKey, VerKey, Signature, MultiSignature, MultiVerKey, \
+    BitmapMultiSignature, ProofOfPossession
 
 import pytest
 
@@ -81,3 +82,30 @@ def multi_sig(signature1: Signature, signature2: Signature) -> MultiSignature:
     assert type(multi_sig) is MultiSignature
     assert multi_sig.c_instance is not None
     return multi_sig
+
+
+@pytest.fixture
+def multi_ver_key(ver_key1: VerKey, ver_key2: VerKey) -> MultiVerKey:
+    multi_ver_key = MultiVerKey.new([ver_key1, ver_key2])
+
+    assert type(multi_ver_key) is MultiVerKey
+    assert multi_ver_key.c_instance is not None
+    return multi_ver_key
+
+
+@pytest.fixture
+def bitmap_multi_sig(signature1: Signature, signature2: Signature) -> BitmapMultiSignature:
+    bitmap_multi_sig = BitmapMultiSignature.new([signature1, signature2], [0, 1], 3)
+
+    assert type(bitmap_multi_sig) is BitmapMultiSignature
+    assert bitmap_multi_sig.c_instance is not None
+    return bitmap_multi_sig
+
+
+@pytest.fixture
+def pop(ver_key1: VerKey, sign_key1: SignKey) -> ProofOfPossession:
+    pop = ProofOfPossession.new(ver_key1, sign_key1)
+
+    assert type(pop) is ProofOfPossession
+    assert pop.c_instance is not None
+    return pop
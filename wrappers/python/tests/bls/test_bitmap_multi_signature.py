@@ -0,0 +1,36 @@
+from indy_crypto.bls import BitmapMultiSignature, Signature
+from indy_crypto.error import IndyCryptoError, ErrorCode
+
+import pytest
+
+
+def test_new(bitmap_multi_sig: BitmapMultiSignature):
+    assert bitmap_multi_sig is not None
+    assert bitmap_multi_sig.as_bitmap() == bytes([0b011])
+
+
+def test_from_bytes(bitmap_multi_sig: BitmapMultiSignature):
+    xbytes = bitmap_multi_sig.as_bytes()
+
+    bitmap_multi_sig2 = BitmapMultiSignature.from_bytes(xbytes)
+    assert type(bitmap_multi_sig2) is BitmapMultiSignature
+
+    xbytes2 = bitmap_multi_sig2.as_bytes()
+    assert xbytes == xbytes2
+
+
+def test_add_merge(bitmap_multi_sig: BitmapMultiSignature, signature1: Signature, signature2: Signature):
+    bitmap_multi_sig2 = BitmapMultiSignature.new([signature1], [0], 3)
+    bitmap_multi_sig2.add(signature2, 1)
+    assert bitmap_multi_sig.as_bytes() == bitmap_multi_sig2.as_bytes()
+
+    bitmap_multi_sig3 = BitmapMultiSignature.new([signature1], [0], 3)
+    bitmap_multi_sig3.merge(BitmapMultiSignature.new([signature2], [1], 3))
+    assert bitmap_multi_sig.as_bytes() == bitmap_multi_sig3.as_bytes()
+
+
+def test_add_for_duplicate_signer(bitmap_multi_sig: BitmapMultiSignature, signature1: Signature):
+    with pytest.raises(IndyCryptoError) as e:
+        bitmap_multi_sig.add(signature1, 0)
+
+    assert ErrorCode.CommonInvalidStructure == e.value.error_code
@@ -1,4 +1,8 @@
-from indy_crypto.bls import Bls, Generator, SignKey, VerKey, Signature, MultiSignature
+from indy_crypto.bls import Bls, Generator, SignKey, VerKey, Signature, MultiSignature, MultiVerKey, \
+    BitmapMultiSignature, AggregateSignature, ProofOfPossession
+from indy_crypto.error import IndyCryptoError, ErrorCode
+
+import pytest
 
 
 def test_sign(signature1: Signature):
@@ -38,3 +42,65 @@ def test_verify_multi_sig_works_for_invalid_signature(generator, message):
 
     valid = Bls.verify_multi_sig(multi_signature_invalid, message, [ver_key1, ver_key2], generator)
     assert not valid
+
+
+def test_verify_multi_sig_for_multi_ver_key(generator: Generator, message: bytes, multi_sig: MultiSignature,
+                                            multi_ver_key: MultiVerKey):
+    valid = Bls.verify_multi_sig_for_multi_ver_key(multi_sig, message, multi_ver_key, generator)
+    assert valid
+
+
+def test_verify_with_quorum(generator: Generator, message: bytes, bitmap_multi_sig: BitmapMultiSignature,
+                            ver_key1: VerKey, ver_key2: VerKey):
+    ver_keys = [ver_key1, ver_key2, VerKey.new(generator, SignKey.new(None))]
+
+    valid = Bls.verify_with_quorum(bitmap_multi_sig, message, ver_keys, 2, generator)
+    assert valid
+
+    valid = Bls.verify_with_quorum(bitmap_multi_sig, message, ver_keys, 3, generator)
+    assert not valid
+
+
+def test_verify_pop(generator: Generator, ver_key1: VerKey, pop: ProofOfPossession):
+    valid = Bls.verify_pop(pop, ver_key1, generator)
+    assert valid
+
+
+def test_verify_pop_works_for_invalid_ver_key(generator: Generator, ver_key2: VerKey, pop: ProofOfPossession):
+    valid = Bls.verify_pop(pop, ver_key2, generator)
+    assert not valid
+
+
+def test_verify_batch(generator: Generator, message: bytes, ver_key1: VerKey, ver_key2: VerKey,
+                      signature1: Signature, signature2: Signature):
+    invalid_idx = Bls.verify_batch([signature1, signature2], [message, message], [ver_key1, ver_key2], generator)
+    assert invalid_idx is None
+
+
+def test_verify_batch_works_for_invalid_signature(generator: Generator, message: bytes, ver_key1: VerKey,
+                                                  signature1: Signature, signature2: Signature):
+    invalid_idx = Bls.verify_batch([signature1, signature2], [message, message], [ver_key1, ver_key1], generator)
+    assert invalid_idx == 1
+
+
+def test_verify_aggregate(generator: Generator, ver_key1: VerKey, ver_key2: VerKey,
+                          sign_key1: SignKey, sign_key2: SignKey):
+    message1 = bytes([1, 2, 3, 4, 5])
+    message2 = bytes([6, 7, 8, 9, 10])
+
+    signature1 = Bls.sign(message1, sign_key1)
+    signature2 = Bls.sign(message2, sign_key2)
+    aggregate_sig = AggregateSignature.new([signature1, signature2])
+
+    valid = Bls.verify_aggregate(aggregate_sig, [message1, message2], [ver_key1, ver_key2], generator)
+    assert valid
+
+
+def test_verify_aggregate_works_for_duplicate_messages(generator: Generator, message: bytes,
+                                                       ver_key1: VerKey, ver_key2: VerKey,
+                                                       signature1: Signature, signature2: Signature):
+    aggregate_sig = AggregateSignature.new([signature1, signature2])
+
+    with pytest.raises(IndyCryptoError) as e:
+        Bls.verify_aggregate(aggregate_sig, [message, message], [ver_key1, ver_key2], generator)
+    assert ErrorCode.CommonInvalidStructure == e.value.error_code
@@ -0,0 +1,24 @@
+from indy_crypto.bls import MultiVerKey, VerKey
+
+
+def test_new(multi_ver_key: MultiVerKey):
+    assert multi_ver_key is not None
+
+
+def test_from_bytes(multi_ver_key: MultiVerKey):
+    xbytes = multi_ver_key.as_bytes()
+
+    multi_ver_key2 = MultiVerKey.from_bytes(xbytes)
+    assert type(multi_ver_key2) is MultiVerKey
+
+    xbytes2 = multi_ver_key2.as_bytes()
+    assert xbytes == xbytes2
+
+
+def test_add_remove(multi_ver_key: MultiVerKey, ver_key1: VerKey, ver_key2: VerKey):
+    multi_ver_key2 = MultiVerKey.new([ver_key1])
+    multi_ver_key2.add(ver_key2)
+    assert multi_ver_key.as_bytes() == multi_ver_key2.as_bytes()
+
+    multi_ver_key2.remove(ver_key2)
+    assert MultiVerKey.new([ver_key1]).as_bytes() == multi_ver_key2.as_bytes()
@@ -22,3 +22,13 @@ def test_from_bytes(sign_key1: SignKey):
 
     xbytes2 = sign_key12.as_bytes()
     assert xbytes == xbytes2
+
+
+def test_derive():
+    seed = bytes(range(32))
+
+    sign_key = SignKey.derive_path(seed, 'm/12381/3600/0/0')
+    assert sign_key.as_bytes() == bytes.fromhex('2150a722b5739048d0e887c4d0934d05a7f2f4e2caddd4b33eb18a4c7b03f56b')
+
+    sign_key2 = SignKey.derive_master(seed).derive_child(12381).derive_child(3600).derive_child(0).derive_child(0)
+    assert sign_key.as_bytes() == sign_key2.as_bytes()
@@ -22,3 +22,12 @@ def test_from_bytes(signature1: Signature):
 
     xbytes2 = signature12.as_bytes()
     assert xbytes == xbytes2
+
+
+def test_from_compressed_bytes(signature1: Signature):
+    xbytes = signature1.as_compressed_bytes()
+    assert len(xbytes) < len(signature1.as_bytes())
+
+    signature12 = Signature.from_compressed_bytes(xbytes)
+    assert type(signature12) is Signature
+    assert signature1.as_bytes() == signature12.as_bytes()
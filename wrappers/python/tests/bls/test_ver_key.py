@@ -1,4 +1,7 @@
 from indy_crypto.bls import VerKey
+from indy_crypto.error import IndyCryptoError, ErrorCode
+
+import pytest
 
 
 def test_new(ver_key1: VerKey):
@@ -22,3 +25,21 @@ def test_from_bytes(ver_key1: VerKey):
 
     xbytes2 = ver_key12.as_bytes()
     assert xbytes == xbytes2
+
+
+def test_from_compressed_bytes(ver_key1: VerKey):
+    xbytes = ver_key1.as_compressed_bytes()
+    assert len(xbytes) < len(ver_key1.as_bytes())
+
+    ver_key12 = VerKey.from_compressed_bytes(xbytes)
+    assert type(ver_key12) is VerKey
+    assert ver_key1.as_bytes() == ver_key12.as_bytes()
+
+
+def test_from_bytes_for_invalid_point(ver_key1: VerKey):
+    xbytes = bytearray(ver_key1.as_bytes())
+    xbytes[-1] ^= 0x01
+
+    with pytest.raises(IndyCryptoError) as e:
+        VerKey.from_bytes(bytes(xbytes))
+    assert ErrorCode.CommonInvalidGroupElement == e.value.error_code